@@ -1,6 +1,38 @@
-use crate::models::{Application, Platform, Status};
-use crate::storage;
-use anyhow::Result;
+use crate::attention::{self, Reason};
+use crate::clipboard::{Clipboard, Copied};
+use crate::columns::{self, Column, ListColumn};
+use crate::config::{self, Config, FollowUpMode};
+use crate::dates;
+use crate::diff;
+use crate::digest;
+use crate::export;
+use crate::fuzzy;
+use crate::goal::GoalProgress;
+use crate::hooks::{HookEvent, Hooks};
+use crate::ical;
+use crate::keymap::{self, Action, KeyMap};
+use crate::merge::{self, Conflict};
+use crate::open;
+use crate::paths;
+use crate::report;
+use crate::snippet::{self, Snippet};
+use crate::stats::{self, Activity, ChartData, CompanySummary, DateRange, ListFilter, ListSummary, StatsCache};
+use crate::models::{
+    self, ActivityEntry, Application, Interview, InterviewKind, Platform, RejectionReason, SalaryRange, Status,
+    StatusChange, TrashedApplication, WorkMode, MAX_INTEREST, TRASH_DAYS,
+};
+use crate::storage::{self, CorruptFile, Data, JsonStorage, Storage};
+use crate::template::{self, Template};
+use crate::text::{self, TextBuffer};
+use crate::ui::date_picker::DatePicker;
+use crate::usage::Usage;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use ratatui::layout::Rect;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+use uuid::Uuid;
 
 /// Current view/screen in the application
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -8,24 +40,57 @@ pub enum View {
     List,
     Form,
     Chart,
+    Narrate,
+    Board,
+    Dashboard,
+}
+
+impl View {
+    /// Name used in usage stats
+    pub fn name(&self) -> &str {
+        match self {
+            View::List => "list",
+            View::Form => "form",
+            View::Chart => "chart",
+            View::Narrate => "read",
+            View::Board => "board",
+            View::Dashboard => "dashboard",
+        }
+    }
 }
 
 /// Form mode: adding new or editing existing
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FormMode {
     Add,
-    Edit(usize),
+    Edit(Uuid),
 }
 
 /// Form field being edited
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FormField {
     CompanyName,
+    JobTitle,
+    Url,
+    ExternalId,
     Platform,
+    Location,
+    WorkMode,
     ResumeModified,
     ResumeVersion,
+    ResumeFile,
+    CoverLetter,
+    CoverLetterVersion,
     Status,
+    Interest,
+    Salary,
     Date,
+    FollowUp,
+    Deadline,
+    ContactName,
+    ContactEmail,
+    Referral,
+    Interviews,
     Notes,
 }
 
@@ -33,11 +98,27 @@ impl FormField {
     pub fn all() -> &'static [FormField] {
         &[
             FormField::CompanyName,
+            FormField::JobTitle,
+            FormField::Url,
+            FormField::ExternalId,
             FormField::Platform,
+            FormField::Location,
+            FormField::WorkMode,
             FormField::ResumeModified,
             FormField::ResumeVersion,
+            FormField::ResumeFile,
+            FormField::CoverLetter,
+            FormField::CoverLetterVersion,
             FormField::Status,
+            FormField::Interest,
+            FormField::Salary,
             FormField::Date,
+            FormField::FollowUp,
+            FormField::Deadline,
+            FormField::ContactName,
+            FormField::ContactEmail,
+            FormField::Referral,
+            FormField::Interviews,
             FormField::Notes,
         ]
     }
@@ -61,22 +142,77 @@ impl FormField {
     pub fn label(&self) -> &str {
         match self {
             FormField::CompanyName => "Company Name",
+            FormField::JobTitle => "Job Title",
+            FormField::Url => "Posting URL",
+            FormField::ExternalId => "Job / Requisition ID",
             FormField::Platform => "Platform",
+            FormField::Location => "Location",
+            FormField::WorkMode => "Work Mode",
             FormField::ResumeModified => "Resume Modified",
             FormField::ResumeVersion => "Resume Version",
+            FormField::ResumeFile => "Resume File",
+            FormField::CoverLetter => "Cover Letter",
+            FormField::CoverLetterVersion => "Cover Letter Version",
             FormField::Status => "Status",
+            FormField::Interest => "Interest",
+            FormField::Salary => "Salary",
             FormField::Date => "Application Date",
+            FormField::FollowUp => "Follow-up",
+            FormField::Deadline => "Deadline",
+            FormField::ContactName => "Contact Name",
+            FormField::ContactEmail => "Contact Email",
+            FormField::Referral => "Referral",
+            FormField::Interviews => "Interviews",
             FormField::Notes => "Notes",
         }
     }
 }
 
+/// Order of the list when there's no search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// The order records were added in, which is how they're stored
+    Added,
+    /// Highest interest first, unrated last
+    Interest,
+    /// Closest upcoming deadline first, then passed ones, then none
+    Deadline,
+}
+
+impl SortKey {
+    pub fn next(&self) -> Self {
+        match self {
+            SortKey::Added => SortKey::Interest,
+            SortKey::Interest => SortKey::Deadline,
+            SortKey::Deadline => SortKey::Added,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            SortKey::Added => "date added",
+            SortKey::Interest => "interest",
+            SortKey::Deadline => "deadline",
+        }
+    }
+}
+
 /// Chart type for statistics view
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum ChartType {
     ByResumeVersion,
     ByPlatform,
+    ByWorkMode,
+    ByPlatformSuccess,
+    ByResumeSuccess,
+    ByReferral,
+    ByCoverLetter,
     ByStatus,
+    RejectionReasons,
+    StatusByPlatform,
+    StatusByInterest,
+    ResponseTimes,
 }
 
 impl ChartType {
@@ -84,7 +220,16 @@ impl ChartType {
         &[
             ChartType::ByResumeVersion,
             ChartType::ByPlatform,
+            ChartType::ByWorkMode,
+            ChartType::ByPlatformSuccess,
+            ChartType::ByResumeSuccess,
+            ChartType::ByReferral,
+            ChartType::ByCoverLetter,
             ChartType::ByStatus,
+            ChartType::RejectionReasons,
+            ChartType::StatusByPlatform,
+            ChartType::StatusByInterest,
+            ChartType::ResponseTimes,
         ]
     }
 
@@ -94,187 +239,4048 @@ impl ChartType {
         charts[(current_idx + 1) % charts.len()]
     }
 
+    pub fn prev(&self) -> Self {
+        let charts = Self::all();
+        let current_idx = charts.iter().position(|c| c == self).unwrap();
+        charts[(current_idx + charts.len() - 1) % charts.len()]
+    }
+
+    /// Position in `all()`, counting from 1
+    pub fn number(&self) -> usize {
+        Self::all().iter().position(|c| c == self).unwrap() + 1
+    }
+
     pub fn title(&self) -> &str {
         match self {
             ChartType::ByResumeVersion => "Applications by Resume Version",
             ChartType::ByPlatform => "Applications by Platform",
+            ChartType::ByWorkMode => "Applications by Work Mode",
+            ChartType::ByPlatformSuccess => "Interview Rate by Platform",
+            ChartType::ByResumeSuccess => "Interview Rate by Resume Version",
+            ChartType::ByReferral => "Interview Rate: Referral vs Cold",
+            ChartType::ByCoverLetter => "Interview Rate: Cover Letter vs None",
             ChartType::ByStatus => "Applications by Status",
+            ChartType::RejectionReasons => "Rejections by Reason",
+            ChartType::StatusByPlatform => "Status by Platform",
+            ChartType::StatusByInterest => "Status by Interest",
+            ChartType::ResponseTimes => "Time in Stage",
+        }
+    }
+
+    /// Bar color of a single-series chart, for `Theme::series_style`
+    pub fn series(&self) -> usize {
+        if *self == ChartType::ByPlatform {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Whether the chart counts applications, so it can show percentages instead
+    pub fn has_percentages(&self) -> bool {
+        !matches!(
+            self,
+            ChartType::ByPlatformSuccess
+                | ChartType::ByResumeSuccess
+                | ChartType::ByReferral
+                | ChartType::ByCoverLetter
+                | ChartType::ResponseTimes
+        )
+    }
+
+    /// Short title in lowercase words joined by dashes, e.g. `status-by-platform`, as used in
+    /// exported file names and `--chart`
+    pub fn slug(&self) -> String {
+        let words: Vec<String> = self.short_title().split_whitespace().map(|word| word.to_lowercase()).collect();
+        words.join("-")
+    }
+
+    /// Name shown for the neighbouring charts in the title bar
+    pub fn short_title(&self) -> &str {
+        match self {
+            ChartType::ByResumeVersion => "Resume Version",
+            ChartType::ByPlatform => "Platform",
+            ChartType::ByWorkMode => "Work Mode",
+            ChartType::ByPlatformSuccess => "Platform Interview Rate",
+            ChartType::ByResumeSuccess => "Resume Interview Rate",
+            ChartType::ByReferral => "Referral vs Cold",
+            ChartType::ByCoverLetter => "Cover Letter",
+            ChartType::ByStatus => "Status",
+            ChartType::RejectionReasons => "Rejection Reasons",
+            ChartType::StatusByPlatform => "Status by Platform",
+            ChartType::StatusByInterest => "Status by Interest",
+            ChartType::ResponseTimes => "Time in Stage",
+        }
+    }
+}
+
+/// Field of the interview round being added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterviewField {
+    Kind,
+    Date,
+    Notes,
+}
+
+impl InterviewField {
+    pub fn next(&self) -> Self {
+        match self {
+            InterviewField::Kind => InterviewField::Date,
+            InterviewField::Date => InterviewField::Notes,
+            InterviewField::Notes => InterviewField::Kind,
+        }
+    }
+
+    pub fn prev(&self) -> Self {
+        match self {
+            InterviewField::Kind => InterviewField::Notes,
+            InterviewField::Date => InterviewField::Kind,
+            InterviewField::Notes => InterviewField::Date,
+        }
+    }
+}
+
+/// Interview round being typed in the interview editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterviewDraft {
+    pub kind: InterviewKind,
+    pub date: String,
+    pub notes: String,
+    pub field: InterviewField,
+    pub error: Option<String>,
+}
+
+/// Popup listing the form's interview rounds, with an optional round being added
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InterviewEditor {
+    pub selected: usize,
+    pub draft: Option<InterviewDraft>,
+}
+
+/// Date field the calendar popup fills in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTarget {
+    Applied,
+    FollowUp,
+    Deadline,
+    /// Date of the interview round being added
+    Interview,
+}
+
+impl DateTarget {
+    pub fn label(&self) -> &str {
+        match self {
+            DateTarget::Applied => "Application Date",
+            DateTarget::FollowUp => "Follow-up",
+            DateTarget::Deadline => "Deadline",
+            DateTarget::Interview => "Interview Date",
+        }
+    }
+}
+
+/// Field of the selected application the copy picker puts on the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyField {
+    CompanyName,
+    Url,
+    ContactEmail,
+    Notes,
+}
+
+impl CopyField {
+    pub fn all() -> &'static [CopyField] {
+        &[CopyField::CompanyName, CopyField::Url, CopyField::ContactEmail, CopyField::Notes]
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            CopyField::CompanyName => "Company name",
+            CopyField::Url => "Posting URL",
+            CopyField::ContactEmail => "Contact email",
+            CopyField::Notes => "Notes",
+        }
+    }
+
+    /// The field's text, or None when it's empty
+    pub fn value<'a>(&self, application: &'a Application) -> Option<&'a str> {
+        let value = match self {
+            CopyField::CompanyName => application.company_name.as_str(),
+            CopyField::Url => application.url.as_deref()?,
+            CopyField::ContactEmail => application.contact_email.as_deref()?,
+            CopyField::Notes => application.notes.as_str(),
+        };
+        Some(value.trim()).filter(|value| !value.is_empty())
+    }
+}
+
+/// Field of the activity entry being typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityField {
+    Date,
+    Text,
+}
+
+impl ActivityField {
+    /// The other field; there are only two
+    pub fn next(&self) -> Self {
+        match self {
+            ActivityField::Date => ActivityField::Text,
+            ActivityField::Text => ActivityField::Date,
+        }
+    }
+}
+
+/// Activity entry being added, or changed when `editing` holds its position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityDraft {
+    pub date: String,
+    pub text: String,
+    pub field: ActivityField,
+    pub editing: Option<usize>,
+    pub error: Option<String>,
+}
+
+/// Popup asking why an application was rejected, opened when a status change sets Rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectionPicker {
+    /// Records that were rejected, or None for the application in the form
+    pub ids: Option<Vec<Uuid>>,
+    /// Highlighted reason, indexing `RejectionReason::all`
+    pub selected: usize,
+}
+
+/// Parts of the dashboard that Tab moves between
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DashboardSection {
+    #[default]
+    Pipeline,
+    Interviews,
+    FollowUps,
+    Recent,
+}
+
+impl DashboardSection {
+    pub fn all() -> &'static [DashboardSection] {
+        &[
+            DashboardSection::Pipeline,
+            DashboardSection::Interviews,
+            DashboardSection::FollowUps,
+            DashboardSection::Recent,
+        ]
+    }
+
+    pub fn title(&self) -> &str {
+        match self {
+            DashboardSection::Pipeline => "Pipeline",
+            DashboardSection::Interviews => "Upcoming Interviews",
+            DashboardSection::FollowUps => "Follow-ups Due",
+            DashboardSection::Recent => "Recent Applications",
+        }
+    }
+
+    /// The section `step` places further along, wrapping around
+    fn step(&self, step: isize) -> Self {
+        let all = Self::all();
+        let position = all.iter().position(|section| section == self).unwrap_or(0) as isize;
+        all[(position + step).rem_euclid(all.len() as isize) as usize]
+    }
+}
+
+/// Focused dashboard section and its selected row
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dashboard {
+    pub section: DashboardSection,
+    pub row: usize,
+}
+
+/// One line of a dashboard section: an application, the date it's listed for and what
+/// happens then
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DashboardEntry {
+    pub index: usize,
+    pub date: NaiveDate,
+    pub detail: String,
+}
+
+/// Everything the dashboard shows; entries index into `applications`, so it's only good
+/// for the generation it was counted in
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashboardData {
+    pub goal: Option<GoalProgress>,
+    /// Live applications per status, in status order
+    pub pipeline: Vec<(Status, u64)>,
+    /// Interview rounds from today through the next week, soonest first
+    pub interviews: Vec<DashboardEntry>,
+    /// Follow-ups due today or earlier, oldest first
+    pub follow_ups: Vec<DashboardEntry>,
+    /// The most recently applied, newest first
+    pub recent: Vec<DashboardEntry>,
+}
+
+impl DashboardData {
+    /// Number of selectable rows in `section`
+    pub fn len(&self, section: DashboardSection) -> usize {
+        match section {
+            DashboardSection::Pipeline => self.pipeline.len(),
+            DashboardSection::Interviews => self.interviews.len(),
+            DashboardSection::FollowUps => self.follow_ups.len(),
+            DashboardSection::Recent => self.recent.len(),
+        }
+    }
+
+    /// Entries of a section listing applications; None for the pipeline
+    pub fn entries(&self, section: DashboardSection) -> Option<&[DashboardEntry]> {
+        match section {
+            DashboardSection::Pipeline => None,
+            DashboardSection::Interviews => Some(&self.interviews),
+            DashboardSection::FollowUps => Some(&self.follow_ups),
+            DashboardSection::Recent => Some(&self.recent),
+        }
+    }
+}
+
+/// Applications listed under Recent Applications on the dashboard
+const DASHBOARD_RECENT: usize = 5;
+
+/// Position in the pipeline board. Rows and scroll offsets are kept per column, indexed like
+/// `status_order`, so each column scrolls on its own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Board {
+    pub column: usize,
+    pub rows: Vec<usize>,
+    pub offsets: Vec<usize>,
+}
+
+impl Board {
+    /// Selected row in `column`
+    pub fn row(&self, column: usize) -> usize {
+        self.rows.get(column).copied().unwrap_or(0)
+    }
+
+    pub fn offset(&self, column: usize) -> usize {
+        self.offsets.get(column).copied().unwrap_or(0)
+    }
+
+    pub fn set_row(&mut self, column: usize, row: usize) {
+        set_at(&mut self.rows, column, row);
+    }
+
+    pub fn set_offset(&mut self, column: usize, offset: usize) {
+        set_at(&mut self.offsets, column, offset);
+    }
+}
+
+/// Set `values[index]`, growing `values` with zeros as needed
+fn set_at(values: &mut Vec<usize>, index: usize, value: usize) {
+    if values.len() <= index {
+        values.resize(index + 1, 0);
+    }
+    values[index] = value;
+}
+
+/// Popup summarizing every application to one company, scrolled by `scroll` lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyPopup {
+    pub summary: CompanySummary,
+    pub scroll: u16,
+}
+
+/// Popup listing an application's activity log, with an optional entry being typed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityLog {
+    pub id: Uuid,
+    pub selected: usize,
+    pub draft: Option<ActivityDraft>,
+}
+
+/// Days within which a second application to the same company asks for confirmation
+const DUPLICATE_WINDOW_DAYS: i64 = 60;
+
+/// Action applied when a yes/no prompt is confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptAction {
+    SetFormFollowUp(NaiveDate),
+    SetFollowUp(Uuid, NaiveDate),
+    AddDuplicate,
+    /// Save the form despite the warnings in the message
+    SaveDespiteWarnings,
+    DiscardForm,
+    DiscardFormAndQuit,
+    DeleteMarked,
+    PurgeTrashed(Uuid),
+    MarkGhosted,
+    QuitWithoutSaving,
+    /// Keep the in-memory version of a record also changed in the data file; no takes the file's
+    KeepMine(Uuid),
+}
+
+/// Yes/no prompt shown over the current view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prompt {
+    pub message: String,
+    pub action: PromptAction,
+}
+
+/// Minimum time between automatic saves of pending changes
+const SAVE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Longest gap between two clicks on the same row that counts as a double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+
+/// Longest pause between typed characters that still extends a jump prefix
+const JUMP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// How long the today summary stays up without a key press
+const TODAY_SUMMARY_SECS: u64 = 4;
+
+/// Calendar file written by the iCal export key, next to the data file
+const ICAL_FILE: &str = "jobtracker.ics";
+
+/// How long a status bar message stays up
+const MESSAGE_SECS: u64 = 4;
+
+/// Kind of status bar message, which decides its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageKind {
+    Info,
+    Error,
+}
+
+/// Company-name prefix being typed to jump through the list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Jump {
+    pub prefix: String,
+    /// Started with the jump key, so every character extends the prefix, bound or not
+    pub explicit: bool,
+    pub typed_at: Instant,
+}
+
+/// Row of the list table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListRow {
+    /// Index into `applications`
+    Application(usize),
+    /// Company header in grouped mode
+    Group(CompanyGroup),
+    /// Index into `applications` of a record listed under its company header
+    Member(usize),
+}
+
+impl ListRow {
+    /// Index into `applications` of the record on this row; None for a header
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ListRow::Application(index) | ListRow::Member(index) => Some(*index),
+            ListRow::Group(_) => None,
         }
     }
 }
 
+/// Company with more than one listed application, as shown on its header row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyGroup {
+    /// Trimmed, lowercased company name shared by the group's applications
+    pub key: String,
+    /// Company name as written on the newest application
+    pub company: String,
+    pub count: usize,
+    /// Furthest status any of the applications reached
+    pub best: Status,
+    pub collapsed: bool,
+}
+
+/// Template picker: the highlighted template and, while renaming it, the name being typed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplatePicker {
+    pub selected: usize,
+    pub renaming: Option<String>,
+}
+
+/// Notes search overlay: the query and the highlighted result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotesSearch {
+    pub query: String,
+    /// Keys go to the query; otherwise they move through the results
+    pub typing: bool,
+    pub selected: usize,
+}
+
+/// Command palette overlay: the typed query and the highlighted command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,
+}
+
+/// Counts shown in the "applied today" overlay
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodaySummary {
+    pub today: usize,
+    pub this_week: usize,
+    pub goal: Option<GoalProgress>,
+    pub last_company: Option<String>,
+    pub shown_at: Instant,
+}
+
 /// Main application state
 pub struct App {
     pub applications: Vec<Application>,
     pub view: View,
     pub list_selected: usize,
+    pub list_offset: usize,
+    pub list_page_size: usize,
+    /// Where the table was last drawn, for mapping mouse clicks to rows
+    pub list_area: Rect,
+    /// Time and row of the last click, to detect double-clicks
+    pub last_click: Option<(Instant, usize)>,
+    /// Only list applications with this status
+    pub status_filter: Option<Status>,
+    /// Only list applications with this work mode
+    pub work_mode_filter: Option<WorkMode>,
+    /// Only list applications that need attention
+    pub attention_filter: bool,
+    /// Applications that need attention and why, as of `stats_day`
+    pub attention: Vec<(Uuid, Reason)>,
+    pub sort: SortKey,
+    /// Fuzzy company search; matching applications are listed best match first
+    pub search_query: String,
+    /// Keys go to the search query instead of the list
+    pub search_typing: bool,
+    /// Jump prefix still being typed; dropped after `JUMP_TIMEOUT` without a key
+    pub jump: Option<Jump>,
+    /// Prefix of the last jump, repeated by the jump-next and jump-previous keys
+    pub last_jump: String,
+    /// List archived applications too, dimmed
+    pub show_archived: bool,
+    /// Group applications to the same company under a header row
+    pub grouped: bool,
+    /// Keys of the company groups whose applications are hidden under their header
+    pub collapsed_groups: HashSet<String>,
+    /// IDs of applications marked for a batch delete or status change
+    pub marked: Vec<Uuid>,
+    /// Record where a visual range selection started; the range runs to the selected row
+    pub visual_anchor: Option<Uuid>,
     pub form_mode: Option<FormMode>,
     pub form_field: FormField,
+    /// First form field drawn when the fields don't all fit on screen
+    pub form_scroll: usize,
     pub form_data: Application,
+    /// Form contents when it was opened, to detect unsaved changes
+    pub form_original: Application,
     pub platform_dropdown_selected: usize,
     pub status_dropdown_selected: usize,
     pub resume_modified_dropdown_selected: usize,
+    pub referral_dropdown_selected: usize,
+    pub cover_letter_dropdown_selected: usize,
+    pub work_mode_dropdown_selected: usize,
+    /// Equal to the rating, with 0 for "Not rated"
+    pub interest_dropdown_selected: usize,
+    pub salary_input: String,
+    /// Resume file path as typed, `~` still unexpanded
+    pub resume_path_input: String,
+    /// Applied date as typed, resolved when leaving the field or saving
+    pub date_input: String,
+    pub notes_cursor: usize,
+    /// Problems found when the form last failed to save, in field order
+    pub form_errors: Vec<(FormField, String)>,
     pub chart_type: ChartType,
+    /// Applied dates the charts count
+    pub chart_range: DateRange,
+    pub chart_legend: bool,
+    /// Bar charts show each bar's percentage of the total instead of its count
+    pub chart_percent: bool,
+    pub board: Board,
+    pub dashboard: Dashboard,
+    /// Deleted applications, oldest deletion first; saved with the applications
+    pub trash: Vec<TrashedApplication>,
+    /// Position in the trash view, which lists the most recent deletion first
+    pub trash_picker: Option<usize>,
+    /// Field highlighted in the copy picker
+    pub copy_picker: Option<usize>,
+    pub clipboard: Clipboard,
+    pub prompt: Option<Prompt>,
+    pub interview_editor: Option<InterviewEditor>,
+    /// Calendar popup over the form or the interview editor
+    pub date_picker: Option<(DateTarget, DatePicker)>,
+    pub activity_log: Option<ActivityLog>,
+    pub status_picker: Option<usize>,
+    pub rejection_picker: Option<RejectionPicker>,
+    /// Columns shown in the list table, in order
+    pub list_columns: Vec<ListColumn>,
+    /// Selected row of the column picker, indexing `Column::all`
+    pub column_picker: Option<usize>,
+    pub notes_search: Option<NotesSearch>,
+    pub palette: Option<Palette>,
+    /// Saved templates, sorted by name
+    pub templates: Vec<Template>,
+    pub template_picker: Option<TemplatePicker>,
+    /// Name being typed to save the form as a template
+    pub template_name: Option<String>,
+    pub today_summary: Option<TodaySummary>,
+    pub company_popup: Option<CompanyPopup>,
+    /// Key binding overlay for the current view
+    pub help_open: bool,
+    /// Status bar message and when it was shown
+    pub message: Option<(String, MessageKind, Instant)>,
+    pub storage: Box<dyn Storage>,
+    /// Data file that couldn't be parsed; nothing is saved until the user picks how to start over
+    pub corrupt: Option<CorruptFile>,
+    /// Applications changed since the last save
+    pub dirty: bool,
+    /// Bumped on every change to the applications and when the day changes, so cached
+    /// stats know to recount
+    pub generation: u64,
+    /// Day the attention list and cached stats were counted for
+    pub stats_day: NaiveDate,
+    pub stats: StatsCache,
+    /// Data as last read from or written to storage, to tell changes made here from ones made
+    /// to the file by something else
+    pub saved: Data,
+    /// Modification time of the data as of `saved`
+    pub disk_modified: Option<SystemTime>,
+    /// Records changed both here and in the file, asked about one at a time
+    pub conflicts: Vec<Conflict>,
+    /// PID of another instance holding the data file lock; nothing can be changed or saved while set
+    pub read_only: Option<u32>,
+    pub last_save: Instant,
+    pub config: Config,
+    pub keymap: KeyMap,
+    pub usage: Usage,
+    pub hooks: Hooks,
     pub should_quit: bool,
 }
 
 impl App {
-    pub fn new() -> Result<Self> {
-        let applications = storage::load_applications()?;
+    /// Open the data file at `path` with the user's config: JSON or SQLite by its extension,
+    /// laid out as the config says
+    pub fn with_storage(path: PathBuf) -> Result<Self> {
+        let config = Config::load()?;
+        let storage = storage::open(&path, config.storage.layout)?;
+        Self::from_storage(config, storage)
+    }
+
+    /// Load the data and the templates kept next to it, and start usage tracking if enabled.
+    /// An unreadable data file opens on the recovery screen instead of failing.
+    pub fn from_storage(config: Config, storage: Box<dyn Storage>) -> Result<Self> {
+        // An unreadable file opens the recovery screen instead of ending the app
+        let (data, corrupt) = match storage.load() {
+            Ok(data) => (data, None),
+            Err(err) => match err.downcast::<CorruptFile>() {
+                Ok(corrupt) => (Data::default(), Some(corrupt)),
+                Err(err) => return Err(err),
+            },
+        };
+        let templates = template::load(&template::templates_path(storage.path()))?;
+        let mut app = Self::with_data(config, storage, data, templates)?;
+        app.usage = Usage::start(app.config.usage.enabled)?;
+        app.corrupt = corrupt;
+        Ok(app)
+    }
+
+    /// App over a config and data that are already loaded, without reading anything from disk.
+    /// `storage` is only written to when the app saves.
+    pub fn with_data(config: Config, storage: Box<dyn Storage>, data: Data, templates: Vec<Template>) -> Result<Self> {
+        let keymap = config.keymap()?;
+        let list_columns = columns::layout(&config.list.columns)?;
+        let usage = Usage::start(false)?;
+        let hooks = Hooks::new(config.hooks.clone());
+        let mut applications = data.applications;
+        let mut trash = data.trash;
+        // Saved on the first autosave so the IDs stay stable from then on
+        let mut dirty = models::assign_missing_ids(&mut applications);
+        let purged = models::purge_expired(&mut trash, chrono::Utc::now());
+        dirty |= purged > 0;
+        let mut message = (purged > 0).then(|| {
+            let text = format!("Purged {} application(s) in the trash for over {} days", purged, TRASH_DAYS);
+            (text, MessageKind::Info, Instant::now())
+        });
+        let today = chrono::Local::now().date_naive();
+        let missed: Vec<&str> = applications
+            .iter()
+            .filter(|application| !application.archived && application.missed_deadline(today))
+            .map(|application| application.company_name.as_str())
+            .collect();
+        if !missed.is_empty() {
+            let text = format!("Deadline passed for {} planned application(s): {}", missed.len(), missed.join(", "));
+            message = Some((text, MessageKind::Error, Instant::now()));
+        }
+        let attention = attention::needs_attention(&applications, &config.attention, today);
+        if message.is_none() && !attention.is_empty() {
+            let text = format!("{} application(s) need attention; ! lists them", attention.len());
+            message = Some((text, MessageKind::Info, Instant::now()));
+        }
+        if !data.unreadable.is_empty() {
+            let text = format!("Skipped unreadable file(s): {}", data.unreadable.join("; "));
+            message = Some((text, MessageKind::Error, Instant::now()));
+        }
+        // IDs assigned and entries purged above count as already saved; they go out with the first save
+        let saved = Data {
+            applications: applications.clone(),
+            trash: trash.clone(),
+            unreadable: Vec::new(),
+        };
         Ok(Self {
             applications,
-            view: View::List,
+            view: if config.dashboard.on_startup { View::Dashboard } else { View::List },
             list_selected: 0,
+            list_offset: 0,
+            list_page_size: 1,
+            list_area: Rect::default(),
+            last_click: None,
+            status_filter: None,
+            work_mode_filter: None,
+            attention_filter: false,
+            attention,
+            sort: SortKey::Added,
+            search_query: String::new(),
+            search_typing: false,
+            jump: None,
+            last_jump: String::new(),
+            show_archived: false,
+            grouped: false,
+            collapsed_groups: HashSet::new(),
+            marked: Vec::new(),
+            visual_anchor: None,
             form_mode: None,
             form_field: FormField::CompanyName,
+            form_scroll: 0,
             form_data: Application::new(),
+            form_original: Application::new(),
             platform_dropdown_selected: 0,
             status_dropdown_selected: 0,
             resume_modified_dropdown_selected: 0,
+            referral_dropdown_selected: 1,
+            cover_letter_dropdown_selected: 1,
+            work_mode_dropdown_selected: work_mode_index(WorkMode::default()),
+            interest_dropdown_selected: 0,
+            salary_input: String::new(),
+            resume_path_input: String::new(),
+            date_input: String::new(),
+            notes_cursor: 0,
+            form_errors: Vec::new(),
             chart_type: ChartType::ByResumeVersion,
+            chart_range: DateRange::default(),
+            chart_legend: false,
+            chart_percent: false,
+            board: Board::default(),
+            dashboard: Dashboard::default(),
+            trash,
+            trash_picker: None,
+            copy_picker: None,
+            clipboard: Clipboard::default(),
+            prompt: None,
+            interview_editor: None,
+            date_picker: None,
+            activity_log: None,
+            status_picker: None,
+            rejection_picker: None,
+            list_columns,
+            column_picker: None,
+            notes_search: None,
+            palette: None,
+            templates,
+            template_picker: None,
+            template_name: None,
+            today_summary: None,
+            company_popup: None,
+            help_open: false,
+            message,
+            saved,
+            disk_modified: storage.modified(),
+            conflicts: Vec::new(),
+            storage,
+            corrupt: None,
+            dirty,
+            generation: 0,
+            stats_day: today,
+            stats: StatsCache::default(),
+            read_only: None,
+            last_save: Instant::now(),
+            config,
+            keymap,
+            usage,
+            hooks,
             should_quit: false,
         })
     }
 
-    /// Save applications to disk
-    pub fn save(&self) -> Result<()> {
-        storage::save_applications(&self.applications)
-    }
-
-    /// Start adding a new application
-    pub fn start_add(&mut self) {
-        self.form_mode = Some(FormMode::Add);
-        self.view = View::Form;
-        self.form_field = FormField::CompanyName;
-        self.form_data = Application::new();
-        self.platform_dropdown_selected = 0;
-        self.status_dropdown_selected = 0;
-        self.resume_modified_dropdown_selected = 0;
+    /// Save applications and the trash to disk and clear the dirty flag. Changes made to the
+    /// file since it was last read are merged in first; conflicting ones hold the save back
+    /// until they are resolved.
+    pub fn save(&mut self) -> Result<()> {
+        if self.read_only.is_some() || self.corrupt.is_some() {
+            return Ok(());
+        }
+        self.reload_if_changed()?;
+        if !self.conflicts.is_empty() {
+            return Ok(());
+        }
+        self.storage.save(&self.applications, &self.trash)?;
+        self.saved = Data {
+            applications: self.applications.clone(),
+            trash: self.trash.clone(),
+            unreadable: Vec::new(),
+        };
+        self.disk_modified = self.storage.modified();
+        self.dirty = false;
+        self.last_save = Instant::now();
+        Ok(())
     }
 
-    /// Start editing the selected application
-    pub fn start_edit(&mut self) {
-        if self.applications.is_empty() {
-            return;
+    /// Merge in changes made to the data file by something else, such as a script or a CLI
+    /// command, since it was last read or written. Records changed on both sides are queued
+    /// in `conflicts` with the in-memory version kept for now.
+    fn reload_if_changed(&mut self) -> Result<()> {
+        let modified = self.storage.modified();
+        if self.corrupt.is_some() || modified == self.disk_modified {
+            return Ok(());
         }
+        let mut data = self.storage.load()?;
+        self.disk_modified = modified;
+        // Records added by hand often have no ID yet; they are saved back with one
+        let mut dirty = models::assign_missing_ids(&mut data.applications);
 
-        let index = self.list_selected;
-        self.form_mode = Some(FormMode::Edit(index));
-        self.view = View::Form;
-        self.form_field = FormField::CompanyName;
-        self.form_data = self.applications[index].clone();
-
-        // Set dropdown selections to match current values
-        self.status_dropdown_selected = Status::all()
-            .iter()
-            .position(|s| *s == self.form_data.status)
-            .unwrap_or(0);
-
-        // Platform dropdown selection
-        let platform_str = self.form_data.platform.as_str();
-        self.platform_dropdown_selected = Platform::presets()
-            .iter()
-            .position(|&p| {
-                if p == "Other" {
-                    !matches!(self.form_data.platform, Platform::LinkedIn | Platform::Indeed | Platform::CompanyWebsite)
-                } else {
-                    Platform::from_str(p).as_str() == platform_str
-                }
-            })
-            .unwrap_or(0);
+        let merged = merge::merge(
+            (&self.saved.applications, &self.saved.trash),
+            (&self.applications, &self.trash),
+            (&data.applications, &data.trash),
+        );
+        dirty |= merged.applications != data.applications || merged.trash != data.trash;
+        let selected = self.selected_index().map(|idx| self.applications[idx].id);
+        self.applications = merged.applications;
+        self.trash = merged.trash;
+        self.saved = data;
+        self.marked.retain(|id| self.applications.iter().any(|application| application.id == *id));
+        self.dirty = dirty && self.read_only.is_none();
+        self.data_changed();
+        match selected.and_then(|id| self.index_of(id)) {
+            Some(index) => self.select_record(index),
+            None => self.clamp_selection(),
+        }
 
-        // Resume modified dropdown selection
-        self.resume_modified_dropdown_selected = if self.form_data.resume_modified { 0 } else { 1 };
+        if merged.from_disk > 0 {
+            let name = self.storage.path().file_name().unwrap_or_default().to_string_lossy().into_owned();
+            self.info(format!("Reloaded {} change(s) made to {} outside the app", merged.from_disk, name));
+        }
+        self.conflicts.extend(merged.conflicts);
+        Ok(())
     }
 
-    /// Save the form data
-    pub fn save_form(&mut self) -> Result<()> {
-        // Validate
-        if self.form_data.company_name.trim().is_empty() {
-            return Ok(()); // Silent validation - don't save if company name is empty
+    /// Ask about the next conflicting record, unless something else is being asked
+    fn prompt_next_conflict(&mut self) {
+        if self.prompt.is_some() {
+            return;
         }
+        let Some(conflict) = self.conflicts.first() else {
+            return;
+        };
+        let file = self.storage.path().file_name().unwrap_or_default().to_string_lossy();
+        let message = match (&conflict.disk, conflict.deleted_here) {
+            (Some(_), true) => format!("{} was deleted here but changed in {}. Keep it deleted?", conflict.company, file),
+            (None, _) => format!("{} was changed here but removed from {}. Keep your version?", conflict.company, file),
+            (Some(_), false) => format!("{} was changed both here and in {}. Keep your version?", conflict.company, file),
+        };
+        self.prompt = Some(Prompt {
+            message,
+            action: PromptAction::KeepMine(conflict.id),
+        });
+    }
 
-        match self.form_mode {
-            Some(FormMode::Add) => {
-                self.applications.push(self.form_data.clone());
-            }
-            Some(FormMode::Edit(index)) => {
-                self.applications[index] = self.form_data.clone();
+    /// Settle a conflict with the in-memory version, or with the file's
+    fn resolve_conflict(&mut self, id: Uuid, keep_mine: bool) {
+        let Some(position) = self.conflicts.iter().position(|conflict| conflict.id == id) else {
+            return;
+        };
+        let conflict = self.conflicts.remove(position);
+        if !keep_mine {
+            let index = self.index_of(id);
+            match (conflict.disk, index) {
+                (Some(theirs), Some(index)) => self.applications[index] = theirs,
+                (Some(theirs), None) => {
+                    self.trash.retain(|entry| entry.application.id != id);
+                    self.applications.push(theirs);
+                }
+                (None, Some(index)) => {
+                    self.applications.remove(index);
+                    self.marked.retain(|marked| *marked != id);
+                }
+                (None, None) => {}
             }
-            None => {}
+            self.clamp_selection();
         }
+        self.mark_dirty();
+    }
 
-        self.save()?;
-        self.view = View::List;
-        self.form_mode = None;
+    /// Keep the in-memory version of every record still in conflict, for a last save on the way out
+    pub fn keep_mine(&mut self) {
+        if !self.conflicts.is_empty() {
+            self.conflicts.clear();
+            self.mark_dirty();
+        }
+    }
 
-        Ok(())
+    /// Record that applications changed; `tick` writes them out
+    fn mark_dirty(&mut self) {
+        self.dirty = self.read_only.is_none();
+        self.data_changed();
     }
 
-    /// Cancel form editing
-    pub fn cancel_form(&mut self) {
-        self.view = View::List;
-        self.form_mode = None;
+    /// Move on to a new generation, dropping cached stats, and recheck what needs attention
+    fn data_changed(&mut self) {
+        self.generation += 1;
+        self.refresh_attention();
     }
 
-    /// Delete the selected application
-    pub fn delete_selected(&mut self) -> Result<()> {
-        if !self.applications.is_empty() {
-            self.applications.remove(self.list_selected);
-            if self.list_selected >= self.applications.len() && self.list_selected > 0 {
-                self.list_selected -= 1;
-            }
-            self.save()?;
-        }
-        Ok(())
+    /// Open without writing anything because instance `pid` holds the data file
+    pub fn set_read_only(&mut self, pid: u32) {
+        self.read_only = Some(pid);
+        self.dirty = false;
     }
 
-    /// Move list selection up
-    pub fn select_previous(&mut self) {
-        if !self.applications.is_empty() {
-            if self.list_selected > 0 {
-                self.list_selected -= 1;
-            }
+    /// Move the unreadable data file aside and start over, with the records that could be
+    /// recovered from it or with none
+    pub fn recover_corrupt(&mut self, keep_recovered: bool) {
+        let Some((corrupt, kept)) = self.set_aside_corrupt() else {
+            return;
+        };
+        let mut message = format!("The unreadable file is kept as {}", kept.display());
+        if keep_recovered {
+            message = format!("Recovered {} application(s). {}", corrupt.recovered.applications.len(), message);
+            self.applications = corrupt.recovered.applications;
+            self.trash = corrupt.recovered.trash;
+            models::assign_missing_ids(&mut self.applications);
         }
+        self.mark_dirty();
+        self.info(message);
     }
 
-    /// Move list selection down
-    pub fn select_next(&mut self) {
-        if !self.applications.is_empty() {
-            if self.list_selected < self.applications.len() - 1 {
-                self.list_selected += 1;
+    /// Move the unreadable data file aside and start over from its newest backup. A backup
+    /// that can't be read either leaves everything as it was.
+    pub fn open_corrupt_backup(&mut self) {
+        let Some(backup) = self.corrupt.as_ref().and_then(|corrupt| corrupt.backup.clone()) else {
+            return;
+        };
+        let data = match JsonStorage::new(&backup).load() {
+            Ok(data) => data,
+            Err(err) => {
+                self.error(format!("Could not open the backup: {:#}", err));
+                return;
             }
+        };
+        let Some((_, kept)) = self.set_aside_corrupt() else {
+            return;
+        };
+        self.applications = data.applications;
+        self.trash = data.trash;
+        models::assign_missing_ids(&mut self.applications);
+        self.mark_dirty();
+        self.info(format!(
+            "Opened {} application(s) from {}. The unreadable file is kept as {}",
+            self.applications.len(),
+            backup.display(),
+            kept.display()
+        ));
+    }
+
+    /// Rename the unreadable data file to its `.corrupt-<timestamp>` name, returning the error
+    /// it was read with and where it went
+    fn set_aside_corrupt(&mut self) -> Option<(CorruptFile, PathBuf)> {
+        if self.refuse_if_read_only() {
+            return None;
+        }
+        let corrupt = self.corrupt.take()?;
+        let kept = corrupt.keep_path(chrono::Local::now());
+        if let Err(err) = std::fs::rename(&corrupt.path, &kept) {
+            self.error(format!("Could not move {} aside: {}", corrupt.path.display(), err));
+            self.corrupt = Some(corrupt);
+            return None;
         }
+        Some((corrupt, kept))
     }
 
-    /// Switch to chart view
-    pub fn show_chart(&mut self) {
+    /// Show why changes aren't allowed in read-only mode; returns whether it is on
+    fn refuse_if_read_only(&mut self) -> bool {
+        let Some(pid) = self.read_only else {
+            return false;
+        };
+        self.error(format!("Read-only: another instance is running (PID {})", pid));
+        true
+    }
+
+    /// Write pending changes once `SAVE_INTERVAL` has passed since the last save
+    fn autosave(&mut self) {
+        if !self.dirty || self.last_save.elapsed() < SAVE_INTERVAL {
+            return;
+        }
+        if let Err(err) = self.save() {
+            // Retry on the next interval rather than every tick
+            self.last_save = Instant::now();
+            self.error(format!("Save failed: {:#}", err));
+        }
+    }
+
+    /// Indices into `applications` of the rows shown in the list, best search match first
+    pub fn visible_indices(&self) -> Vec<usize> {
+        let indices = (0..self.applications.len())
+            .filter(|&idx| self.show_archived || !self.applications[idx].archived)
+            .filter(|&idx| self.status_filter.as_ref().is_none_or(|status| self.applications[idx].status == *status))
+            .filter(|&idx| self.work_mode_filter.is_none_or(|mode| self.applications[idx].work_mode == mode))
+            .filter(|&idx| !self.attention_filter || self.needs_attention(self.applications[idx].id).is_some());
+        if self.search_query.is_empty() {
+            let mut indices: Vec<usize> = indices.collect();
+            // Stable, so equally rated applications keep the order they were added in
+            match self.sort {
+                SortKey::Added => {}
+                SortKey::Interest => indices.sort_by_key(|&idx| std::cmp::Reverse(self.applications[idx].interest)),
+                SortKey::Deadline => {
+                    let today = chrono::Local::now().date_naive();
+                    indices.sort_by_key(|&idx| match self.applications[idx].days_to_deadline(today) {
+                        Some(days) if days >= 0 => (0, days),
+                        // The most recently closed first
+                        Some(days) => (1, -days),
+                        None => (2, 0),
+                    });
+                }
+            }
+            return indices;
+        }
+
+        let mut scored: Vec<(usize, i64)> = indices
+            .filter_map(|idx| self.search_score(&self.applications[idx]).map(|score| (idx, score)))
+            .collect();
+        // Stable, so equally good matches keep their usual order
+        scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(idx, _)| idx).collect()
+    }
+
+    /// How well the search matches an application: fuzzily on the company name, or with the
+    /// query found whole in its job or requisition ID, so an ID pasted from an email finds it
+    fn search_score(&self, application: &Application) -> Option<i64> {
+        let company = fuzzy::score(&self.search_query, &application.company_name).map(|m| m.score);
+        let id = application
+            .external_id
+            .as_deref()
+            .and_then(|id| fuzzy::score_substring(self.search_query.trim(), id))
+            .map(|m| m.score);
+        company.max(id)
+    }
+
+    /// Rows of the list table: the visible applications, or in grouped mode each company
+    /// with several of them as a header followed by its applications, newest first.
+    /// Companies keep the order their first application has in the flat list.
+    pub fn list_rows(&self) -> Vec<ListRow> {
+        let visible = self.visible_indices();
+        if !self.grouped {
+            return visible.into_iter().map(ListRow::Application).collect();
+        }
+
+        let mut groups: Vec<(String, Vec<usize>)> = Vec::new();
+        for idx in visible {
+            let key = group_key(&self.applications[idx].company_name);
+            match groups.iter_mut().find(|(group, _)| *group == key) {
+                Some((_, members)) => members.push(idx),
+                None => groups.push((key, vec![idx])),
+            }
+        }
+
+        let statuses = self.status_order();
+        let mut rows = Vec::new();
+        for (key, mut members) in groups {
+            if let [only] = members[..] {
+                rows.push(ListRow::Application(only));
+                continue;
+            }
+            // Stable, so applications from the same day keep their list order
+            members.sort_by_key(|&idx| std::cmp::Reverse(self.applications[idx].applied_date));
+            let reached: Vec<&Status> = members.iter().map(|&idx| &self.applications[idx].status).collect();
+            let collapsed = self.collapsed_groups.contains(&key);
+            rows.push(ListRow::Group(CompanyGroup {
+                company: self.applications[members[0]].company_name.trim().to_string(),
+                count: members.len(),
+                best: best_status(&statuses, &reached),
+                collapsed,
+                key,
+            }));
+            if !collapsed {
+                rows.extend(members.into_iter().map(ListRow::Member));
+            }
+        }
+        rows
+    }
+
+    /// Row showing `applications[index]`, or the header it's collapsed under
+    fn row_of(&self, index: usize) -> Option<usize> {
+        let rows = self.list_rows();
+        rows.iter().position(|row| row.index() == Some(index)).or_else(|| {
+            let key = group_key(&self.applications.get(index)?.company_name);
+            rows.iter()
+                .position(|row| matches!(row, ListRow::Group(group) if group.key == key))
+        })
+    }
+
+    /// Switch between one row per application and applications grouped by company
+    pub fn toggle_grouped(&mut self) {
+        let selected = self.selected_index();
+        self.grouped = !self.grouped;
+        self.reselect(selected);
+        self.info(if self.grouped {
+            "Grouping applications by company"
+        } else {
+            "Listing every application on its own row"
+        });
+    }
+
+    /// Company group whose header row is selected
+    pub fn selected_group(&self) -> Option<CompanyGroup> {
+        match self.list_rows().into_iter().nth(self.list_selected)? {
+            ListRow::Group(group) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Collapse or expand the group whose header is selected
+    pub fn toggle_selected_group(&mut self) {
+        let Some(group) = self.selected_group() else {
+            return;
+        };
+        if !self.collapsed_groups.remove(&group.key) {
+            self.collapsed_groups.insert(group.key);
+        }
+        self.clamp_selection();
+    }
+
+    /// Index into `applications` of the record with `id`
+    pub fn index_of(&self, id: Uuid) -> Option<usize> {
+        self.applications.iter().position(|application| application.id == id)
+    }
+
+    /// Index into `applications` of the selected list row; None on a company header
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list_rows().get(self.list_selected).and_then(ListRow::index)
+    }
+
+    /// Statuses in dropdown and chart order, including configured custom statuses
+    pub fn status_order(&self) -> Vec<Status> {
+        self.config.status_order(&self.applications)
+    }
+
+    /// Platform dropdown options, including configured presets
+    pub fn platform_presets(&self) -> Vec<String> {
+        self.config.platform_presets()
+    }
+
+    /// Whether the platform dropdown is on "Other", where a custom name is typed
+    pub fn other_platform_selected(&self) -> bool {
+        self.platform_dropdown_selected + 1 == self.platform_presets().len()
+    }
+
+    /// Show only applications with `status`; choosing the active filter again clears it
+    pub fn toggle_status_filter(&mut self, status: Status) {
+        let filter = (self.status_filter.as_ref() != Some(&status)).then_some(status);
+        self.set_status_filter(filter);
+    }
+
+    /// Change the status filter, keeping the selected record selected if it's still shown
+    pub fn set_status_filter(&mut self, filter: Option<Status>) {
+        let selected = self.selected_index();
+        self.status_filter = filter;
+        self.reselect(selected);
+    }
+
+    /// Start typing a search query, keeping any current one
+    pub fn start_search(&mut self) {
+        self.search_typing = true;
+    }
+
+    /// Add a character to the search query and select the best match
+    pub fn push_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.list_selected = 0;
+        self.list_offset = 0;
+    }
+
+    pub fn pop_search_char(&mut self) {
+        text::pop_grapheme(&mut self.search_query);
+        self.list_selected = 0;
+        self.list_offset = 0;
+    }
+
+    /// Stop typing and keep the results listed
+    pub fn finish_search(&mut self) {
+        self.search_typing = false;
+        if self.search_query.is_empty() {
+            self.clamp_selection();
+        }
+    }
+
+    /// Drop the search, keeping the selected record selected
+    pub fn clear_search(&mut self) {
+        let selected = self.selected_index();
+        self.search_query.clear();
+        self.search_typing = false;
+        self.reselect(selected);
+    }
+
+    /// Open the notes search with an empty query
+    pub fn open_notes_search(&mut self) {
+        self.notes_search = Some(NotesSearch {
+            query: String::new(),
+            typing: true,
+            selected: 0,
+        });
+    }
+
+    /// Close the notes search
+    pub fn close_notes_search(&mut self) {
+        self.notes_search = None;
+    }
+
+    /// Applications whose notes contain the notes search query, with the matching snippet,
+    /// most recently applied first
+    pub fn notes_search_results(&self) -> Vec<(usize, Snippet)> {
+        let Some(search) = &self.notes_search else {
+            return Vec::new();
+        };
+        let mut results: Vec<(usize, Snippet)> = self
+            .applications
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, application)| {
+                Some((idx, snippet::find(&search.query, &application.notes, snippet::CONTEXT)?))
+            })
+            .collect();
+        results.sort_by(|(a, _), (b, _)| {
+            let (a, b) = (&self.applications[*a], &self.applications[*b]);
+            b.applied_date.cmp(&a.applied_date).then_with(|| a.company_name.cmp(&b.company_name))
+        });
+        results
+    }
+
+    /// Add a character to the notes search query, going back to the first result
+    pub fn push_notes_search_char(&mut self, c: char) {
+        if let Some(search) = self.notes_search.as_mut() {
+            search.query.push(c);
+            search.selected = 0;
+        }
+    }
+
+    /// Remove the last character of the notes search query
+    pub fn pop_notes_search_char(&mut self) {
+        if let Some(search) = self.notes_search.as_mut() {
+            text::pop_grapheme(&mut search.query);
+            search.selected = 0;
+        }
+    }
+
+    /// Stop typing the notes search query to move through the results
+    pub fn finish_notes_search(&mut self) {
+        if self.notes_search_results().is_empty() {
+            return;
+        }
+        if let Some(search) = self.notes_search.as_mut() {
+            search.typing = false;
+        }
+    }
+
+    /// Go back to typing the notes search query
+    pub fn edit_notes_search(&mut self) {
+        if let Some(search) = self.notes_search.as_mut() {
+            search.typing = true;
+        }
+    }
+
+    /// Move the notes search highlight by `delta` results, stopping at either end
+    pub fn move_notes_search(&mut self, delta: isize) {
+        let last = self.notes_search_results().len().saturating_sub(1);
+        if let Some(search) = self.notes_search.as_mut() {
+            search.selected = search.selected.saturating_add_signed(delta).min(last);
+        }
+    }
+
+    pub fn open_palette(&mut self) {
+        self.palette = Some(Palette {
+            query: String::new(),
+            selected: 0,
+        });
+    }
+
+    pub fn close_palette(&mut self) {
+        self.palette = None;
+    }
+
+    /// Actions of the current view matching the palette query by description or name, best
+    /// match first; all of them in help order while the query is empty
+    pub fn palette_results(&self) -> Vec<(Action, &'static str)> {
+        let Some(palette) = &self.palette else {
+            return Vec::new();
+        };
+        let mut scored: Vec<(Action, &'static str, i64)> = keymap::palette_actions(self.view)
+            .into_iter()
+            .filter_map(|(action, description)| {
+                let name = action.name().replace('_', " ");
+                let score = [description, action.description(), name.as_str()]
+                    .iter()
+                    .filter_map(|candidate| fuzzy::score(&palette.query, candidate))
+                    .map(|m| m.score)
+                    .max()?;
+                Some((action, description, score))
+            })
+            .collect();
+        // Stable, so equally good matches keep their help order
+        scored.sort_by_key(|&(_, _, score)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(action, description, _)| (action, description)).collect()
+    }
+
+    pub fn push_palette_char(&mut self, c: char) {
+        if let Some(palette) = self.palette.as_mut() {
+            palette.query.push(c);
+            palette.selected = 0;
+        }
+    }
+
+    pub fn pop_palette_char(&mut self) {
+        if let Some(palette) = self.palette.as_mut() {
+            text::pop_grapheme(&mut palette.query);
+            palette.selected = 0;
+        }
+    }
+
+    /// Move the palette highlight by `delta` rows, staying within the results
+    pub fn move_palette(&mut self, delta: isize) {
+        let last = self.palette_results().len().saturating_sub(1);
+        if let Some(palette) = self.palette.as_mut() {
+            palette.selected = palette.selected.saturating_add_signed(delta).min(last);
+        }
+    }
+
+    /// Close the palette, returning the highlighted action to run
+    pub fn take_palette_action(&mut self) -> Option<Action> {
+        let selected = self.palette.as_ref()?.selected;
+        let action = self.palette_results().get(selected).map(|(action, _)| *action);
+        if action.is_some() {
+            self.palette = None;
+        }
+        action
+    }
+
+    /// Edit the application of the highlighted notes search result, with the cursor on the match
+    pub fn open_notes_search_result(&mut self) {
+        let Some(selected) = self.notes_search.as_ref().map(|search| search.selected) else {
+            return;
+        };
+        let Some((index, snippet)) = self.notes_search_results().into_iter().nth(selected) else {
+            return;
+        };
+        if self.refuse_if_read_only() {
+            return;
+        }
+        self.notes_search = None;
+        self.select_record(index);
+        self.edit_application(index);
+        self.form_field = FormField::Notes;
+        // On the start of the grapheme, in case the match began with a combining mark
+        self.notes_cursor = text::previous_boundary(&self.form_data.notes, snippet.offset + 1);
+    }
+
+    /// Start typing a jump prefix; until it times out every character goes to it
+    pub fn start_jump(&mut self) {
+        self.jump = Some(Jump {
+            prefix: String::new(),
+            explicit: true,
+            typed_at: Instant::now(),
+        });
+    }
+
+    /// Jump prefix typed within the last `JUMP_TIMEOUT`, if any
+    pub fn active_jump(&self) -> Option<&Jump> {
+        self.jump.as_ref().filter(|jump| jump.typed_at.elapsed() < JUMP_TIMEOUT)
+    }
+
+    /// Add a character to the jump prefix, starting a new one if the last timed out,
+    /// and select the next company starting with it. The selected row stays if it still matches.
+    pub fn push_jump_char(&mut self, c: char) {
+        if self.active_jump().is_none() {
+            self.jump = None;
+        }
+        let jump = self.jump.get_or_insert_with(|| Jump {
+            prefix: String::new(),
+            explicit: false,
+            typed_at: Instant::now(),
+        });
+        jump.prefix.push(c);
+        jump.typed_at = Instant::now();
+        let prefix = jump.prefix.clone();
+        let include_selected = prefix.chars().count() > 1;
+        self.jump_to(&prefix, true, include_selected);
+        self.last_jump = prefix;
+    }
+
+    pub fn pop_jump_char(&mut self) {
+        if let Some(jump) = self.jump.as_mut() {
+            text::pop_grapheme(&mut jump.prefix);
+            jump.typed_at = Instant::now();
+            if !jump.prefix.is_empty() {
+                self.last_jump = jump.prefix.clone();
+            }
+        }
+    }
+
+    pub fn end_jump(&mut self) {
+        self.jump = None;
+    }
+
+    /// Repeat the last jump to the next (or previous) matching company
+    pub fn repeat_jump(&mut self, forward: bool) {
+        self.jump = None;
+        if self.last_jump.is_empty() {
+            self.info("Nothing to repeat; jump to a company first");
+            return;
+        }
+        let prefix = self.last_jump.clone();
+        self.jump_to(&prefix, forward, false);
+    }
+
+    /// Select the nearest row, wrapping around, whose company starts with `prefix`
+    fn jump_to(&mut self, prefix: &str, forward: bool, include_selected: bool) {
+        let rows = self.list_rows();
+        let len = rows.len();
+        let wanted = prefix.to_lowercase();
+        let steps = if include_selected { 0..len } else { 1..len + 1 };
+        let found = steps
+            .map(|step| {
+                if forward {
+                    (self.list_selected + step) % len
+                } else {
+                    (self.list_selected + len - step) % len
+                }
+            })
+            .find(|&row| {
+                let company = match &rows[row] {
+                    ListRow::Group(group) => &group.company,
+                    row => &self.applications[row.index().unwrap_or_default()].company_name,
+                };
+                company.trim().to_lowercase().starts_with(&wanted)
+            });
+        match found {
+            Some(row) => self.list_selected = row,
+            None => self.info(format!("No company starts with \"{}\"", prefix)),
+        }
+    }
+
+    /// Step the work mode filter through Remote, Hybrid and Onsite, then back to showing all
+    pub fn cycle_work_mode_filter(&mut self) {
+        let next = match self.work_mode_filter {
+            None => Some(WorkMode::Remote),
+            Some(WorkMode::Remote) => Some(WorkMode::Hybrid),
+            Some(WorkMode::Hybrid) => Some(WorkMode::Onsite),
+            Some(_) => None,
+        };
+        let selected = self.selected_index();
+        self.work_mode_filter = next;
+        self.reselect(selected);
+    }
+
+    /// List only applications that need attention, or everything again
+    pub fn toggle_attention_filter(&mut self) {
+        let selected = self.selected_index();
+        self.attention_filter = !self.attention_filter;
+        self.reselect(selected);
+        if self.attention_filter && self.attention.is_empty() {
+            self.info("Nothing needs attention");
+        }
+    }
+
+    /// Why an application needs attention, if it does
+    pub fn needs_attention(&self, id: Uuid) -> Option<&Reason> {
+        self.attention.iter().find(|(flagged, _)| *flagged == id).map(|(_, reason)| reason)
+    }
+
+    /// Recheck which applications need attention, after edits or when the day changes
+    fn refresh_attention(&mut self) {
+        self.attention = attention::needs_attention(&self.applications, &self.config.attention, self.stats_day);
+    }
+
+    /// Numbers behind the current chart, counted once per generation, chart and range
+    pub fn chart_data(&self) -> ChartData {
+        self.stats.chart(self.generation, self.chart_type, self.chart_range, || {
+            stats::chart_data(
+                self.chart_type,
+                self.applications.iter(),
+                self.chart_range,
+                &self.status_order(),
+                &self.platform_presets(),
+                self.stats_day,
+            )
+        })
+    }
+
+    /// Counts over the listed applications, counted once per generation and list filter; the
+    /// sort order and moving the selection don't change them
+    pub fn list_summary(&self) -> ListSummary {
+        let filter = ListFilter {
+            status: self.status_filter.clone(),
+            work_mode: self.work_mode_filter,
+            attention: self.attention_filter,
+            show_archived: self.show_archived,
+            search: self.search_query.clone(),
+        };
+        self.stats.summary(self.generation, filter, || {
+            let listed: Vec<&Application> = self.visible_indices().into_iter().map(|idx| &self.applications[idx]).collect();
+            ListSummary {
+                total: listed.len(),
+                attention: listed.iter().filter(|a| self.needs_attention(a.id).is_some()).count(),
+                statuses: stats::by_status(listed.iter().copied(), &self.status_order()),
+            }
+        })
+    }
+
+    /// Switch the list between the order records were added in and highest interest first
+    pub fn cycle_sort(&mut self) {
+        let selected = self.selected_index();
+        self.sort = self.sort.next();
+        self.reselect(selected);
+        self.info(format!("Sorted by {}", self.sort.label()));
+    }
+
+    /// Show or hide archived applications in the list
+    pub fn toggle_show_archived(&mut self) {
+        let selected = self.selected_index();
+        self.show_archived = !self.show_archived;
+        self.reselect(selected);
+        self.info(if self.show_archived {
+            "Showing archived applications"
+        } else {
+            "Hiding archived applications"
+        });
+    }
+
+    /// Select the row showing `index` after the visible rows changed, or the first row
+    fn reselect(&mut self, index: Option<usize>) {
+        self.list_selected = index.and_then(|index| self.row_of(index)).unwrap_or(0);
+        self.clamp_selection();
+    }
+
+    /// Select the row showing `applications[index]` if it's visible, otherwise stay in place
+    fn select_record(&mut self, index: usize) {
+        if let Some(row) = self.row_of(index) {
+            self.list_selected = row;
+        }
+        self.clamp_selection();
+    }
+
+    /// Keep the list selection and scroll offset within the visible rows.
+    /// Call after anything that adds, removes, hides or reorders rows.
+    fn clamp_selection(&mut self) {
+        let rows = self.list_rows().len();
+        self.list_selected = self.list_selected.min(rows.saturating_sub(1));
+        self.list_offset = self.list_offset.min(self.list_selected);
+        // A click before the change may have been on a different record
+        self.last_click = None;
+    }
+
+    /// Start adding a new application
+    pub fn start_add(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        self.form_mode = Some(FormMode::Add);
+        self.view = View::Form;
+        self.form_field = FormField::CompanyName;
+        self.form_scroll = 0;
+        self.form_data = Application::new();
+        self.form_original = self.form_data.clone();
+        self.platform_dropdown_selected = 0;
+        self.status_dropdown_selected = self
+            .status_order()
+            .iter()
+            .position(|status| *status == Status::Applied)
+            .unwrap_or(0);
+        self.resume_modified_dropdown_selected = 0;
+        self.referral_dropdown_selected = 1;
+        self.cover_letter_dropdown_selected = 1;
+        self.work_mode_dropdown_selected = work_mode_index(WorkMode::default());
+        self.interest_dropdown_selected = 0;
+        self.salary_input.clear();
+        self.resume_path_input.clear();
+        self.date_input = self.form_data.applied_date.to_string();
+        self.notes_cursor = 0;
+        self.form_errors.clear();
+    }
+
+    /// Open the template picker
+    pub fn open_template_picker(&mut self) {
+        if self.templates.is_empty() {
+            let key = self.keymap.label(Action::SaveTemplate);
+            self.info(format!("No templates yet; press {} in the form to save one", key));
+        } else {
+            self.template_picker = Some(TemplatePicker {
+                selected: 0,
+                renaming: None,
+            });
+        }
+    }
+
+    /// Close the template picker
+    pub fn close_template_picker(&mut self) {
+        self.template_picker = None;
+    }
+
+    /// Move the template picker highlight by `delta`, stopping at either end
+    pub fn move_template_picker(&mut self, delta: isize) {
+        let last = self.templates.len().saturating_sub(1);
+        if let Some(picker) = self.template_picker.as_mut() {
+            picker.selected = picker.selected.saturating_add_signed(delta).min(last);
+        }
+    }
+
+    /// Start adding an application pre-filled from the highlighted template
+    pub fn add_from_template(&mut self) {
+        let Some(template) = self
+            .template_picker
+            .as_ref()
+            .and_then(|picker| self.templates.get(picker.selected))
+            .cloned()
+        else {
+            return;
+        };
+        self.template_picker = None;
+        self.start_add();
+        if self.form_mode != Some(FormMode::Add) {
+            return;
+        }
+        template.apply(&mut self.form_data);
+        self.load_form_inputs();
+        // Cancelling right away has nothing to lose
+        self.form_original = self.form_data.clone();
+    }
+
+    /// Delete the highlighted template
+    pub fn delete_template(&mut self) -> Result<()> {
+        let Some(selected) = self.template_picker.as_ref().map(|picker| picker.selected) else {
+            return Ok(());
+        };
+        if selected >= self.templates.len() {
+            return Ok(());
+        }
+        let removed = self.templates.remove(selected);
+        self.template_picker = if self.templates.is_empty() {
+            None
+        } else {
+            Some(TemplatePicker {
+                selected: selected.min(self.templates.len() - 1),
+                renaming: None,
+            })
+        };
+        self.save_templates()?;
+        self.info(format!("Deleted template {}", removed.name));
+        Ok(())
+    }
+
+    /// Start typing a new name for the highlighted template
+    pub fn start_rename_template(&mut self) {
+        if let Some(picker) = self.template_picker.as_mut() {
+            picker.renaming = self.templates.get(picker.selected).map(|template| template.name.clone());
+        }
+    }
+
+    /// Rename the highlighted template to the typed name, keeping it highlighted
+    pub fn finish_rename_template(&mut self) -> Result<()> {
+        let Some((selected, Some(name))) = self
+            .template_picker
+            .as_ref()
+            .map(|picker| (picker.selected, picker.renaming.clone()))
+        else {
+            return Ok(());
+        };
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            self.error("Template name can't be empty");
+            return Ok(());
+        }
+        let taken = self
+            .templates
+            .iter()
+            .enumerate()
+            .any(|(idx, template)| idx != selected && template.name.eq_ignore_ascii_case(&name));
+        if taken {
+            self.error(format!("A template named {} already exists", name));
+            return Ok(());
+        }
+
+        self.templates[selected].name = name.clone();
+        template::sort(&mut self.templates);
+        let selected = self.templates.iter().position(|template| template.name == name).unwrap_or(0);
+        self.template_picker = Some(TemplatePicker {
+            selected,
+            renaming: None,
+        });
+        self.save_templates()?;
+        self.info(format!("Renamed template to {}", name));
+        Ok(())
+    }
+
+    /// Start typing a name to save the form's reusable fields as a template
+    pub fn start_save_template(&mut self) {
+        self.template_name = Some(String::new());
+    }
+
+    /// Save the form as a template under the typed name, replacing one with the same name
+    pub fn finish_save_template(&mut self) -> Result<()> {
+        let Some(name) = self.template_name.as_deref().map(str::trim).map(str::to_string) else {
+            return Ok(());
+        };
+        if name.is_empty() {
+            self.error("Template name can't be empty");
+            return Ok(());
+        }
+        self.template_name = None;
+
+        let mut application = self.form_data.clone();
+        application.resume_path = trimmed(Some(self.resume_path_input.clone())).map(|path| paths::expand_home(&path));
+        let template = Template::from_application(&name, &application);
+        let message = match self.templates.iter().position(|t| t.name.eq_ignore_ascii_case(&name)) {
+            Some(existing) => {
+                self.templates[existing] = template;
+                format!("Updated template {}", name)
+            }
+            None => {
+                self.templates.push(template);
+                format!("Saved template {}", name)
+            }
+        };
+        template::sort(&mut self.templates);
+        self.save_templates()?;
+        self.info(message);
+        Ok(())
+    }
+
+    fn save_templates(&self) -> Result<()> {
+        template::save(&template::templates_path(self.storage.path()), &self.templates)
+    }
+
+    /// Start editing the selected application
+    pub fn start_edit(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        self.edit_application(index);
+    }
+
+    /// Open the form on the application at `index`
+    fn edit_application(&mut self, index: usize) {
+        self.form_mode = Some(FormMode::Edit(self.applications[index].id));
+        self.view = View::Form;
+        self.form_field = FormField::CompanyName;
+        self.form_scroll = 0;
+        self.form_data = self.applications[index].clone();
+        self.form_original = self.form_data.clone();
+        self.load_form_inputs();
+    }
+
+    /// Set the dropdown selections and typed inputs to match `form_data`
+    fn load_form_inputs(&mut self) {
+        // Set dropdown selections to match current values
+        self.status_dropdown_selected = self
+            .status_order()
+            .iter()
+            .position(|s| *s == self.form_data.status)
+            .unwrap_or(0);
+
+        // Platform dropdown selection: the matching preset, otherwise "Other" with the text kept
+        let platform_str = self.form_data.platform.as_str();
+        let presets = self.platform_presets();
+        self.platform_dropdown_selected = presets[..presets.len() - 1]
+            .iter()
+            .position(|preset| preset.eq_ignore_ascii_case(platform_str.trim()))
+            .unwrap_or(presets.len() - 1);
+
+        // Resume modified dropdown selection
+        self.resume_modified_dropdown_selected = if self.form_data.resume_modified { 0 } else { 1 };
+        self.referral_dropdown_selected = if self.form_data.referral { 0 } else { 1 };
+        self.cover_letter_dropdown_selected = if self.form_data.cover_letter { 0 } else { 1 };
+        self.work_mode_dropdown_selected = work_mode_index(self.form_data.work_mode);
+        self.interest_dropdown_selected = self.form_data.interest.min(MAX_INTEREST) as usize;
+
+        self.salary_input = self
+            .form_data
+            .salary
+            .as_ref()
+            .map(|salary| salary.to_string())
+            .unwrap_or_default();
+        self.resume_path_input = self.form_data.resume_path.as_deref().map(paths::contract_home).unwrap_or_default();
+        self.date_input = self.form_data.applied_date.to_string();
+        self.notes_cursor = self.form_data.notes.chars().count();
+        self.form_errors.clear();
+    }
+
+    /// Save the form data
+    pub fn save_form(&mut self) -> Result<()> {
+        if !self.validate_form() {
+            return Ok(());
+        }
+
+        let warnings = self.form_warnings();
+        if !warnings.is_empty() {
+            self.prompt = Some(Prompt {
+                message: format!("{}. Save anyway?", warnings.join(". ")),
+                action: PromptAction::SaveDespiteWarnings,
+            });
+            return Ok(());
+        }
+        self.save_checked_form()
+    }
+
+    /// Save the validated form, asking first when adding an application that looks like a
+    /// recent one
+    fn save_checked_form(&mut self) -> Result<()> {
+        if self.form_mode == Some(FormMode::Add) {
+            if let Some(existing) = self.recent_duplicate(&self.form_data.company_name) {
+                let existing = &self.applications[existing];
+                self.prompt = Some(Prompt {
+                    message: format!(
+                        "You already applied to {} on {} ({}). Add anyway?",
+                        existing.company_name.trim(),
+                        existing.applied_date,
+                        existing.status.as_str()
+                    ),
+                    action: PromptAction::AddDuplicate,
+                });
+                return Ok(());
+            }
+        }
+
+        self.commit_form()
+    }
+
+    /// Check the form and normalize its input. On problems, shows them all next to their
+    /// fields and focuses the first. Returns whether the form can be saved.
+    fn validate_form(&mut self) -> bool {
+        self.form_errors = self.check_form();
+        if let Some((field, _)) = self.form_errors.first() {
+            self.form_field = *field;
+            return false;
+        }
+
+        self.form_data.salary = SalaryRange::parse(&self.salary_input).ok();
+        self.resolve_date_input();
+        self.form_data.url = trimmed(self.form_data.url.take());
+        self.form_data.external_id = trimmed(self.form_data.external_id.take());
+        // "Other" text naming a preset is stored as that preset
+        self.form_data.platform = Platform::from_name(&self.form_data.platform.as_str(), &self.platform_presets());
+        // A file that doesn't exist (yet) is only flagged, never refused
+        self.form_data.resume_path = trimmed(Some(self.resume_path_input.clone())).map(|path| paths::expand_home(&path));
+        self.form_data.job_title = trimmed(self.form_data.job_title.take());
+        self.form_data.location = trimmed(self.form_data.location.take());
+        self.form_data.contact_name = trimmed(self.form_data.contact_name.take());
+        self.form_data.contact_email = trimmed(self.form_data.contact_email.take());
+        true
+    }
+
+    /// Things in the validated form that are probably mistakes but may be meant, such as a
+    /// pasted job description in the notes. Only what was changed in this edit is checked, so
+    /// an old record doesn't warn every time it's saved.
+    fn form_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let (data, original) = (&self.form_data, &self.form_original);
+        let adding = self.form_mode == Some(FormMode::Add);
+
+        let limit = self.config.warnings.notes_length;
+        let length = data.notes.chars().count();
+        if limit > 0 && length > limit && (adding || data.notes != original.notes) {
+            warnings.push(format!("Notes are {} characters long (warning above {})", length, limit));
+        }
+
+        let company = data.company_name.trim().to_lowercase();
+        let url_like = company.contains("://")
+            || company.starts_with("www.")
+            || (!company.contains(char::is_whitespace) && company.contains('.') && company.contains('/'));
+        if url_like && (adding || data.company_name != original.company_name) {
+            warnings.push(format!("Company name \"{}\" looks like a URL", data.company_name.trim()));
+        }
+
+        let today = chrono::Local::now().date_naive();
+        let dates = [
+            ("Application date", Some(data.applied_date), Some(original.applied_date)),
+            ("Follow-up", data.follow_up, original.follow_up),
+            ("Deadline", data.deadline, original.deadline),
+        ];
+        for (label, date, original) in dates {
+            let Some(date) = date.filter(|date| adding || Some(*date) != original) else {
+                continue;
+            };
+            match (date - today).num_days() {
+                days if days > 365 => warnings.push(format!("{} {} is more than a year away", label, date)),
+                days if days < -365 => warnings.push(format!("{} {} is more than a year ago", label, date)),
+                _ => {}
+            }
+        }
+        warnings
+    }
+
+    /// Every problem that keeps the form from saving, in field order
+    pub fn check_form(&self) -> Vec<(FormField, String)> {
+        FormField::all()
+            .iter()
+            .filter_map(|&field| self.check_field(field).map(|message| (field, message)))
+            .collect()
+    }
+
+    /// Why `field` can't be saved as it is, if it can't
+    fn check_field(&self, field: FormField) -> Option<String> {
+        match field {
+            FormField::CompanyName if self.form_data.company_name.trim().is_empty() => {
+                Some("Company name is required".to_string())
+            }
+            FormField::Url => trimmed(self.form_data.url.clone())
+                .filter(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+                .map(|_| "URL must start with http:// or https://".to_string()),
+            FormField::Salary if !self.salary_input.trim().is_empty() => SalaryRange::parse(&self.salary_input).err(),
+            FormField::Date => {
+                let today = chrono::Local::now().date_naive();
+                dates::parse_date_input(&self.date_input, today).err().map(|err| err.to_string())
+            }
+            FormField::ContactEmail => trimmed(self.form_data.contact_email.clone())
+                .filter(|email| !email.contains('@'))
+                .map(|_| "Email must contain @".to_string()),
+            _ => None,
+        }
+    }
+
+    /// Show `message` as the error of `field`, or clear its error when None
+    fn set_field_error(&mut self, field: FormField, message: Option<String>) {
+        self.form_errors.retain(|(f, _)| *f != field);
+        if let Some(message) = message {
+            self.form_errors.push((field, message));
+            let order = |field: &FormField| FormField::all().iter().position(|f| f == field);
+            self.form_errors.sort_by_key(|(field, _)| order(field));
+        }
+    }
+
+    /// Error shown next to `field`, if any
+    pub fn field_error(&self, field: FormField) -> Option<&str> {
+        self.form_errors
+            .iter()
+            .find(|(f, _)| *f == field)
+            .map(|(_, message)| message.as_str())
+    }
+
+    /// Most recent live application to the same company within the duplicate window
+    fn recent_duplicate(&self, company_name: &str) -> Option<usize> {
+        let name = company_name.trim().to_lowercase();
+        let cutoff = chrono::Local::now().date_naive() - chrono::Duration::days(DUPLICATE_WINDOW_DAYS);
+        (0..self.applications.len())
+            .filter(|&idx| {
+                let application = &self.applications[idx];
+                application.company_name.trim().to_lowercase() == name
+                    && application.applied_date >= cutoff
+            })
+            .max_by_key(|&idx| self.applications[idx].applied_date)
+    }
+
+    /// Write the validated form to the applications and return to the list
+    fn commit_form(&mut self) -> Result<()> {
+        let (index, before_status, message) = match self.form_mode {
+            Some(FormMode::Add) => {
+                self.applications.push(self.form_data.clone());
+                (self.applications.len() - 1, None, diff::save_message(None, &self.form_data))
+            }
+            Some(FormMode::Edit(id)) => {
+                let Some(index) = self.index_of(id) else {
+                    self.error(format!("{} no longer exists; not saved", self.form_original.company_name));
+                    self.close_form();
+                    return Ok(());
+                };
+                if self.form_data.status != self.applications[index].status {
+                    let change = StatusChange {
+                        status: self.form_data.status.clone(),
+                        date: chrono::Local::now().date_naive(),
+                    };
+                    self.form_data.status_history.push(change);
+                }
+                let before = std::mem::replace(&mut self.applications[index], self.form_data.clone());
+                let message = diff::save_message(Some(&before), &self.form_data);
+                (index, Some(before.status), message)
+            }
+            None => return Ok(()),
+        };
+
+        self.mark_dirty();
+        self.fire_hooks(index, before_status);
+        self.info(message);
+        self.view = View::List;
+        self.form_mode = None;
+        // Follow the saved record, unless it no longer matches the status filter
+        self.select_record(index);
+
+        Ok(())
+    }
+
+    /// Open the interview rounds popup for the form's application
+    /// Open the calendar for a date field, on the date typed there or else today
+    pub fn open_date_picker(&mut self, target: DateTarget) {
+        let today = chrono::Local::now().date_naive();
+        let current = match target {
+            DateTarget::Applied => dates::parse_date_input(&self.date_input, today).ok(),
+            DateTarget::FollowUp => self.form_data.follow_up,
+            DateTarget::Deadline => self.form_data.deadline,
+            DateTarget::Interview => self
+                .interview_editor
+                .as_ref()
+                .and_then(|editor| editor.draft.as_ref())
+                .and_then(|draft| dates::parse_date_input(&draft.date, today).ok()),
+        };
+        self.date_picker = Some((target, DatePicker::new(current.unwrap_or(today))));
+    }
+
+    /// Put the highlighted date into the field the calendar was opened for, which keeps the focus
+    pub fn accept_date_picker(&mut self) {
+        let Some((target, picker)) = self.date_picker.take() else {
+            return;
+        };
+        match target {
+            DateTarget::Applied => {
+                self.date_input = picker.date.to_string();
+                self.set_field_error(FormField::Date, None);
+            }
+            DateTarget::FollowUp => self.form_data.follow_up = Some(picker.date),
+            DateTarget::Deadline => self.form_data.deadline = Some(picker.date),
+            DateTarget::Interview => {
+                if let Some(draft) = self.interview_editor.as_mut().and_then(|editor| editor.draft.as_mut()) {
+                    draft.date = picker.date.to_string();
+                    draft.error = None;
+                }
+            }
+        }
+    }
+
+    pub fn open_interview_editor(&mut self) {
+        self.interview_editor = Some(InterviewEditor::default());
+    }
+
+    pub fn close_interview_editor(&mut self) {
+        self.interview_editor = None;
+    }
+
+    /// Start typing a new interview round, dated today
+    pub fn start_interview_draft(&mut self) {
+        if let Some(editor) = self.interview_editor.as_mut() {
+            editor.draft = Some(InterviewDraft {
+                kind: InterviewKind::default(),
+                date: chrono::Local::now().date_naive().to_string(),
+                notes: String::new(),
+                field: InterviewField::Kind,
+                error: None,
+            });
+        }
+    }
+
+    /// Add the round being typed to the form, keeping rounds sorted by date
+    pub fn save_interview_draft(&mut self) {
+        let Some(editor) = self.interview_editor.as_mut() else {
+            return;
+        };
+        let Some(draft) = editor.draft.as_mut() else {
+            return;
+        };
+
+        let Ok(date) = NaiveDate::parse_from_str(draft.date.trim(), "%Y-%m-%d") else {
+            draft.error = Some("Date must be YYYY-MM-DD".to_string());
+            draft.field = InterviewField::Date;
+            return;
+        };
+
+        let interview = Interview {
+            kind: draft.kind,
+            date,
+            notes: draft.notes.trim().to_string(),
+        };
+        editor.draft = None;
+
+        let interviews = &mut self.form_data.interviews;
+        let position = interviews.partition_point(|existing| existing.date <= interview.date);
+        interviews.insert(position, interview);
+        editor.selected = position;
+    }
+
+    /// Remove the selected interview round from the form
+    pub fn delete_selected_interview(&mut self) {
+        let Some(editor) = self.interview_editor.as_mut() else {
+            return;
+        };
+        if editor.selected < self.form_data.interviews.len() {
+            self.form_data.interviews.remove(editor.selected);
+            editor.selected = editor.selected.min(self.form_data.interviews.len().saturating_sub(1));
+        }
+    }
+
+    /// Open the activity log of the selected application
+    pub fn open_activity_log(&mut self) {
+        if let Some(index) = self.selected_index() {
+            let id = self.applications[index].id;
+            self.activity_log = Some(ActivityLog { id, selected: 0, draft: None });
+        }
+    }
+
+    pub fn close_activity_log(&mut self) {
+        self.activity_log = None;
+    }
+
+    /// Application whose activity log is open
+    pub fn activity_log_index(&self) -> Option<usize> {
+        let log = self.activity_log.as_ref()?;
+        self.applications.iter().position(|application| application.id == log.id)
+    }
+
+    pub fn move_activity_log(&mut self, delta: isize) {
+        let len = self.activity_log_index().map_or(0, |index| self.applications[index].activity.len());
+        if let Some(log) = self.activity_log.as_mut() {
+            log.selected = log.selected.saturating_add_signed(delta).min(len.saturating_sub(1));
+        }
+    }
+
+    /// Start typing a new entry, dated today
+    pub fn start_activity_draft(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        if let Some(log) = self.activity_log.as_mut() {
+            log.draft = Some(ActivityDraft {
+                date: chrono::Local::now().date_naive().to_string(),
+                text: String::new(),
+                field: ActivityField::Text,
+                editing: None,
+                error: None,
+            });
+        }
+    }
+
+    /// Start changing the selected entry
+    pub fn edit_selected_activity(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.activity_log_index() else {
+            return;
+        };
+        let Some(log) = self.activity_log.as_mut() else {
+            return;
+        };
+        if let Some(entry) = self.applications[index].activity.get(log.selected) {
+            log.draft = Some(ActivityDraft {
+                date: entry.date.to_string(),
+                text: entry.text.clone(),
+                field: ActivityField::Text,
+                editing: Some(log.selected),
+                error: None,
+            });
+        }
+    }
+
+    /// Add or update the entry being typed, keeping entries sorted by date
+    pub fn save_activity_draft(&mut self) {
+        let Some(index) = self.activity_log_index() else {
+            return;
+        };
+        let Some(draft) = self.activity_log.as_mut().and_then(|log| log.draft.as_mut()) else {
+            return;
+        };
+
+        let today = chrono::Local::now().date_naive();
+        let date = match dates::parse_date_input(&draft.date, today) {
+            Ok(date) => date,
+            Err(err) => {
+                draft.error = Some(err.to_string());
+                draft.field = ActivityField::Date;
+                return;
+            }
+        };
+        let text = draft.text.trim().to_string();
+        if text.is_empty() {
+            draft.error = Some("Describe what happened".to_string());
+            draft.field = ActivityField::Text;
+            return;
+        }
+        let editing = draft.editing;
+
+        let before = self.applications[index].clone();
+        let activity = &mut self.applications[index].activity;
+        if let Some(position) = editing.filter(|&position| position < activity.len()) {
+            activity.remove(position);
+        }
+        let position = activity.partition_point(|existing| existing.date <= date);
+        activity.insert(position, ActivityEntry { date, text });
+        if let Some(log) = self.activity_log.as_mut() {
+            log.draft = None;
+            log.selected = position;
+        }
+
+        self.mark_dirty();
+        self.info(diff::save_message(Some(&before), &self.applications[index]));
+    }
+
+    /// Remove the selected entry from the log
+    pub fn delete_selected_activity(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.activity_log_index() else {
+            return;
+        };
+        let Some(log) = self.activity_log.as_mut() else {
+            return;
+        };
+        if log.selected >= self.applications[index].activity.len() {
+            return;
+        }
+        let before = self.applications[index].clone();
+        self.applications[index].activity.remove(log.selected);
+        log.selected = log.selected.min(self.applications[index].activity.len().saturating_sub(1));
+
+        self.mark_dirty();
+        self.info(diff::save_message(Some(&before), &self.applications[index]));
+    }
+
+    /// Cancel form editing, asking first if anything was changed
+    pub fn cancel_form(&mut self) {
+        if self.form_has_changes() {
+            self.prompt = Some(Prompt {
+                message: "Discard changes?".to_string(),
+                action: PromptAction::DiscardForm,
+            });
+            return;
+        }
+        self.close_form();
+    }
+
+    /// Whether the form differs from what it was opened with
+    pub fn form_has_changes(&self) -> bool {
+        let original_resume_path = self
+            .form_original
+            .resume_path
+            .as_deref()
+            .map(paths::contract_home)
+            .unwrap_or_default();
+        let original_salary = self
+            .form_original
+            .salary
+            .as_ref()
+            .map(|salary| salary.to_string())
+            .unwrap_or_default();
+        self.form_data != self.form_original
+            || self.salary_input.trim() != original_salary
+            || self.date_input.trim() != self.form_original.applied_date.to_string()
+            || self.resume_path_input.trim() != original_resume_path
+    }
+
+    fn close_form(&mut self) {
+        self.view = View::List;
+        self.form_mode = None;
+    }
+
+    /// IDs of the applications a batch operation applies to: marked records and the
+    /// visual range, skipping records moved to the trash since they were marked
+    pub fn batch_ids(&self) -> Vec<Uuid> {
+        let mut ids: Vec<Uuid> = self.marked.clone();
+        for id in self.visual_range() {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        ids.retain(|id| self.index_of(*id).is_some());
+        ids
+    }
+
+    /// Records between the visual anchor and the selected row, in list order
+    fn visual_range(&self) -> Vec<Uuid> {
+        let Some(anchor) = self.visual_anchor else {
+            return Vec::new();
+        };
+        let rows = self.list_rows();
+        let Some(start) = self.index_of(anchor).and_then(|index| self.row_of(index)) else {
+            return Vec::new();
+        };
+        let end = self.list_selected.min(rows.len().saturating_sub(1));
+        let (from, to) = if start <= end { (start, end) } else { (end, start) };
+        rows[from..=to]
+            .iter()
+            .filter_map(ListRow::index)
+            .map(|idx| self.applications[idx].id)
+            .collect()
+    }
+
+    /// Mark the selected application, or unmark it, and move to the next row
+    pub fn toggle_mark_selected(&mut self) {
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        let id = self.applications[index].id;
+        match self.marked.iter().position(|marked| *marked == id) {
+            Some(position) => {
+                self.marked.remove(position);
+            }
+            None => self.marked.push(id),
+        }
+        self.select_next();
+    }
+
+    /// Start a visual range at the selected row, or fix the current range as marks
+    pub fn toggle_visual_select(&mut self) {
+        if self.visual_anchor.is_some() {
+            self.marked = self.batch_ids();
+            self.visual_anchor = None;
+            return;
+        }
+        if let Some(index) = self.selected_index() {
+            self.visual_anchor = Some(self.applications[index].id);
+        }
+    }
+
+    /// Drop all marks and the visual range
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.visual_anchor = None;
+    }
+
+    /// Delete the marked applications, or the selected one when nothing is marked
+    pub fn delete_batch(&mut self) {
+        let count = self.batch_ids().len();
+        if count == 0 {
+            self.delete_selected();
+            return;
+        }
+        if self.refuse_if_read_only() {
+            return;
+        }
+        self.prompt = Some(Prompt {
+            message: format!(
+                "Move {} marked application{} to the trash?",
+                count,
+                if count == 1 { "" } else { "s" }
+            ),
+            action: PromptAction::DeleteMarked,
+        });
+    }
+
+    /// Move every marked application to the trash
+    fn delete_marked(&mut self) {
+        let ids = self.batch_ids();
+        for &id in &ids {
+            if let Some(index) = self.index_of(id) {
+                self.move_to_trash(index);
+            }
+        }
+        self.clear_marks();
+        self.clamp_selection();
+        let key = self.keymap.label(Action::Deleted);
+        self.info(format!("Moved {} applications to the trash ({}: trash)", ids.len(), key));
+    }
+
+    /// Move the selected application to the trash, where it can be restored
+    pub fn delete_selected(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+
+        let company = self.applications[index].company_name.clone();
+        self.move_to_trash(index);
+        self.clamp_selection();
+        let key = self.keymap.label(Action::Deleted);
+        self.info(format!("Moved {} to the trash ({}: trash)", company, key));
+    }
+
+    fn move_to_trash(&mut self, index: usize) {
+        let application = self.applications.remove(index);
+        self.trash.push(TrashedApplication {
+            deleted_at: chrono::Utc::now(),
+            position: index,
+            application,
+        });
+        self.mark_dirty();
+    }
+
+    /// Archive the selected application, or unarchive it if it already is.
+    /// Archived records stay in stats and charts but are hidden from the list unless shown.
+    pub fn toggle_archive_selected(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+
+        let application = &mut self.applications[index];
+        application.archived = !application.archived;
+        let message = if application.archived {
+            format!(
+                "Archived {} ({}: show archived)",
+                application.company_name,
+                self.keymap.label(Action::ShowArchived)
+            )
+        } else {
+            format!("Unarchived {}", application.company_name)
+        };
+        self.mark_dirty();
+        self.clamp_selection();
+        self.info(message);
+    }
+
+    /// Rate the selected application one star higher or lower, between not rated and `MAX_INTEREST`
+    pub fn change_interest(&mut self, up: bool) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+
+        let before = self.applications[index].clone();
+        let interest = if up {
+            before.interest.saturating_add(1).min(MAX_INTEREST)
+        } else {
+            before.interest.saturating_sub(1)
+        };
+        if interest == before.interest {
+            return;
+        }
+        self.applications[index].interest = interest;
+        self.mark_dirty();
+        // The row moves when the list is sorted by interest
+        self.select_record(index);
+        self.info(diff::save_message(Some(&before), &self.applications[index]));
+    }
+
+    /// Move the selected Planned application to Applied, dated today
+    pub fn promote_selected(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        if self.applications[index].status != Status::Planned {
+            self.info("Only Planned applications can be promoted");
+            return;
+        }
+
+        let today = chrono::Local::now().date_naive();
+        let proposed = self.proposed_follow_up(&Status::Applied, self.applications[index].follow_up);
+        let before = self.applications[index].clone();
+        let application = &mut self.applications[index];
+        application.set_status(Status::Applied, today);
+        application.applied_date = today;
+
+        if let Some(date) = proposed {
+            match self.config.follow_up.mode {
+                FollowUpMode::Set => self.applications[index].follow_up = Some(date),
+                FollowUpMode::Propose => {
+                    let id = self.applications[index].id;
+                    self.propose_follow_up(date, PromptAction::SetFollowUp(id, date))
+                }
+            }
+        }
+
+        self.mark_dirty();
+        // The row moves to its new date
+        self.select_record(index);
+        self.info(diff::save_message(Some(&before), &self.applications[index]));
+        self.fire_hooks(index, Some(before.status));
+    }
+
+    /// Open the trash view, or close it if it's open
+    pub fn toggle_trash(&mut self) {
+        if self.trash_picker.is_some() {
+            self.trash_picker = None;
+        } else if self.trash.is_empty() {
+            self.info("The trash is empty");
+        } else {
+            self.trash_picker = Some(0);
+        }
+    }
+
+    pub fn close_trash(&mut self) {
+        self.trash_picker = None;
+    }
+
+    /// Position in `trash` of the entry highlighted in the trash view
+    fn trash_position(&self) -> Option<usize> {
+        let selected = self.trash_picker?;
+        (selected < self.trash.len()).then(|| self.trash.len() - 1 - selected)
+    }
+
+    /// Put the highlighted entry back where it was in the list
+    pub fn restore_trashed(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(position) = self.trash_position() else {
+            return;
+        };
+        let index = self.restore(position);
+        let message = format!("Restored {}", self.applications[index].company_name);
+        self.select_record(index);
+        self.after_trash_change();
+        self.info(message);
+    }
+
+    /// Restore everything in the trash, most recent deletion first so positions line up
+    pub fn restore_all_trashed(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let count = self.trash.len();
+        while !self.trash.is_empty() {
+            self.restore(self.trash.len() - 1);
+        }
+        self.after_trash_change();
+        self.info(format!("Restored {} application(s)", count));
+    }
+
+    /// Move `trash[position]` back into the list, returning its index there
+    fn restore(&mut self, position: usize) -> usize {
+        let trashed = self.trash.remove(position);
+        let index = trashed.position.min(self.applications.len());
+        self.applications.insert(index, trashed.application);
+        self.mark_dirty();
+        index
+    }
+
+    /// Ask before deleting the highlighted entry for good
+    pub fn start_purge_trashed(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(position) = self.trash_position() else {
+            return;
+        };
+        let application = &self.trash[position].application;
+        self.prompt = Some(Prompt {
+            message: format!("Permanently delete {}? This can't be undone.", application.company_name),
+            action: PromptAction::PurgeTrashed(application.id),
+        });
+    }
+
+    fn purge_trashed(&mut self, id: Uuid) {
+        let Some(position) = self.trash.iter().position(|trashed| trashed.application.id == id) else {
+            return;
+        };
+        let trashed = self.trash.remove(position);
+        self.mark_dirty();
+        self.after_trash_change();
+        self.info(format!("Permanently deleted {}", trashed.application.company_name));
+    }
+
+    /// Keep the trash view's highlight in range, closing it once the trash is empty
+    fn after_trash_change(&mut self) {
+        self.trash_picker = match self.trash_picker {
+            _ if self.trash.is_empty() => None,
+            Some(selected) => Some(selected.min(self.trash.len() - 1)),
+            None => None,
+        };
+        self.clamp_selection();
+    }
+
+    /// Visible row index under a screen position in the list table, if any
+    pub fn list_row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.list_area;
+        // Top border, header row and its margin
+        let first_row = area.y + 3;
+        let last_row = area.bottom().saturating_sub(1);
+        if column <= area.x || column + 1 >= area.right() || row < first_row || row >= last_row {
+            return None;
+        }
+        let index = self.list_offset + (row - first_row) as usize;
+        (index < self.list_rows().len()).then_some(index)
+    }
+
+    /// Select the clicked row; a second click on it soon after opens the edit form,
+    /// or collapses or expands a company header
+    pub fn click_list_row(&mut self, index: usize) {
+        let now = Instant::now();
+        let double = self
+            .last_click
+            .is_some_and(|(at, row)| row == index && now.duration_since(at) <= DOUBLE_CLICK);
+
+        self.list_selected = index;
+        if double {
+            self.last_click = None;
+            if self.selected_group().is_some() {
+                self.toggle_selected_group();
+            } else {
+                self.start_edit();
+            }
+        } else {
+            self.last_click = Some((now, index));
+        }
+    }
+
+    /// Move list selection up
+    pub fn select_previous(&mut self) {
+        if self.list_selected > 0 {
+            self.list_selected -= 1;
+        }
+    }
+
+    /// Move list selection down
+    pub fn select_next(&mut self) {
+        if self.list_selected + 1 < self.list_rows().len() {
+            self.list_selected += 1;
+        }
+    }
+
+    /// Move list selection up by one page
+    pub fn select_page_up(&mut self) {
+        self.list_selected = self.list_selected.saturating_sub(self.list_page_size.max(1));
+    }
+
+    /// Move list selection down by one page
+    pub fn select_page_down(&mut self) {
+        let rows = self.list_rows().len();
+        if rows > 0 {
+            self.list_selected = (self.list_selected + self.list_page_size.max(1)).min(rows - 1);
+        }
+    }
+
+    /// Jump to the first application
+    pub fn select_first(&mut self) {
+        self.list_selected = 0;
+    }
+
+    /// Jump to the last application
+    pub fn select_last(&mut self) {
+        self.list_selected = self.list_rows().len().saturating_sub(1);
+    }
+
+    /// Open the selected application's posting URL in the browser
+    pub fn open_selected_url(&mut self) {
+        let Some(application) = self.selected_index().map(|idx| &self.applications[idx]) else {
+            self.info("No application selected");
+            return;
+        };
+
+        let Some(url) = application.url.clone() else {
+            let message = format!("No URL saved for {}", application.company_name);
+            self.info(message);
+            return;
+        };
+
+        match open::open(&url) {
+            Ok(()) => self.info(format!("Opened {}", url)),
+            Err(err) => self.error(format!("Could not open {}: {}", url, err)),
+        }
+    }
+
+    /// Open the selected application's resume file with the system's default handler
+    pub fn open_selected_resume(&mut self) {
+        let Some(application) = self.selected_index().map(|idx| &self.applications[idx]) else {
+            self.info("No application selected");
+            return;
+        };
+
+        let Some(path) = application.resume_path.clone() else {
+            let message = format!("No resume file saved for {}", application.company_name);
+            self.info(message);
+            return;
+        };
+
+        let shown = paths::contract_home(&path);
+        if !path.exists() {
+            self.error(format!("Resume file not found: {}", shown));
+            return;
+        }
+        match open::open(&path) {
+            Ok(()) => self.info(format!("Opened {}", shown)),
+            Err(err) => self.error(format!("Could not open {}: {}", shown, err)),
+        }
+    }
+
+    /// Switch to chart view
+    pub fn show_chart(&mut self) {
         self.view = View::Chart;
         self.chart_type = ChartType::ByResumeVersion;
     }
 
-    /// Switch to next chart type
-    pub fn next_chart(&mut self) {
-        self.chart_type = self.chart_type.next();
+    /// Write a Markdown report of the live applications to the current directory
+    pub fn write_report(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let path = report::default_path(today);
+        let content = report::markdown(self.applications.iter(), &self.status_order(), &self.platform_presets(), today);
+        match std::fs::write(&path, content) {
+            Ok(()) => self.info(format!("Report written to {}", path.display())),
+            Err(err) => self.error(format!("Could not write {}: {}", path.display(), err)),
+        }
+    }
+
+    /// Write the selected application's one-pager next to the data file
+    pub fn export_application(&mut self) {
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        let application = &self.applications[index];
+        let dir = self.storage.path().parent().unwrap_or(std::path::Path::new("."));
+        let path = dir.join(report::application_file_name(application));
+        match std::fs::write(&path, report::application_markdown(application)) {
+            Ok(()) => self.info(format!("Written to {}", path.display())),
+            Err(err) => self.error(format!("Could not write {}: {}", path.display(), err)),
+        }
+    }
+
+    /// Save the current chart as an SVG image next to the data file
+    pub fn export_chart(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let data = self.chart_data();
+        let Some(svg) = export::svg_chart(self.chart_type, self.chart_range, &data, &self.config.theme, self.config.charts.min_sample)
+        else {
+            self.info("No data to export");
+            return;
+        };
+        let dir = self.storage.path().parent().unwrap_or(std::path::Path::new("."));
+        let path = dir.join(export::chart_file_name(self.chart_type, today));
+        match std::fs::write(&path, svg) {
+            Ok(()) => self.info(format!("Chart written to {}", path.display())),
+            Err(err) => self.error(format!("Could not write {}: {}", path.display(), err)),
+        }
+    }
+
+    /// Write interviews and follow-ups to `jobtracker.ics` next to the data file
+    pub fn export_ical(&mut self) {
+        let events = ical::events(&self.applications);
+        if events.is_empty() {
+            self.info("No interviews or follow-ups to export");
+            return;
+        }
+        let dir = self.storage.path().parent().unwrap_or(std::path::Path::new("."));
+        let path = dir.join(ICAL_FILE);
+        match std::fs::write(&path, ical::calendar(&events, chrono::Utc::now())) {
+            Ok(()) => self.info(format!("{} event(s) written to {}", events.len(), path.display())),
+            Err(err) => self.error(format!("Could not write {}: {}", path.display(), err)),
+        }
+    }
+
+    /// Switch to next chart type
+    pub fn next_chart(&mut self) {
+        self.chart_type = self.chart_type.next();
+    }
+
+    /// Switch to previous chart type
+    pub fn prev_chart(&mut self) {
+        self.chart_type = self.chart_type.prev();
+    }
+
+    /// Cycle the applied dates the charts count: last 30 days, last 90 days, this year, all time
+    pub fn next_chart_range(&mut self) {
+        self.chart_range = self.chart_range.next();
+    }
+
+    pub fn prev_chart_range(&mut self) {
+        self.chart_range = self.chart_range.prev();
+    }
+
+    /// Switch to the linear, one-application-per-screen view
+    pub fn show_narrate(&mut self) {
+        self.view = View::Narrate;
+    }
+
+    /// Switch to the pipeline board, on the card of the selected application
+    pub fn show_board(&mut self) {
+        self.view = View::Board;
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        for (column, (_, cards)) in self.board_columns().into_iter().enumerate() {
+            if let Some(row) = cards.iter().position(|&card| card == index) {
+                self.board.column = column;
+                self.board.set_row(column, row);
+            }
+        }
+    }
+
+    /// The listed applications in a column per status, in `status_order`, most recently
+    /// applied first
+    pub fn board_columns(&self) -> Vec<(Status, Vec<usize>)> {
+        let visible = self.visible_indices();
+        self.status_order()
+            .into_iter()
+            .map(|status| {
+                let mut cards: Vec<usize> = visible
+                    .iter()
+                    .copied()
+                    .filter(|&idx| self.applications[idx].status == status)
+                    .collect();
+                cards.sort_by_key(|&idx| std::cmp::Reverse(self.applications[idx].applied_date));
+                (status, cards)
+            })
+            .collect()
+    }
+
+    /// Index into `applications` of the selected board card
+    pub fn board_selected_index(&self) -> Option<usize> {
+        let columns = self.board_columns();
+        let (_, cards) = columns.get(self.board.column)?;
+        cards.get(self.board.row(self.board.column).min(cards.len().saturating_sub(1))).copied()
+    }
+
+    /// Move to the column `step` columns to the right, or left when negative
+    pub fn move_board_column(&mut self, step: isize) {
+        let last = self.status_order().len().saturating_sub(1);
+        self.board.column = self.board.column.saturating_add_signed(step).min(last);
+    }
+
+    /// Move the selection `step` cards down the current column, or up when negative
+    pub fn move_board_row(&mut self, step: isize) {
+        let column = self.board.column;
+        let len = self.board_columns().get(column).map_or(0, |(_, cards)| cards.len());
+        let row = self.board.row(column).min(len.saturating_sub(1));
+        self.board.set_row(column, row.saturating_add_signed(step).min(len.saturating_sub(1)));
+    }
+
+    /// Move the selected card to the next status, or the previous one when `step` is negative,
+    /// and follow it there
+    pub fn move_board_card(&mut self, step: isize) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(index) = self.board_selected_index() else {
+            return;
+        };
+        let statuses = self.status_order();
+        let Some(column) = self.board.column.checked_add_signed(step).filter(|&column| column < statuses.len()) else {
+            return;
+        };
+        self.change_status(index, statuses[column].clone());
+
+        self.board.column = column;
+        let columns = self.board_columns();
+        if let Some(row) = columns[column].1.iter().position(|&card| card == index) {
+            self.board.set_row(column, row);
+        }
+    }
+
+    /// Leave the board for the list, selecting the application of the selected card
+    pub fn close_board(&mut self) {
+        if let Some(index) = self.board_selected_index() {
+            self.select_record(index);
+        }
+        self.show_list();
+    }
+
+    /// Return to list view
+    pub fn show_list(&mut self) {
+        self.view = View::List;
+    }
+
+    /// Switch to the dashboard, keeping the section and row it was left on where they still exist
+    pub fn show_dashboard(&mut self) {
+        self.view = View::Dashboard;
+        let len = self.dashboard_data().len(self.dashboard.section);
+        self.dashboard.row = self.dashboard.row.min(len.saturating_sub(1));
+    }
+
+    /// Applications sent as of `stats_day`, counted once per generation. The one weekly count
+    /// behind the list title and summary line, the dashboard and the today summary, whatever
+    /// the list filters.
+    pub fn activity(&self) -> Activity {
+        self.stats.activity(self.generation, || {
+            stats::activity(&self.applications, self.stats_day, self.config.week_starts_on)
+        })
+    }
+
+    /// What the dashboard shows, counted once per generation
+    pub fn dashboard_data(&self) -> DashboardData {
+        self.stats.dashboard(self.generation, || {
+            let today = self.stats_day;
+            let horizon = today + chrono::Days::new(digest::DAYS);
+            let live: Vec<(usize, &Application)> = self
+                .applications
+                .iter()
+                .enumerate()
+                .filter(|(_, application)| !application.archived)
+                .collect();
+
+            let mut interviews: Vec<DashboardEntry> = live
+                .iter()
+                .flat_map(|&(index, application)| {
+                    application
+                        .interviews
+                        .iter()
+                        .filter(|interview| interview.date >= today && interview.date <= horizon)
+                        .map(move |interview| DashboardEntry {
+                            index,
+                            date: interview.date,
+                            detail: interview.kind.as_str().to_string(),
+                        })
+                })
+                .collect();
+            interviews.sort_by_key(|entry| entry.date);
+
+            let mut follow_ups: Vec<DashboardEntry> = live
+                .iter()
+                .filter_map(|&(index, application)| {
+                    let date = application.follow_up.filter(|date| *date <= today)?;
+                    let detail = match (today - date).num_days() {
+                        0 => "today".to_string(),
+                        days => format!("{}d overdue", days),
+                    };
+                    Some(DashboardEntry { index, date, detail })
+                })
+                .collect();
+            follow_ups.sort_by_key(|entry| entry.date);
+
+            let mut sent: Vec<&(usize, &Application)> =
+                live.iter().filter(|(_, application)| application.status != Status::Planned).collect();
+            // Stable, so of those applied the same day the last added comes first
+            sent.reverse();
+            sent.sort_by_key(|(_, application)| std::cmp::Reverse(application.applied_date));
+            let recent = sent
+                .into_iter()
+                .take(DASHBOARD_RECENT)
+                .map(|&(index, application)| DashboardEntry {
+                    index,
+                    date: application.applied_date,
+                    detail: application.status.as_str().to_string(),
+                })
+                .collect();
+
+            DashboardData {
+                goal: self.goal_progress(),
+                pipeline: stats::by_status(live.iter().map(|&(_, application)| application), &self.status_order()),
+                interviews,
+                follow_ups,
+                recent,
+            }
+        })
+    }
+
+    /// Focus the next dashboard section, or the previous one when `step` is negative
+    pub fn move_dashboard_section(&mut self, step: isize) {
+        self.dashboard.section = self.dashboard.section.step(step);
+        self.dashboard.row = 0;
+    }
+
+    /// Move the selection `step` rows down the focused section, or up when negative
+    pub fn move_dashboard_row(&mut self, step: isize) {
+        let last = self.dashboard_data().len(self.dashboard.section).saturating_sub(1);
+        self.dashboard.row = self.dashboard.row.saturating_add_signed(step).min(last);
+    }
+
+    /// Follow the selected dashboard row: a pipeline status lists its applications, any
+    /// other row opens its application in read mode
+    pub fn open_dashboard_row(&mut self) {
+        let data = self.dashboard_data();
+        let row = self.dashboard.row;
+        match data.entries(self.dashboard.section) {
+            None => {
+                let Some((status, _)) = data.pipeline.get(row) else {
+                    return;
+                };
+                // Cleared so the list shows what the dashboard counted
+                self.work_mode_filter = None;
+                self.attention_filter = false;
+                self.search_query.clear();
+                self.set_status_filter(Some(status.clone()));
+                self.show_list();
+            }
+            Some(entries) => {
+                let Some(entry) = entries.get(row) else {
+                    return;
+                };
+                self.reveal_record(entry.index);
+                self.show_narrate();
+            }
+        }
+    }
+
+    /// Select `applications[index]` in the list, first clearing any filter, search or
+    /// collapsed group that hides it
+    fn reveal_record(&mut self, index: usize) {
+        if !self.list_rows().iter().any(|row| row.index() == Some(index)) {
+            self.status_filter = None;
+            self.work_mode_filter = None;
+            self.attention_filter = false;
+            self.search_query.clear();
+            self.show_archived |= self.applications[index].archived;
+            self.collapsed_groups.remove(&group_key(&self.applications[index].company_name));
+        }
+        self.select_record(index);
+    }
+
+    /// Move to next form field
+    pub fn next_field(&mut self) {
+        self.leave_field();
+        self.form_field = self.form_field.next();
+    }
+
+    /// Move to previous form field
+    pub fn prev_field(&mut self) {
+        self.leave_field();
+        self.form_field = self.form_field.prev();
+    }
+
+    /// Resolve typed input in the field losing focus, and drop its error once it's fixed
+    fn leave_field(&mut self) {
+        if self.form_field == FormField::Date {
+            self.resolve_date_input();
+        } else if self.field_error(self.form_field).is_some() {
+            self.set_field_error(self.form_field, self.check_field(self.form_field));
+        }
+    }
+
+    /// Turn the typed date into the applied date, or show why it can't be.
+    /// Returns whether the input was understood.
+    fn resolve_date_input(&mut self) -> bool {
+        let today = chrono::Local::now().date_naive();
+        match dates::parse_date_input(&self.date_input, today) {
+            Ok(date) => {
+                self.form_data.applied_date = date;
+                self.date_input = date.to_string();
+                self.set_field_error(FormField::Date, None);
+                true
+            }
+            Err(err) => {
+                self.set_field_error(FormField::Date, Some(err.to_string()));
+                false
+            }
+        }
+    }
+
+    /// Set the form status, applying the configured follow-up interval if it changed
+    pub fn set_form_status(&mut self, status: Status) {
+        if self.form_data.status == status {
+            return;
+        }
+        let proposed = self.proposed_follow_up(&status, self.form_data.follow_up);
+        if status == Status::Rejected {
+            self.open_rejection_picker(None);
+        } else {
+            self.form_data.rejection_reason = None;
+        }
+        self.form_data.status = status;
+
+        let Some(date) = proposed else {
+            return;
+        };
+
+        match self.config.follow_up.mode {
+            FollowUpMode::Set => self.form_data.follow_up = Some(date),
+            FollowUpMode::Propose => self.propose_follow_up(date, PromptAction::SetFormFollowUp(date)),
+        }
+    }
+
+    /// Ask why `ids` were rejected, or the application in the form when None,
+    /// starting from the reason already recorded
+    fn open_rejection_picker(&mut self, ids: Option<Vec<Uuid>>) {
+        let current = match &ids {
+            None => self.form_data.rejection_reason,
+            Some(ids) => ids.first().and_then(|&id| self.index_of(id)).and_then(|idx| self.applications[idx].rejection_reason),
+        };
+        let selected = current
+            .and_then(|reason| RejectionReason::all().iter().position(|r| *r == reason))
+            .unwrap_or(0);
+        self.rejection_picker = Some(RejectionPicker { ids, selected });
+    }
+
+    /// Leave the reason unspecified
+    pub fn close_rejection_picker(&mut self) {
+        self.rejection_picker = None;
+    }
+
+    /// Record the reason highlighted in the rejection picker
+    pub fn apply_rejection_picker(&mut self) {
+        let Some(picker) = self.rejection_picker.take() else {
+            return;
+        };
+        let reason = RejectionReason::all()[picker.selected];
+        let Some(ids) = picker.ids else {
+            self.form_data.rejection_reason = Some(reason);
+            return;
+        };
+        for application in self.applications.iter_mut().filter(|application| ids.contains(&application.id)) {
+            application.rejection_reason = Some(reason);
+        }
+        self.mark_dirty();
+        self.info(format!("Rejection reason: {}", reason.as_str()));
+    }
+
+    /// Open the quick status picker for the selected application
+    pub fn open_status_picker(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let Some(application) = self.selected_index().map(|idx| &self.applications[idx]) else {
+            self.info("No application selected");
+            return;
+        };
+
+        let current = self
+            .status_order()
+            .iter()
+            .position(|s| *s == application.status)
+            .unwrap_or(0);
+        self.status_picker = Some(current);
     }
 
-    /// Return to list view
-    pub fn show_list(&mut self) {
-        self.view = View::List;
+    /// Open the picker choosing which field of the selected application to copy
+    pub fn open_copy_picker(&mut self) {
+        if self.selected_index().is_none() {
+            self.info("No application selected");
+            return;
+        }
+        self.copy_picker = Some(0);
     }
 
-    /// Move to next form field
-    pub fn next_field(&mut self) {
-        self.form_field = self.form_field.next();
+    pub fn close_copy_picker(&mut self) {
+        self.copy_picker = None;
     }
 
-    /// Move to previous form field
-    pub fn prev_field(&mut self) {
-        self.form_field = self.form_field.prev();
+    /// Copy the field chosen in the copy picker and close it
+    pub fn copy_picked_field(&mut self) {
+        let Some(field) = self.copy_picker.take().and_then(|idx| CopyField::all().get(idx)) else {
+            return;
+        };
+        let Some(index) = self.selected_index() else {
+            return;
+        };
+        let Some(text) = field.value(&self.applications[index]).map(str::to_string) else {
+            self.info(format!("{} is empty", field.label()));
+            return;
+        };
+        match self.clipboard.copy(&text) {
+            Ok(Copied::System) => self.info(format!("{} copied", field.label())),
+            Ok(Copied::Terminal) => self.info(format!("{} sent to the terminal clipboard", field.label())),
+            Err(err) => self.error(format!("Could not copy {}: {}", field.label().to_lowercase(), err)),
+        }
+    }
+
+    /// Open the picker that shows and hides list columns
+    pub fn open_column_picker(&mut self) {
+        self.column_picker = Some(0);
+    }
+
+    /// Show or hide the column selected in the column picker
+    pub fn toggle_picker_column(&mut self) {
+        let Some(column) = self.column_picker.and_then(|idx| Column::all().get(idx)) else {
+            return;
+        };
+        if !columns::toggle(&mut self.list_columns, *column) {
+            self.info("The last column can't be hidden");
+        }
+    }
+
+    /// Close the column picker, writing a changed layout to the config file
+    pub fn close_column_picker(&mut self) -> Result<()> {
+        self.column_picker = None;
+        let specs = columns::specs(&self.list_columns);
+        if columns::layout(&self.config.list.columns)? == self.list_columns {
+            return Ok(());
+        }
+        config::save_list_columns(&specs).context("Could not save the column layout")?;
+        self.config.list.columns = specs;
+        self.info("Column layout saved to the config file");
+        Ok(())
+    }
+
+    /// Close the quick status picker without changing anything
+    pub fn close_status_picker(&mut self) {
+        self.status_picker = None;
+    }
+
+    /// Apply the status chosen in the quick status picker and save
+    pub fn apply_status_picker(&mut self) -> Result<()> {
+        let Some(selected) = self.status_picker.take() else {
+            return Ok(());
+        };
+        let Some(status) = self.status_order().get(selected).cloned() else {
+            return Ok(());
+        };
+        let batch = self.batch_ids();
+        if !batch.is_empty() {
+            self.apply_status_to_batch(&batch, status);
+            return Ok(());
+        }
+        if let Some(index) = self.selected_index() {
+            self.change_status(index, status);
+        }
+        Ok(())
+    }
+
+    /// Change the status of `applications[index]`, asking for a rejection reason and applying
+    /// the configured follow-up interval
+    fn change_status(&mut self, index: usize, status: Status) {
+        let proposed = self.proposed_follow_up(&status, self.applications[index].follow_up);
+        let application = &mut self.applications[index];
+
+        if application.status == status {
+            return;
+        }
+        let before = application.clone();
+        application.set_status(status.clone(), chrono::Local::now().date_naive());
+        if status == Status::Rejected {
+            self.open_rejection_picker(Some(vec![before.id]));
+        }
+
+        if let Some(date) = proposed {
+            match self.config.follow_up.mode {
+                FollowUpMode::Set => self.applications[index].follow_up = Some(date),
+                FollowUpMode::Propose => {
+                    let id = self.applications[index].id;
+                    self.propose_follow_up(date, PromptAction::SetFollowUp(id, date))
+                }
+            }
+        }
+
+        self.mark_dirty();
+        self.info(diff::save_message(Some(&before), &self.applications[index]));
+        self.fire_hooks(index, Some(before.status));
+        self.clamp_selection();
+    }
+
+    /// Change the status of every application in `ids` and clear the marks.
+    /// Follow-ups are only applied in "set" mode; one proposal per record would be too many prompts.
+    fn apply_status_to_batch(&mut self, ids: &[Uuid], status: Status) {
+        // Records that were already rejected keep their own reason
+        let newly_rejected: Vec<Uuid> = ids
+            .iter()
+            .copied()
+            .filter(|&id| self.index_of(id).is_some_and(|idx| self.applications[idx].status != Status::Rejected))
+            .collect();
+        let changed = self.set_status_of(ids, &status);
+        if status == Status::Rejected && !newly_rejected.is_empty() {
+            self.open_rejection_picker(Some(newly_rejected));
+        }
+        self.clear_marks();
+        self.clamp_selection();
+        self.info(format!("Changed {} of {} marked to {}", changed, ids.len(), status.as_str()));
+    }
+
+    /// Live applications still in Applied past the ghosting threshold, archived ones left out
+    pub fn ghosted_ids(&self) -> Vec<Uuid> {
+        let today = chrono::Local::now().date_naive();
+        self.applications.iter()
+            .filter(|application| !application.archived && self.config.ghosting.is_ghosted(application, today))
+            .map(|application| application.id)
+            .collect()
+    }
+
+    /// Ask before marking every ghosted application with the configured ghosting status
+    pub fn confirm_mark_ghosted(&mut self) {
+        if self.refuse_if_read_only() {
+            return;
+        }
+        let count = self.ghosted_ids().len();
+        if count == 0 {
+            self.info(format!("No applications in Applied for {} or more", self.config.ghosting.after));
+            return;
+        }
+        self.prompt = Some(Prompt {
+            message: format!(
+                "Mark {} application{} in Applied for {} or more as {}?",
+                count,
+                if count == 1 { "" } else { "s" },
+                self.config.ghosting.after,
+                self.config.ghosting.status().as_str()
+            ),
+            action: PromptAction::MarkGhosted,
+        });
+    }
+
+    fn mark_ghosted(&mut self) {
+        let ids = self.ghosted_ids();
+        let status = self.config.ghosting.status();
+        let changed = self.set_status_of(&ids, &status);
+        // Ghosted is the one rejection whose reason is known without asking
+        if status == Status::Rejected {
+            for application in self.applications.iter_mut().filter(|application| ids.contains(&application.id)) {
+                application.rejection_reason.get_or_insert(RejectionReason::NoResponse);
+            }
+        }
+        self.clamp_selection();
+        self.info(format!(
+            "Marked {} ghosted application{} as {}",
+            changed,
+            if changed == 1 { "" } else { "s" },
+            status.as_str()
+        ));
+    }
+
+    /// Change the status of the applications with `ids`, returning how many changed
+    fn set_status_of(&mut self, ids: &[Uuid], status: &Status) -> usize {
+        let today = chrono::Local::now().date_naive();
+        let mut changed = 0;
+        for &id in ids {
+            let Some(index) = self.index_of(id) else {
+                continue;
+            };
+            if self.applications[index].status == *status {
+                continue;
+            }
+            let proposed = self.proposed_follow_up(status, self.applications[index].follow_up);
+            let application = &mut self.applications[index];
+            let before = application.status.clone();
+            application.set_status(status.clone(), today);
+            if self.config.follow_up.mode == FollowUpMode::Set {
+                application.follow_up = proposed.or(application.follow_up);
+            }
+            self.fire_hooks(index, Some(before));
+            changed += 1;
+        }
+
+        if changed > 0 {
+            self.mark_dirty();
+        }
+        changed
+    }
+
+    /// Run the configured hooks for a saved application.
+    /// `before` is its status before the save, or `None` if it was just added.
+    fn fire_hooks(&self, index: usize, before: Option<Status>) {
+        let application = &self.applications[index];
+        match before {
+            None => self.hooks.fire(HookEvent::Added(application)),
+            Some(from) if from != application.status => {
+                self.hooks.fire(HookEvent::StatusChanged { application, from: &from })
+            }
+            Some(_) => return,
+        }
+        if application.status == Status::Offer {
+            self.hooks.fire(HookEvent::OfferReceived(application));
+        }
+    }
+
+    /// Show failures of hooks that finished in the background
+    fn poll_hooks(&mut self) {
+        for finished in self.hooks.finished() {
+            if let Err(err) = finished.result {
+                self.error(format!("Hook {} failed: {:#}", finished.hook, err));
+            }
+        }
+    }
+
+    /// Follow-up date configured for a status change, if one should be applied
+    fn proposed_follow_up(&self, status: &Status, current: Option<NaiveDate>) -> Option<NaiveDate> {
+        let today = chrono::Local::now().date_naive();
+        self.config.follow_up.propose(status, current, today)
+    }
+
+    fn propose_follow_up(&mut self, date: NaiveDate, action: PromptAction) {
+        self.prompt = Some(Prompt {
+            message: format!("Set follow-up to {}?", date),
+            action,
+        });
+    }
+
+    /// Apply the action of the open prompt
+    pub fn confirm_prompt(&mut self) -> Result<()> {
+        if let Some(prompt) = self.prompt.take() {
+            match prompt.action {
+                PromptAction::SetFormFollowUp(date) => self.form_data.follow_up = Some(date),
+                PromptAction::SetFollowUp(id, date) => {
+                    if let Some(application) = self.applications.iter_mut().find(|a| a.id == id) {
+                        application.follow_up = Some(date);
+                        self.mark_dirty();
+                    }
+                }
+                PromptAction::AddDuplicate => self.commit_form()?,
+                PromptAction::SaveDespiteWarnings => self.save_checked_form()?,
+                PromptAction::DiscardForm => self.close_form(),
+                PromptAction::DiscardFormAndQuit => {
+                    self.close_form();
+                    self.quit()?
+                }
+                PromptAction::DeleteMarked => self.delete_marked(),
+                PromptAction::PurgeTrashed(id) => self.purge_trashed(id),
+                PromptAction::MarkGhosted => self.mark_ghosted(),
+                PromptAction::QuitWithoutSaving => self.finish_quit()?,
+                PromptAction::KeepMine(id) => self.resolve_conflict(id, true),
+            }
+        }
+        Ok(())
+    }
+
+    /// Dismiss the open prompt without applying it; for a conflict that means taking the file's version
+    pub fn dismiss_prompt(&mut self) {
+        if let Some(Prompt { action: PromptAction::KeepMine(id), .. }) = self.prompt.take() {
+            self.resolve_conflict(id, false);
+        }
+    }
+
+    /// Insert a character (or newline) at the notes cursor
+    pub fn notes_insert(&mut self, c: char) {
+        self.edit_notes(|buffer| buffer.insert(c));
+    }
+
+    /// Delete the grapheme before the notes cursor, e.g. a whole emoji or an accented letter
+    pub fn notes_backspace(&mut self) {
+        self.edit_notes(|buffer| buffer.delete_back());
+    }
+
+    /// Apply an edit or motion to the notes at the notes cursor
+    pub fn edit_notes(&mut self, edit: impl FnOnce(&mut TextBuffer)) {
+        let mut buffer = TextBuffer::new(&mut self.form_data.notes, self.notes_cursor);
+        edit(&mut buffer);
+        self.notes_cursor = buffer.cursor();
+    }
+
+    pub fn notes_cursor_left(&mut self) {
+        self.notes_cursor = text::previous_boundary(&self.form_data.notes, self.notes_cursor);
+    }
+
+    pub fn notes_cursor_right(&mut self) {
+        self.notes_cursor = text::next_boundary(&self.form_data.notes, self.notes_cursor);
+    }
+
+    /// Move the notes cursor up one line; returns false when already on the first line
+    pub fn notes_cursor_up(&mut self) -> bool {
+        let (line, column) = self.notes_cursor_position();
+        if line == 0 {
+            return false;
+        }
+        self.notes_cursor = self.notes_cursor_at(line - 1, column);
+        true
+    }
+
+    /// Move the notes cursor down one line; returns false when already on the last line
+    pub fn notes_cursor_down(&mut self) -> bool {
+        let (line, column) = self.notes_cursor_position();
+        if line + 1 >= self.form_data.notes.split('\n').count() {
+            return false;
+        }
+        self.notes_cursor = self.notes_cursor_at(line + 1, column);
+        true
+    }
+
+    /// Line and column (in graphemes) of the notes cursor
+    pub fn notes_cursor_position(&self) -> (usize, usize) {
+        let before: String = self.form_data.notes.chars().take(self.notes_cursor).collect();
+        let line = before.matches('\n').count();
+        let column = text::grapheme_count(before.rsplit('\n').next().unwrap_or_default());
+        (line, column)
+    }
+
+    /// Character index of grapheme `column` on `line`, clamped to the line's length
+    fn notes_cursor_at(&self, line: usize, column: usize) -> usize {
+        let lines: Vec<&str> = self.form_data.notes.split('\n').collect();
+        let line_start: usize = lines[..line].iter().map(|l| l.chars().count() + 1).sum();
+        line_start + text::grapheme_to_char(lines[line], column)
+    }
+
+    /// Progress towards the configured goal in the current period
+    pub fn goal_progress(&self) -> Option<GoalProgress> {
+        let today = chrono::Local::now().date_naive();
+        self.config
+            .goal
+            .progress(self.applications.iter(), today, self.config.week_starts_on)
+    }
+
+    /// Summarize every application to the company of the selected row
+    pub fn open_company_popup(&mut self) {
+        let company = match self.list_rows().get(self.list_selected) {
+            Some(ListRow::Group(group)) => group.company.clone(),
+            Some(row) => self.applications[row.index().unwrap_or_default()].company_name.clone(),
+            None => return,
+        };
+        if let Some(summary) = stats::company_summary(&company, &self.applications) {
+            self.company_popup = Some(CompanyPopup { summary, scroll: 0 });
+        }
+    }
+
+    pub fn close_company_popup(&mut self) {
+        self.company_popup = None;
+    }
+
+    /// Scroll the company popup by `lines`, up when negative; the popup stops at its last line
+    pub fn scroll_company_popup(&mut self, lines: i16) {
+        if let Some(popup) = self.company_popup.as_mut() {
+            popup.scroll = popup.scroll.saturating_add_signed(lines);
+        }
+    }
+
+    /// Show how many applications went out today and this week
+    pub fn show_today_summary(&mut self) {
+        let activity = self.activity();
+        self.today_summary = Some(TodaySummary {
+            today: activity.today,
+            this_week: activity.this_week,
+            goal: self.goal_progress(),
+            last_company: activity.last_company,
+            shown_at: Instant::now(),
+        });
+    }
+
+    /// Whether the data file was written by something else since it was last read or saved
+    pub fn file_changed(&self) -> bool {
+        self.corrupt.is_none() && self.storage.modified() != self.disk_modified
+    }
+
+    /// Merge in the changes made to the data file outside the app
+    pub fn on_file_changed(&mut self) {
+        if let Err(err) = self.reload_if_changed() {
+            // Not retried until the file changes again
+            self.disk_modified = self.storage.modified();
+            self.error(format!("Could not reload {}: {:#}", self.storage.path().display(), err));
+        }
+        self.prompt_next_conflict();
+    }
+
+    /// Periodic work: report hooks that failed in the background, ask about conflicting
+    /// records, save pending changes and expire timed overlays and messages
+    pub fn on_tick(&mut self) {
+        self.poll_hooks();
+        let today = chrono::Local::now().date_naive();
+        if today != self.stats_day {
+            self.stats_day = today;
+            self.data_changed();
+        }
+        self.prompt_next_conflict();
+        self.autosave();
+        if self
+            .today_summary
+            .as_ref()
+            .is_some_and(|summary| summary.shown_at.elapsed() >= Duration::from_secs(TODAY_SUMMARY_SECS))
+        {
+            self.today_summary = None;
+        }
+        if self.jump.is_some() && self.active_jump().is_none() {
+            self.jump = None;
+        }
+        if self
+            .message
+            .as_ref()
+            .is_some_and(|(_, _, shown_at)| shown_at.elapsed() >= Duration::from_secs(MESSAGE_SECS))
+        {
+            self.message = None;
+        }
+    }
+
+    /// Show a message in the status bar until the next key press or for a few seconds
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), MessageKind::Info, Instant::now()));
+    }
+
+    /// Show an error in the status bar; it outlasts key presses so there is time to read it
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), MessageKind::Error, Instant::now()));
+    }
+
+    /// Quit the application.
+    /// If the final save fails, asks whether to quit anyway instead of exiting.
+    pub fn quit(&mut self) -> Result<()> {
+        if self.dirty {
+            if let Err(err) = self.save() {
+                self.prompt = Some(Prompt {
+                    message: format!("Could not save: {:#}. Quit without saving?", err),
+                    action: PromptAction::QuitWithoutSaving,
+                });
+                return Ok(());
+            }
+        }
+        // The save found records changed in the file too; they're asked about first
+        if !self.conflicts.is_empty() {
+            self.prompt_next_conflict();
+            return Ok(());
+        }
+        self.finish_quit()
+    }
+
+    /// Ctrl+C: quit from anywhere, asking first if the form has unsaved edits.
+    /// A second Ctrl+C dismisses the question.
+    pub fn interrupt(&mut self) -> Result<()> {
+        if self.prompt.is_some() {
+            self.dismiss_prompt();
+            return Ok(());
+        }
+        if self.view == View::Form && self.form_has_changes() {
+            self.prompt = Some(Prompt {
+                message: "Discard changes and quit?".to_string(),
+                action: PromptAction::DiscardFormAndQuit,
+            });
+            return Ok(());
+        }
+        self.quit()
     }
 
-    /// Quit the application
-    pub fn quit(&mut self) {
+    /// Stop the event loop; failing to write usage stats doesn't keep the app open
+    fn finish_quit(&mut self) -> Result<()> {
         self.should_quit = true;
+        self.usage.finish()
+    }
+}
+
+/// Position of a work mode in the form dropdown
+/// Applications whose company names match apart from case and surrounding spaces share a group
+fn group_key(company: &str) -> String {
+    company.trim().to_lowercase()
+}
+
+/// Furthest status in `reached`: the latest one before Rejected in `order`, or when none
+/// got that far, the earliest of the rest, so a group is only Rejected if all of it was
+fn best_status(order: &[Status], reached: &[&Status]) -> Status {
+    let rejected = order.iter().position(|status| *status == Status::Rejected).unwrap_or(order.len());
+    let rank = |status: &Status| match order.iter().position(|s| s == status) {
+        Some(position) if position < rejected => position as isize,
+        Some(position) => -(position as isize) - 1,
+        None => isize::MIN,
+    };
+    reached
+        .iter()
+        .copied()
+        .max_by_key(|status| rank(status))
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn work_mode_index(mode: WorkMode) -> usize {
+    WorkMode::all().iter().position(|m| *m == mode).unwrap_or(0)
+}
+
+/// Trim optional text, treating blank input as unset
+fn trimmed(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{JsonStorage, DATA_FILE};
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn application(company: &str) -> Application {
+        Application {
+            company_name: company.to_string(),
+            applied_date: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+            ..Application::new()
+        }
+    }
+
+    /// App over `applications`, saving to a data file in a fresh directory
+    fn app_with(applications: Vec<Application>) -> (TempDir, App) {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::new(dir.path().join(DATA_FILE));
+        let data = Data { applications, ..Data::default() };
+        let app = App::with_data(Config::default(), Box::new(storage), data, Vec::new()).unwrap();
+        (dir, app)
+    }
+
+    fn app(companies: &[&str]) -> (TempDir, App) {
+        app_with(companies.iter().map(|company| application(company)).collect())
+    }
+
+    fn selected_company(app: &App) -> Option<&str> {
+        app.selected_index().map(|index| app.applications[index].company_name.as_str())
+    }
+
+    /// Give a file written by "another process" a modification time the app hasn't seen,
+    /// even where timestamps are too coarse for two writes in a row to differ
+    fn set_modified_long_ago(path: &Path) {
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(std::time::UNIX_EPOCH).unwrap();
+    }
+
+    #[test]
+    fn deleting_the_last_row_selects_the_new_last() {
+        let (_dir, mut app) = app(&["Acme", "Globex", "Initech"]);
+        app.list_selected = 2;
+        app.delete_selected();
+        assert_eq!(app.list_selected, 1);
+        assert_eq!(selected_company(&app), Some("Globex"));
+    }
+
+    #[test]
+    fn deleting_the_only_row_leaves_nothing_to_edit() {
+        let (_dir, mut app) = app(&["Acme"]);
+        app.delete_selected();
+        assert_eq!(app.list_selected, 0);
+        assert_eq!(app.selected_index(), None);
+        app.start_edit();
+        assert_eq!(app.view, View::List);
+        assert_eq!(app.form_mode, None);
+    }
+
+    #[test]
+    fn editing_after_an_external_reload_opens_a_remaining_record() {
+        let (dir, mut app) = app(&["Acme", "Globex", "Initech"]);
+        app.list_selected = 2;
+        let acme = app.applications[0].clone();
+        let path = dir.path().join(DATA_FILE);
+        JsonStorage::new(&path).save(&[acme], &[]).unwrap();
+        set_modified_long_ago(&path);
+
+        app.reload_if_changed().unwrap();
+        assert_eq!(app.applications.len(), 1);
+        assert_eq!(app.list_selected, 0);
+        app.start_edit();
+        assert_eq!(app.view, View::Form);
+        assert_eq!(app.form_data.company_name, "Acme");
+    }
+
+    #[test]
+    fn filtering_keeps_the_selection_on_a_visible_row() {
+        let (_dir, mut app) = app(&["Acme", "Globex", "Initech"]);
+        app.applications[0].status = Status::Interview;
+        app.list_selected = 2;
+        app.set_status_filter(Some(Status::Interview));
+        assert_eq!(selected_company(&app), Some("Acme"));
+    }
+
+    /// What's in the data file now
+    fn on_disk(dir: &TempDir) -> Data {
+        JsonStorage::new(dir.path().join(DATA_FILE)).load().unwrap()
+    }
+
+    #[test]
+    fn added_edited_and_deleted_records_reach_the_data_file() {
+        let (dir, mut app) = app(&["Acme"]);
+        app.save().unwrap();
+
+        app.start_add();
+        app.form_data.company_name = "Globex".to_string();
+        app.form_data.job_title = Some("  Engineer ".to_string());
+        let applied = chrono::Local::now().date_naive() - chrono::Days::new(2);
+        app.date_input = applied.to_string();
+        app.save_form().unwrap();
+        assert_eq!(app.view, View::List);
+        assert!(app.dirty);
+        app.save().unwrap();
+        assert!(!app.dirty);
+        let data = on_disk(&dir);
+        let globex = &data.applications[1];
+        assert_eq!(globex.company_name, "Globex");
+        assert_eq!(globex.job_title.as_deref(), Some("Engineer"));
+        assert_eq!(globex.applied_date, applied);
+
+        assert_eq!(selected_company(&app), Some("Globex"));
+        app.start_edit();
+        app.form_data.status = Status::Interview;
+        app.save_form().unwrap();
+        app.save().unwrap();
+        let globex = &on_disk(&dir).applications[1];
+        assert_eq!(globex.status, Status::Interview);
+        assert_eq!(globex.status_history.last().map(|change| &change.status), Some(&Status::Interview));
+
+        app.delete_selected();
+        app.save().unwrap();
+        let data = on_disk(&dir);
+        assert_eq!(data.applications.len(), 1);
+        assert_eq!(data.trash.len(), 1);
+        assert_eq!(data.trash[0].application.company_name, "Globex");
+        assert_eq!(data.trash[0].position, 1);
+    }
+
+    #[test]
+    fn saved_data_reopens_unchanged() {
+        let (dir, mut app) = app(&["Acme", "Globex"]);
+        app.list_selected = 1;
+        app.delete_selected();
+        app.save().unwrap();
+
+        let storage = JsonStorage::new(dir.path().join(DATA_FILE));
+        let reopened = App::with_data(Config::default(), Box::new(storage), on_disk(&dir), Vec::new()).unwrap();
+        assert_eq!(reopened.applications, app.applications);
+        assert_eq!(reopened.trash, app.trash);
+        assert!(!reopened.dirty);
+    }
+
+    /// Run the autosave as if `SAVE_INTERVAL` had passed since the last save
+    fn autosave_now(app: &mut App) {
+        app.last_save = Instant::now().checked_sub(SAVE_INTERVAL).unwrap_or(app.last_save);
+        app.autosave();
+    }
+
+    fn companies(applications: &[Application]) -> Vec<&str> {
+        applications.iter().map(|application| application.company_name.as_str()).collect()
+    }
+
+    #[test]
+    fn deleted_then_restored_records_are_back_in_place_after_quitting() {
+        let (dir, mut app) = app(&["Acme", "Globex", "Initech"]);
+        app.list_selected = 1;
+        app.delete_selected();
+        assert_eq!(app.list_summary().total, 2);
+
+        // Autosaved while deleted: in the file's trash, not its applications
+        autosave_now(&mut app);
+        assert!(!app.dirty);
+        let data = on_disk(&dir);
+        assert_eq!(companies(&data.applications), ["Acme", "Initech"]);
+        assert_eq!(data.trash.len(), 1);
+
+        app.toggle_trash();
+        app.restore_trashed();
+        assert_eq!(app.trash_picker, None);
+        assert_eq!(selected_company(&app), Some("Globex"));
+        app.quit().unwrap();
+        assert!(app.should_quit);
+        let data = on_disk(&dir);
+        assert_eq!(companies(&data.applications), ["Acme", "Globex", "Initech"]);
+        assert!(data.trash.is_empty());
+    }
+
+    #[test]
+    fn restoring_everything_after_an_autosave_keeps_the_original_order() {
+        let (dir, mut app) = app(&["Acme", "Globex", "Initech", "Hooli"]);
+        app.list_selected = 3;
+        app.delete_selected();
+        app.list_selected = 0;
+        app.delete_selected();
+        autosave_now(&mut app);
+        assert_eq!(companies(&on_disk(&dir).applications), ["Globex", "Initech"]);
+
+        app.restore_all_trashed();
+        autosave_now(&mut app);
+        app.quit().unwrap();
+        let data = on_disk(&dir);
+        assert_eq!(companies(&data.applications), ["Acme", "Globex", "Initech", "Hooli"]);
+        assert!(data.trash.is_empty());
+    }
+
+    #[test]
+    fn records_deleted_before_quitting_can_be_restored_next_session() {
+        let (dir, mut app) = app(&["Acme", "Globex"]);
+        app.delete_selected();
+        autosave_now(&mut app);
+        app.quit().unwrap();
+        let data = on_disk(&dir);
+        assert_eq!(companies(&data.applications), ["Globex"]);
+        assert_eq!(data.trash.len(), 1);
+
+        let storage = JsonStorage::new(dir.path().join(DATA_FILE));
+        let mut app = App::with_data(Config::default(), Box::new(storage), data, Vec::new()).unwrap();
+        app.toggle_trash();
+        app.restore_trashed();
+        app.quit().unwrap();
+        let data = on_disk(&dir);
+        assert_eq!(companies(&data.applications), ["Acme", "Globex"]);
+        assert!(data.trash.is_empty());
+    }
+
+    #[test]
+    fn quitting_without_pending_changes_leaves_the_file_alone() {
+        let (dir, mut app) = app(&["Acme"]);
+        app.delete_selected();
+        autosave_now(&mut app);
+        let path = dir.path().join(DATA_FILE);
+        set_modified_long_ago(&path);
+        app.quit().unwrap();
+        assert!(app.should_quit);
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+        assert_eq!(modified, std::time::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn a_form_that_fails_validation_saves_nothing() {
+        let (dir, mut app) = app(&["Acme"]);
+        app.save().unwrap();
+        app.start_add();
+        app.save_form().unwrap();
+        assert_eq!(app.view, View::Form);
+        assert_eq!(app.form_field, FormField::CompanyName);
+        assert_eq!(app.applications.len(), 1);
+        assert!(!app.dirty);
+        assert_eq!(on_disk(&dir).applications.len(), 1);
+    }
+
+    #[test]
+    fn changes_made_by_another_process_are_merged_before_saving() {
+        let (dir, mut app) = app(&["Acme"]);
+        app.save().unwrap();
+        let mut data = on_disk(&dir);
+        data.applications.push(application("Initech"));
+        let path = dir.path().join(DATA_FILE);
+        JsonStorage::new(&path).save(&data.applications, &data.trash).unwrap();
+        set_modified_long_ago(&path);
+
+        app.start_add();
+        app.form_data.company_name = "Globex".to_string();
+        app.save_form().unwrap();
+        app.save().unwrap();
+        let companies: Vec<String> = on_disk(&dir).applications.into_iter().map(|a| a.company_name).collect();
+        assert_eq!(companies, ["Acme", "Initech", "Globex"]);
+    }
+
+    #[test]
+    fn navigating_a_large_list_reuses_the_cached_stats() {
+        let (_dir, mut app) = app_with((0..10_000).map(|i| application(&format!("Company {}", i))).collect());
+        app.list_page_size = 20;
+        let summary = app.list_summary();
+        let chart = app.chart_data();
+        let dashboard = app.dashboard_data();
+        let activity = app.activity();
+        assert_eq!(summary.total, 10_000);
+
+        // Changed behind the cache's back: anything re-aggregated would count the offer
+        app.applications[0].status = Status::Offer;
+        for _ in 0..100 {
+            app.select_next();
+        }
+        while app.list_selected + 1 < 10_000 {
+            app.select_page_down();
+            assert_eq!(app.list_summary(), summary);
+            assert_eq!(app.chart_data(), chart);
+        }
+        app.select_first();
+        app.select_last();
+        assert_eq!(app.list_summary(), summary);
+        assert_eq!(app.dashboard_data(), dashboard);
+        assert_eq!(app.activity(), activity);
+
+        // A change that goes through the app is counted
+        app.mark_dirty();
+        let offers = app.list_summary().statuses.into_iter().find(|(status, _)| *status == Status::Offer);
+        assert_eq!(offers, Some((Status::Offer, 1)));
+    }
+
+    /// Wednesday 2024-05-15, in a week starting Monday the 13th
+    fn summary_app() -> (TempDir, App) {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 5, d).unwrap();
+        let (dir, mut app) = app_with(vec![
+            Application { applied_date: day(10), ..application("Hooli") },
+            Application { applied_date: day(13), ..application("Globex") },
+            Application { applied_date: day(15), ..application("Acme") },
+            Application { applied_date: day(15), archived: true, ..application("Umbrella") },
+            Application { applied_date: day(15), status: Status::Planned, ..application("Initech") },
+        ]);
+        app.stats_day = day(15);
+        (dir, app)
+    }
+
+    #[test]
+    fn today_summary_counts_sent_applications_including_archived() {
+        let (_dir, mut app) = summary_app();
+        app.show_today_summary();
+        let summary = app.today_summary.clone().unwrap();
+        assert_eq!(summary.today, 2);
+        assert_eq!(summary.this_week, 3);
+        assert_eq!(summary.last_company.as_deref(), Some("Umbrella"));
+    }
+
+    #[test]
+    fn today_summary_reads_the_cached_counts() {
+        let (_dir, mut app) = summary_app();
+        let activity = app.activity();
+
+        // Changed behind the cache's back: a recount would see a fourth application this week
+        app.applications[0].applied_date = app.stats_day;
+        app.show_today_summary();
+        assert_eq!(app.today_summary.as_ref().unwrap().this_week, activity.this_week);
+
+        app.mark_dirty();
+        app.show_today_summary();
+        assert_eq!(app.today_summary.as_ref().unwrap().this_week, 4);
+        assert_eq!(app.today_summary.as_ref().unwrap().today, 3);
+    }
+
+    #[test]
+    fn today_summary_leaves_the_records_and_view_alone() {
+        let (dir, mut app) = summary_app();
+        app.list_selected = 2;
+        let applications = app.applications.clone();
+        let generation = app.generation;
+
+        app.show_today_summary();
+        assert!(app.today_summary.is_some());
+        assert_eq!(app.applications, applications);
+        assert_eq!(app.generation, generation);
+        assert_eq!(app.view, View::List);
+        assert_eq!(app.list_selected, 2);
+        assert!(!app.dirty);
+        assert!(!dir.path().join(DATA_FILE).exists());
+    }
+
+
+    /// App opened on an unreadable data file, on the recovery screen, with `backup` as the
+    /// newest backup if given
+    fn corrupt_app(backup: Option<&str>) -> (TempDir, App) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DATA_FILE);
+        if let Some(backup) = backup {
+            std::fs::write(crate::storage::backup_path(&path, 1), backup).unwrap();
+        }
+        let acme = serde_json::to_string(&application("Acme")).unwrap();
+        std::fs::write(&path, format!("{{ \"version\": 3, \"applications\": [{}, {{ \"company_name\": ", acme)).unwrap();
+        let app = App::from_storage(Config::default(), Box::new(JsonStorage::new(&path))).unwrap();
+        (dir, app)
     }
+
+    /// Files in `dir` whose names start with `prefix`
+    fn files_starting(dir: &TempDir, prefix: &str) -> usize {
+        std::fs::read_dir(dir.path())
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().file_name().to_string_lossy().starts_with(prefix))
+            .count()
+    }
+
+    #[test]
+    fn opening_a_storage_loads_its_data_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("elsewhere.json");
+        JsonStorage::new(&path).save(&[application("Acme")], &[]).unwrap();
+
+        let mut app = App::from_storage(Config::default(), Box::new(JsonStorage::new(&path))).unwrap();
+        assert!(app.corrupt.is_none());
+        assert_eq!(selected_company(&app), Some("Acme"));
+        app.delete_selected();
+        app.quit().unwrap();
+        let data = JsonStorage::new(&path).load().unwrap();
+        assert!(data.applications.is_empty());
+        assert_eq!(data.trash.len(), 1);
+        assert!(!dir.path().join(DATA_FILE).exists());
+    }
+
+    #[test]
+    fn starting_from_the_recovered_records_sets_the_bad_file_aside() {
+        let (dir, mut app) = corrupt_app(None);
+        assert_eq!(app.corrupt.as_ref().unwrap().lost, 1);
+        app.recover_corrupt(true);
+        assert!(app.corrupt.is_none());
+        assert_eq!(selected_company(&app), Some("Acme"));
+        assert_eq!(files_starting(&dir, "applications.json.corrupt-"), 1);
+
+        app.quit().unwrap();
+        assert_eq!(on_disk(&dir).applications.len(), 1);
+        // The file was set aside, so there was nothing to back up
+        assert_eq!(files_starting(&dir, "applications.json.bak-"), 0);
+    }
+
+    #[test]
+    fn opening_the_newest_backup_starts_from_its_records() {
+        let backup = serde_json::json!({ "version": 3, "applications": [application("Globex"), application("Initech")] });
+        let (dir, mut app) = corrupt_app(Some(&backup.to_string()));
+        app.open_corrupt_backup();
+        assert!(app.corrupt.is_none());
+        assert_eq!(app.applications.len(), 2);
+        assert_eq!(selected_company(&app), Some("Globex"));
+        assert_eq!(files_starting(&dir, "applications.json.corrupt-"), 1);
+
+        app.quit().unwrap();
+        let companies: Vec<String> = on_disk(&dir).applications.into_iter().map(|a| a.company_name).collect();
+        assert_eq!(companies, ["Globex", "Initech"]);
+        // Still there to go back to
+        assert_eq!(std::fs::read_to_string(crate::storage::backup_path(&dir.path().join(DATA_FILE), 1)).unwrap(), backup.to_string());
+    }
+
+    #[test]
+    fn an_unreadable_backup_leaves_the_recovery_screen_up() {
+        let (dir, mut app) = corrupt_app(Some("[{ \"company_name\": "));
+        app.open_corrupt_backup();
+        assert!(app.corrupt.is_some());
+        assert!(app.applications.is_empty());
+        assert!(matches!(&app.message, Some((text, MessageKind::Error, _)) if text.starts_with("Could not open the backup")));
+        assert!(dir.path().join(DATA_FILE).exists());
+        assert_eq!(files_starting(&dir, "applications.json.corrupt-"), 0);
+    }
+
+    #[test]
+    fn without_a_backup_there_is_nothing_to_open() {
+        let (dir, mut app) = corrupt_app(None);
+        assert_eq!(app.corrupt.as_ref().unwrap().backup, None);
+        app.open_corrupt_backup();
+        assert!(app.corrupt.is_some());
+        assert!(dir.path().join(DATA_FILE).exists());
+    }
+
 }
@@ -0,0 +1,187 @@
+use crate::dates;
+use crate::models::{Application, Status};
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use uuid::Uuid;
+
+/// How long an application may go without news in a status before it needs attention, from
+/// the `[attention]` config section. Statuses without an entry are never flagged.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AttentionConfig {
+    pub thresholds: BTreeMap<String, String>,
+}
+
+impl Default for AttentionConfig {
+    fn default() -> Self {
+        let thresholds = [("Interview", "7d"), ("Offer", "3d")]
+            .into_iter()
+            .map(|(status, threshold)| (status.to_string(), threshold.to_string()))
+            .collect();
+        Self { thresholds }
+    }
+}
+
+impl AttentionConfig {
+    /// Quiet time allowed in a status, if it has a threshold
+    pub fn threshold(&self, status: &Status) -> Option<Duration> {
+        self.thresholds
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(status.as_str()))
+            .and_then(|(_, threshold)| dates::parse_duration(threshold).ok())
+    }
+}
+
+/// Why an application needs attention
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// Nothing has happened for `days` days since it moved into `status`, past `threshold`
+    Quiet { status: Status, days: i64, threshold: i64 },
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Quiet { status, days, .. } => {
+                write!(f, "{} for {}d with no activity", status.as_str(), days)
+            }
+        }
+    }
+}
+
+/// One check of a single application; returns why it needs attention, if it does
+type Rule = fn(&Application, &AttentionConfig, NaiveDate) -> Option<Reason>;
+
+/// Checks run on every application, in order; the first that fires gives the reason
+const RULES: &[Rule] = &[quiet_in_status];
+
+/// Live applications that need attention as of `today`, by ID, in list order.
+/// Archived records are left out.
+pub fn needs_attention(applications: &[Application], config: &AttentionConfig, today: NaiveDate) -> Vec<(Uuid, Reason)> {
+    applications
+        .iter()
+        .filter(|application| !application.archived)
+        .filter_map(|application| {
+            RULES
+                .iter()
+                .find_map(|rule| rule(application, config, today))
+                .map(|reason| (application.id, reason))
+        })
+        .collect()
+}
+
+/// In a status with a threshold for longer than it allows, counting from the latest of the
+/// move into the status, activity entries and interview rounds (a round still to come counts
+/// as news)
+fn quiet_in_status(application: &Application, config: &AttentionConfig, today: NaiveDate) -> Option<Reason> {
+    let threshold = config.threshold(&application.status)?.num_days();
+    let entered = application
+        .status_history
+        .iter()
+        .rev()
+        .find(|change| change.status == application.status)
+        .map_or(application.applied_date, |change| change.date);
+    let activity = application.activity.iter().map(|entry| entry.date);
+    let rounds = application.interviews.iter().map(|interview| interview.date);
+    let last = activity.chain(rounds).fold(entered, NaiveDate::max);
+    let days = (today - last).num_days();
+    (days > threshold).then(|| Reason::Quiet {
+        status: application.status.clone(),
+        days,
+        threshold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ActivityEntry, Interview, InterviewKind, StatusChange};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    /// Applied on May 1st and moved into `status` on `entered`
+    fn in_status(id: u128, status: Status, entered: u32) -> Application {
+        Application {
+            id: Uuid::from_u128(id),
+            status: status.clone(),
+            status_history: vec![StatusChange { status, date: date(entered) }],
+            applied_date: date(1),
+            ..Application::new()
+        }
+    }
+
+    fn flagged(applications: &[Application], config: &AttentionConfig, today: NaiveDate) -> Vec<u128> {
+        needs_attention(applications, config, today).into_iter().map(|(id, _)| id.as_u128()).collect()
+    }
+
+    #[test]
+    fn flags_once_the_threshold_has_passed() {
+        let config = AttentionConfig::default();
+        let interview = [in_status(1, Status::Interview, 3)];
+        assert_eq!(flagged(&interview, &config, date(10)), Vec::<u128>::new());
+        let reasons = needs_attention(&interview, &config, date(11));
+        let expected = Reason::Quiet { status: Status::Interview, days: 8, threshold: 7 };
+        assert_eq!(reasons, [(Uuid::from_u128(1), expected)]);
+        assert_eq!(reasons[0].1.to_string(), "Interview for 8d with no activity");
+    }
+
+    #[test]
+    fn statuses_without_a_threshold_are_never_flagged() {
+        let applied = [in_status(1, Status::Applied, 1)];
+        assert_eq!(flagged(&applied, &AttentionConfig::default(), date(31)), Vec::<u128>::new());
+    }
+
+    #[test]
+    fn counts_from_the_latest_move_into_the_status() {
+        let mut offer = in_status(1, Status::Offer, 2);
+        offer.status_history.push(StatusChange { status: Status::Interview, date: date(4) });
+        offer.status_history.push(StatusChange { status: Status::Offer, date: date(8) });
+        assert_eq!(flagged(&[offer], &AttentionConfig::default(), date(11)), Vec::<u128>::new());
+
+        // Without any history, from the applied date
+        let quiet = Application { status_history: Vec::new(), ..in_status(2, Status::Offer, 1) };
+        assert_eq!(flagged(&[quiet], &AttentionConfig::default(), date(5)), [2]);
+    }
+
+    #[test]
+    fn activity_and_interview_rounds_reset_the_clock() {
+        let config = AttentionConfig::default();
+        let mut noted = in_status(1, Status::Interview, 1);
+        noted.activity.push(ActivityEntry { date: date(6), text: "Recruiter emailed".to_string() });
+        let mut upcoming = in_status(2, Status::Interview, 1);
+        upcoming.interviews.push(Interview { kind: InterviewKind::Onsite, date: date(20), notes: String::new() });
+        let silent = in_status(3, Status::Interview, 1);
+        assert_eq!(flagged(&[noted, upcoming, silent], &config, date(12)), [3]);
+    }
+
+    #[test]
+    fn archived_applications_are_left_out() {
+        let archived = Application { archived: true, ..in_status(1, Status::Interview, 1) };
+        assert_eq!(flagged(&[archived], &AttentionConfig::default(), date(31)), Vec::<u128>::new());
+    }
+
+    #[test]
+    fn thresholds_match_statuses_ignoring_case() {
+        let thresholds = [("ghosted", "2w"), ("applied", "3"), ("offer", "soon")];
+        let config = AttentionConfig {
+            thresholds: thresholds.into_iter().map(|(status, t)| (status.to_string(), t.to_string())).collect(),
+        };
+        assert_eq!(config.threshold(&Status::Other("Ghosted".to_string())), Some(Duration::weeks(2)));
+        assert_eq!(config.threshold(&Status::Applied), Some(Duration::days(3)));
+        // Unreadable thresholds and statuses without one never flag
+        assert_eq!(config.threshold(&Status::Offer), None);
+        assert_eq!(config.threshold(&Status::Interview), None);
+
+        let applications = [
+            in_status(1, Status::Other("Ghosted".to_string()), 1),
+            in_status(2, Status::Applied, 1),
+            in_status(3, Status::Offer, 1),
+        ];
+        assert_eq!(flagged(&applications, &config, date(10)), [2]);
+        assert_eq!(flagged(&applications, &config, date(16)), [1, 2]);
+    }
+}
@@ -0,0 +1,815 @@
+use crate::app::ChartType;
+use crate::config::{self, Config, StorageLayout};
+use crate::dates;
+use crate::digest;
+use crate::export;
+use crate::ical;
+use crate::keymap::Action;
+use crate::lock::{self, Lock};
+use crate::models::{self, Application, Status, WorkMode};
+use crate::mutation::Mutation;
+use crate::narrate;
+use crate::notify;
+use crate::preset;
+use crate::report;
+use crate::storage::{self, Data, Import, Storage};
+use crate::usage::UsageStats;
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::cmp::Reverse;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// Command-line arguments; without a subcommand the TUI starts
+#[derive(Debug, Parser)]
+#[command(name = "jobtracker", about = "Track job applications from the terminal")]
+pub struct Cli {
+    /// Skip interactive confirmations
+    #[arg(long, global = true)]
+    pub yes: bool,
+
+    /// Data file; `.db`, `.sqlite` or `.sqlite3` uses SQLite (imports applications.json on first use)
+    #[arg(long, visible_alias = "file", global = true, default_value = storage::DATA_FILE)]
+    pub db: PathBuf,
+
+    /// Add applications from LinkedIn's applied-jobs CSV export; the same as `import-linkedin`
+    #[arg(long, value_name = "PATH")]
+    pub import_linkedin: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub start: StartArgs,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Where the TUI opens, instead of the unfiltered list
+#[derive(Debug, Args)]
+pub struct StartArgs {
+    /// Open in this view
+    #[arg(long, value_enum)]
+    pub view: Option<StartView>,
+
+    /// Open on this chart, named as in the chart title bar, e.g. `platform` or `status-by-platform`
+    #[arg(long, value_parser = parse_chart)]
+    pub chart: Option<ChartType>,
+
+    /// Open with the list filtered, as `status=<status>` or `work-mode=<mode>`; can be repeated
+    #[arg(long, value_name = "KEY=VALUE", value_parser = parse_filter)]
+    pub filter: Vec<StartFilter>,
+
+    /// Open on the form for a new application
+    #[arg(long, conflicts_with_all = ["view", "chart"])]
+    pub add: bool,
+}
+
+impl StartArgs {
+    /// Whether any flag was given, which only makes sense when starting the TUI
+    pub fn is_set(&self) -> bool {
+        self.view.is_some() || self.chart.is_some() || !self.filter.is_empty() || self.add
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StartView {
+    List,
+    Chart,
+    Board,
+    /// Read mode, one application per screen
+    Read,
+    Dashboard,
+}
+
+/// List filter given with `--filter`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartFilter {
+    /// Checked against the configured statuses once they're loaded
+    Status(String),
+    WorkMode(WorkMode),
+}
+
+fn parse_chart(name: &str) -> Result<ChartType, String> {
+    ChartType::all()
+        .iter()
+        .copied()
+        .find(|chart| chart.slug().eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| {
+            let names: Vec<String> = ChartType::all().iter().map(ChartType::slug).collect();
+            format!("expected one of: {}", names.join(", "))
+        })
+}
+
+fn parse_filter(spec: &str) -> Result<StartFilter, String> {
+    let Some((key, value)) = spec.split_once('=') else {
+        return Err("expected KEY=VALUE, e.g. status=interview".to_string());
+    };
+    match key.trim().to_ascii_lowercase().replace('_', "-").as_str() {
+        "status" => Ok(StartFilter::Status(value.trim().to_string())),
+        "work-mode" => WorkMode::from_name(value)
+            .filter(|mode| *mode != WorkMode::Unknown)
+            .map(StartFilter::WorkMode)
+            .ok_or_else(|| "expected a work mode of remote, hybrid or onsite".to_string()),
+        other => Err(format!("unknown filter '{}' (expected status or work-mode)", other)),
+    }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Print applications
+    List {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ListFormat::Plain)]
+        format: ListFormat,
+    },
+    /// Move every application for a company to the trash
+    Delete {
+        /// Company name (case-insensitive)
+        #[arg(long)]
+        company: String,
+
+        /// Print what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Change the status of matching applications
+    BulkStatus {
+        /// New status
+        #[arg(long)]
+        to: String,
+
+        /// Only change applications currently in this status
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Only change applications for this company (case-insensitive)
+        #[arg(long)]
+        company: Option<String>,
+
+        /// Print what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Merge applications with the same company and job title (ignoring case) into the one
+    /// added first; the duplicates go to the trash
+    Merge {
+        /// Only merge applications for this company (case-insensitive)
+        #[arg(long)]
+        company: Option<String>,
+
+        /// Print what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export applications to a file
+    Export {
+        /// Output format
+        #[arg(long, value_enum)]
+        format: ExportFormat,
+
+        /// Output file (defaults to stdout)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+
+        /// Replace company names with placeholders and omit notes and contacts
+        #[arg(long)]
+        anonymize: bool,
+    },
+    /// Write interviews and follow-up dates to an iCalendar file, as `export --format ical` does
+    ExportIcal {
+        /// Calendar file to write
+        path: PathBuf,
+    },
+    /// Add applications from LinkedIn's applied-jobs CSV export, skipping ones already tracked
+    ImportLinkedin {
+        /// CSV file exported from LinkedIn
+        path: PathBuf,
+
+        /// Print what would be added without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Add applications piped in on stdin, as a JSON array or one JSON object per line, skipping
+    /// ones already tracked. Fails when any record can't be read, after adding the rest.
+    Import {
+        /// How the input is laid out (defaults to an array if it starts with `[`)
+        #[arg(long, value_enum)]
+        format: Option<ImportFormat>,
+
+        /// Print what would be added without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Write a Markdown report with totals, breakdowns and active applications
+    Report {
+        /// Output file (defaults to report-YYYY-MM-DD.md)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Summarize the last week: applications sent, status changes, upcoming interviews,
+    /// follow-ups due and conversion
+    Digest {
+        /// First day covered, in any form the date fields accept (defaults to 6 days ago)
+        #[arg(long, allow_hyphen_values = true)]
+        since: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = DigestFormat::Text)]
+        format: DigestFormat,
+
+        /// Output file (defaults to stdout)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Show local usage metrics (requires `[usage] enabled = true`)
+    Usage,
+    /// Remind about interviews today or tomorrow and overdue follow-ups with a desktop
+    /// notification, printing instead when none can be shown; silent when there's nothing due
+    Notify,
+    /// Copy the JSON data into the file or directory layout and switch the `[storage] layout`
+    /// setting to it; the old copy is left in place
+    Migrate {
+        /// Layout to move to
+        #[arg(long, value_enum)]
+        to: LayoutArg,
+    },
+    /// Share settings and key bindings between machines
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Write the config file as a preset (never includes applications)
+    Export {
+        /// Preset file to write
+        path: PathBuf,
+    },
+    /// Apply a preset, asking before replacing each section
+    Import {
+        /// Preset file to read
+        path: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ListFormat {
+    /// One tab-separated line per application
+    Plain,
+    /// Screen-reader-friendly sentences
+    Narrate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LayoutArg {
+    /// Everything in one JSON file
+    File,
+    /// One JSON file per application plus an index, for keeping the data in git
+    Directory,
+}
+
+impl LayoutArg {
+    fn layout(self) -> StorageLayout {
+        match self {
+            LayoutArg::File => StorageLayout::File,
+            LayoutArg::Directory => StorageLayout::Directory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Static HTML kanban board
+    HtmlBoard,
+    /// iCalendar file with an all-day event per interview round and follow-up
+    Ical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ImportFormat {
+    /// One JSON array of objects
+    Json,
+    /// One JSON object per line
+    Jsonl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DigestFormat {
+    /// Markdown, for pasting into a journal
+    Md,
+    /// Plain text
+    Text,
+    /// JSON, for scripts
+    Json,
+}
+
+/// Run a CLI subcommand
+pub fn run(command: Command, yes: bool, db: &Path, storage: &dyn Storage) -> Result<()> {
+    if let Command::Config { command } = command {
+        return run_config(command, yes);
+    }
+    if let Command::Migrate { to } = command {
+        return migrate(db, to.layout());
+    }
+    let mut data = storage.load()?;
+    for file in &data.unreadable {
+        eprintln!("Skipped unreadable file {}", file);
+    }
+    models::assign_missing_ids(&mut data.applications);
+    let applications = &data.applications;
+    let config = Config::load()?;
+    let statuses = config.status_order(applications);
+
+    // Records on stdin leave no way to answer a confirmation
+    let yes = yes || matches!(command, Command::Import { .. });
+    let mut failed = 0;
+    let (mutation, dry_run) = match command {
+        Command::List { format } => {
+            print_list(applications, &statuses, format);
+            return Ok(());
+        }
+        Command::Export { format, output, anonymize } => {
+            return run_export(applications, &statuses, format, output, anonymize);
+        }
+        Command::ExportIcal { path } => {
+            return run_export(applications, &statuses, ExportFormat::Ical, Some(path), false);
+        }
+        Command::Report { output } => {
+            return write_report(applications, &statuses, &config.platform_presets(), output)
+        }
+        Command::Digest { since, format, output } => return run_digest(applications, since, format, output),
+        Command::Usage => return print_usage(applications),
+        Command::Notify => {
+            let today = chrono::Local::now().date_naive();
+            notify::send(&notify::reminders(applications, today), today);
+            return Ok(());
+        }
+        Command::Config { .. } | Command::Migrate { .. } => unreachable!("handled before loading applications"),
+        Command::Delete { company, dry_run } => {
+            let indices = matching(applications, |a| same_company(a, &company));
+            (Mutation::Delete(indices), dry_run)
+        }
+        Command::ImportLinkedin { path, dry_run } => {
+            let import = storage::import_linkedin_csv(&path, applications)?;
+            print_import_summary(&import, "row");
+            (Mutation::Add(import.applications), dry_run)
+        }
+        Command::Import { format, dry_run } => {
+            let text = io::read_to_string(io::stdin()).context("Failed to read stdin")?;
+            let array = match format {
+                Some(ImportFormat::Json) => true,
+                Some(ImportFormat::Jsonl) => false,
+                None => text.trim_start().starts_with('['),
+            };
+            let import = storage::import_json(&text, array, applications, &statuses, &config.platform_presets())?;
+            print_import_summary(&import, "record");
+            failed = import.errors.len();
+            (Mutation::Add(import.applications), dry_run)
+        }
+        Command::BulkStatus { to, from, company, dry_run } => {
+            let status = parse_status(&to, &statuses)?;
+            let from = from.as_deref().map(|name| parse_status(name, &statuses)).transpose()?;
+            let indices = matching(applications, |a| {
+                from.as_ref().is_none_or(|s| a.status == *s)
+                    && company.as_deref().is_none_or(|c| same_company(a, c))
+            });
+            (Mutation::SetStatus { indices, status }, dry_run)
+        }
+        Command::Merge { company, dry_run } => {
+            let indices = matching(applications, |a| company.as_deref().is_none_or(|c| same_company(a, c)));
+            (Mutation::Merge(duplicates(applications, &indices)), dry_run)
+        }
+    };
+
+    apply_mutation(mutation, dry_run, yes, db, storage, &mut data)?;
+    if failed > 0 {
+        bail!("{} record(s) could not be imported", failed);
+    }
+    Ok(())
+}
+
+/// Show what `mutation` changes and, unless it's a dry run, write it after confirmation
+fn apply_mutation(
+    mutation: Mutation,
+    dry_run: bool,
+    yes: bool,
+    db: &Path,
+    storage: &dyn Storage,
+    data: &mut Data,
+) -> Result<()> {
+    let report = mutation.simulate(&data.applications);
+    if report.is_empty() {
+        println!("Nothing to change.");
+        return Ok(());
+    }
+
+    print!("{}", report);
+    if dry_run {
+        println!("Dry run: {} record(s) would change, nothing written.", report.len());
+        return Ok(());
+    }
+
+    // The TUI would overwrite the change on its next save
+    let _lock = match lock::acquire(db)? {
+        Lock::Owned(lock) => lock,
+        Lock::HeldBy(pid) => bail!("{} is open in another jobtracker (PID {}); close it first", db.display(), pid),
+    };
+
+    if !yes && !confirm(&format!("Apply {} change(s)?", report.len()))? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let report = mutation.apply(data);
+    storage.save(&data.applications, &data.trash)?;
+    println!("{} record(s) changed.", report.len());
+    Ok(())
+}
+
+fn print_list(applications: &[Application], statuses: &[Status], format: ListFormat) {
+    match format {
+        ListFormat::Plain => {
+            for application in applications {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    application.company_name,
+                    application.platform.as_str(),
+                    application.status.as_str(),
+                    application.applied_date
+                );
+            }
+        }
+        ListFormat::Narrate => {
+            let today = chrono::Local::now().date_naive();
+            for sentence in narrate::summary(applications, statuses) {
+                println!("{}", sentence);
+            }
+            for application in applications {
+                println!("{}", narrate::describe(application, today));
+            }
+        }
+    }
+}
+
+/// Counts of what an import found, then each record that couldn't be read on stderr
+fn print_import_summary(import: &Import, noun: &str) {
+    println!(
+        "Read {} {}(s): {} new, {} already tracked, {} with errors.",
+        import.rows(),
+        noun,
+        import.applications.len(),
+        import.skipped,
+        import.errors.len()
+    );
+    for error in &import.errors {
+        eprintln!("  {}", error);
+    }
+}
+
+fn print_usage(applications: &[Application]) -> Result<()> {
+    if !Config::load()?.usage.enabled {
+        println!("Usage metrics are off. Set `enabled = true` under [usage] in the config file to collect them.");
+        return Ok(());
+    }
+
+    let stats = UsageStats::load()?;
+    let mut summary = format!(
+        "You've logged {} application(s) across {} session(s)",
+        applications.len(),
+        stats.sessions
+    );
+    if let Some((action, _)) = stats.top_action() {
+        summary.push_str(&format!("; most-used action: {}", action.description()));
+    }
+    println!("{}.", summary);
+
+    if !stats.view_seconds.is_empty() {
+        println!("\nTime per view:");
+        for (view, seconds) in &stats.view_seconds {
+            println!("  {:<8} {}", view, format_seconds(*seconds));
+        }
+    }
+
+    let mut actions: Vec<(Action, u64)> = stats
+        .actions
+        .iter()
+        .filter_map(|(name, count)| Action::from_name(name).map(|action| (action, *count)))
+        .collect();
+    actions.sort_by_key(|(_, count)| Reverse(*count));
+    if !actions.is_empty() {
+        println!("\nActions:");
+        for (action, count) in actions {
+            println!("  {:<20} {}", action.description(), count);
+        }
+    }
+    Ok(())
+}
+
+/// "2h 05m", "12m", "40s"
+fn format_seconds(seconds: u64) -> String {
+    match (seconds / 3600, seconds % 3600 / 60) {
+        (0, 0) => format!("{}s", seconds),
+        (0, minutes) => format!("{}m", minutes),
+        (hours, minutes) => format!("{}h {:02}m", hours, minutes),
+    }
+}
+
+fn run_export(
+    applications: &[Application],
+    statuses: &[Status],
+    format: ExportFormat,
+    output: Option<PathBuf>,
+    anonymize: bool,
+) -> Result<()> {
+    let anonymized;
+    let applications = if anonymize {
+        anonymized = export::anonymize(applications);
+        &anonymized
+    } else {
+        applications
+    };
+
+    let today = chrono::Local::now().date_naive();
+    let content = match format {
+        ExportFormat::HtmlBoard => export::html_board(applications, statuses, today),
+        ExportFormat::Ical => ical::calendar(&ical::events(applications), chrono::Utc::now()),
+    };
+
+    match output {
+        Some(path) => {
+            fs::write(&path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Exported to {}", path.display());
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
+/// Copy the data at `db` from the other JSON layout into `to` and make `to` the configured layout
+fn migrate(db: &Path, to: StorageLayout) -> Result<()> {
+    if storage::is_sqlite(db) {
+        bail!("{} is a SQLite database; only JSON data can change layout", db.display());
+    }
+    let from = match to {
+        StorageLayout::File => StorageLayout::Directory,
+        StorageLayout::Directory => StorageLayout::File,
+    };
+    let source = storage::open(db, from)?;
+    let target = storage::open(db, to)?;
+    if !source.path().exists() {
+        bail!("No data at {} to migrate", source.path().display());
+    }
+    if target.path().exists() {
+        bail!("{} already exists; move it aside first", target.path().display());
+    }
+
+    // The TUI would go on saving to the old layout
+    let _lock = match lock::acquire(db)? {
+        Lock::Owned(lock) => lock,
+        Lock::HeldBy(pid) => bail!("{} is open in another jobtracker (PID {}); close it first", db.display(), pid),
+    };
+
+    let mut data = source.load()?;
+    if !data.unreadable.is_empty() {
+        bail!(
+            "Some files in {} can't be read; fix or remove them first:\n  {}",
+            source.path().display(),
+            data.unreadable.join("\n  ")
+        );
+    }
+    models::assign_missing_ids(&mut data.applications);
+    target.save(&data.applications, &data.trash)?;
+    config::save_storage_layout(to)?;
+    println!(
+        "Copied {} application(s) from {} to {} and set `layout = \"{}\"` under [storage]. {} is left as it was and can be removed.",
+        data.applications.len(),
+        source.path().display(),
+        target.path().display(),
+        to.as_str(),
+        source.path().display()
+    );
+    Ok(())
+}
+
+fn run_config(command: ConfigCommand, yes: bool) -> Result<()> {
+    let config_path = config::config_path().ok_or_else(|| anyhow!("Could not locate the config directory"))?;
+    match command {
+        ConfigCommand::Export { path } => {
+            if !config_path.exists() {
+                bail!("No config file at {} to export", config_path.display());
+            }
+            // Refuse to share a config that wouldn't load
+            Config::load()?;
+            let preset = preset::export(&read_toml(&config_path)?)?;
+            fs::write(&path, preset).with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Exported preset to {}", path.display());
+        }
+        ConfigCommand::Import { path } => import_preset(&path, &config_path, yes)?,
+    }
+    Ok(())
+}
+
+fn import_preset(path: &Path, config_path: &Path, yes: bool) -> Result<()> {
+    let content = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let preset = preset::parse(&content).with_context(|| format!("Invalid preset {}", path.display()))?;
+
+    let mut config = if config_path.exists() {
+        read_toml(config_path)?
+    } else {
+        toml::Table::new()
+    };
+
+    let mut imported = Vec::new();
+    for section in preset::sections(&preset) {
+        let label = if section == preset::GENERAL {
+            "general settings".to_string()
+        } else {
+            format!("[{}]", section)
+        };
+        if yes || confirm(&format!("Replace {}?", label))? {
+            preset::apply(&mut config, &preset, &section);
+            imported.push(label);
+        }
+    }
+    if imported.is_empty() {
+        println!("Nothing imported.");
+        return Ok(());
+    }
+
+    let content = toml::to_string_pretty(&config)?;
+    Config::parse(&content).context("The preset would leave the config invalid; nothing was changed")?;
+
+    if config_path.exists() {
+        let backup = config_path.with_extension("toml.bak");
+        fs::copy(config_path, &backup)
+            .with_context(|| format!("Failed to back up config to {}", backup.display()))?;
+        println!("Backed up the previous config to {}", backup.display());
+    } else if let Some(dir) = config_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(config_path, content)
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+    println!("Imported {} into {}", imported.join(", "), config_path.display());
+    Ok(())
+}
+
+fn read_toml(path: &Path) -> Result<toml::Table> {
+    let content = fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+fn write_report(
+    applications: &[Application],
+    statuses: &[Status],
+    platforms: &[String],
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let path = output.unwrap_or_else(|| report::default_path(today));
+    fs::write(&path, report::markdown(applications, statuses, platforms, today))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("Report written to {}", path.display());
+    Ok(())
+}
+
+fn run_digest(
+    applications: &[Application],
+    since: Option<String>,
+    format: DigestFormat,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let since = match since {
+        Some(input) => dates::parse_date_input(&input, today)?,
+        None => digest::default_since(today),
+    };
+    let digest = digest::build(applications, since, today);
+    let content = match format {
+        DigestFormat::Md => digest::markdown(&digest),
+        DigestFormat::Text => digest::text(&digest),
+        DigestFormat::Json => serde_json::to_string_pretty(&digest).context("Failed to serialize digest")? + "\n",
+    };
+    match output {
+        Some(path) => {
+            fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Digest written to {}", path.display());
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
+
+fn matching(applications: &[Application], predicate: impl Fn(&Application) -> bool) -> Vec<usize> {
+    applications
+        .iter()
+        .enumerate()
+        .filter(|(_, application)| predicate(application))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Groups of `indices` whose applications share a company and job title, ignoring case, in list
+/// order; applications without a duplicate are left out
+fn duplicates(applications: &[Application], indices: &[usize]) -> Vec<Vec<usize>> {
+    let key = |idx: usize| {
+        let application = &applications[idx];
+        let title = application.job_title.as_deref().unwrap_or("");
+        (application.company_name.trim().to_lowercase(), title.trim().to_lowercase())
+    };
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    for &idx in indices {
+        match groups.iter_mut().find(|group| key(group[0]) == key(idx)) {
+            Some(group) => group.push(idx),
+            None => groups.push(vec![idx]),
+        }
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+fn same_company(application: &Application, company: &str) -> bool {
+    application.company_name.trim().eq_ignore_ascii_case(company.trim())
+}
+
+/// Status named case-insensitively, out of the built-ins and the configured custom statuses
+fn parse_status(name: &str, statuses: &[Status]) -> Result<Status> {
+    statuses
+        .iter()
+        .find(|status| status.as_str().eq_ignore_ascii_case(name.trim()))
+        .cloned()
+        .ok_or_else(|| {
+            let names: Vec<&str> = statuses.iter().map(|s| s.as_str()).collect();
+            anyhow!("Unknown status '{}' (expected one of: {})", name, names.join(", "))
+        })
+}
+
+/// Ask a y/N question on the terminal
+fn confirm(question: &str) -> Result<bool> {
+    print!("{} [y/N] ", question);
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::JsonStorage;
+
+    #[test]
+    fn dry_runs_leave_the_data_file_byte_identical() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(storage::DATA_FILE);
+        let storage = JsonStorage::new(&path);
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let applications: Vec<Application> = ["Acme", "Globex", "ACME"]
+            .into_iter()
+            .map(|company| Application {
+                company_name: company.to_string(),
+                applied_date: date,
+                ..Application::new()
+            })
+            .collect();
+        storage.save(&applications, &[]).unwrap();
+        let before = fs::read(&path).unwrap();
+
+        let mut data = storage.load().unwrap();
+        let added = Application { company_name: "Initech".to_string(), ..Application::new() };
+        for mutation in [
+            Mutation::Delete(matching(&data.applications, |a| same_company(a, "acme"))),
+            Mutation::SetStatus { indices: vec![0, 1, 2], status: Status::Rejected },
+            Mutation::Add(vec![added]),
+            Mutation::Merge(duplicates(&data.applications, &[0, 1, 2])),
+        ] {
+            apply_mutation(mutation, true, true, &path, &storage, &mut data).unwrap();
+            assert_eq!(fs::read(&path).unwrap(), before);
+            assert_eq!(data.applications, applications);
+        }
+    }
+
+    #[test]
+    fn duplicates_match_company_and_title_ignoring_case() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let application = |company: &str, title: Option<&str>| Application {
+            company_name: company.to_string(),
+            job_title: title.map(str::to_string),
+            applied_date: date,
+            ..Application::new()
+        };
+        let applications = vec![
+            application("Acme", Some("Engineer")),
+            application("Acme", Some("Designer")),
+            application(" acme ", Some("engineer")),
+            application("Globex", None),
+            application("Globex", None),
+            application("Initech", None),
+        ];
+        assert_eq!(duplicates(&applications, &[0, 1, 2, 3, 4, 5]), vec![vec![0, 2], vec![3, 4]]);
+        assert_eq!(duplicates(&applications, &[0, 1, 3]), Vec::<Vec<usize>>::new());
+    }
+}
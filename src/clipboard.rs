@@ -0,0 +1,61 @@
+use std::io::Write;
+
+/// Where copied text ended up
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Copied {
+    /// The system clipboard
+    System,
+    /// Sent to the terminal as an OSC 52 sequence, for SSH sessions without a clipboard provider
+    Terminal,
+}
+
+/// System clipboard, connected on first use. Kept open for the whole session because on X11
+/// the copied text is only available while the process that copied it still owns the clipboard.
+#[derive(Default)]
+pub struct Clipboard {
+    system: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    /// Copy `text` to the system clipboard, falling back to OSC 52 when there's none, e.g. on a
+    /// headless machine. Errors only when the fallback can't be written either.
+    pub fn copy(&mut self, text: &str) -> std::io::Result<Copied> {
+        if self.system.is_none() {
+            self.system = arboard::Clipboard::new().ok();
+        }
+        if let Some(system) = self.system.as_mut() {
+            if system.set_text(text).is_ok() {
+                return Ok(Copied::System);
+            }
+            // Reconnect next time; the display may have gone away
+            self.system = None;
+        }
+
+        let mut stdout = std::io::stdout();
+        stdout.write_all(osc52(text).as_bytes())?;
+        stdout.flush()?;
+        Ok(Copied::Terminal)
+    }
+}
+
+/// Escape sequence asking the terminal to put `text` on its clipboard
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", base64(text.as_bytes()))
+}
+
+/// Standard base64 with padding
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, &byte)| group | (byte as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
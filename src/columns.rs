@@ -0,0 +1,196 @@
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// Column of the list table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Company,
+    JobTitle,
+    Platform,
+    Location,
+    ResumeVersion,
+    Status,
+    Interest,
+    Salary,
+    Interviews,
+    Date,
+    Deadline,
+}
+
+impl Column {
+    pub fn all() -> &'static [Column] {
+        &[
+            Column::Company,
+            Column::JobTitle,
+            Column::Platform,
+            Column::Location,
+            Column::ResumeVersion,
+            Column::Status,
+            Column::Interest,
+            Column::Salary,
+            Column::Interviews,
+            Column::Date,
+            Column::Deadline,
+        ]
+    }
+
+    /// Identifier used in the `[list]` config section
+    pub fn name(&self) -> &str {
+        match self {
+            Column::Company => "company",
+            Column::JobTitle => "title",
+            Column::Platform => "platform",
+            Column::Location => "location",
+            Column::ResumeVersion => "resume",
+            Column::Status => "status",
+            Column::Interest => "interest",
+            Column::Salary => "salary",
+            Column::Interviews => "interviews",
+            Column::Date => "date",
+            Column::Deadline => "deadline",
+        }
+    }
+
+    pub fn header(&self) -> &str {
+        match self {
+            Column::Company => "Company",
+            Column::JobTitle => "Title",
+            Column::Platform => "Platform",
+            Column::Location => "Location",
+            Column::ResumeVersion => "Resume Ver",
+            Column::Status => "Status",
+            Column::Interest => "Interest",
+            Column::Salary => "Salary",
+            Column::Interviews => "Interviews",
+            Column::Date => "Date",
+            Column::Deadline => "Deadline",
+        }
+    }
+
+    /// Width weight when the config doesn't give one
+    pub fn default_width(&self) -> u16 {
+        match self {
+            Column::Company => 16,
+            Column::JobTitle => 16,
+            Column::Platform => 10,
+            Column::Location => 17,
+            Column::ResumeVersion => 8,
+            Column::Status => 10,
+            Column::Interest => 7,
+            Column::Salary => 9,
+            Column::Interviews => 11,
+            Column::Date => 17,
+            Column::Deadline => 9,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Column> {
+        Self::all().iter().copied().find(|column| column.name() == name)
+    }
+}
+
+/// Columns shown when the config doesn't list any
+const DEFAULT_COLUMNS: &[Column] = &[
+    Column::Company,
+    Column::Platform,
+    Column::Location,
+    Column::ResumeVersion,
+    Column::Status,
+    Column::Interest,
+    Column::Salary,
+    Column::Interviews,
+    Column::Date,
+    Column::Deadline,
+];
+
+/// One entry of `columns` in the config file: a name, or a name with a width weight
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ColumnSpec {
+    Name(String),
+    Weighted { name: String, width: u16 },
+}
+
+/// A column shown in the list and its share of the table width
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListColumn {
+    pub column: Column,
+    pub width: u16,
+}
+
+impl ListColumn {
+    fn new(column: Column) -> Self {
+        Self {
+            column,
+            width: column.default_width(),
+        }
+    }
+}
+
+/// Columns in display order from the config entries; no entries gives the default layout
+pub fn layout(specs: &[ColumnSpec]) -> Result<Vec<ListColumn>> {
+    if specs.is_empty() {
+        return Ok(DEFAULT_COLUMNS.iter().map(|column| ListColumn::new(*column)).collect());
+    }
+
+    let mut layout: Vec<ListColumn> = Vec::new();
+    for spec in specs {
+        let (name, width) = match spec {
+            ColumnSpec::Name(name) => (name, None),
+            ColumnSpec::Weighted { name, width } => (name, Some(*width)),
+        };
+        let column = Column::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Column::all().iter().map(|column| column.name()).collect();
+            anyhow!("Unknown column '{}' (expected one of: {})", name, names.join(", "))
+        })?;
+        if width == Some(0) {
+            bail!("Column '{}' needs a width of at least 1", name);
+        }
+        if layout.iter().any(|shown| shown.column == column) {
+            bail!("Column '{}' is listed twice", name);
+        }
+        layout.push(ListColumn {
+            column,
+            width: width.unwrap_or(column.default_width()),
+        });
+    }
+    Ok(layout)
+}
+
+/// Config entries for a layout, leaving out widths that are the default
+pub fn specs(layout: &[ListColumn]) -> Vec<ColumnSpec> {
+    layout
+        .iter()
+        .map(|shown| {
+            let name = shown.column.name().to_string();
+            if shown.width == shown.column.default_width() {
+                ColumnSpec::Name(name)
+            } else {
+                ColumnSpec::Weighted {
+                    name,
+                    width: shown.width,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Show `column` if hidden, or hide it if shown. A shown column goes after the ones
+/// that come before it in `Column::all` order. Returns false instead of hiding the last column.
+pub fn toggle(layout: &mut Vec<ListColumn>, column: Column) -> bool {
+    if let Some(idx) = layout.iter().position(|shown| shown.column == column) {
+        if layout.len() == 1 {
+            return false;
+        }
+        layout.remove(idx);
+        return true;
+    }
+
+    let order = |column: Column| Column::all().iter().position(|c| *c == column);
+    let idx = layout
+        .iter()
+        .rposition(|shown| order(shown.column) < order(column))
+        .map_or(0, |idx| idx + 1);
+    layout.insert(idx, ListColumn::new(column));
+    true
+}
@@ -0,0 +1,445 @@
+use crate::attention::AttentionConfig;
+use crate::columns::{self, ColumnSpec};
+use crate::dates::{self, WeekStart};
+use crate::goal::GoalConfig;
+use crate::hooks::HooksConfig;
+use crate::keymap::{KeyBinding, KeyMap};
+use crate::models::{Application, Platform, Status};
+use crate::theme::Theme;
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// User configuration loaded from `~/.config/jobtracker/config.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub week_starts_on: WeekStart,
+    pub follow_up: FollowUpConfig,
+    pub aging: AgingConfig,
+    pub attention: AttentionConfig,
+    pub ghosting: GhostingConfig,
+    pub goal: GoalConfig,
+    /// Key overrides by action name, e.g. `save_form = "ctrl+w"`
+    pub keys: BTreeMap<String, KeyBinding>,
+    pub usage: UsageConfig,
+    pub theme: Theme,
+    pub charts: ChartConfig,
+    pub list: ListConfig,
+    pub dashboard: DashboardConfig,
+    pub warnings: WarningConfig,
+    pub hooks: HooksConfig,
+    pub storage: StorageConfig,
+    /// Status order for dropdowns and charts; names other than the built-ins add custom statuses
+    pub statuses: Vec<String>,
+    /// Extra platform presets, listed after the built-ins in the form dropdown
+    pub platforms: Vec<String>,
+}
+
+/// Chart settings from the `[charts]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ChartConfig {
+    /// Platforms with fewer applications are greyed out in the interview rate chart
+    pub min_sample: u64,
+}
+
+impl Default for ChartConfig {
+    fn default() -> Self {
+        Self { min_sample: 3 }
+    }
+}
+
+/// List table settings from the `[list]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    /// Columns in display order, each a name or `{ name, width }`; empty for the default layout
+    pub columns: Vec<ColumnSpec>,
+    /// Show applied dates as their age ("3d", "6w") instead of ISO dates
+    pub relative_dates: bool,
+}
+
+/// Dashboard settings from the `[dashboard]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DashboardConfig {
+    /// Open on the dashboard instead of the list
+    pub on_startup: bool,
+}
+
+/// Limits past which saving the form asks first, from the `[warnings]` section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WarningConfig {
+    /// Notes longer than this many characters, such as a pasted job description; 0 turns it off
+    pub notes_length: usize,
+}
+
+impl Default for WarningConfig {
+    fn default() -> Self {
+        Self { notes_length: 10_000 }
+    }
+}
+
+/// Data file settings from the `[storage]` section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct StorageConfig {
+    pub layout: StorageLayout,
+}
+
+/// How JSON data is laid out on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageLayout {
+    /// Everything in one file
+    #[default]
+    File,
+    /// A directory with one file per application and an index, for keeping the data in git
+    Directory,
+}
+
+impl StorageLayout {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageLayout::File => "file",
+            StorageLayout::Directory => "directory",
+        }
+    }
+}
+
+/// Local-only usage metrics, off unless enabled
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct UsageConfig {
+    pub enabled: bool,
+}
+
+/// How a follow-up date is applied after a status change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FollowUpMode {
+    #[default]
+    Propose,
+    Set,
+}
+
+/// Default follow-up intervals per status
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FollowUpConfig {
+    pub mode: FollowUpMode,
+    pub intervals: BTreeMap<String, String>,
+}
+
+impl Default for FollowUpConfig {
+    fn default() -> Self {
+        let intervals = [("Applied", "7d"), ("Interview", "3d"), ("Offer", "2d")]
+            .into_iter()
+            .map(|(status, interval)| (status.to_string(), interval.to_string()))
+            .collect();
+        Self {
+            mode: FollowUpMode::default(),
+            intervals,
+        }
+    }
+}
+
+impl FollowUpConfig {
+    /// Follow-up interval configured for a status, if any
+    pub fn interval(&self, status: &Status) -> Option<Duration> {
+        self.intervals
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(status.as_str()))
+            .and_then(|(_, interval)| dates::parse_duration(interval).ok())
+    }
+
+    /// Follow-up date to apply after changing to `status`.
+    /// An existing follow-up that is still upcoming is never overwritten.
+    pub fn propose(&self, status: &Status, current: Option<NaiveDate>, today: NaiveDate) -> Option<NaiveDate> {
+        if current.is_some_and(|date| date >= today) {
+            return None;
+        }
+        self.interval(status).map(|interval| today + interval)
+    }
+}
+
+/// How long an application may sit in a status before it counts as stale
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct AgingConfig {
+    /// Threshold for statuses without their own entry (Rejected never ages unless listed)
+    pub default: String,
+    pub thresholds: BTreeMap<String, String>,
+}
+
+impl Default for AgingConfig {
+    fn default() -> Self {
+        Self {
+            default: "14d".to_string(),
+            thresholds: BTreeMap::new(),
+        }
+    }
+}
+
+/// An application past its aging threshold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Staleness {
+    pub status: Status,
+    pub days: i64,
+    pub threshold: i64,
+}
+
+impl fmt::Display for Staleness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d in {}, threshold {}d", self.days, self.status.as_str(), self.threshold)
+    }
+}
+
+impl AgingConfig {
+    /// Aging threshold for a status: its own entry, else the default
+    pub fn threshold(&self, status: &Status) -> Option<Duration> {
+        let own = self
+            .thresholds
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(status.as_str()));
+
+        match own {
+            Some((_, threshold)) => dates::parse_duration(threshold).ok(),
+            None if *status == Status::Rejected => None,
+            None => dates::parse_duration(&self.default).ok(),
+        }
+    }
+
+    /// Staleness of an application, if it has been quiet for at least its status threshold.
+    /// Age is counted from the applied date until status history is tracked.
+    pub fn staleness(&self, application: &Application, today: NaiveDate) -> Option<Staleness> {
+        let threshold = self.threshold(&application.status)?.num_days();
+        let days = (today - application.applied_date).num_days();
+        (days >= threshold).then(|| Staleness {
+            status: application.status.clone(),
+            days,
+            threshold,
+        })
+    }
+}
+
+/// When an application still in Applied counts as ghosted, and what to mark it as
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GhostingConfig {
+    /// Time since the applied date after which no reply means ghosted
+    pub after: String,
+    /// Status the bulk action sets, a built-in or custom one such as "Ghosted"
+    pub status: String,
+}
+
+impl Default for GhostingConfig {
+    fn default() -> Self {
+        Self {
+            after: "30d".to_string(),
+            status: "Rejected".to_string(),
+        }
+    }
+}
+
+impl GhostingConfig {
+    pub fn threshold(&self) -> Option<Duration> {
+        dates::parse_duration(&self.after).ok()
+    }
+
+    /// Status ghosted applications are marked as
+    pub fn status(&self) -> Status {
+        Status::from_str(&self.status)
+    }
+
+    /// Whether an application is still in Applied at least `after` past its applied date
+    pub fn is_ghosted(&self, application: &Application, today: NaiveDate) -> bool {
+        application.status == Status::Applied
+            && self
+                .threshold()
+                .is_some_and(|threshold| today - application.applied_date >= threshold)
+    }
+}
+
+impl Config {
+    /// Load the config file, falling back to defaults when it doesn't exist
+    pub fn load() -> Result<Self> {
+        let Some(path) = config_path() else {
+            return Ok(Self::default());
+        };
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+        Self::parse(&content).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Parse and validate config file contents
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: Config = toml::from_str(content)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (status, interval) in &self.follow_up.intervals {
+            dates::parse_duration(interval)
+                .with_context(|| format!("Invalid follow-up interval for {}", status))?;
+        }
+        dates::parse_duration(&self.aging.default).context("Invalid default aging threshold")?;
+        for (status, threshold) in &self.aging.thresholds {
+            dates::parse_duration(threshold)
+                .with_context(|| format!("Invalid aging threshold for {}", status))?;
+        }
+        for (status, threshold) in &self.attention.thresholds {
+            dates::parse_duration(threshold)
+                .with_context(|| format!("Invalid attention threshold for {}", status))?;
+        }
+        dates::parse_duration(&self.ghosting.after).context("Invalid ghosting threshold")?;
+        if self.ghosting.status.trim().is_empty() {
+            bail!("Ghosting status can't be empty");
+        }
+        KeyMap::from_config(&self.keys).context("Invalid [keys] binding")?;
+        columns::layout(&self.list.columns).context("Invalid [list] columns")?;
+        self.theme.validate().context("Invalid [theme] colors")?;
+        Ok(())
+    }
+
+    /// Statuses in display order: the configured list, then any built-ins it leaves out,
+    /// then custom statuses still used by `applications` but no longer configured
+    pub fn status_order(&self, applications: &[Application]) -> Vec<Status> {
+        let mut order: Vec<Status> = Vec::new();
+        let configured = self.statuses.iter().map(|name| Status::from_str(name));
+        let used = applications.iter().map(|application| application.status.clone());
+        for status in configured.chain(Status::all().iter().cloned()).chain(used) {
+            if !status.as_str().is_empty() && !order.contains(&status) {
+                order.push(status);
+            }
+        }
+        order
+    }
+
+    /// Platform dropdown options: the built-ins, then configured presets, then "Other"
+    pub fn platform_presets(&self) -> Vec<String> {
+        let builtins = &Platform::presets()[..Platform::presets().len() - 1];
+        let mut presets: Vec<String> = builtins.iter().map(|name| name.to_string()).collect();
+        for name in self.platforms.iter().map(|name| name.trim()) {
+            let taken = name.eq_ignore_ascii_case("Other")
+                || presets.iter().any(|preset| preset.eq_ignore_ascii_case(name));
+            if !name.is_empty() && !taken {
+                presets.push(name.to_string());
+            }
+        }
+        presets.push("Other".to_string());
+        presets
+    }
+
+    /// Key bindings with the `[keys]` overrides applied
+    pub fn keymap(&self) -> Result<KeyMap> {
+        KeyMap::from_config(&self.keys)
+    }
+}
+
+/// Write the list columns to the `[list]` section of the config file, keeping the other settings
+pub fn save_list_columns(columns: &[ColumnSpec]) -> Result<()> {
+    save_setting("list", "columns", toml::Value::try_from(columns)?)
+}
+
+/// Write the storage layout to the `[storage]` section of the config file, keeping the other settings
+pub fn save_storage_layout(layout: StorageLayout) -> Result<()> {
+    save_setting("storage", "layout", toml::Value::String(layout.as_str().to_string()))
+}
+
+/// Set `key` in `section` of the config file, creating either when missing
+fn save_setting(section: &str, key: &str, value: toml::Value) -> Result<()> {
+    let path = config_path().ok_or_else(|| anyhow!("Could not locate the config directory"))?;
+    let mut config = if path.exists() {
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        toml::from_str(&content).with_context(|| format!("Invalid config file {}", path.display()))?
+    } else {
+        toml::Table::new()
+    };
+
+    let table = config
+        .entry(section)
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+    let Some(table) = table.as_table_mut() else {
+        bail!("`{}` in {} is not a section", section, path.display());
+    };
+    table.insert(key.to_string(), value);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, toml::to_string_pretty(&config)?)
+        .with_context(|| format!("Failed to write config file {}", path.display()))
+}
+
+/// Location of the config file (`$XDG_CONFIG_HOME` or `~/.config`)
+pub fn config_path() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("jobtracker").join("config.toml"))
+}
+
+/// Directory for files the program writes itself (`$XDG_DATA_HOME` or `~/.local/share`)
+pub fn data_dir() -> Option<PathBuf> {
+    env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share"))
+        })
+        .map(|dir| dir.join("jobtracker"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    #[test]
+    fn propose_uses_the_interval_of_the_new_status() {
+        let config = FollowUpConfig::default();
+        assert_eq!(config.propose(&Status::Applied, None, day(1)), Some(day(8)));
+        assert_eq!(config.propose(&Status::Interview, None, day(1)), Some(day(4)));
+        assert_eq!(config.propose(&Status::Offer, None, day(1)), Some(day(3)));
+        assert_eq!(config.propose(&Status::Rejected, None, day(1)), None);
+    }
+
+    #[test]
+    fn propose_matches_custom_statuses_ignoring_case() {
+        let mut config = FollowUpConfig::default();
+        config.intervals.insert("phone screen".to_string(), "1w".to_string());
+        let status = Status::Other("Phone Screen".to_string());
+        assert_eq!(config.propose(&status, None, day(1)), Some(day(8)));
+    }
+
+    #[test]
+    fn propose_keeps_an_upcoming_follow_up() {
+        let config = FollowUpConfig::default();
+        assert_eq!(config.propose(&Status::Interview, Some(day(20)), day(10)), None);
+        assert_eq!(config.propose(&Status::Interview, Some(day(10)), day(10)), None);
+    }
+
+    #[test]
+    fn propose_replaces_a_past_follow_up() {
+        let config = FollowUpConfig::default();
+        assert_eq!(config.propose(&Status::Interview, Some(day(9)), day(10)), Some(day(13)));
+    }
+}
@@ -0,0 +1,239 @@
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, Days, Duration, NaiveDate, Weekday};
+use serde::Deserialize;
+
+/// First day of the week used for all weekly statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WeekStart {
+    #[default]
+    Monday,
+    Sunday,
+}
+
+/// First day of the week containing `date`
+pub fn week_start(date: NaiveDate, starts_on: WeekStart) -> NaiveDate {
+    let offset = match starts_on {
+        WeekStart::Monday => date.weekday().num_days_from_monday(),
+        WeekStart::Sunday => date.weekday().num_days_from_sunday(),
+    };
+    date - Duration::days(offset as i64)
+}
+
+/// Whether two dates fall into the same week
+pub fn same_week(a: NaiveDate, b: NaiveDate, starts_on: WeekStart) -> bool {
+    week_start(a, starts_on) == week_start(b, starts_on)
+}
+
+/// Whether two dates fall into the same calendar month
+pub fn same_month(a: NaiveDate, b: NaiveDate) -> bool {
+    (a.year(), a.month()) == (b.year(), b.month())
+}
+
+/// Parse a typed date: "2024-05-12", "05-12" (this year), "today", "yesterday",
+/// "-3" (three days ago) or a weekday such as "mon" (the most recent one, today included)
+pub fn parse_date_input(input: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    match input.as_str() {
+        "" => bail!("Enter a date"),
+        "today" => return Ok(today),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        _ => {}
+    }
+
+    if let Some(days) = input.strip_prefix('-') {
+        let days: u64 = days
+            .parse()
+            .with_context(|| format!("Invalid number of days in '{}'", input))?;
+        return today
+            .checked_sub_days(Days::new(days))
+            .with_context(|| format!("'{}' is too far back", input));
+    }
+    if let Ok(weekday) = input.parse::<Weekday>() {
+        let back = (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        return Ok(today - Duration::days(back as i64));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+        return Ok(date);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(&format!("{}-{}", today.year(), input), "%Y-%m-%d") {
+        return Ok(date);
+    }
+    bail!("Unknown date '{}' (try 2024-05-12, 05-12, today, -3 or mon)", input)
+}
+
+/// How long ago `date` was, as in the list's age column: "today", "3d", "6w", "5mo" or "2y";
+/// dates after today read "in 3d" and so on. Months and years are calendar ones, so they
+/// count the same across a year boundary.
+pub fn format_relative(date: NaiveDate, today: NaiveDate) -> String {
+    if date == today {
+        return "today".to_string();
+    }
+    let (from, to) = if date < today { (date, today) } else { (today, date) };
+    let days = (to - from).num_days();
+    let mut months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    if to.day() < from.day() {
+        months -= 1;
+    }
+    // Weeks up to about two months, so "8w" rather than a vague "1mo"
+    let age = match days {
+        0..=13 => format!("{}d", days),
+        14..=62 => format!("{}w", days / 7),
+        _ if months < 12 => format!("{}mo", months.max(2)),
+        _ => format!("{}y", months / 12),
+    };
+    if date > today {
+        format!("in {}", age)
+    } else {
+        age
+    }
+}
+
+/// Parse a short duration such as "3d" or "1w" (a bare number means days)
+pub fn parse_duration(input: &str) -> Result<Duration> {
+    let input = input.trim();
+    let (number, unit) = match input.char_indices().last() {
+        Some((idx, c)) if c.is_ascii_alphabetic() => (&input[..idx], c.to_ascii_lowercase()),
+        Some(_) => (input, 'd'),
+        None => bail!("Empty duration"),
+    };
+
+    let count: i64 = number
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid duration '{}'", input))?;
+    if count < 0 {
+        bail!("Duration '{}' must not be negative", input);
+    }
+
+    match unit {
+        'd' => Ok(Duration::days(count)),
+        'w' => Ok(Duration::weeks(count)),
+        _ => bail!("Unknown duration unit in '{}' (use d or w)", input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_reads_days_and_weeks() {
+        assert_eq!(parse_duration("3d").unwrap(), Duration::days(3));
+        assert_eq!(parse_duration("2W").unwrap(), Duration::weeks(2));
+        assert_eq!(parse_duration(" 10 ").unwrap(), Duration::days(10));
+        assert_eq!(parse_duration("0d").unwrap(), Duration::zero());
+    }
+
+    #[test]
+    fn parse_duration_rejects_bad_input() {
+        for input in ["", "d", "-1d", "3m", "1.5w", "three days"] {
+            assert!(parse_duration(input).is_err(), "{:?} parsed", input);
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn format_relative_uses_days_then_weeks() {
+        let today = date(2024, 5, 10);
+        assert_eq!(format_relative(today, today), "today");
+        assert_eq!(format_relative(date(2024, 5, 9), today), "1d");
+        assert_eq!(format_relative(date(2024, 4, 27), today), "13d");
+        assert_eq!(format_relative(date(2024, 4, 26), today), "2w");
+        assert_eq!(format_relative(date(2024, 3, 9), today), "8w");
+    }
+
+    #[test]
+    fn format_relative_counts_calendar_months_and_years() {
+        let today = date(2024, 5, 10);
+        assert_eq!(format_relative(date(2024, 3, 8), today), "2mo");
+        assert_eq!(format_relative(date(2023, 5, 11), today), "11mo");
+        assert_eq!(format_relative(date(2023, 5, 10), today), "1y");
+        assert_eq!(format_relative(date(2021, 12, 1), today), "2y");
+    }
+
+    #[test]
+    fn format_relative_across_year_boundaries() {
+        let today = date(2025, 1, 2);
+        assert_eq!(format_relative(date(2024, 12, 31), today), "2d");
+        assert_eq!(format_relative(date(2024, 10, 2), today), "3mo");
+        assert_eq!(format_relative(date(2024, 1, 3), today), "11mo");
+        assert_eq!(format_relative(date(2024, 1, 2), today), "1y");
+        // A year from a leap day is a day short of a calendar year
+        assert_eq!(format_relative(date(2024, 2, 29), date(2025, 2, 28)), "11mo");
+    }
+
+    #[test]
+    fn format_relative_future_dates_read_in() {
+        let today = date(2024, 12, 30);
+        assert_eq!(format_relative(date(2024, 12, 31), today), "in 1d");
+        assert_eq!(format_relative(date(2025, 1, 20), today), "in 3w");
+        assert_eq!(format_relative(date(2025, 4, 1), today), "in 3mo");
+        assert_eq!(format_relative(date(2027, 1, 1), today), "in 2y");
+    }
+
+    #[test]
+    fn weeks_start_on_the_configured_day() {
+        // Wednesday 2024-05-15
+        let wednesday = date(2024, 5, 15);
+        assert_eq!(week_start(wednesday, WeekStart::Monday), date(2024, 5, 13));
+        assert_eq!(week_start(wednesday, WeekStart::Sunday), date(2024, 5, 12));
+        // The start day is its own week's start
+        assert_eq!(week_start(date(2024, 5, 13), WeekStart::Monday), date(2024, 5, 13));
+        assert_eq!(week_start(date(2024, 5, 12), WeekStart::Sunday), date(2024, 5, 12));
+    }
+
+    #[test]
+    fn same_week_splits_at_the_start_day() {
+        // Saturday 11th, Sunday 12th, Monday 13th May 2024
+        let (saturday, sunday, monday) = (date(2024, 5, 11), date(2024, 5, 12), date(2024, 5, 13));
+        assert!(same_week(saturday, sunday, WeekStart::Monday));
+        assert!(!same_week(sunday, monday, WeekStart::Monday));
+        assert!(!same_week(saturday, sunday, WeekStart::Sunday));
+        assert!(same_week(sunday, monday, WeekStart::Sunday));
+        // Order doesn't matter, and a week later is another week
+        assert!(same_week(monday, saturday + Duration::days(7), WeekStart::Monday));
+        assert!(!same_week(monday, monday + Duration::days(7), WeekStart::Monday));
+    }
+
+    #[test]
+    fn same_week_across_a_month_boundary() {
+        // Friday 31st May, Saturday 1st, Sunday 2nd and Monday 3rd June 2024
+        let (friday, saturday, sunday, monday) =
+            (date(2024, 5, 31), date(2024, 6, 1), date(2024, 6, 2), date(2024, 6, 3));
+        assert!(same_week(friday, sunday, WeekStart::Monday));
+        assert!(!same_week(friday, monday, WeekStart::Monday));
+        assert!(same_week(friday, saturday, WeekStart::Sunday));
+        assert!(!same_week(friday, sunday, WeekStart::Sunday));
+        assert!(same_week(sunday, monday, WeekStart::Sunday));
+    }
+
+    #[test]
+    fn same_week_across_a_year_boundary() {
+        // Sunday 29th, Monday 30th and Tuesday 31st December 2024, Wednesday 1st and
+        // Saturday 4th January 2025
+        let (sunday, monday) = (date(2024, 12, 29), date(2024, 12, 30));
+        let (new_year, saturday) = (date(2025, 1, 1), date(2025, 1, 4));
+        assert!(same_week(monday, new_year, WeekStart::Monday));
+        assert!(same_week(date(2024, 12, 31), date(2025, 1, 5), WeekStart::Monday));
+        assert!(!same_week(sunday, monday, WeekStart::Monday));
+        assert!(same_week(sunday, saturday, WeekStart::Sunday));
+        assert!(!same_week(saturday, date(2025, 1, 5), WeekStart::Sunday));
+        assert_eq!(week_start(new_year, WeekStart::Monday), monday);
+        assert_eq!(week_start(new_year, WeekStart::Sunday), sunday);
+    }
+
+    #[test]
+    fn same_week_when_the_year_starts_on_the_start_day() {
+        // 2023 began on a Sunday, the last day of a Monday week and the first of a Sunday one
+        let (saturday, sunday, monday) = (date(2022, 12, 31), date(2023, 1, 1), date(2023, 1, 2));
+        assert!(same_week(saturday, sunday, WeekStart::Monday));
+        assert!(!same_week(sunday, monday, WeekStart::Monday));
+        assert!(!same_week(saturday, sunday, WeekStart::Sunday));
+        assert!(same_week(sunday, monday, WeekStart::Sunday));
+    }
+
+}
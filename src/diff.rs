@@ -0,0 +1,293 @@
+use crate::models::{Application, SalaryRange, Status};
+use chrono::NaiveDate;
+
+/// Maximum length of a save message shown in the status bar
+pub const MESSAGE_WIDTH: usize = 72;
+
+/// Compact description of each field that differs between two versions of a record
+pub fn field_changes(before: &Application, after: &Application) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if before.company_name != after.company_name {
+        changes.push(format!("renamed from {}", before.company_name));
+    }
+    if before.job_title != after.job_title {
+        changes.push(text_change(
+            "title",
+            before.job_title.as_deref().unwrap_or_default(),
+            after.job_title.as_deref().unwrap_or_default(),
+        ));
+    }
+    if before.url != after.url {
+        changes.push("url updated".to_string());
+    }
+    if before.platform != after.platform {
+        changes.push(format!(
+            "platform {}→{}",
+            before.platform.as_str(),
+            after.platform.as_str()
+        ));
+    }
+    if before.external_id != after.external_id {
+        changes.push(text_change(
+            "job ID",
+            before.external_id.as_deref().unwrap_or_default(),
+            after.external_id.as_deref().unwrap_or_default(),
+        ));
+    }
+    if before.location != after.location {
+        changes.push(text_change(
+            "location",
+            before.location.as_deref().unwrap_or_default(),
+            after.location.as_deref().unwrap_or_default(),
+        ));
+    }
+    if before.work_mode != after.work_mode {
+        changes.push(format!(
+            "work mode {}→{}",
+            before.work_mode.as_str(),
+            after.work_mode.as_str()
+        ));
+    }
+    if before.resume_modified != after.resume_modified {
+        changes.push(format!(
+            "resume modified {}→{}",
+            yes_no(before.resume_modified),
+            yes_no(after.resume_modified)
+        ));
+    }
+    if before.resume_version != after.resume_version {
+        changes.push(text_change("resume", &before.resume_version, &after.resume_version));
+    }
+    if before.resume_path != after.resume_path {
+        changes.push("resume file updated".to_string());
+    }
+    if before.cover_letter != after.cover_letter {
+        changes.push(format!(
+            "cover letter {}→{}",
+            yes_no(before.cover_letter),
+            yes_no(after.cover_letter)
+        ));
+    }
+    if before.cover_letter_version != after.cover_letter_version {
+        changes.push(text_change("cover letter", &before.cover_letter_version, &after.cover_letter_version));
+    }
+    if before.status != after.status {
+        changes.push(format!(
+            "status {}→{}",
+            before.status.as_str(),
+            after.status.as_str()
+        ));
+    }
+    if before.rejection_reason != after.rejection_reason {
+        match after.rejection_reason {
+            Some(reason) => changes.push(format!("rejected: {}", reason.as_str().to_lowercase())),
+            None if after.status == Status::Rejected => changes.push("rejection reason cleared".to_string()),
+            // Cleared along with the status change already listed
+            None => {}
+        }
+    }
+    if before.interest != after.interest {
+        changes.push(format!("interest {}→{}", before.interest, after.interest));
+    }
+    if before.salary != after.salary {
+        changes.push(format!(
+            "salary {}→{}",
+            optional_salary(before.salary.as_ref()),
+            optional_salary(after.salary.as_ref())
+        ));
+    }
+    if before.applied_date != after.applied_date {
+        changes.push(format!("date {}→{}", before.applied_date, after.applied_date));
+    }
+    if before.follow_up != after.follow_up {
+        changes.push(format!(
+            "follow-up {}→{}",
+            optional_date(before.follow_up),
+            optional_date(after.follow_up)
+        ));
+    }
+    if before.deadline != after.deadline {
+        changes.push(format!(
+            "deadline {}→{}",
+            optional_date(before.deadline),
+            optional_date(after.deadline)
+        ));
+    }
+    if before.contact_name != after.contact_name || before.contact_email != after.contact_email {
+        changes.push("contact updated".to_string());
+    }
+    if before.referral != after.referral {
+        changes.push(format!("referral {}→{}", yes_no(before.referral), yes_no(after.referral)));
+    }
+    if before.interviews != after.interviews {
+        changes.push(format!(
+            "interviews {}→{}",
+            before.interviews.len(),
+            after.interviews.len()
+        ));
+    }
+    if before.activity.len() != after.activity.len() {
+        changes.push(format!("activity {}→{}", before.activity.len(), after.activity.len()));
+    } else if before.activity != after.activity {
+        changes.push("activity updated".to_string());
+    }
+    if before.notes != after.notes {
+        changes.push(length_change("notes", &before.notes, &after.notes));
+    }
+    if before.archived != after.archived {
+        let change = if after.archived { "archived" } else { "unarchived" };
+        changes.push(change.to_string());
+    }
+
+    changes
+}
+
+/// Message shown after saving a record, e.g. "Saved: Stripe (status Applied→Interview)".
+/// `before` is `None` for newly added records.
+pub fn save_message(before: Option<&Application>, after: &Application) -> String {
+    let details = match before {
+        None => "new".to_string(),
+        Some(before) => {
+            let changes = field_changes(before, after);
+            if changes.is_empty() {
+                "no changes".to_string()
+            } else {
+                changes.join(", ")
+            }
+        }
+    };
+
+    truncate(&format!("Saved: {} ({})", after.company_name, details), MESSAGE_WIDTH)
+}
+
+/// Short text fields show both values; long ones only how much they grew or shrank
+fn text_change(label: &str, before: &str, after: &str) -> String {
+    if before.chars().count() <= 16 && after.chars().count() <= 16 {
+        format!("{} {}→{}", label, or_none(before), or_none(after))
+    } else {
+        length_change(label, before, after)
+    }
+}
+
+fn length_change(label: &str, before: &str, after: &str) -> String {
+    let before_len = before.chars().count() as i64;
+    let after_len = after.chars().count() as i64;
+    match after_len - before_len {
+        0 => format!("{} edited", label),
+        delta => format!("{} {:+} chars", label, delta),
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+fn or_none(value: &str) -> &str {
+    if value.is_empty() {
+        "none"
+    } else {
+        value
+    }
+}
+
+fn optional_date(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.to_string()).unwrap_or_else(|| "none".to_string())
+}
+
+fn optional_salary(salary: Option<&SalaryRange>) -> String {
+    salary.map(|s| s.compact()).unwrap_or_else(|| "none".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RejectionReason;
+
+    fn application() -> Application {
+        Application {
+            company_name: "Stripe".to_string(),
+            applied_date: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+            ..Application::new()
+        }
+    }
+
+    #[test]
+    fn unchanged_records_have_no_changes() {
+        let before = application();
+        assert!(field_changes(&before, &before.clone()).is_empty());
+        assert_eq!(save_message(Some(&before), &before), "Saved: Stripe (no changes)");
+    }
+
+    #[test]
+    fn new_records_are_marked_new() {
+        assert_eq!(save_message(None, &application()), "Saved: Stripe (new)");
+    }
+
+    #[test]
+    fn changes_are_listed_in_field_order() {
+        let before = application();
+        let mut after = before.clone();
+        after.status = Status::Interview;
+        after.salary = Some(SalaryRange { min: 120_000, max: 150_000, currency: "USD".to_string() });
+        after.follow_up = NaiveDate::from_ymd_opt(2024, 5, 8);
+        assert_eq!(
+            field_changes(&before, &after),
+            vec!["status Applied→Interview", "salary none→120–150k", "follow-up none→2024-05-08"]
+        );
+    }
+
+    #[test]
+    fn short_text_shows_both_values_and_long_text_the_length() {
+        let before = application();
+        let mut after = before.clone();
+        after.resume_version = "v2".to_string();
+        after.notes = "Spoke to the hiring manager".to_string();
+        assert_eq!(field_changes(&before, &after), vec!["resume none→v2", "notes +27 chars"]);
+
+        let mut edited = after.clone();
+        edited.notes = "Spoke to the hiring director".to_string();
+        assert_eq!(field_changes(&after, &edited), vec!["notes +1 chars"]);
+        edited.notes = "Spoke to the hiring managex".to_string();
+        assert_eq!(field_changes(&after, &edited), vec!["notes edited"]);
+    }
+
+    #[test]
+    fn rejection_reason_is_named_with_the_status_change() {
+        let before = application();
+        let mut after = before.clone();
+        after.status = Status::Rejected;
+        after.rejection_reason = Some(RejectionReason::AfterOnsite);
+        assert_eq!(field_changes(&before, &after), vec!["status Applied→Rejected", "rejected: after onsite"]);
+        // Moving off Rejected clears the reason without a second entry
+        let mut reopened = after.clone();
+        reopened.status = Status::Interview;
+        reopened.rejection_reason = None;
+        assert_eq!(field_changes(&after, &reopened), vec!["status Rejected→Interview"]);
+    }
+
+    #[test]
+    fn long_messages_are_truncated() {
+        let before = application();
+        let mut after = before.clone();
+        after.company_name = "A company with a really quite long name".to_string();
+        after.status = Status::Offer;
+        after.interest = 5;
+        let message = save_message(Some(&before), &after);
+        assert_eq!(message.chars().count(), MESSAGE_WIDTH);
+        assert!(message.ends_with('…'));
+    }
+}
@@ -0,0 +1,291 @@
+use crate::models::{Application, Status};
+use crate::stats::{self, Conversion};
+use chrono::{Days, NaiveDate};
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Days covered by default, today included
+pub const DAYS: u64 = 7;
+
+/// What happened between `since` and `today`, and what's coming up
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Digest {
+    pub since: NaiveDate,
+    pub today: NaiveDate,
+    pub applied: Vec<Entry>,
+    pub status_changes: Vec<Entry>,
+    /// Interview rounds from today through the next week
+    pub upcoming_interviews: Vec<Entry>,
+    /// Follow-ups due today or earlier
+    pub follow_ups_due: Vec<Entry>,
+    /// Conversion across every application, not only the ones in the period
+    pub conversion: Rates,
+}
+
+/// One line of the digest: a company, what happened and when
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Entry {
+    pub date: NaiveDate,
+    pub company: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Rates {
+    pub total: u64,
+    pub interviewed: u64,
+    pub offers: u64,
+    /// Percent that reached an interview; None without applications
+    pub interview_rate: Option<u64>,
+    /// Percent that got an offer; None without applications
+    pub offer_rate: Option<u64>,
+}
+
+/// Default first day: a week ago, counting today as one of its days
+pub fn default_since(today: NaiveDate) -> NaiveDate {
+    today - Days::new(DAYS - 1)
+}
+
+/// Collect the digest; archived applications still count as sent and changed, but
+/// their interviews and follow-ups are left out like in reminders
+pub fn build(applications: &[Application], since: NaiveDate, today: NaiveDate) -> Digest {
+    let in_period = |date: NaiveDate| date >= since && date <= today;
+    let horizon = today + Days::new(DAYS);
+    let mut digest = Digest {
+        since,
+        today,
+        applied: Vec::new(),
+        status_changes: Vec::new(),
+        upcoming_interviews: Vec::new(),
+        follow_ups_due: Vec::new(),
+        conversion: rates(Conversion::from_applications(applications)),
+    };
+
+    for application in applications {
+        let company = application.company_name.trim().to_string();
+        if application.status != Status::Planned && in_period(application.applied_date) {
+            digest.applied.push(Entry {
+                date: application.applied_date,
+                company: company.clone(),
+                detail: application.job_title.clone().unwrap_or_default(),
+            });
+        }
+        for change in application.status_history.iter().filter(|change| in_period(change.date)) {
+            digest.status_changes.push(Entry {
+                date: change.date,
+                company: company.clone(),
+                detail: change.status.as_str().to_string(),
+            });
+        }
+        if application.archived {
+            continue;
+        }
+        for interview in &application.interviews {
+            if interview.date >= today && interview.date <= horizon {
+                digest.upcoming_interviews.push(Entry {
+                    date: interview.date,
+                    company: company.clone(),
+                    detail: interview.kind.as_str().to_string(),
+                });
+            }
+        }
+        if let Some(date) = application.follow_up.filter(|date| *date <= today) {
+            digest.follow_ups_due.push(Entry { date, company, detail: String::new() });
+        }
+    }
+
+    for entries in [
+        &mut digest.applied,
+        &mut digest.status_changes,
+        &mut digest.upcoming_interviews,
+        &mut digest.follow_ups_due,
+    ] {
+        entries.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.company.cmp(&b.company)));
+    }
+    digest
+}
+
+fn rates(conversion: Conversion) -> Rates {
+    Rates {
+        total: conversion.total,
+        interviewed: conversion.interviewed,
+        offers: conversion.offers,
+        interview_rate: stats::rate(conversion.interviewed, conversion.total),
+        offer_rate: stats::rate(conversion.offers, conversion.total),
+    }
+}
+
+/// Markdown digest with a section per list, for pasting into a journal
+pub fn markdown(digest: &Digest) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Digest: {} to {}", digest.since, digest.today);
+    for (title, entries) in sections(digest) {
+        let _ = writeln!(out, "\n## {} ({})\n", title, entries.len());
+        if entries.is_empty() {
+            out.push_str("None.\n");
+        }
+        for entry in entries {
+            let _ = writeln!(out, "- {}", describe(entry));
+        }
+    }
+    let _ = writeln!(out, "\n## Conversion\n");
+    for line in conversion_lines(&digest.conversion) {
+        let _ = writeln!(out, "- {}", line);
+    }
+    out
+}
+
+/// Plain-text digest, indented instead of marked up
+pub fn text(digest: &Digest) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Digest: {} to {}", digest.since, digest.today);
+    for (title, entries) in sections(digest) {
+        let _ = writeln!(out, "\n{} ({})", title, entries.len());
+        for entry in entries {
+            let _ = writeln!(out, "  {}", describe(entry));
+        }
+    }
+    let _ = writeln!(out, "\nConversion");
+    for line in conversion_lines(&digest.conversion) {
+        let _ = writeln!(out, "  {}", line);
+    }
+    out
+}
+
+fn sections(digest: &Digest) -> [(&str, &[Entry]); 4] {
+    [
+        ("Applications sent", &digest.applied),
+        ("Status changes", &digest.status_changes),
+        ("Upcoming interviews", &digest.upcoming_interviews),
+        ("Follow-ups due", &digest.follow_ups_due),
+    ]
+}
+
+/// "2024-05-02 Acme: Technical", or without the detail when there is none
+fn describe(entry: &Entry) -> String {
+    if entry.detail.is_empty() {
+        format!("{} {}", entry.date, entry.company)
+    } else {
+        format!("{} {}: {}", entry.date, entry.company, entry.detail)
+    }
+}
+
+fn conversion_lines(rates: &Rates) -> [String; 3] {
+    let percent = |rate: Option<u64>| rate.map(|rate| format!(" ({}%)", rate)).unwrap_or_default();
+    [
+        format!("Applications: {}", rates.total),
+        format!("Reached an interview: {}{}", rates.interviewed, percent(rates.interview_rate)),
+        format!("Offers: {}{}", rates.offers, percent(rates.offer_rate)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interview, InterviewKind, StatusChange};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn application(company: &str, applied: u32) -> Application {
+        Application {
+            company_name: company.to_string(),
+            applied_date: date(applied),
+            ..Application::new()
+        }
+    }
+
+    fn week() -> Digest {
+        let acme = Application {
+            job_title: Some("Engineer".to_string()),
+            status: Status::Interview,
+            status_history: vec![StatusChange { status: Status::Interview, date: date(8) }],
+            interviews: vec![Interview { kind: InterviewKind::Technical, date: date(12), notes: String::new() }],
+            ..application(" Acme ", 5)
+        };
+        let globex = Application { follow_up: Some(date(9)), ..application("Globex", 1) };
+        let initech = Application { status: Status::Planned, ..application("Initech", 6) };
+        // Archived: still sent, but its interview and follow-up are left out
+        let hooli = Application {
+            archived: true,
+            follow_up: Some(date(1)),
+            interviews: vec![Interview { kind: InterviewKind::Phone, date: date(11), notes: String::new() }],
+            ..application("Hooli", 7)
+        };
+        build(&[globex, hooli, initech, acme], default_since(date(10)), date(10))
+    }
+
+    #[test]
+    fn markdown_lists_each_section_and_the_conversion() {
+        let expected = "\
+# Digest: 2024-05-04 to 2024-05-10
+
+## Applications sent (2)
+
+- 2024-05-05 Acme: Engineer
+- 2024-05-07 Hooli
+
+## Status changes (1)
+
+- 2024-05-08 Acme: Interview
+
+## Upcoming interviews (1)
+
+- 2024-05-12 Acme: Technical
+
+## Follow-ups due (1)
+
+- 2024-05-09 Globex
+
+## Conversion
+
+- Applications: 3
+- Reached an interview: 2 (67%)
+- Offers: 0 (0%)
+";
+        assert_eq!(markdown(&week()), expected);
+    }
+
+    #[test]
+    fn text_indents_instead_of_marking_up() {
+        let expected = "\
+Digest: 2024-05-04 to 2024-05-10
+
+Applications sent (2)
+  2024-05-05 Acme: Engineer
+  2024-05-07 Hooli
+
+Status changes (1)
+  2024-05-08 Acme: Interview
+
+Upcoming interviews (1)
+  2024-05-12 Acme: Technical
+
+Follow-ups due (1)
+  2024-05-09 Globex
+
+Conversion
+  Applications: 3
+  Reached an interview: 2 (67%)
+  Offers: 0 (0%)
+";
+        assert_eq!(text(&week()), expected);
+    }
+
+    #[test]
+    fn empty_sections_say_none_and_rates_are_left_out() {
+        let digest = build(&[], date(4), date(10));
+        let markdown = markdown(&digest);
+        assert_eq!(markdown.matches("None.").count(), 4);
+        assert!(markdown.ends_with("- Applications: 0\n- Reached an interview: 0\n- Offers: 0\n"));
+    }
+
+    #[test]
+    fn period_includes_both_ends() {
+        let applications = [application("Acme", 4), application("Globex", 10), application("Initech", 3)];
+        let digest = build(&applications, date(4), date(10));
+        let companies: Vec<&str> = digest.applied.iter().map(|entry| entry.company.as_str()).collect();
+        assert_eq!(companies, ["Acme", "Globex"]);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::app::App;
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyEvent, MouseEvent};
+use std::time::{Duration, Instant};
+
+/// Time between ticks, which expire messages and save pending changes
+const TICK_RATE: Duration = Duration::from_millis(100);
+
+/// How often the data file is checked for changes made outside the app
+const FILE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Something for the main loop to act on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppEvent {
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    /// Text pasted in one piece, from terminals with bracketed paste
+    Paste(String),
+    /// The terminal changed size
+    Resize,
+    /// The tick interval passed
+    Tick,
+    /// The data file was written by something else since the app last read or saved it
+    FileChanged,
+}
+
+/// Terminal input and timers as one stream of events
+pub struct Events {
+    last_tick: Instant,
+    last_file_check: Instant,
+}
+
+impl Events {
+    pub fn new() -> Self {
+        Self {
+            last_tick: Instant::now(),
+            last_file_check: Instant::now(),
+        }
+    }
+
+    /// Wait for the next event. Timers are checked before input, so a held-down key can't
+    /// keep messages from expiring or changes from being saved.
+    pub fn next(&mut self, app: &App) -> Result<AppEvent> {
+        loop {
+            if let Some(event) = self.due(app) {
+                return Ok(event);
+            }
+            let timeout = TICK_RATE.saturating_sub(self.last_tick.elapsed());
+            if event::poll(timeout)? {
+                if let Some(event) = translate(event::read()?) {
+                    return Ok(event);
+                }
+            }
+        }
+    }
+
+    /// The next event if one is ready, without waiting
+    pub fn try_next(&mut self, app: &App) -> Result<Option<AppEvent>> {
+        if let Some(event) = self.due(app) {
+            return Ok(Some(event));
+        }
+        while event::poll(Duration::ZERO)? {
+            if let Some(event) = translate(event::read()?) {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+
+    /// A timer event whose time has come
+    fn due(&mut self, app: &App) -> Option<AppEvent> {
+        if self.last_file_check.elapsed() >= FILE_CHECK_INTERVAL {
+            self.last_file_check = Instant::now();
+            if app.file_changed() {
+                return Some(AppEvent::FileChanged);
+            }
+        }
+        if self.last_tick.elapsed() >= TICK_RATE {
+            self.last_tick = Instant::now();
+            return Some(AppEvent::Tick);
+        }
+        None
+    }
+}
+
+/// The terminal events the app reacts to; focus changes are ignored
+fn translate(event: Event) -> Option<AppEvent> {
+    match event {
+        Event::Key(key) => Some(AppEvent::Key(key)),
+        Event::Mouse(mouse) => Some(AppEvent::Mouse(mouse)),
+        Event::Paste(text) => Some(AppEvent::Paste(text)),
+        Event::Resize(_, _) => Some(AppEvent::Resize),
+        _ => None,
+    }
+}
@@ -0,0 +1,596 @@
+use crate::app::ChartType;
+use crate::models::{Application, Interview, Status};
+use crate::stats::{self, ChartData, Conversion, DateRange, DaySummary, ResponseTimes};
+use crate::theme::{Base, Theme};
+use chrono::NaiveDate;
+use ratatui::style::{Color, Style};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Replace company names with stable placeholders ("Company A", "Company B", ...)
+/// and drop free-text, contact, posting and file fields that could identify them
+pub fn anonymize(applications: &[Application]) -> Vec<Application> {
+    let mut aliases: HashMap<String, String> = HashMap::new();
+
+    applications
+        .iter()
+        .map(|application| {
+            let key = application.company_name.trim().to_lowercase();
+            let next = aliases.len();
+            let alias = aliases
+                .entry(key)
+                .or_insert_with(|| format!("Company {}", alias_letters(next)))
+                .clone();
+
+            let interviews = application
+                .interviews
+                .iter()
+                .map(|interview| Interview {
+                    notes: String::new(),
+                    ..interview.clone()
+                })
+                .collect();
+
+            Application {
+                company_name: alias,
+                url: None,
+                external_id: None,
+                contact_name: None,
+                contact_email: None,
+                resume_path: None,
+                interviews,
+                activity: Vec::new(),
+                notes: String::new(),
+                ..application.clone()
+            }
+        })
+        .collect()
+}
+
+/// CSS class coloring a status column; custom statuses share one color
+fn column_class(status: &Status) -> String {
+    match status {
+        Status::Other(_) => "custom".to_string(),
+        _ => status.as_str().to_lowercase(),
+    }
+}
+
+/// "A".."Z", then "AA", "AB", ...
+fn alias_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'A' + (index % 26) as u8) as char);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Render a self-contained HTML kanban board with one column per status in `statuses`
+pub fn html_board(applications: &[Application], statuses: &[Status], today: NaiveDate) -> String {
+    let mut html = String::new();
+    html.push_str(BOARD_HEADER);
+
+    for status in statuses {
+        let mut cards: Vec<&Application> = applications
+            .iter()
+            .filter(|application| application.status == *status)
+            .collect();
+        cards.sort_by_key(|application| std::cmp::Reverse(application.applied_date));
+
+        let _ = writeln!(
+            html,
+            "<section class=\"column {}\">\n<h2>{} <span class=\"count\">{}</span></h2>",
+            column_class(status),
+            escape(status.as_str()),
+            cards.len()
+        );
+
+        for application in cards {
+            let _ = writeln!(
+                html,
+                "<article class=\"card\">\n<h3>{}</h3>\n<p>{} · {}</p>\n</article>",
+                escape(&application.company_name),
+                escape(&application.platform.as_str()),
+                age(application.applied_date, today)
+            );
+        }
+
+        html.push_str("</section>\n");
+    }
+
+    html.push_str(BOARD_FOOTER);
+    html
+}
+
+fn age(date: NaiveDate, today: NaiveDate) -> String {
+    match (today - date).num_days() {
+        0 => "today".to_string(),
+        days if days < 0 => format!("in {}d", -days),
+        days => format!("{}d ago", days),
+    }
+}
+
+/// Escape text for use in HTML element content and attributes
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// File name for an exported chart, e.g. `chart-status-by-platform-2024-05-12.svg`
+pub fn chart_file_name(chart: ChartType, today: NaiveDate) -> String {
+    format!("chart-{}-{}.svg", chart.slug(), today)
+}
+
+/// One bar of an SVG chart: stacked segments bottom-up, with text above it
+struct SvgBar {
+    segments: Vec<(u64, String)>,
+    /// Counts written inside the segments, for stacked bars
+    segment_labels: bool,
+    top: String,
+}
+
+/// Bars drawn side by side over one label
+struct SvgColumn {
+    label: String,
+    bars: Vec<SvgBar>,
+}
+
+const SVG_PLOT_TOP: u32 = 80;
+const SVG_BASELINE: u32 = 290;
+const SVG_BAR_WIDTH: u32 = 36;
+const SVG_MARGIN: u32 = 40;
+
+/// Render a chart's data as a standalone SVG image, or None when there's nothing to draw.
+/// Exported images go on white pages, so they use the light theme's colors; `palette`
+/// and color overrides still apply.
+pub fn svg_chart(
+    chart: ChartType,
+    range: DateRange,
+    data: &ChartData,
+    theme: &Theme,
+    min_sample: u64,
+) -> Option<String> {
+    if data.is_empty() {
+        return None;
+    }
+    let title = format!("{} · {}", chart.title(), range.label());
+    let theme = Theme {
+        base: Base::Light,
+        ..theme.clone()
+    };
+    let muted = css_color(theme.muted_style());
+
+    let mut legend: Vec<(String, String)> = Vec::new();
+    let mut footnote = None;
+    let (columns, max): (Vec<SvgColumn>, u64) = match data {
+        ChartData::ResponseTimes(times) => return Some(svg_table(&title, times)),
+        ChartData::Counts(counts) => {
+            let color = css_color(theme.series_style(chart.series()));
+            let columns = counts
+                .iter()
+                .map(|(label, count)| SvgColumn {
+                    label: label.clone(),
+                    bars: vec![SvgBar {
+                        segments: vec![(*count, color.clone())],
+                        segment_labels: false,
+                        top: count.to_string(),
+                    }],
+                })
+                .collect();
+            (columns, counts.iter().map(|(_, count)| *count).max().unwrap_or(0))
+        }
+        ChartData::Conversions(conversions) if chart == ChartType::ByResumeSuccess => {
+            let sent = css_color(theme.series_style(0));
+            let interviewed = css_color(theme.series_style(1));
+            legend.push((sent.clone(), "Sent".to_string()));
+            legend.push((interviewed.clone(), "Reached interview".to_string()));
+            let columns = conversions
+                .iter()
+                .map(|(version, conversion)| {
+                    let star = if conversion.total < min_sample { "*" } else { "" };
+                    let percent = stats::rate(conversion.interviewed, conversion.total).unwrap_or(0);
+                    SvgColumn {
+                        label: format!("{}{} {}%", version, star, percent),
+                        bars: vec![
+                            single_bar(conversion.total, &sent),
+                            single_bar(conversion.interviewed, &interviewed),
+                        ],
+                    }
+                })
+                .collect();
+            if conversions.iter().any(|(_, conversion)| conversion.total < min_sample) {
+                footnote = Some(format!("* fewer than {} applications", min_sample));
+            }
+            (columns, conversions.iter().map(|(_, conversion)| conversion.total).max().unwrap_or(0))
+        }
+        ChartData::Conversions(conversions) => {
+            let color = css_color(theme.series_style(1));
+            let columns = conversions
+                .iter()
+                .map(|(group, conversion)| rate_column(group, conversion, min_sample, &color, &muted))
+                .collect();
+            if conversions.iter().any(|(_, conversion)| conversion.total < min_sample) {
+                footnote = Some(format!("* fewer than {} applications", min_sample));
+            }
+            (columns, 100)
+        }
+        ChartData::Statuses(counts) => {
+            let columns = counts
+                .iter()
+                .map(|(status, count)| SvgColumn {
+                    label: status.as_str().to_string(),
+                    bars: vec![single_bar(*count, &css_color(theme.status_style(status)))],
+                })
+                .collect();
+            (columns, counts.iter().map(|(_, count)| *count).max().unwrap_or(0))
+        }
+        ChartData::StatusByGroup(groups) => {
+            let mut shown: Vec<&Status> = Vec::new();
+            for (_, counts) in groups {
+                for (status, count) in counts {
+                    if *count > 0 && !shown.contains(&status) {
+                        shown.push(status);
+                    }
+                }
+            }
+            // Keep the legend in status order rather than first-seen order
+            let order: Vec<&Status> = groups[0].1.iter().map(|(status, _)| status).collect();
+            shown.sort_by_key(|status| order.iter().position(|s| s == status));
+            legend = shown
+                .iter()
+                .map(|status| (css_color(theme.status_style(status)), status.as_str().to_string()))
+                .collect();
+
+            let columns = groups
+                .iter()
+                .map(|(group, counts)| {
+                    let total: u64 = counts.iter().map(|(_, count)| count).sum();
+                    SvgColumn {
+                        label: group.clone(),
+                        bars: vec![SvgBar {
+                            segments: counts
+                                .iter()
+                                .filter(|(_, count)| *count > 0)
+                                .map(|(status, count)| (*count, css_color(theme.status_style(status))))
+                                .collect(),
+                            segment_labels: true,
+                            top: total.to_string(),
+                        }],
+                    }
+                })
+                .collect();
+            let max = groups
+                .iter()
+                .map(|(_, counts)| counts.iter().map(|(_, count)| count).sum::<u64>())
+                .max()
+                .unwrap_or(0);
+            (columns, max)
+        }
+    };
+
+    Some(svg_bars(&title, &columns, max.max(1), &legend, footnote.as_deref()))
+}
+
+fn single_bar(value: u64, color: &str) -> SvgBar {
+    SvgBar {
+        segments: vec![(value, color.to_string())],
+        segment_labels: false,
+        top: value.to_string(),
+    }
+}
+
+/// Interview rate bar; small samples are greyed out and starred as in the chart view
+fn rate_column(group: &str, conversion: &Conversion, min_sample: u64, color: &str, muted: &str) -> SvgColumn {
+    let percent = stats::rate(conversion.interviewed, conversion.total).unwrap_or(0);
+    let (color, star) = if conversion.total < min_sample { (muted, "*") } else { (color, "") };
+    SvgColumn {
+        label: format!("{}{}", group, star),
+        bars: vec![SvgBar {
+            segments: vec![(percent, color.to_string())],
+            segment_labels: false,
+            top: format!("{}% ({}/{})", percent, conversion.interviewed, conversion.total),
+        }],
+    }
+}
+
+fn svg_bars(
+    title: &str,
+    columns: &[SvgColumn],
+    max: u64,
+    legend: &[(String, String)],
+    footnote: Option<&str>,
+) -> String {
+    let bars_per_column = columns.iter().map(|column| column.bars.len()).max().unwrap_or(1) as u32;
+    let column_width = (bars_per_column * (SVG_BAR_WIDTH + 4) + 40).max(90);
+    let width = (SVG_MARGIN * 2 + column_width * columns.len() as u32).max(480);
+    let height = 380;
+    let left = (width - column_width * columns.len() as u32) / 2;
+    let plot_height = (SVG_BASELINE - SVG_PLOT_TOP) as u64;
+
+    let mut svg = svg_header(title, width, height);
+    for (idx, column) in columns.iter().enumerate() {
+        let column_x = left + idx as u32 * column_width;
+        let bars_width = column.bars.len() as u32 * (SVG_BAR_WIDTH + 4) - 4;
+        let mut x = column_x + (column_width - bars_width) / 2;
+        for bar in &column.bars {
+            let mut y = SVG_BASELINE;
+            for (value, color) in &bar.segments {
+                let h = (value * plot_height / max) as u32;
+                y -= h;
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    x, y, SVG_BAR_WIDTH, h, color
+                );
+                if bar.segment_labels && h >= 14 {
+                    let _ = writeln!(
+                        svg,
+                        "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"11\" fill=\"#ffffff\">{}</text>",
+                        x + SVG_BAR_WIDTH / 2,
+                        y + h / 2 + 4,
+                        value
+                    );
+                }
+            }
+            let _ = writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"11\">{}</text>",
+                x + SVG_BAR_WIDTH / 2,
+                y - 5,
+                escape(&bar.top)
+            );
+            x += SVG_BAR_WIDTH + 4;
+        }
+        let label: String = column.label.chars().take(16).collect();
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-size=\"12\">{}</text>",
+            column_x + column_width / 2,
+            SVG_BASELINE + 18,
+            escape(&label)
+        );
+    }
+    let _ = writeln!(
+        svg,
+        "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#888888\"/>",
+        left,
+        SVG_BASELINE,
+        width - left,
+        SVG_BASELINE
+    );
+
+    if !legend.is_empty() {
+        let mut x = SVG_MARGIN;
+        for (color, label) in legend {
+            let _ = writeln!(
+                svg,
+                "<rect x=\"{}\" y=\"{}\" width=\"12\" height=\"12\" fill=\"{}\"/>\n<text x=\"{}\" y=\"{}\" font-size=\"12\">{}</text>",
+                x,
+                SVG_BASELINE + 40,
+                color,
+                x + 16,
+                SVG_BASELINE + 50,
+                escape(label)
+            );
+            x += 30 + label.chars().count() as u32 * 7;
+        }
+    }
+    if let Some(footnote) = footnote {
+        let _ = writeln!(
+            svg,
+            "<text x=\"{}\" y=\"{}\" font-size=\"11\" fill=\"#666666\">{}</text>",
+            SVG_MARGIN,
+            height - 12,
+            escape(footnote)
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// The time in stage chart is a table in the TUI too
+fn svg_table(title: &str, times: &ResponseTimes) -> String {
+    let rows: [(&str, Option<DaySummary>); 4] = [
+        ("Applied → first response", times.first_response),
+        ("Applied → interview", times.applied_to_interview),
+        ("Interview → offer", times.interview_to_offer),
+        ("Still waiting (so far)", times.waiting),
+    ];
+    let columns = [40, 260, 330, 430];
+    let mut svg = svg_header(title, 540, 90 + rows.len() as u32 * 24);
+
+    for (x, heading) in columns.iter().zip(["Stage", "Apps", "Average", "Median"]) {
+        let _ = writeln!(svg, "<text x=\"{}\" y=\"84\" font-size=\"12\" font-weight=\"bold\">{}</text>", x, heading);
+    }
+    for (idx, (stage, summary)) in rows.iter().enumerate() {
+        let cells = match summary {
+            Some(summary) => [
+                stage.to_string(),
+                summary.count.to_string(),
+                format!("{:.1} days", summary.mean),
+                format!("{:.1} days", summary.median),
+            ],
+            None => [stage.to_string(), "0".to_string(), "—".to_string(), "—".to_string()],
+        };
+        let y = 108 + idx as u32 * 24;
+        for (x, cell) in columns.iter().zip(cells) {
+            let _ = writeln!(svg, "<text x=\"{}\" y=\"{}\" font-size=\"12\">{}</text>", x, y, escape(&cell));
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn svg_header(title: &str, width: u32, height: u32) -> String {
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\">",
+        w = width,
+        h = height
+    );
+    let _ = writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>");
+    let _ = writeln!(
+        svg,
+        "<text x=\"{}\" y=\"36\" font-size=\"18\" font-weight=\"bold\">{}</text>",
+        SVG_MARGIN,
+        escape(title)
+    );
+    svg
+}
+
+/// CSS color for a style's foreground, using xterm's shades for the named colors
+fn css_color(style: Style) -> String {
+    let hex = match style.fg {
+        Some(Color::Rgb(r, g, b)) => return format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Some(Color::Black) => "#000000",
+        Some(Color::Red) => "#cd0000",
+        Some(Color::Green) => "#00a000",
+        Some(Color::Yellow) => "#cdcd00",
+        Some(Color::Blue) => "#0000ee",
+        Some(Color::Magenta) => "#cd00cd",
+        Some(Color::Cyan) => "#00cdcd",
+        Some(Color::Gray) => "#e5e5e5",
+        Some(Color::DarkGray) => "#7f7f7f",
+        Some(Color::LightRed) => "#ff0000",
+        Some(Color::LightGreen) => "#00ff00",
+        Some(Color::LightYellow) => "#ffff00",
+        Some(Color::LightBlue) => "#5c5cff",
+        Some(Color::LightMagenta) => "#ff00ff",
+        Some(Color::LightCyan) => "#00ffff",
+        Some(Color::White) => "#ffffff",
+        // No color (palette "none"), the terminal default or a palette index
+        _ => "#555555",
+    };
+    hex.to_string()
+}
+
+const BOARD_HEADER: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Job Applications</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 1rem; background: #f4f5f7; color: #172b4d; }
+main { display: flex; flex-wrap: wrap; gap: 1rem; align-items: flex-start; }
+.column { flex: 1 1 14rem; background: #ebecf0; border-radius: 6px; padding: 0.5rem; }
+.column h2 { font-size: 1rem; margin: 0.25rem 0.25rem 0.5rem; }
+.count { color: #5e6c84; font-weight: normal; }
+.card { background: #fff; border-radius: 4px; padding: 0.5rem; margin-bottom: 0.5rem; box-shadow: 0 1px 1px rgba(9, 30, 66, 0.25); }
+.card h3 { font-size: 0.95rem; margin: 0 0 0.25rem; }
+.card p { font-size: 0.8rem; margin: 0; color: #5e6c84; }
+.planned { border-top: 4px solid #0052cc; }
+.applied { border-top: 4px solid #d4a017; }
+.interview { border-top: 4px solid #00a3bf; }
+.offer { border-top: 4px solid #36b37e; }
+.rejected { border-top: 4px solid #de350b; }
+.custom { border-top: 4px solid #6554c0; }
+</style>
+</head>
+<body>
+<h1>Job Applications</h1>
+<main>
+"#;
+
+const BOARD_FOOTER: &str = "</main>\n</body>\n</html>\n";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ical;
+    use crate::models::{ActivityEntry, InterviewKind, Platform};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn applications() -> Vec<Application> {
+        vec![
+            Application {
+                company_name: "Acme & Sons".to_string(),
+                job_title: Some("Backend Engineer".to_string()),
+                url: Some("https://jobs.acme.example/123".to_string()),
+                external_id: Some("ACME-REQ-77".to_string()),
+                status: Status::Interview,
+                applied_date: date(2),
+                follow_up: Some(date(20)),
+                contact_name: Some("Wile Coyote".to_string()),
+                contact_email: Some("wile@acme.example".to_string()),
+                interviews: vec![Interview {
+                    kind: InterviewKind::Onsite,
+                    date: date(14),
+                    notes: "Ask Road Runner about the team".to_string(),
+                }],
+                activity: vec![ActivityEntry { date: date(6), text: "Acme recruiter called".to_string() }],
+                notes: "Referred by Marvin at Acme".to_string(),
+                ..Application::new()
+            },
+            Application {
+                company_name: "<Globex>".to_string(),
+                platform: Platform::Other("Hacker \"News\"".to_string()),
+                applied_date: date(10),
+                ..Application::new()
+            },
+            Application {
+                company_name: "Initech".to_string(),
+                status: Status::Rejected,
+                applied_date: date(1),
+                ..Application::new()
+            },
+            // Same company as the first, spelled differently
+            Application {
+                company_name: " acme & sons".to_string(),
+                applied_date: date(12),
+                ..Application::new()
+            },
+        ]
+    }
+
+    /// Text from the fixture that must not survive anonymizing
+    const PRIVATE: &[&str] = &[
+        "Acme", "acme", "Globex", "Initech", "ACME-REQ", "Wile", "Coyote", "Road Runner", "recruiter", "Marvin",
+    ];
+
+    #[test]
+    fn html_board_matches_the_golden_file() {
+        let html = html_board(&applications(), Status::all(), date(12));
+        insta::assert_snapshot!("html_board", html);
+    }
+
+    #[test]
+    fn anonymized_exports_leak_no_identifying_text() {
+        let anonymized = anonymize(&applications());
+        let html = html_board(&anonymized, Status::all(), date(12));
+        let calendar = ical::calendar(&ical::events(&anonymized), chrono::Utc::now());
+        let json = serde_json::to_string(&anonymized).unwrap();
+        for output in [&html, &calendar, &json] {
+            for private in PRIVATE {
+                assert!(!output.contains(private), "{:?} leaked into:\n{}", private, output);
+            }
+        }
+        assert!(html.contains("<h3>Company A</h3>"));
+    }
+
+    #[test]
+    fn anonymize_gives_each_company_one_stable_alias() {
+        let companies: Vec<String> = anonymize(&applications()).into_iter().map(|a| a.company_name).collect();
+        assert_eq!(companies, ["Company A", "Company B", "Company C", "Company A"]);
+        assert_eq!(alias_letters(25), "Z");
+        assert_eq!(alias_letters(26), "AA");
+        assert_eq!(alias_letters(27), "AB");
+    }
+}
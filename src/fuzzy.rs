@@ -0,0 +1,153 @@
+/// Where a query matched a candidate and how well; higher scores are better matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub score: i64,
+    /// Character indices of the candidate that matched, in order
+    pub positions: Vec<usize>,
+}
+
+/// Added to every substring match so it ranks above any scattered match
+const SUBSTRING: i64 = 100_000;
+const PREFIX_BONUS: i64 = 50;
+const WORD_START_BONUS: i64 = 10;
+const CONSECUTIVE_BONUS: i64 = 5;
+
+/// Match `query` against `candidate`, ignoring case.
+/// A query found as a substring ranks above one found only as a subsequence, e.g. "msft" in
+/// "Microsoft"; earlier substrings and subsequences landing on word starts rank higher still.
+/// An empty query matches everything with no positions.
+pub fn score(query: &str, candidate: &str) -> Option<Match> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(Match {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    // One lowercase char per candidate char so positions line up with the original
+    let candidate: Vec<char> = candidate
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+
+    substring(&query, &candidate).or_else(|| subsequence(&query, &candidate))
+}
+
+/// Like `score`, but only matching a query found as a substring, for codes and IDs where
+/// scattered letters mean nothing. An empty query matches nothing.
+pub fn score_substring(query: &str, candidate: &str) -> Option<Match> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return None;
+    }
+    let candidate: Vec<char> = candidate
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+    substring(&query, &candidate)
+}
+
+fn substring(query: &[char], candidate: &[char]) -> Option<Match> {
+    let start = candidate.windows(query.len()).position(|window| window == query)?;
+    let mut score = SUBSTRING - start as i64;
+    if start == 0 {
+        score += PREFIX_BONUS;
+    } else if is_word_start(candidate, start) {
+        score += WORD_START_BONUS;
+    }
+    Some(Match {
+        score,
+        positions: (start..start + query.len()).collect(),
+    })
+}
+
+/// Leftmost subsequence, scored by word starts and runs of consecutive matches minus the gaps
+fn subsequence(query: &[char], candidate: &[char]) -> Option<Match> {
+    let mut positions = Vec::with_capacity(query.len());
+    let mut from = 0;
+    for c in query {
+        let offset = candidate[from..].iter().position(|candidate| candidate == c)?;
+        positions.push(from + offset);
+        from += offset + 1;
+    }
+
+    let mut score = 0;
+    for (i, &pos) in positions.iter().enumerate() {
+        if is_word_start(candidate, pos) {
+            score += WORD_START_BONUS;
+        }
+        match i.checked_sub(1).map(|prev| positions[prev]) {
+            Some(prev) if pos == prev + 1 => score += CONSECUTIVE_BONUS,
+            Some(prev) => score -= (pos - prev - 1) as i64,
+            None => score -= pos as i64,
+        }
+    }
+    Some(Match { score, positions })
+}
+
+fn is_word_start(candidate: &[char], pos: usize) -> bool {
+    pos == 0 || !candidate[pos - 1].is_alphanumeric()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positions(query: &str, candidate: &str) -> Option<Vec<usize>> {
+        score(query, candidate).map(|m| m.positions)
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(score("", "Acme"), Some(Match { score: 0, positions: Vec::new() }));
+        assert_eq!(score_substring("", "REQ-42"), None);
+    }
+
+    #[test]
+    fn case_is_ignored() {
+        assert_eq!(positions("ACME", "acme corp"), Some(vec![0, 1, 2, 3]));
+        assert_eq!(positions("corp", "Acme CORP"), Some(vec![5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn subsequences_match_scattered_letters() {
+        assert_eq!(positions("msft", "Microsoft"), Some(vec![0, 5, 7, 8]));
+        assert_eq!(positions("tfsm", "Microsoft"), None);
+    }
+
+    #[test]
+    fn substrings_rank_above_subsequences() {
+        let substring = score("soft", "Microsoft").unwrap().score;
+        let subsequence = score("msft", "Microsoft").unwrap().score;
+        assert!(substring > subsequence);
+    }
+
+    #[test]
+    fn prefixes_and_word_starts_rank_higher() {
+        let prefix = score("go", "Google").unwrap().score;
+        let word_start = score("go", "Big Google").unwrap().score;
+        let inside = score("go", "Mongo").unwrap().score;
+        assert!(prefix > word_start);
+        assert!(word_start > inside);
+    }
+
+    #[test]
+    fn consecutive_letters_rank_above_gaps() {
+        let run = score("acm", "Ax Acme").unwrap().score;
+        let gaps = score("acm", "Axcxm").unwrap().score;
+        assert!(run > gaps);
+    }
+
+    #[test]
+    fn positions_count_characters_not_bytes() {
+        assert_eq!(positions("ric", "Zürich"), Some(vec![2, 3, 4]));
+        // 'İ' lowercases to two chars; the candidate keeps one per char so positions line up
+        assert_eq!(positions("stan", "İstanbul"), Some(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn substring_only_scoring_ignores_scattered_letters() {
+        assert!(score_substring("42", "REQ-1042").is_some());
+        assert_eq!(score_substring("r42", "REQ-1042"), None);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::dates::{self, WeekStart};
+use crate::models::{Application, Status};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fmt;
+
+/// Period an application goal covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GoalPeriod {
+    #[default]
+    Week,
+    Month,
+}
+
+impl GoalPeriod {
+    /// "this week" or "this month"
+    pub fn label(&self) -> &'static str {
+        match self {
+            GoalPeriod::Week => "this week",
+            GoalPeriod::Month => "this month",
+        }
+    }
+
+    /// Whether `date` falls in the same period as `today`
+    pub fn contains(&self, date: NaiveDate, today: NaiveDate, week_starts_on: WeekStart) -> bool {
+        match self {
+            GoalPeriod::Week => dates::same_week(date, today, week_starts_on),
+            GoalPeriod::Month => dates::same_month(date, today),
+        }
+    }
+}
+
+/// Application goal from the `[goal]` config section; a count of 0 means no goal
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default)]
+pub struct GoalConfig {
+    pub count: u32,
+    pub period: GoalPeriod,
+}
+
+impl GoalConfig {
+    /// Applications sent in the current period against the goal, if one is set
+    pub fn progress<'a>(
+        &self,
+        applications: impl IntoIterator<Item = &'a Application>,
+        today: NaiveDate,
+        week_starts_on: WeekStart,
+    ) -> Option<GoalProgress> {
+        if self.count == 0 {
+            return None;
+        }
+        let done = applications
+            .into_iter()
+            .filter(|a| a.status != Status::Planned)
+            .filter(|a| a.applied_date <= today && self.period.contains(a.applied_date, today, week_starts_on))
+            .count();
+        Some(GoalProgress {
+            done,
+            target: self.count as usize,
+            period: self.period,
+        })
+    }
+}
+
+/// Progress towards the goal in the current period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoalProgress {
+    pub done: usize,
+    pub target: usize,
+    pub period: GoalPeriod,
+}
+
+impl GoalProgress {
+    pub fn reached(&self) -> bool {
+        self.done >= self.target
+    }
+
+    /// Fraction of the goal reached, capped at 1.0
+    pub fn ratio(&self) -> f64 {
+        (self.done as f64 / self.target as f64).min(1.0)
+    }
+}
+
+/// "7/10 this week"
+impl fmt::Display for GoalProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} {}", self.done, self.target, self.period.label())
+    }
+}
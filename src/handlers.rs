@@ -1,27 +1,386 @@
-use crate::app::{App, FormField, View};
-use crate::models::{Platform, Status};
+use crate::app::{ActivityField, App, CopyField, DateTarget, FormField, InterviewDraft, InterviewField, MessageKind, PromptAction, View};
+use crate::columns::Column;
+use crate::keymap::Action;
+use crate::models::{InterviewKind, Platform, RejectionReason, Status, WorkMode, MAX_INTEREST};
+use crate::text::{self, TextBuffer};
 use anyhow::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+
+/// Handle a key press, showing anything that goes wrong in the status bar
+pub fn handle_key_event(app: &mut App, key: KeyEvent) {
+    // Errors stay up until they expire; other messages go with the next key
+    if app.message.as_ref().is_some_and(|(_, kind, _)| *kind == MessageKind::Info) {
+        app.message = None;
+    }
+    if let Err(err) = handle_key(app, key) {
+        app.error(format!("{:#}", err));
+    }
+}
 
 /// Handle keyboard events based on current view
-pub fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<()> {
+fn handle_key(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Raw mode turns Ctrl+C into a key press rather than a signal, so it can't lose a form's edits
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        return app.interrupt();
+    }
+
+    // Nothing else works until the user decides what to do about an unreadable data file
+    if app.corrupt.is_some() {
+        return handle_corrupt_keys(app, key);
+    }
+
+    // The today summary sits above everything; any key dismisses it
+    if app.today_summary.is_some() {
+        app.today_summary = None;
+        return Ok(());
+    }
+    if dispatch(app, &key, &[Action::TodaySummary]).is_some() {
+        app.show_today_summary();
+        return Ok(());
+    }
+
+    // The help overlay takes every key until it's closed
+    if app.help_open {
+        if dispatch(app, &key, &[Action::Help, Action::Cancel]).is_some() {
+            app.help_open = false;
+        }
+        return Ok(());
+    }
+
+    if app.prompt.is_some() {
+        return handle_prompt_keys(app, key);
+    }
+
+    if app.date_picker.is_some() {
+        handle_date_picker_keys(app, key);
+        return Ok(());
+    }
+
+    if app.interview_editor.is_some() {
+        handle_interview_editor_keys(app, key);
+        return Ok(());
+    }
+
+    if app.activity_log.is_some() {
+        handle_activity_log_keys(app, key);
+        return Ok(());
+    }
+
+    if app.rejection_picker.is_some() {
+        handle_rejection_picker_keys(app, key);
+        return Ok(());
+    }
+
+    if app.status_picker.is_some() {
+        return handle_status_picker_keys(app, key);
+    }
+
+    if app.copy_picker.is_some() {
+        handle_copy_picker_keys(app, key);
+        return Ok(());
+    }
+
+    if app.company_popup.is_some() {
+        handle_company_popup_keys(app, key);
+        return Ok(());
+    }
+
+    if app.trash_picker.is_some() {
+        handle_trash_keys(app, key);
+        return Ok(());
+    }
+
+    if app.column_picker.is_some() {
+        return handle_column_picker_keys(app, key);
+    }
+
+    if app.notes_search.is_some() {
+        handle_notes_search_keys(app, key);
+        return Ok(());
+    }
+
+    if app.template_name.is_some() {
+        return handle_template_name_keys(app, key);
+    }
+
+    if app.template_picker.is_some() {
+        return handle_template_picker_keys(app, key);
+    }
+
+    if app.palette.is_some() {
+        return handle_palette_keys(app, key);
+    }
+
+    if app.view == View::List && app.search_typing {
+        handle_search_keys(app, key);
+        return Ok(());
+    }
+
+    if app.view == View::List && app.active_jump().is_some_and(|jump| jump.explicit) && handle_jump_keys(app, key) {
+        return Ok(());
+    }
+
+    if !types_text(app, &key) && dispatch(app, &key, &[Action::Help]).is_some() {
+        app.help_open = true;
+        return Ok(());
+    }
+
     match app.view {
-        View::List => handle_list_keys(app, key),
-        View::Form => handle_form_keys(app, key),
-        View::Chart => handle_chart_keys(app, key),
+        View::List => handle_list_keys(app, key)?,
+        View::Form => handle_form_keys(app, key)?,
+        View::Chart => handle_chart_keys(app, key)?,
+        View::Narrate => handle_narrate_keys(app, key)?,
+        View::Board => handle_board_keys(app, key),
+        View::Dashboard => handle_dashboard_keys(app, key),
     }
+    app.usage.track_view(app.view);
+    Ok(())
+}
+
+/// Handle mouse events; only the list view responds to them
+pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    if overlay_open(app) || app.view != View::List {
+        return;
+    }
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if let Some(index) = app.list_row_at(mouse.column, mouse.row) {
+                app.click_list_row(index);
+            }
+        }
+        MouseEventKind::ScrollUp => app.select_previous(),
+        MouseEventKind::ScrollDown => app.select_next(),
+        _ => {}
+    }
+}
+
+/// Insert pasted text at the cursor of the focused form field or into the list search, all at
+/// once so letters the list binds, such as j and k, go in too. Single-line fields get the
+/// lines joined with spaces. Pastes anywhere else, such as into a dropdown or a popup, are
+/// dropped.
+pub fn handle_paste(app: &mut App, pasted: &str) {
+    if overlay_open(app) {
+        return;
+    }
+    if app.view == View::List && app.search_typing {
+        for c in single_line(pasted).chars() {
+            app.push_search_char(c);
+        }
+    } else if app.view == View::Form && app.form_field == FormField::Notes {
+        let pasted = pasted.replace("\r\n", "\n").replace('\r', "\n");
+        let pasted: String = pasted.chars().filter(|&c| c == '\n' || !c.is_control()).collect();
+        app.edit_notes(|buffer| buffer.insert_str(&pasted));
+    } else if app.view == View::Form {
+        if is_dropdown(app.form_field) && !(app.form_field == FormField::Platform && app.other_platform_selected()) {
+            return;
+        }
+        for c in single_line(pasted).chars() {
+            handle_text_input(app, c);
+        }
+    }
+}
+
+/// Pasted text as one line: each line trimmed and joined with a space, control characters dropped
+fn single_line(pasted: &str) -> String {
+    let line = pasted.split(['\r', '\n']).map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>().join(" ");
+    line.chars().filter(|c| !c.is_control()).collect()
 }
 
+/// Whether a popup or overlay is taking input instead of the view beneath it
+fn overlay_open(app: &App) -> bool {
+    app.corrupt.is_some()
+        || app.today_summary.is_some()
+        || app.help_open
+        || app.prompt.is_some()
+        || app.interview_editor.is_some()
+        || app.date_picker.is_some()
+        || app.activity_log.is_some()
+        || app.status_picker.is_some()
+        || app.rejection_picker.is_some()
+        || app.copy_picker.is_some()
+        || app.company_popup.is_some()
+        || app.trash_picker.is_some()
+        || app.column_picker.is_some()
+        || app.notes_search.is_some()
+        || app.template_name.is_some()
+        || app.template_picker.is_some()
+        || app.palette.is_some()
+}
+
+/// Resolve the key to one of `candidates`, counting it in the usage stats
+fn dispatch(app: &mut App, key: &KeyEvent, candidates: &[Action]) -> Option<Action> {
+    let action = app.keymap.action(key, candidates)?;
+    app.usage.record_action(action);
+    Some(action)
+}
+
+/// Actions available in list view, in lookup order
+const LIST_ACTIONS: &[Action] = &[
+    Action::Quit,
+    Action::Add,
+    Action::Edit,
+    Action::Archive,
+    Action::ShowArchived,
+    Action::Delete,
+    Action::Deleted,
+    Action::Mark,
+    Action::VisualSelect,
+    Action::Cancel,
+    Action::QuickStatus,
+    Action::Copy,
+    Action::OpenUrl,
+    Action::OpenResume,
+    Action::Chart,
+    Action::ReadMode,
+    Action::Board,
+    Action::Dashboard,
+    Action::Up,
+    Action::Down,
+    Action::PageUp,
+    Action::PageDown,
+    Action::Top,
+    Action::Bottom,
+    Action::FilterApplied,
+    Action::FilterInterview,
+    Action::FilterOffer,
+    Action::FilterRejected,
+    Action::ClearFilter,
+    Action::FilterWorkMode,
+    Action::FilterAttention,
+    Action::Sort,
+    Action::InterestUp,
+    Action::InterestDown,
+    Action::Promote,
+    Action::Company,
+    Action::Columns,
+    Action::RelativeDates,
+    Action::Group,
+    Action::Templates,
+    Action::ExportIcal,
+    Action::MarkGhosted,
+    Action::Search,
+    Action::SearchNotes,
+    Action::Jump,
+    Action::JumpNext,
+    Action::JumpPrev,
+    Action::Palette,
+];
+
+/// Actions available in chart view, in lookup order
+const CHART_ACTIONS: &[Action] = &[
+    Action::Cancel,
+    Action::NextChart,
+    Action::PrevChart,
+    Action::NextRange,
+    Action::PrevRange,
+    Action::Legend,
+    Action::Percent,
+    Action::Report,
+    Action::ExportChart,
+    Action::Palette,
+];
+
+/// Actions available in read mode, in lookup order
+const NARRATE_ACTIONS: &[Action] = &[
+    Action::Cancel,
+    Action::Quit,
+    Action::Up,
+    Action::Down,
+    Action::OpenResume,
+    Action::Copy,
+    Action::Activity,
+    Action::ExportApplication,
+    Action::Palette,
+];
+
+/// Actions available on the pipeline board, in lookup order
+const BOARD_ACTIONS: &[Action] = &[Action::Cancel, Action::Quit, Action::Up, Action::Down, Action::Palette];
+
+/// Actions available on the dashboard
+const DASHBOARD_ACTIONS: &[Action] = &[Action::Cancel, Action::Quit, Action::Up, Action::Down, Action::Palette];
+
+/// Actions available in form view, in lookup order
+const FORM_ACTIONS: &[Action] = &[
+    Action::Cancel,
+    Action::SaveForm,
+    Action::SaveTemplate,
+    Action::PrevField,
+    Action::NextField,
+];
+
 /// Handle keyboard events in list view
 fn handle_list_keys(app: &mut App, key: KeyEvent) -> Result<()> {
-    match key.code {
-        KeyCode::Char('q') => app.quit(),
-        KeyCode::Char('a') => app.start_add(),
-        KeyCode::Char('e') => app.start_edit(),
-        KeyCode::Char('d') => app.delete_selected()?,
-        KeyCode::Char('g') => app.show_chart(),
-        KeyCode::Up | KeyCode::Char('k') => app.select_previous(),
-        KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+    let Some(action) = dispatch(app, &key, LIST_ACTIONS) else {
+        if key.code == KeyCode::Enter {
+            app.toggle_selected_group();
+        }
+        // Letters and digits no action uses jump to a company by typing its start
+        if let KeyCode::Char(c) = key.code {
+            if c.is_alphanumeric() && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                app.push_jump_char(c);
+            }
+        }
+        return Ok(());
+    };
+    app.end_jump();
+    run_list_action(app, action)
+}
+
+/// Carry out a list view action, whether from its key or the command palette
+fn run_list_action(app: &mut App, action: Action) -> Result<()> {
+    match action {
+        Action::Palette => app.open_palette(),
+        Action::Quit => app.quit()?,
+        Action::Add => app.start_add(),
+        Action::Edit => app.start_edit(),
+        Action::Archive => app.toggle_archive_selected(),
+        Action::ShowArchived => app.toggle_show_archived(),
+        Action::Delete => app.delete_batch(),
+        Action::Deleted => app.toggle_trash(),
+        // Space on a company header collapses it rather than marking
+        Action::Mark if app.selected_group().is_some() => app.toggle_selected_group(),
+        Action::Mark => app.toggle_mark_selected(),
+        Action::VisualSelect => app.toggle_visual_select(),
+        Action::Cancel if app.marked.is_empty() && app.visual_anchor.is_none() => app.clear_search(),
+        Action::Cancel => app.clear_marks(),
+        Action::QuickStatus => app.open_status_picker(),
+        Action::Copy => app.open_copy_picker(),
+        Action::OpenUrl => app.open_selected_url(),
+        Action::OpenResume => app.open_selected_resume(),
+        Action::Chart => app.show_chart(),
+        Action::ReadMode => app.show_narrate(),
+        Action::Board => app.show_board(),
+        Action::Dashboard => app.show_dashboard(),
+        Action::Up => app.select_previous(),
+        Action::Down => app.select_next(),
+        Action::PageUp => app.select_page_up(),
+        Action::PageDown => app.select_page_down(),
+        Action::Top => app.select_first(),
+        Action::Bottom => app.select_last(),
+        Action::FilterApplied => app.toggle_status_filter(Status::Applied),
+        Action::FilterInterview => app.toggle_status_filter(Status::Interview),
+        Action::FilterOffer => app.toggle_status_filter(Status::Offer),
+        Action::FilterRejected => app.toggle_status_filter(Status::Rejected),
+        Action::ClearFilter => app.set_status_filter(None),
+        Action::FilterWorkMode => app.cycle_work_mode_filter(),
+        Action::FilterAttention => app.toggle_attention_filter(),
+        Action::Sort => app.cycle_sort(),
+        Action::InterestUp => app.change_interest(true),
+        Action::InterestDown => app.change_interest(false),
+        Action::Promote => app.promote_selected(),
+        Action::Company => app.open_company_popup(),
+        Action::Columns => app.open_column_picker(),
+        Action::RelativeDates => app.config.list.relative_dates = !app.config.list.relative_dates,
+        Action::Group => app.toggle_grouped(),
+        Action::Templates => app.open_template_picker(),
+        Action::ExportIcal => app.export_ical(),
+        Action::MarkGhosted => app.confirm_mark_ghosted(),
+        Action::Search => app.start_search(),
+        Action::SearchNotes => app.open_notes_search(),
+        Action::Jump => app.start_jump(),
+        Action::JumpNext => app.repeat_jump(true),
+        Action::JumpPrev => app.repeat_jump(false),
         _ => {}
     }
     Ok(())
@@ -29,90 +388,614 @@ fn handle_list_keys(app: &mut App, key: KeyEvent) -> Result<()> {
 
 /// Handle keyboard events in form view
 fn handle_form_keys(app: &mut App, key: KeyEvent) -> Result<()> {
-    match key.code {
-        KeyCode::Esc => app.cancel_form(),
-        KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.save_form()?;
+    match dispatch(app, &key, FORM_ACTIONS) {
+        Some(Action::Cancel) => {
+            app.cancel_form();
+            return Ok(());
+        }
+        Some(Action::SaveForm) => return app.save_form(),
+        Some(Action::SaveTemplate) => {
+            app.start_save_template();
+            return Ok(());
         }
+        Some(Action::PrevField) => {
+            // Moves up a line within Notes, otherwise to the previous field
+            let moved = app.form_field == FormField::Notes && app.notes_cursor_up();
+            if !moved {
+                app.prev_field();
+            }
+            return Ok(());
+        }
+        Some(Action::NextField) => {
+            // Moves down a line within Notes, otherwise to the next field
+            let moved = app.form_field == FormField::Notes && app.notes_cursor_down();
+            if !moved {
+                app.next_field();
+            }
+            return Ok(());
+        }
+        _ => {}
+    }
+
+    // Up/down keys choose an option while a dropdown field is focused
+    if is_dropdown(app.form_field) {
+        match dispatch(app, &key, &[Action::Up, Action::Down]) {
+            Some(Action::Up) => {
+                dropdown_previous(app);
+                return Ok(());
+            }
+            Some(Action::Down) => {
+                dropdown_next(app);
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+
+    match key.code {
         KeyCode::Enter => {
             // In dropdown fields, Enter selects the option and moves to next field
-            // On the last field (Notes), Enter saves the form
+            // In Notes, Enter starts a new line
             match app.form_field {
                 FormField::Platform => {
-                    let selected = Platform::presets()[app.platform_dropdown_selected];
-                    app.form_data.platform = Platform::from_str(selected);
+                    let presets = app.platform_presets();
+                    app.form_data.platform = Platform::from_str(&presets[app.platform_dropdown_selected]);
                     app.next_field();
                 }
                 FormField::Status => {
-                    app.form_data.status = Status::all()[app.status_dropdown_selected];
+                    if let Some(status) = app.status_order().get(app.status_dropdown_selected) {
+                        app.set_form_status(status.clone());
+                    }
+                    app.next_field();
+                }
+                FormField::WorkMode => {
+                    app.form_data.work_mode = WorkMode::all()[app.work_mode_dropdown_selected];
+                    app.next_field();
+                }
+                FormField::Interest => {
+                    app.form_data.interest = app.interest_dropdown_selected as u8;
                     app.next_field();
                 }
                 FormField::ResumeModified => {
                     app.form_data.resume_modified = app.resume_modified_dropdown_selected == 0;
                     app.next_field();
                 }
-                FormField::Notes => {
-                    // Last field - save the form
-                    app.save_form()?;
+                FormField::Referral => {
+                    app.form_data.referral = app.referral_dropdown_selected == 0;
+                    app.next_field();
+                }
+                FormField::CoverLetter => {
+                    app.form_data.cover_letter = app.cover_letter_dropdown_selected == 0;
+                    app.next_field();
                 }
+                FormField::Date => app.open_date_picker(DateTarget::Applied),
+                FormField::FollowUp => app.open_date_picker(DateTarget::FollowUp),
+                FormField::Deadline => app.open_date_picker(DateTarget::Deadline),
+                FormField::Interviews => app.open_interview_editor(),
+                FormField::Notes => app.notes_insert('\n'),
                 _ => app.next_field(),
             }
         }
-        KeyCode::Up => {
-            // Up arrow moves to previous field
-            app.prev_field();
+        KeyCode::Left if app.form_field == FormField::Notes => app.notes_cursor_left(),
+        KeyCode::Right if app.form_field == FormField::Notes => app.notes_cursor_right(),
+        KeyCode::Char(c) if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            handle_word_edit(app, c, key.modifiers.contains(KeyModifiers::ALT));
         }
-        KeyCode::Down => {
-            // Down arrow moves to next field
-            app.next_field();
+        KeyCode::Char(c) => {
+            handle_text_input(app, c);
         }
-        KeyCode::Char('k') => {
-            // k navigates within dropdown options
-            match app.form_field {
-                FormField::Platform => {
-                    if app.platform_dropdown_selected > 0 {
-                        app.platform_dropdown_selected -= 1;
-                    }
-                }
-                FormField::Status => {
-                    if app.status_dropdown_selected > 0 {
-                        app.status_dropdown_selected -= 1;
-                    }
-                }
-                FormField::ResumeModified => {
-                    if app.resume_modified_dropdown_selected > 0 {
-                        app.resume_modified_dropdown_selected -= 1;
-                    }
-                }
-                _ => {}
+        KeyCode::Backspace => {
+            handle_backspace(app);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Whether a plain character would be typed into the focused form field, e.g. a `?` in Notes
+fn types_text(app: &App, key: &KeyEvent) -> bool {
+    let plain = matches!(key.code, KeyCode::Char(_))
+        && !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    let text_field = match app.form_field {
+        FormField::Platform => app.other_platform_selected(),
+        field => matches!(
+            field,
+            FormField::CompanyName
+                | FormField::JobTitle
+                | FormField::Url
+                | FormField::ExternalId
+                | FormField::Location
+                | FormField::ResumeVersion
+                | FormField::ResumeFile
+                | FormField::CoverLetterVersion
+                | FormField::Salary
+                | FormField::Date
+                | FormField::ContactName
+                | FormField::ContactEmail
+                | FormField::Notes
+        ),
+    };
+    plain && app.view == View::Form && text_field
+}
+
+/// Whether a form field is chosen from a dropdown list
+fn is_dropdown(field: FormField) -> bool {
+    matches!(
+        field,
+        FormField::Platform
+            | FormField::WorkMode
+            | FormField::Status
+            | FormField::Interest
+            | FormField::ResumeModified
+            | FormField::CoverLetter
+            | FormField::Referral
+    )
+}
+
+/// Move the focused dropdown's selection up one option
+fn dropdown_previous(app: &mut App) {
+    let selected = match app.form_field {
+        FormField::Platform => &mut app.platform_dropdown_selected,
+        FormField::WorkMode => &mut app.work_mode_dropdown_selected,
+        FormField::Status => &mut app.status_dropdown_selected,
+        FormField::Interest => &mut app.interest_dropdown_selected,
+        FormField::ResumeModified => &mut app.resume_modified_dropdown_selected,
+        FormField::Referral => &mut app.referral_dropdown_selected,
+        FormField::CoverLetter => &mut app.cover_letter_dropdown_selected,
+        _ => return,
+    };
+    *selected = selected.saturating_sub(1);
+}
+
+/// Move the focused dropdown's selection down one option
+fn dropdown_next(app: &mut App) {
+    let status_count = app.status_order().len();
+    let platform_count = app.platform_presets().len();
+    let (selected, len) = match app.form_field {
+        FormField::Platform => (&mut app.platform_dropdown_selected, platform_count),
+        FormField::WorkMode => (&mut app.work_mode_dropdown_selected, WorkMode::all().len()),
+        FormField::Status => (&mut app.status_dropdown_selected, status_count),
+        FormField::Interest => (&mut app.interest_dropdown_selected, MAX_INTEREST as usize + 1),
+        FormField::ResumeModified => (&mut app.resume_modified_dropdown_selected, 2),
+        FormField::Referral => (&mut app.referral_dropdown_selected, 2),
+        FormField::CoverLetter => (&mut app.cover_letter_dropdown_selected, 2),
+        _ => return,
+    };
+    *selected = (*selected + 1).min(len - 1);
+}
+
+/// Handle keyboard events while a yes/no prompt is open
+fn handle_prompt_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    // Losing changes needs an explicit `y` so a stray Enter can't do it
+    let enter_confirms = app.prompt.as_ref().is_some_and(|prompt| {
+        !matches!(
+            prompt.action,
+            PromptAction::DiscardForm | PromptAction::DiscardFormAndQuit | PromptAction::QuitWithoutSaving
+        )
+    });
+    match key.code {
+        KeyCode::Char('y') => app.confirm_prompt()?,
+        KeyCode::Enter if enter_confirms => app.confirm_prompt()?,
+        KeyCode::Char('n') | KeyCode::Esc => app.dismiss_prompt(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events in the interview rounds popup
+fn handle_interview_editor_keys(app: &mut App, key: KeyEvent) {
+    let drafting = app
+        .interview_editor
+        .as_ref()
+        .is_some_and(|editor| editor.draft.is_some());
+    if drafting {
+        handle_interview_draft_keys(app, key);
+        return;
+    }
+
+    let actions = [Action::Cancel, Action::Up, Action::Down, Action::Add, Action::Delete];
+    match dispatch(app, &key, &actions) {
+        Some(Action::Cancel) => app.close_interview_editor(),
+        Some(Action::Add) => app.start_interview_draft(),
+        Some(Action::Delete) => app.delete_selected_interview(),
+        Some(Action::Up) => {
+            if let Some(editor) = app.interview_editor.as_mut() {
+                editor.selected = editor.selected.saturating_sub(1);
             }
         }
-        KeyCode::Char('j') => {
-            // j navigates within dropdown options
-            match app.form_field {
-                FormField::Platform => {
-                    if app.platform_dropdown_selected < Platform::presets().len() - 1 {
-                        app.platform_dropdown_selected += 1;
-                    }
-                }
-                FormField::Status => {
-                    if app.status_dropdown_selected < Status::all().len() - 1 {
-                        app.status_dropdown_selected += 1;
-                    }
-                }
-                FormField::ResumeModified => {
-                    if app.resume_modified_dropdown_selected < 1 {
-                        app.resume_modified_dropdown_selected += 1;
-                    }
-                }
-                _ => {}
+        Some(Action::Down) => {
+            let last = app.form_data.interviews.len().saturating_sub(1);
+            if let Some(editor) = app.interview_editor.as_mut() {
+                editor.selected = (editor.selected + 1).min(last);
             }
         }
-        KeyCode::Char(c) => {
-            handle_text_input(app, c);
+        _ => {}
+    }
+}
+
+/// Handle keyboard events while typing a new interview round
+fn handle_interview_draft_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, &[Action::Cancel, Action::PrevField, Action::NextField]) {
+        Some(Action::Cancel) => {
+            if let Some(editor) = app.interview_editor.as_mut() {
+                editor.draft = None;
+            }
+            return;
+        }
+        Some(Action::PrevField) => {
+            if let Some(draft) = interview_draft(app) {
+                draft.field = draft.field.prev();
+            }
+            return;
+        }
+        Some(Action::NextField) => {
+            if let Some(draft) = interview_draft(app) {
+                draft.field = draft.field.next();
+            }
+            return;
         }
+        _ => {}
+    }
+
+    let on_date = interview_draft(app).is_some_and(|draft| draft.field == InterviewField::Date);
+    match key.code {
+        KeyCode::Enter if on_date => {
+            app.open_date_picker(DateTarget::Interview);
+            return;
+        }
+        KeyCode::Enter => {
+            app.save_interview_draft();
+            return;
+        }
+        _ => {}
+    }
+
+    let Some(draft) = interview_draft(app) else {
+        return;
+    };
+    let kinds = InterviewKind::all();
+    let kind = kinds.iter().position(|kind| *kind == draft.kind).unwrap_or(0);
+    match (draft.field, key.code) {
+        (_, KeyCode::Tab) => draft.field = draft.field.next(),
+        (_, KeyCode::BackTab) => draft.field = draft.field.prev(),
+        (InterviewField::Kind, KeyCode::Left) => draft.kind = kinds[(kind + kinds.len() - 1) % kinds.len()],
+        (InterviewField::Kind, KeyCode::Right | KeyCode::Char(' ')) => {
+            draft.kind = kinds[(kind + 1) % kinds.len()]
+        }
+        (InterviewField::Date, KeyCode::Char(c)) if c.is_ascii_digit() || c == '-' => draft.date.push(c),
+        (InterviewField::Date, KeyCode::Backspace) => {
+            text::pop_grapheme(&mut draft.date);
+        }
+        (InterviewField::Notes, KeyCode::Char(c)) => draft.notes.push(c),
+        (InterviewField::Notes, KeyCode::Backspace) => {
+            text::pop_grapheme(&mut draft.notes);
+        }
+        _ => {}
+    }
+}
+
+fn interview_draft(app: &mut App) -> Option<&mut InterviewDraft> {
+    app.interview_editor.as_mut().and_then(|editor| editor.draft.as_mut())
+}
+
+/// Handle keyboard events in the calendar popup: h/l move a day, j/k a week, [/] a month
+fn handle_date_picker_keys(app: &mut App, key: KeyEvent) {
+    if dispatch(app, &key, &[Action::Cancel]).is_some() {
+        app.date_picker = None;
+        return;
+    }
+    if key.code == KeyCode::Enter {
+        app.accept_date_picker();
+        return;
+    }
+
+    let Some((_, picker)) = app.date_picker.as_mut() else {
+        return;
+    };
+    match key.code {
+        KeyCode::Char('h') | KeyCode::Left => picker.move_days(-1),
+        KeyCode::Char('l') | KeyCode::Right => picker.move_days(1),
+        KeyCode::Char('k') | KeyCode::Up => picker.move_days(-7),
+        KeyCode::Char('j') | KeyCode::Down => picker.move_days(7),
+        KeyCode::Char('[') | KeyCode::PageUp => picker.move_months(-1),
+        KeyCode::Char(']') | KeyCode::PageDown => picker.move_months(1),
+        KeyCode::Char('t') => picker.date = chrono::Local::now().date_naive(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the activity log popup
+fn handle_activity_log_keys(app: &mut App, key: KeyEvent) {
+    let drafting = app.activity_log.as_ref().is_some_and(|log| log.draft.is_some());
+    if drafting {
+        handle_activity_draft_keys(app, key);
+        return;
+    }
+
+    let actions = [Action::Cancel, Action::Up, Action::Down, Action::Add, Action::Edit, Action::Delete];
+    match dispatch(app, &key, &actions) {
+        Some(Action::Cancel) => app.close_activity_log(),
+        Some(Action::Add) => app.start_activity_draft(),
+        Some(Action::Edit) => app.edit_selected_activity(),
+        Some(Action::Delete) => app.delete_selected_activity(),
+        Some(Action::Up) => app.move_activity_log(-1),
+        Some(Action::Down) => app.move_activity_log(1),
+        _ if key.code == KeyCode::Enter => app.edit_selected_activity(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events while typing an activity entry
+fn handle_activity_draft_keys(app: &mut App, key: KeyEvent) {
+    if dispatch(app, &key, &[Action::Cancel]).is_some() {
+        if let Some(log) = app.activity_log.as_mut() {
+            log.draft = None;
+        }
+        return;
+    }
+    if key.code == KeyCode::Enter {
+        app.save_activity_draft();
+        return;
+    }
+
+    let Some(draft) = app.activity_log.as_mut().and_then(|log| log.draft.as_mut()) else {
+        return;
+    };
+    match (draft.field, key.code) {
+        (_, KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down) => draft.field = draft.field.next(),
+        (ActivityField::Date, KeyCode::Char(c)) => draft.date.push(c),
+        (ActivityField::Date, KeyCode::Backspace) => {
+            text::pop_grapheme(&mut draft.date);
+        }
+        (ActivityField::Text, KeyCode::Char(c)) => draft.text.push(c),
+        (ActivityField::Text, KeyCode::Backspace) => {
+            text::pop_grapheme(&mut draft.text);
+        }
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the rejection reason picker; `Esc` leaves the reason unspecified
+fn handle_rejection_picker_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down]) {
+        Some(Action::Cancel) => app.close_rejection_picker(),
+        Some(Action::Up) => {
+            if let Some(picker) = app.rejection_picker.as_mut() {
+                picker.selected = picker.selected.saturating_sub(1);
+            }
+        }
+        Some(Action::Down) => {
+            if let Some(picker) = app.rejection_picker.as_mut() {
+                picker.selected = (picker.selected + 1).min(RejectionReason::all().len() - 1);
+            }
+        }
+        _ if key.code == KeyCode::Enter => app.apply_rejection_picker(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the quick status picker
+fn handle_status_picker_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down]) {
+        Some(Action::Cancel) => app.close_status_picker(),
+        Some(Action::Up) => {
+            if let Some(selected) = app.status_picker.as_mut() {
+                *selected = selected.saturating_sub(1);
+            }
+        }
+        Some(Action::Down) => {
+            let last = app.status_order().len() - 1;
+            if let Some(selected) = app.status_picker.as_mut() {
+                *selected = (*selected + 1).min(last);
+            }
+        }
+        _ if key.code == KeyCode::Enter => app.apply_status_picker()?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events on the recovery screen for an unreadable data file
+fn handle_corrupt_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    let recovered = app.corrupt.as_ref().is_some_and(|corrupt| !corrupt.recovered.applications.is_empty());
+    let backup = app.corrupt.as_ref().is_some_and(|corrupt| corrupt.backup.is_some());
+    match key.code {
+        KeyCode::Char('b') if backup => app.open_corrupt_backup(),
+        KeyCode::Char('r') if recovered => app.recover_corrupt(true),
+        KeyCode::Char('e') => app.recover_corrupt(false),
+        KeyCode::Char('q') | KeyCode::Esc => app.quit()?,
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events in the copy picker
+fn handle_copy_picker_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down]) {
+        Some(Action::Cancel) => app.close_copy_picker(),
+        Some(Action::Up) => {
+            if let Some(selected) = app.copy_picker.as_mut() {
+                *selected = selected.saturating_sub(1);
+            }
+        }
+        Some(Action::Down) => {
+            let last = CopyField::all().len() - 1;
+            if let Some(selected) = app.copy_picker.as_mut() {
+                *selected = (*selected + 1).min(last);
+            }
+        }
+        _ if key.code == KeyCode::Enter => app.copy_picked_field(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events while typing a search query; arrows still move through the results
+fn handle_search_keys(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.clear_search(),
+        KeyCode::Enter => app.finish_search(),
+        KeyCode::Backspace => app.pop_search_char(),
+        KeyCode::Up => app.select_previous(),
+        KeyCode::Down => app.select_next(),
+        KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.push_search_char(c)
+        }
+        _ => {}
+    }
+}
+
+/// Handle a key while a jump started with the jump key is being typed.
+/// Returns false for keys that end the jump and should still do their usual thing.
+fn handle_jump_keys(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => app.end_jump(),
+        KeyCode::Backspace => app.pop_jump_char(),
+        KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.push_jump_char(c)
+        }
+        _ => {
+            app.end_jump();
+            return false;
+        }
+    }
+    true
+}
+
+/// Handle keyboard events in the list column picker
+fn handle_column_picker_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down, Action::Mark]) {
+        Some(Action::Cancel) => app.close_column_picker()?,
+        Some(Action::Up) => {
+            if let Some(selected) = app.column_picker.as_mut() {
+                *selected = selected.saturating_sub(1);
+            }
+        }
+        Some(Action::Down) => {
+            let last = Column::all().len() - 1;
+            if let Some(selected) = app.column_picker.as_mut() {
+                *selected = (*selected + 1).min(last);
+            }
+        }
+        Some(Action::Mark) => app.toggle_picker_column(),
+        _ if key.code == KeyCode::Enter => app.toggle_picker_column(),
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Handle keyboard events in the company summary, which scrolls when it doesn't fit
+fn handle_company_popup_keys(app: &mut App, key: KeyEvent) {
+    let candidates = [Action::Cancel, Action::Company, Action::Up, Action::Down, Action::PageUp, Action::PageDown];
+    match dispatch(app, &key, &candidates) {
+        Some(Action::Cancel | Action::Company) => app.close_company_popup(),
+        Some(Action::Up) => app.scroll_company_popup(-1),
+        Some(Action::Down) => app.scroll_company_popup(1),
+        Some(Action::PageUp) => app.scroll_company_popup(-10),
+        Some(Action::PageDown) => app.scroll_company_popup(10),
+        _ if key.code == KeyCode::Char('q') => app.close_company_popup(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the trash view
+fn handle_trash_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, &[Action::Cancel, Action::Deleted, Action::Up, Action::Down]) {
+        Some(Action::Cancel | Action::Deleted) => app.close_trash(),
+        Some(Action::Up) => {
+            if let Some(selected) = app.trash_picker.as_mut() {
+                *selected = selected.saturating_sub(1);
+            }
+        }
+        Some(Action::Down) => {
+            let last = app.trash.len().saturating_sub(1);
+            if let Some(selected) = app.trash_picker.as_mut() {
+                *selected = (*selected + 1).min(last);
+            }
+        }
+        _ => match key.code {
+            KeyCode::Enter | KeyCode::Char('r') => app.restore_trashed(),
+            KeyCode::Char('R') => app.restore_all_trashed(),
+            KeyCode::Char('D') => app.start_purge_trashed(),
+            _ => {}
+        },
+    }
+}
+
+/// Handle keyboard events in the notes search, typing the query or moving through the results
+fn handle_notes_search_keys(app: &mut App, key: KeyEvent) {
+    if app.notes_search.as_ref().is_some_and(|search| search.typing) {
+        match key.code {
+            KeyCode::Esc => app.close_notes_search(),
+            KeyCode::Enter => app.finish_notes_search(),
+            KeyCode::Backspace => app.pop_notes_search_char(),
+            KeyCode::Up => app.move_notes_search(-1),
+            KeyCode::Down => app.move_notes_search(1),
+            KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+                app.push_notes_search_char(c)
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down, Action::Search]) {
+        Some(Action::Cancel) => app.close_notes_search(),
+        Some(Action::Up) => app.move_notes_search(-1),
+        Some(Action::Down) => app.move_notes_search(1),
+        Some(Action::Search) => app.edit_notes_search(),
+        _ if key.code == KeyCode::Enter => app.open_notes_search_result(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the template picker, or while renaming a template in it
+fn handle_template_picker_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    let renaming = app.template_picker.as_mut().and_then(|picker| picker.renaming.as_mut());
+    if let Some(name) = renaming {
+        match key.code {
+            KeyCode::Esc => {
+                if let Some(picker) = app.template_picker.as_mut() {
+                    picker.renaming = None;
+                }
+            }
+            KeyCode::Enter => app.finish_rename_template()?,
+            KeyCode::Backspace => {
+                text::pop_grapheme(name);
+            }
+            KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => name.push(c),
+            _ => {}
+        }
+        return Ok(());
+    }
+
+    match dispatch(app, &key, &[Action::Cancel, Action::Up, Action::Down, Action::Delete]) {
+        Some(Action::Cancel) => app.close_template_picker(),
+        Some(Action::Up) => app.move_template_picker(-1),
+        Some(Action::Down) => app.move_template_picker(1),
+        Some(Action::Delete) => app.delete_template()?,
+        _ => match key.code {
+            KeyCode::Enter => app.add_from_template(),
+            KeyCode::Char('r') => app.start_rename_template(),
+            _ => {}
+        },
+    }
+    Ok(())
+}
+
+/// Handle keyboard events while typing the name of a template saved from the form
+fn handle_template_name_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    match key.code {
+        KeyCode::Esc => app.template_name = None,
+        KeyCode::Enter => app.finish_save_template()?,
         KeyCode::Backspace => {
-            handle_backspace(app);
+            if let Some(name) = app.template_name.as_mut() {
+                text::pop_grapheme(name);
+            }
+        }
+        KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            if let Some(name) = app.template_name.as_mut() {
+                name.push(c);
+            }
         }
         _ => {}
     }
@@ -121,47 +1004,219 @@ fn handle_form_keys(app: &mut App, key: KeyEvent) -> Result<()> {
 
 /// Handle keyboard events in chart view
 fn handle_chart_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    if let Some(action) = dispatch(app, &key, CHART_ACTIONS) {
+        run_chart_action(app, action);
+    }
+    Ok(())
+}
+
+/// Carry out a chart view action, whether from its key or the command palette
+fn run_chart_action(app: &mut App, action: Action) {
+    match action {
+        Action::Palette => app.open_palette(),
+        Action::Cancel => app.show_list(),
+        Action::NextChart => app.next_chart(),
+        Action::PrevChart => app.prev_chart(),
+        Action::NextRange => app.next_chart_range(),
+        Action::PrevRange => app.prev_chart_range(),
+        Action::Legend => app.chart_legend = !app.chart_legend,
+        Action::Percent => app.chart_percent = !app.chart_percent,
+        Action::Report => app.write_report(),
+        Action::ExportChart => app.export_chart(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the linear (screen-reader-friendly) view
+fn handle_narrate_keys(app: &mut App, key: KeyEvent) -> Result<()> {
+    match dispatch(app, &key, NARRATE_ACTIONS) {
+        Some(action) => run_narrate_action(app, action),
+        None => match key.code {
+            KeyCode::Left | KeyCode::Char('p') => app.select_previous(),
+            KeyCode::Right | KeyCode::Char('n') => app.select_next(),
+            _ => {}
+        },
+    }
+    Ok(())
+}
+
+/// Carry out a read mode action, whether from its key or the command palette
+fn run_narrate_action(app: &mut App, action: Action) {
+    match action {
+        Action::Palette => app.open_palette(),
+        Action::Cancel | Action::Quit => app.show_list(),
+        Action::OpenResume => app.open_selected_resume(),
+        Action::Copy => app.open_copy_picker(),
+        Action::Activity => app.open_activity_log(),
+        Action::ExportApplication => app.export_application(),
+        Action::Up => app.select_previous(),
+        Action::Down => app.select_next(),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events on the pipeline board
+fn handle_board_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, BOARD_ACTIONS) {
+        Some(action) => run_board_action(app, action),
+        None => match key.code {
+            KeyCode::Left | KeyCode::Char('h') => app.move_board_column(-1),
+            KeyCode::Right | KeyCode::Char('l') => app.move_board_column(1),
+            KeyCode::Char('[') => app.move_board_card(-1),
+            KeyCode::Char(']') => app.move_board_card(1),
+            _ => {}
+        },
+    }
+}
+
+/// Carry out a board action, whether from its key or the command palette
+fn run_board_action(app: &mut App, action: Action) {
+    match action {
+        Action::Palette => app.open_palette(),
+        Action::Cancel | Action::Quit => app.close_board(),
+        Action::Up => app.move_board_row(-1),
+        Action::Down => app.move_board_row(1),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events on the dashboard
+fn handle_dashboard_keys(app: &mut App, key: KeyEvent) {
+    match dispatch(app, &key, DASHBOARD_ACTIONS) {
+        Some(action) => run_dashboard_action(app, action),
+        None => match key.code {
+            KeyCode::Tab => app.move_dashboard_section(1),
+            KeyCode::BackTab => app.move_dashboard_section(-1),
+            KeyCode::Enter => app.open_dashboard_row(),
+            KeyCode::Char('l') => app.show_list(),
+            _ => {}
+        },
+    }
+}
+
+/// Carry out a dashboard action, whether from its key or the command palette
+fn run_dashboard_action(app: &mut App, action: Action) {
+    match action {
+        Action::Palette => app.open_palette(),
+        Action::Cancel | Action::Quit => app.show_list(),
+        Action::Up => app.move_dashboard_row(-1),
+        Action::Down => app.move_dashboard_row(1),
+        _ => {}
+    }
+}
+
+/// Handle keyboard events in the command palette
+fn handle_palette_keys(app: &mut App, key: KeyEvent) -> Result<()> {
     match key.code {
-        KeyCode::Esc => app.show_list(),
-        KeyCode::Tab => app.next_chart(),
+        KeyCode::Esc => app.close_palette(),
+        KeyCode::Enter => {
+            if let Some(action) = app.take_palette_action() {
+                app.usage.record_action(action);
+                return run_action(app, action);
+            }
+        }
+        KeyCode::Backspace => app.pop_palette_char(),
+        KeyCode::Up => app.move_palette(-1),
+        KeyCode::Down => app.move_palette(1),
+        KeyCode::Char(c) if !key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => {
+            app.push_palette_char(c)
+        }
         _ => {}
     }
     Ok(())
 }
 
-/// Handle text input for form fields
-fn handle_text_input(app: &mut App, c: char) {
-    // Skip j/k for dropdown navigation
-    if c == 'j' || c == 'k' {
-        return;
+/// Carry out an action picked in the command palette as if its key had been pressed in the
+/// current view
+fn run_action(app: &mut App, action: Action) -> Result<()> {
+    match action {
+        Action::TodaySummary => app.show_today_summary(),
+        Action::Help => app.help_open = true,
+        _ => match app.view {
+            View::List => {
+                app.end_jump();
+                run_list_action(app, action)?
+            }
+            View::Chart => run_chart_action(app, action),
+            View::Narrate => run_narrate_action(app, action),
+            View::Board => run_board_action(app, action),
+            View::Dashboard => run_dashboard_action(app, action),
+            View::Form => {}
+        },
     }
+    app.usage.track_view(app.view);
+    Ok(())
+}
 
+/// Handle text input for form fields
+fn handle_text_input(app: &mut App, c: char) {
     match app.form_field {
         FormField::CompanyName => app.form_data.company_name.push(c),
+        FormField::JobTitle => app.form_data.job_title.get_or_insert_with(String::new).push(c),
+        FormField::Url => app.form_data.url.get_or_insert_with(String::new).push(c),
+        FormField::ExternalId => app.form_data.external_id.get_or_insert_with(String::new).push(c),
+        FormField::Location => app.form_data.location.get_or_insert_with(String::new).push(c),
         FormField::ResumeVersion => app.form_data.resume_version.push(c),
-        FormField::Notes => app.form_data.notes.push(c),
-        FormField::Platform => {
-            // If on "Other" option, allow custom input
-            if app.platform_dropdown_selected == Platform::presets().len() - 1 {
-                if let Platform::Other(ref mut custom) = app.form_data.platform {
-                    custom.push(c);
-                } else {
-                    app.form_data.platform = Platform::Other(c.to_string());
-                }
+        FormField::ResumeFile => app.resume_path_input.push(c),
+        FormField::CoverLetterVersion => app.form_data.cover_letter_version.push(c),
+        FormField::ContactName => app.form_data.contact_name.get_or_insert_with(String::new).push(c),
+        FormField::ContactEmail => app.form_data.contact_email.get_or_insert_with(String::new).push(c),
+        FormField::Salary => app.salary_input.push(c),
+        FormField::Notes => app.notes_insert(c),
+        // If on "Other" option, allow custom input
+        FormField::Platform if app.other_platform_selected() => {
+            if let Platform::Other(ref mut custom) = app.form_data.platform {
+                custom.push(c);
+            } else {
+                app.form_data.platform = Platform::Other(c.to_string());
             }
         }
-        FormField::Date => {
-            // Allow date input (basic implementation)
-            // Format: YYYY-MM-DD
-            let current = app.form_data.applied_date.to_string();
-            if c.is_ascii_digit() || c == '-' {
-                let new_date = format!("{}{}", current, c);
-                if let Ok(date) = chrono::NaiveDate::parse_from_str(&new_date, "%Y-%m-%d") {
-                    app.form_data.applied_date = date;
-                }
+        FormField::Date => app.date_input.push(c),
+        _ => {}
+    }
+}
+
+/// Readline-style editing in text fields: Ctrl+W deletes the previous word, Ctrl+U and Ctrl+K
+/// clear to the start and end of the line, Alt+B and Alt+F move by word. Single-line fields
+/// are edited at their end, so word motion only applies to Notes.
+fn handle_word_edit(app: &mut App, c: char, alt: bool) {
+    let edit: fn(&mut TextBuffer) = match (alt, c) {
+        (false, 'w') => |buffer| buffer.delete_word_back(),
+        (false, 'u') => |buffer| buffer.delete_to_line_start(),
+        (false, 'k') => |buffer| buffer.delete_to_line_end(),
+        (true, 'b') => |buffer| buffer.word_back(),
+        (true, 'f') => |buffer| buffer.word_forward(),
+        _ => return,
+    };
+    if app.form_field == FormField::Notes {
+        app.edit_notes(edit);
+    } else if let Some(text) = focused_text(app) {
+        edit(&mut TextBuffer::at_end(text));
+    }
+}
+
+/// The text of the focused single-line form field, if it has any
+fn focused_text(app: &mut App) -> Option<&mut String> {
+    match app.form_field {
+        FormField::CompanyName => Some(&mut app.form_data.company_name),
+        FormField::JobTitle => app.form_data.job_title.as_mut(),
+        FormField::Url => app.form_data.url.as_mut(),
+        FormField::ExternalId => app.form_data.external_id.as_mut(),
+        FormField::Location => app.form_data.location.as_mut(),
+        FormField::ResumeVersion => Some(&mut app.form_data.resume_version),
+        FormField::ResumeFile => Some(&mut app.resume_path_input),
+        FormField::CoverLetterVersion => Some(&mut app.form_data.cover_letter_version),
+        FormField::ContactName => app.form_data.contact_name.as_mut(),
+        FormField::ContactEmail => app.form_data.contact_email.as_mut(),
+        FormField::Salary => Some(&mut app.salary_input),
+        FormField::Date => Some(&mut app.date_input),
+        FormField::Platform if app.other_platform_selected() => {
+            match app.form_data.platform {
+                Platform::Other(ref mut custom) => Some(custom),
+                _ => None,
             }
         }
-        _ => {}
+        _ => None,
     }
 }
 
@@ -169,22 +1224,66 @@ fn handle_text_input(app: &mut App, c: char) {
 fn handle_backspace(app: &mut App) {
     match app.form_field {
         FormField::CompanyName => {
-            app.form_data.company_name.pop();
+            text::pop_grapheme(&mut app.form_data.company_name);
+        }
+        FormField::JobTitle => {
+            if let Some(title) = app.form_data.job_title.as_mut() {
+                text::pop_grapheme(title);
+            }
+        }
+        FormField::Url => {
+            if let Some(url) = app.form_data.url.as_mut() {
+                text::pop_grapheme(url);
+            }
+        }
+        FormField::ExternalId => {
+            if let Some(id) = app.form_data.external_id.as_mut() {
+                text::pop_grapheme(id);
+            }
+        }
+        FormField::Location => {
+            if let Some(location) = app.form_data.location.as_mut() {
+                text::pop_grapheme(location);
+            }
         }
         FormField::ResumeVersion => {
-            app.form_data.resume_version.pop();
+            text::pop_grapheme(&mut app.form_data.resume_version);
         }
-        FormField::Notes => {
-            app.form_data.notes.pop();
+        FormField::ResumeFile => {
+            text::pop_grapheme(&mut app.resume_path_input);
         }
-        FormField::Platform => {
-            // If on "Other" option, allow backspace
-            if app.platform_dropdown_selected == Platform::presets().len() - 1 {
-                if let Platform::Other(ref mut custom) = app.form_data.platform {
-                    custom.pop();
-                }
+        FormField::CoverLetterVersion => {
+            text::pop_grapheme(&mut app.form_data.cover_letter_version);
+        }
+        FormField::ContactName => {
+            if let Some(name) = app.form_data.contact_name.as_mut() {
+                text::pop_grapheme(name);
+            }
+        }
+        FormField::ContactEmail => {
+            if let Some(email) = app.form_data.contact_email.as_mut() {
+                text::pop_grapheme(email);
             }
         }
+        FormField::Salary => {
+            text::pop_grapheme(&mut app.salary_input);
+        }
+        FormField::Notes => app.notes_backspace(),
+        // If on "Other" option, allow backspace
+        FormField::Platform if app.other_platform_selected() => {
+            if let Platform::Other(ref mut custom) = app.form_data.platform {
+                text::pop_grapheme(custom);
+            }
+        }
+        FormField::Date => {
+            text::pop_grapheme(&mut app.date_input);
+        }
+        FormField::FollowUp => {
+            app.form_data.follow_up = None;
+        }
+        FormField::Deadline => {
+            app.form_data.deadline = None;
+        }
         _ => {}
     }
 }
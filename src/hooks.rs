@@ -0,0 +1,261 @@
+use crate::config;
+use crate::models::{Application, Status};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Version of the JSON payload written to hook commands; bumped on incompatible changes
+pub const PAYLOAD_VERSION: u32 = 1;
+
+/// External commands run on application events, from the `[hooks]` config section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HooksConfig {
+    pub enabled: bool,
+    /// Seconds a hook may run before it is killed
+    pub timeout_secs: u64,
+    pub on_add: Option<String>,
+    pub on_status_change: Option<String>,
+    pub on_offer: Option<String>,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_secs: 10,
+            on_add: None,
+            on_status_change: None,
+            on_offer: None,
+        }
+    }
+}
+
+/// Something that happened to an application
+#[derive(Debug, Clone, Copy)]
+pub enum HookEvent<'a> {
+    Added(&'a Application),
+    StatusChanged { application: &'a Application, from: &'a Status },
+    OfferReceived(&'a Application),
+}
+
+impl HookEvent<'_> {
+    /// Config key of the hook run for this event
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::Added(_) => "on_add",
+            HookEvent::StatusChanged { .. } => "on_status_change",
+            HookEvent::OfferReceived(_) => "on_offer",
+        }
+    }
+
+    fn payload(&self) -> Payload<'_> {
+        let (application, previous_status) = match *self {
+            HookEvent::Added(application) | HookEvent::OfferReceived(application) => (application, None),
+            HookEvent::StatusChanged { application, from } => (application, Some(from)),
+        };
+        Payload {
+            version: PAYLOAD_VERSION,
+            event: self.name().trim_start_matches("on_"),
+            timestamp: chrono::Local::now().to_rfc3339(),
+            application,
+            previous_status,
+        }
+    }
+}
+
+/// JSON written to a hook's stdin
+#[derive(Serialize)]
+struct Payload<'a> {
+    version: u32,
+    event: &'a str,
+    timestamp: String,
+    application: &'a Application,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_status: Option<&'a Status>,
+}
+
+/// Outcome of one hook run
+#[derive(Debug)]
+pub struct HookResult {
+    pub hook: &'static str,
+    pub result: Result<()>,
+}
+
+/// Runs hooks in the background and collects their results
+pub struct Hooks {
+    config: HooksConfig,
+    sender: Sender<HookResult>,
+    receiver: Receiver<HookResult>,
+}
+
+impl Hooks {
+    pub fn new(config: HooksConfig) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            config,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Start the hook configured for `event`, if any, without waiting for it
+    pub fn fire(&self, event: HookEvent) {
+        if !self.config.enabled {
+            return;
+        }
+        let command = match event {
+            HookEvent::Added(_) => &self.config.on_add,
+            HookEvent::StatusChanged { .. } => &self.config.on_status_change,
+            HookEvent::OfferReceived(_) => &self.config.on_offer,
+        };
+        let Some(command) = command.clone() else {
+            return;
+        };
+
+        let hook = event.name();
+        let payload = match serde_json::to_string(&event.payload()) {
+            Ok(payload) => payload,
+            Err(err) => {
+                let _ = self.sender.send(HookResult { hook, result: Err(err.into()) });
+                return;
+            }
+        };
+        let timeout = Duration::from_secs(self.config.timeout_secs);
+        let sender = self.sender.clone();
+
+        thread::spawn(move || {
+            let result = run(&command, &payload, timeout);
+            log(hook, &command, &result);
+            let _ = sender.send(HookResult { hook, result });
+        });
+    }
+
+    /// Results of hooks that finished since the last call
+    pub fn finished(&self) -> Vec<HookResult> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+/// Run a hook through the shell, writing the payload to its stdin
+fn run(command: &str, payload: &str, timeout: Duration) -> Result<()> {
+    let mut child = shell(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .with_context(|| format!("could not start '{}'", command))?;
+
+    let deadline = Instant::now() + timeout;
+    if let Some(mut stdin) = child.stdin.take() {
+        // Written from its own thread, since a hook that doesn't read a payload larger than the
+        // pipe buffer would block the write past the deadline. Killing the hook ends the write;
+        // a hook that ignores its input may close stdin early, which isn't a failure either.
+        let payload = payload.to_string();
+        thread::spawn(move || {
+            let _ = stdin.write_all(payload.as_bytes());
+        });
+    }
+
+    loop {
+        if let Some(status) = child.try_wait().context("could not wait for hook")? {
+            if !status.success() {
+                bail!("'{}' exited with {}", command, status);
+            }
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            bail!("'{}' timed out after {}s", command, timeout.as_secs());
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("sh");
+    shell.arg("-c").arg(command);
+    shell
+}
+
+#[cfg(target_os = "windows")]
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("cmd");
+    shell.args(["/C", command]);
+    shell
+}
+
+/// Append the outcome to `hooks.log` in the data directory
+fn log(hook: &str, command: &str, result: &Result<()>) {
+    let Some(dir) = config::data_dir() else {
+        return;
+    };
+    let outcome = match result {
+        Ok(()) => "ok".to_string(),
+        Err(err) => format!("failed: {:#}", err),
+    };
+    let line = format!("{} {} {}: {}\n", chrono::Local::now().to_rfc3339(), hook, command, outcome);
+
+    let _ = std::fs::create_dir_all(&dir);
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(dir.join("hooks.log")) {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+#[cfg(all(test, not(target_os = "windows")))]
+mod tests {
+    use super::*;
+
+    fn payload() -> String {
+        let application = Application {
+            company_name: "Acme".to_string(),
+            ..Application::new()
+        };
+        let event = HookEvent::StatusChanged { application: &application, from: &Status::Applied };
+        serde_json::to_string(&event.payload()).unwrap()
+    }
+
+    #[test]
+    fn hook_receives_the_payload_on_stdin() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("payload.json");
+        run(&format!("cat > '{}'", path.display()), &payload(), Duration::from_secs(5)).unwrap();
+
+        let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["version"], PAYLOAD_VERSION);
+        assert_eq!(written["event"], "status_change");
+        assert_eq!(written["application"]["company_name"], "Acme");
+        assert_eq!(written["previous_status"], "Applied");
+    }
+
+    #[test]
+    fn failing_hook_reports_its_exit_status() {
+        let err = run("cat > /dev/null; exit 3", &payload(), Duration::from_secs(5)).unwrap_err();
+        assert!(err.to_string().contains("exited with"), "{}", err);
+    }
+
+    #[test]
+    fn hanging_hook_is_killed_at_the_timeout() {
+        let started = Instant::now();
+        let err = run("sleep 10", &payload(), Duration::from_secs(1)).unwrap_err();
+        assert_eq!(err.to_string(), "'sleep 10' timed out after 1s");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn hook_that_never_reads_a_large_payload_still_times_out() {
+        // Far more than a pipe buffer holds, so writing it all would block
+        let payload = "x".repeat(4 << 20);
+        let started = Instant::now();
+        let err = run("sleep 10", &payload, Duration::from_secs(1)).unwrap_err();
+        assert!(err.to_string().contains("timed out"), "{}", err);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::models::Application;
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+/// Longest content line in octets before it's folded (RFC 5545 section 3.1)
+const LINE_OCTETS: usize = 75;
+
+/// An all-day calendar event for an interview round or a follow-up
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// Derived from the application ID, so a re-export updates the event instead of adding one
+    pub uid: String,
+    pub date: NaiveDate,
+    pub summary: String,
+    pub description: String,
+    pub url: Option<String>,
+}
+
+/// One event per interview round and per follow-up date, skipping archived applications
+pub fn events(applications: &[Application]) -> Vec<Event> {
+    let mut events = Vec::new();
+    for application in applications.iter().filter(|a| !a.archived) {
+        let company = application.company_name.trim();
+        let mut about = Vec::new();
+        if let Some(title) = &application.job_title {
+            about.push(title.clone());
+        }
+        about.push(format!("Status: {}", application.status.as_str()));
+
+        for (round, interview) in application.interviews.iter().enumerate() {
+            let mut description = about.clone();
+            if !interview.notes.trim().is_empty() {
+                description.push(interview.notes.trim().to_string());
+            }
+            events.push(Event {
+                uid: format!("{}-interview-{}@jobtracker", application.id, round + 1),
+                date: interview.date,
+                summary: format!("Interview: {} — {}", company, interview.kind.as_str().to_lowercase()),
+                description: description.join("\n"),
+                url: application.url.clone(),
+            });
+        }
+        if let Some(date) = application.follow_up {
+            events.push(Event {
+                uid: format!("{}-follow-up@jobtracker", application.id),
+                date,
+                summary: format!("Follow up: {}", company),
+                description: about.join("\n"),
+                url: application.url.clone(),
+            });
+        }
+    }
+    events.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.summary.cmp(&b.summary)));
+    events
+}
+
+/// The events as an iCalendar file, stamped with `now`
+pub fn calendar(events: &[Event], now: DateTime<Utc>) -> String {
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//jobtracker//jobtracker//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", escape(&event.uid)));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART;VALUE=DATE:{}", event.date.format("%Y%m%d")));
+        // All-day events end on the following day, exclusive
+        let end = event.date + Days::new(1);
+        lines.push(format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape(&event.description)));
+        }
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{}", url));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+/// Escape a TEXT value: backslashes, semicolons, commas and line breaks
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A content line ending in CRLF, folded onto continuation lines that start with a space
+/// so no line is longer than 75 octets. Never splits a multi-byte character.
+pub fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / LINE_OCTETS * 3 + 2);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interview, InterviewKind};
+    use chrono::TimeZone;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn acme() -> Application {
+        Application {
+            id: uuid::Uuid::from_u128(1),
+            company_name: "Acme, Inc.".to_string(),
+            job_title: Some("Engineer".to_string()),
+            interviews: vec![Interview {
+                kind: InterviewKind::Technical,
+                date: date(10),
+                notes: "Bring laptop".to_string(),
+            }],
+            follow_up: Some(date(3)),
+            ..Application::new()
+        }
+    }
+
+    #[test]
+    fn escapes_text_values() {
+        assert_eq!(escape(r"a\b;c,d"), r"a\\b\;c\,d");
+        assert_eq!(escape("one\r\ntwo\nthree"), r"one\ntwo\nthree");
+    }
+
+    #[test]
+    fn short_lines_are_not_folded() {
+        assert_eq!(fold("SUMMARY:Acme"), "SUMMARY:Acme\r\n");
+        let line = "x".repeat(LINE_OCTETS);
+        assert_eq!(fold(&line), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn long_lines_fold_at_75_octets() {
+        let folded = fold(&"x".repeat(200));
+        let lines: Vec<&str> = folded.split_terminator("\r\n").collect();
+        assert_eq!(lines.iter().map(|line| line.len()).collect::<Vec<_>>(), [75, 75, 52]);
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", "x".repeat(200)));
+    }
+
+    #[test]
+    fn folding_never_splits_a_character() {
+        // 'é' is two octets, so the 38th would straddle the 75-octet boundary
+        let line = "é".repeat(40);
+        let folded = fold(&line);
+        let lines: Vec<&str> = folded.split_terminator("\r\n").collect();
+        assert_eq!(lines[0].len(), 74);
+        assert!(lines.iter().all(|line| line.len() <= LINE_OCTETS));
+        assert_eq!(folded.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+
+    #[test]
+    fn events_cover_interviews_and_follow_ups_in_date_order() {
+        let archived = Application { archived: true, ..acme() };
+        let events = events(&[acme(), archived]);
+        let summaries: Vec<&str> = events.iter().map(|e| e.summary.as_str()).collect();
+        assert_eq!(summaries, ["Follow up: Acme, Inc.", "Interview: Acme, Inc. — technical"]);
+        assert_eq!(events[1].uid, format!("{}-interview-1@jobtracker", uuid::Uuid::from_u128(1)));
+        assert_eq!(events[1].description, "Engineer\nStatus: Applied\nBring laptop");
+    }
+
+    #[test]
+    fn calendar_escapes_and_ends_all_day_events_the_next_day() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+        let calendar = calendar(&events(&[acme()]), now);
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(calendar.contains("DTSTAMP:20240501T120000Z\r\n"));
+        assert!(calendar.contains("DTSTART;VALUE=DATE:20240510\r\nDTEND;VALUE=DATE:20240511\r\n"));
+        assert!(calendar.contains("SUMMARY:Follow up: Acme\\, Inc.\r\n"));
+        assert!(calendar.contains("DESCRIPTION:Engineer\\nStatus: Applied\\nBring laptop\r\n"));
+    }
+}
@@ -0,0 +1,700 @@
+use crate::app::View;
+use anyhow::{anyhow, bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Named action that can be bound to keys in the config file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Add,
+    Edit,
+    Delete,
+    Archive,
+    ShowArchived,
+    QuickStatus,
+    OpenUrl,
+    Copy,
+    Deleted,
+    Mark,
+    VisualSelect,
+    ReadMode,
+    Chart,
+    Board,
+    Dashboard,
+    Up,
+    Down,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    PrevField,
+    NextField,
+    SaveForm,
+    SaveTemplate,
+    Cancel,
+    NextChart,
+    PrevChart,
+    NextRange,
+    PrevRange,
+    Legend,
+    Percent,
+    Report,
+    ExportChart,
+    TodaySummary,
+    FilterApplied,
+    FilterInterview,
+    FilterOffer,
+    FilterRejected,
+    ClearFilter,
+    FilterWorkMode,
+    FilterAttention,
+    Sort,
+    InterestUp,
+    InterestDown,
+    Promote,
+    Company,
+    Columns,
+    RelativeDates,
+    Group,
+    Templates,
+    ExportIcal,
+    MarkGhosted,
+    Search,
+    SearchNotes,
+    Jump,
+    JumpNext,
+    JumpPrev,
+    OpenResume,
+    Activity,
+    ExportApplication,
+    Palette,
+    Help,
+}
+
+impl Action {
+    pub fn all() -> &'static [Action] {
+        &[
+            Action::Quit,
+            Action::Add,
+            Action::Edit,
+            Action::Delete,
+            Action::Archive,
+            Action::ShowArchived,
+            Action::QuickStatus,
+            Action::OpenUrl,
+            Action::Copy,
+            Action::Deleted,
+            Action::Mark,
+            Action::VisualSelect,
+            Action::ReadMode,
+            Action::Chart,
+            Action::Board,
+            Action::Dashboard,
+            Action::Up,
+            Action::Down,
+            Action::PageUp,
+            Action::PageDown,
+            Action::Top,
+            Action::Bottom,
+            Action::PrevField,
+            Action::NextField,
+            Action::SaveForm,
+            Action::SaveTemplate,
+            Action::Cancel,
+            Action::NextChart,
+            Action::PrevChart,
+            Action::NextRange,
+            Action::PrevRange,
+            Action::Legend,
+            Action::Percent,
+            Action::Report,
+            Action::ExportChart,
+            Action::TodaySummary,
+            Action::FilterApplied,
+            Action::FilterInterview,
+            Action::FilterOffer,
+            Action::FilterRejected,
+            Action::ClearFilter,
+            Action::FilterWorkMode,
+            Action::FilterAttention,
+            Action::Sort,
+            Action::InterestUp,
+            Action::InterestDown,
+            Action::Promote,
+            Action::Company,
+            Action::Columns,
+            Action::RelativeDates,
+            Action::Group,
+            Action::Templates,
+            Action::ExportIcal,
+            Action::MarkGhosted,
+            Action::Search,
+            Action::SearchNotes,
+            Action::Jump,
+            Action::JumpNext,
+            Action::JumpPrev,
+            Action::OpenResume,
+            Action::Activity,
+            Action::ExportApplication,
+            Action::Palette,
+            Action::Help,
+        ]
+    }
+
+    /// Name used in the `[keys]` config section
+    pub fn name(&self) -> &str {
+        match self {
+            Action::Quit => "quit",
+            Action::Add => "add",
+            Action::Edit => "edit",
+            Action::Delete => "delete",
+            Action::Archive => "archive",
+            Action::ShowArchived => "show_archived",
+            Action::QuickStatus => "status",
+            Action::OpenUrl => "open_url",
+            Action::Copy => "copy",
+            Action::Deleted => "deleted",
+            Action::Mark => "mark",
+            Action::VisualSelect => "visual",
+            Action::ReadMode => "read",
+            Action::Chart => "chart",
+            Action::Board => "board",
+            Action::Dashboard => "dashboard",
+            Action::Up => "up",
+            Action::Down => "down",
+            Action::PageUp => "page_up",
+            Action::PageDown => "page_down",
+            Action::Top => "top",
+            Action::Bottom => "bottom",
+            Action::PrevField => "prev_field",
+            Action::NextField => "next_field",
+            Action::SaveForm => "save_form",
+            Action::SaveTemplate => "save_template",
+            Action::Cancel => "cancel",
+            Action::NextChart => "next_chart",
+            Action::PrevChart => "prev_chart",
+            Action::NextRange => "next_range",
+            Action::PrevRange => "prev_range",
+            Action::Legend => "legend",
+            Action::Percent => "percent",
+            Action::Report => "report",
+            Action::ExportChart => "export_chart",
+            Action::TodaySummary => "today",
+            Action::FilterApplied => "filter_applied",
+            Action::FilterInterview => "filter_interview",
+            Action::FilterOffer => "filter_offer",
+            Action::FilterRejected => "filter_rejected",
+            Action::ClearFilter => "clear_filter",
+            Action::FilterWorkMode => "filter_work_mode",
+            Action::FilterAttention => "filter_attention",
+            Action::Sort => "sort",
+            Action::InterestUp => "interest_up",
+            Action::InterestDown => "interest_down",
+            Action::Promote => "promote",
+            Action::Company => "company",
+            Action::Columns => "columns",
+            Action::RelativeDates => "relative_dates",
+            Action::Group => "group",
+            Action::Templates => "templates",
+            Action::ExportIcal => "export_ical",
+            Action::MarkGhosted => "mark_ghosted",
+            Action::Search => "search",
+            Action::SearchNotes => "search_notes",
+            Action::Jump => "jump",
+            Action::JumpNext => "jump_next",
+            Action::JumpPrev => "jump_prev",
+            Action::OpenResume => "open_resume",
+            Action::Activity => "activity",
+            Action::ExportApplication => "export_application",
+            Action::Palette => "palette",
+            Action::Help => "help",
+        }
+    }
+
+    /// Human-readable description, e.g. "quick status change"
+    pub fn description(&self) -> &str {
+        match self {
+            Action::Quit => "quit",
+            Action::Add => "add application",
+            Action::Edit => "edit application",
+            Action::Delete => "move to trash",
+            Action::Archive => "archive application",
+            Action::ShowArchived => "show archived",
+            Action::QuickStatus => "quick status change",
+            Action::OpenUrl => "open posting URL",
+            Action::Copy => "copy to clipboard",
+            Action::Deleted => "trash",
+            Action::Mark => "mark application",
+            Action::VisualSelect => "select a range",
+            Action::ReadMode => "read mode",
+            Action::Chart => "charts",
+            Action::Board => "pipeline board",
+            Action::Dashboard => "dashboard",
+            Action::Up => "move up",
+            Action::Down => "move down",
+            Action::PageUp => "page up",
+            Action::PageDown => "page down",
+            Action::Top => "jump to top",
+            Action::Bottom => "jump to bottom",
+            Action::PrevField => "previous field",
+            Action::NextField => "next field",
+            Action::SaveForm => "save form",
+            Action::SaveTemplate => "save form as template",
+            Action::Cancel => "cancel",
+            Action::NextChart => "next chart",
+            Action::PrevChart => "previous chart",
+            Action::NextRange => "next date range",
+            Action::PrevRange => "previous date range",
+            Action::Legend => "toggle chart legend",
+            Action::Percent => "toggle counts and percentages",
+            Action::Report => "write Markdown report",
+            Action::ExportChart => "export chart as SVG",
+            Action::TodaySummary => "today's progress",
+            Action::FilterApplied => "show only Applied",
+            Action::FilterInterview => "show only Interview",
+            Action::FilterOffer => "show only Offer",
+            Action::FilterRejected => "show only Rejected",
+            Action::ClearFilter => "clear status filter",
+            Action::FilterWorkMode => "cycle work mode filter",
+            Action::FilterAttention => "show only applications needing attention",
+            Action::Sort => "cycle list order",
+            Action::InterestUp => "raise interest",
+            Action::InterestDown => "lower interest",
+            Action::Promote => "promote planned application",
+            Action::Company => "company summary",
+            Action::Columns => "choose list columns",
+            Action::RelativeDates => "toggle relative dates",
+            Action::Group => "group applications by company",
+            Action::Templates => "add from a template",
+            Action::ExportIcal => "export calendar",
+            Action::MarkGhosted => "mark ghosted applications",
+            Action::Search => "search companies",
+            Action::SearchNotes => "search notes",
+            Action::Jump => "jump to company",
+            Action::JumpNext => "repeat jump forward",
+            Action::JumpPrev => "repeat jump backward",
+            Action::OpenResume => "open resume file",
+            Action::Activity => "activity log",
+            Action::ExportApplication => "export one-pager",
+            Action::Palette => "command palette",
+            Action::Help => "show key bindings",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        Self::all().iter().copied().find(|action| action.name() == name)
+    }
+
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Quit => &["q"],
+            Action::Add => &["a"],
+            Action::Edit => &["e"],
+            Action::Delete => &["d"],
+            Action::Archive => &["A"],
+            Action::ShowArchived => &["z"],
+            Action::QuickStatus => &["c"],
+            Action::OpenUrl => &["o"],
+            Action::Copy => &["y"],
+            Action::Deleted => &["u"],
+            Action::Mark => &["space"],
+            Action::VisualSelect => &["v"],
+            Action::ReadMode => &["r"],
+            Action::Chart => &["g"],
+            Action::Board => &["b"],
+            Action::Dashboard => &["H"],
+            Action::Up => &["k", "up"],
+            Action::Down => &["j", "down"],
+            Action::PageUp => &["pageup"],
+            Action::PageDown => &["pagedown"],
+            Action::Top => &["home"],
+            Action::Bottom => &["G", "end"],
+            Action::PrevField => &["up"],
+            Action::NextField => &["down"],
+            Action::SaveForm => &["ctrl+s"],
+            Action::SaveTemplate => &["ctrl+t"],
+            Action::Cancel => &["esc"],
+            Action::NextChart => &["tab", "right"],
+            Action::PrevChart => &["backtab", "left"],
+            Action::NextRange => &["]"],
+            Action::PrevRange => &["["],
+            Action::Legend => &["l"],
+            Action::Percent => &["%"],
+            Action::Report => &["m"],
+            Action::ExportChart => &["x"],
+            Action::TodaySummary => &["f5"],
+            Action::FilterApplied => &["1"],
+            Action::FilterInterview => &["2"],
+            Action::FilterOffer => &["3"],
+            Action::FilterRejected => &["4"],
+            Action::ClearFilter => &["0"],
+            Action::FilterWorkMode => &["w"],
+            Action::FilterAttention => &["!"],
+            Action::Sort => &["S"],
+            Action::InterestUp => &["+"],
+            Action::InterestDown => &["-"],
+            Action::Promote => &["p"],
+            Action::Company => &["i"],
+            Action::Columns => &["C"],
+            Action::RelativeDates => &["D"],
+            Action::Group => &["ctrl+g"],
+            Action::Templates => &["T"],
+            Action::ExportIcal => &["I"],
+            Action::MarkGhosted => &["X"],
+            Action::Search => &["/"],
+            Action::SearchNotes => &["F"],
+            Action::Jump => &["'"],
+            Action::JumpNext => &["n"],
+            Action::JumpPrev => &["N"],
+            Action::OpenResume => &["O"],
+            Action::Activity => &["L"],
+            Action::ExportApplication => &["P"],
+            Action::Palette => &[":"],
+            Action::Help => &["?", "f1"],
+        }
+    }
+}
+
+/// Key column of a help entry: an action's configured keys or a key that can't be rebound
+#[derive(Debug, Clone, Copy)]
+pub enum HelpKey {
+    Action(Action),
+    Fixed(&'static str),
+}
+
+/// One row of the help overlay
+#[derive(Debug, Clone, Copy)]
+pub struct Binding {
+    pub key: HelpKey,
+    pub description: &'static str,
+    /// Views the binding applies in; empty for bindings that work everywhere
+    pub views: &'static [View],
+}
+
+impl Binding {
+    /// Whether the binding works in every view rather than a particular one
+    pub fn is_global(&self) -> bool {
+        self.views.is_empty()
+    }
+}
+
+/// Help entry for a rebindable action
+const fn action(action: Action, description: &'static str, views: &'static [View]) -> Binding {
+    Binding { key: HelpKey::Action(action), description, views }
+}
+
+/// Help entry for a key that is handled directly rather than through an action
+const fn fixed(key: &'static str, description: &'static str, views: &'static [View]) -> Binding {
+    Binding { key: HelpKey::Fixed(key), description, views }
+}
+
+const LIST: &[View] = &[View::List];
+const FORM: &[View] = &[View::Form];
+const CHART: &[View] = &[View::Chart];
+const NARRATE: &[View] = &[View::Narrate];
+const BOARD: &[View] = &[View::Board];
+const DASHBOARD: &[View] = &[View::Dashboard];
+const EVERYWHERE: &[View] = &[];
+/// Views without text fields, where `:` can open the command palette
+const PALETTE: &[View] = &[View::List, View::Chart, View::Narrate, View::Board, View::Dashboard];
+
+/// Every key binding, in the order the help overlay lists them
+pub const BINDINGS: &[Binding] = &[
+    action(Action::Up, "move up", &[View::List, View::Narrate, View::Board, View::Dashboard]),
+    action(Action::Down, "move down", &[View::List, View::Narrate, View::Board, View::Dashboard]),
+    action(Action::PageUp, "page up", LIST),
+    action(Action::PageDown, "page down", LIST),
+    action(Action::Top, "jump to top", LIST),
+    action(Action::Bottom, "jump to bottom", LIST),
+    action(Action::Add, "add application", LIST),
+    action(Action::Edit, "edit application", LIST),
+    action(Action::Archive, "archive or unarchive", LIST),
+    action(Action::ShowArchived, "show or hide archived", LIST),
+    action(Action::Delete, "move to the trash (all marked, if any)", LIST),
+    action(Action::Deleted, "open or close the trash: r restores, D deletes for good", LIST),
+    action(Action::Mark, "mark or unmark", LIST),
+    action(Action::VisualSelect, "select a range", LIST),
+    action(Action::Cancel, "clear marks, else the search", LIST),
+    action(Action::QuickStatus, "change status (of all marked, if any)", LIST),
+    action(Action::OpenUrl, "open posting URL", LIST),
+    action(Action::OpenResume, "open resume file", &[View::List, View::Narrate]),
+    action(Action::Copy, "copy company, URL, contact email or notes", &[View::List, View::Narrate]),
+    action(Action::Chart, "charts", LIST),
+    action(Action::ReadMode, "read mode", LIST),
+    action(Action::Board, "pipeline board: a column per status", LIST),
+    action(Action::Dashboard, "dashboard: this week, pipeline, interviews, follow-ups, recent", LIST),
+    action(Action::FilterApplied, "show only Applied", LIST),
+    action(Action::FilterInterview, "show only Interview", LIST),
+    action(Action::FilterOffer, "show only Offer", LIST),
+    action(Action::FilterRejected, "show only Rejected", LIST),
+    action(Action::ClearFilter, "clear status filter", LIST),
+    action(Action::Search, "fuzzy search by company", LIST),
+    action(Action::SearchNotes, "search the notes of every application", LIST),
+    action(Action::Jump, "jump to a company by typing its start", LIST),
+    action(Action::JumpNext, "next company matching the last jump", LIST),
+    action(Action::JumpPrev, "previous company matching the last jump", LIST),
+    action(Action::FilterWorkMode, "step through work mode filters", LIST),
+    action(Action::FilterAttention, "show only applications needing attention, or all again", LIST),
+    action(Action::Sort, "sort by date added or by interest", LIST),
+    action(Action::InterestUp, "rate one star higher", LIST),
+    action(Action::InterestDown, "rate one star lower", LIST),
+    action(Action::Promote, "mark a planned application as applied today", LIST),
+    action(Action::Company, "summary of every application to the selected company", LIST),
+    action(Action::Columns, "show or hide columns", LIST),
+    action(Action::RelativeDates, "show applied dates as ISO dates or as their age", LIST),
+    action(Action::Group, "group applications by company", LIST),
+    action(Action::Templates, "add from a template, or rename and delete templates", LIST),
+    action(Action::ExportIcal, "write interviews and follow-ups to jobtracker.ics", LIST),
+    fixed("Enter", "collapse or expand a company (on its header)", LIST),
+    action(Action::MarkGhosted, "mark every ghosted application", LIST),
+    action(Action::Quit, "quit", LIST),
+    action(Action::PrevField, "previous field", FORM),
+    action(Action::NextField, "next field", FORM),
+    action(Action::Up, "previous option in a dropdown", FORM),
+    action(Action::Down, "next option in a dropdown", FORM),
+    fixed("Enter", "next field, new line in Notes, edit rounds, calendar on dates", FORM),
+    fixed("←/→", "move the cursor in Notes", FORM),
+    fixed("Ctrl+W/U/K", "delete the previous word, to the start or to the end of the line", FORM),
+    fixed("Alt+B/F", "move back or forward a word in Notes", FORM),
+    fixed("Backspace", "clear the follow-up date (on Follow-up)", FORM),
+    action(Action::SaveForm, "save", FORM),
+    action(Action::SaveTemplate, "save platform, resume, work mode and notes as a template", FORM),
+    action(Action::Cancel, "cancel", FORM),
+    action(Action::NextChart, "next chart", CHART),
+    action(Action::PrevChart, "previous chart", CHART),
+    action(Action::NextRange, "next date range: last 30 days, last 90 days, this year, all time", CHART),
+    action(Action::PrevRange, "previous date range", CHART),
+    action(Action::Legend, "show or hide the legend", CHART),
+    action(Action::Percent, "show counts or each bar's percentage of the total", CHART),
+    action(Action::Report, "write Markdown report", CHART),
+    action(Action::ExportChart, "save the chart as an SVG image", CHART),
+    action(Action::Cancel, "back to list", &[View::Chart, View::Narrate, View::Board, View::Dashboard]),
+    fixed("←/→ p/n", "previous/next application", NARRATE),
+    action(Action::Activity, "activity log: add, edit or remove dated entries", NARRATE),
+    action(Action::ExportApplication, "write everything about this application to a Markdown file", NARRATE),
+    fixed("←/→ h/l", "previous/next status column", BOARD),
+    fixed("[ / ]", "move the application to the previous/next status", BOARD),
+    fixed("Tab/Shift+Tab", "next/previous section", DASHBOARD),
+    fixed("Enter", "list a status, or read an application", DASHBOARD),
+    fixed("l", "back to list", DASHBOARD),
+    action(Action::Palette, "command palette: find any action by name and run it", PALETTE),
+    action(Action::TodaySummary, "today's progress", EVERYWHERE),
+    action(Action::Help, "show or hide this help", EVERYWHERE),
+    fixed("Ctrl+C", "quit (asks first if the form has unsaved edits)", EVERYWHERE),
+];
+
+/// Actions the command palette offers in `view`: every action with a help entry there or
+/// everywhere, in help order, described as in the help overlay
+pub fn palette_actions(view: View) -> Vec<(Action, &'static str)> {
+    let mut actions: Vec<(Action, &'static str)> = Vec::new();
+    for binding in BINDINGS.iter().filter(|binding| binding.is_global() || binding.views.contains(&view)) {
+        if let HelpKey::Action(action) = binding.key {
+            if action != Action::Palette && !actions.iter().any(|(a, _)| *a == action) {
+                actions.push((action, binding.description));
+            }
+        }
+    }
+    actions
+}
+
+/// A single key combination such as "ctrl+s" or "x"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeySpec {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Parse a key spec like "x", "G", "ctrl+s", "alt+enter" or "pagedown"
+    pub fn parse(spec: &str) -> Result<Self> {
+        let spec = spec.trim();
+        let mut parts: Vec<&str> = spec.split('+').collect();
+        // "+" itself, or a combination ending in "+", binds the plus key
+        if spec.ends_with('+') {
+            parts.retain(|part| !part.is_empty());
+            parts.push("+");
+        }
+        let Some(key) = parts.pop() else {
+            bail!("Empty key spec");
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in parts {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => bail!("Unknown modifier '{}' in key '{}'", other, spec),
+            };
+        }
+
+        let code = match key.to_ascii_lowercase().as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            lower => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => match lower.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                        Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                        _ => bail!("Unknown key '{}'", spec),
+                    },
+                }
+            }
+        };
+
+        // Shift is implied by the character itself ("G"), so it isn't compared for chars
+        if matches!(code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        Ok(Self { code, modifiers })
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        let mut modifiers = key.modifiers;
+        if matches!(key.code, KeyCode::Char(_) | KeyCode::BackTab) {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+        key.code == self.code && modifiers == self.modifiers
+    }
+}
+
+impl fmt::Display for KeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => write!(f, "{}", c),
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::BackTab => write!(f, "Shift+Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            _ => write!(f, "?"),
+        }
+    }
+}
+
+/// One key or a list of keys for an action in the config file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBinding {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyBinding {
+    fn specs(&self) -> Vec<&str> {
+        match self {
+            KeyBinding::One(spec) => vec![spec.as_str()],
+            KeyBinding::Many(specs) => specs.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Keys bound to each action, with config overrides applied over the defaults
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<Action, Vec<KeySpec>>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        let bindings = Action::all()
+            .iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .map(|spec| KeySpec::parse(spec).expect("default key specs are valid"))
+                    .collect();
+                (*action, keys)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl KeyMap {
+    /// Build the key map from the `[keys]` config section
+    pub fn from_config(keys: &BTreeMap<String, KeyBinding>) -> Result<Self> {
+        let mut keymap = Self::default();
+        for (name, binding) in keys {
+            let action = Action::from_name(name)
+                .ok_or_else(|| anyhow!("Unknown action '{}' in [keys]", name))?;
+            let specs = binding
+                .specs()
+                .into_iter()
+                .map(KeySpec::parse)
+                .collect::<Result<Vec<_>>>()?;
+            keymap.bindings.insert(action, specs);
+        }
+        Ok(keymap)
+    }
+
+    /// Whether `key` is bound to `action`
+    pub fn matches(&self, action: Action, key: &KeyEvent) -> bool {
+        self.bindings
+            .get(&action)
+            .is_some_and(|specs| specs.iter().any(|spec| spec.matches(key)))
+    }
+
+    /// First of `candidates` that `key` is bound to
+    pub fn action(&self, key: &KeyEvent, candidates: &[Action]) -> Option<Action> {
+        candidates
+            .iter()
+            .copied()
+            .find(|action| self.matches(*action, key))
+    }
+
+    /// Keys bound to `action` for help text, e.g. "k/↑"
+    pub fn label(&self, action: Action) -> String {
+        self.bindings
+            .get(&action)
+            .map(|specs| {
+                specs
+                    .iter()
+                    .map(|spec| spec.to_string())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, Once};
+
+/// Lock file of the instance that holds it, removed by the panic hook if it's still set
+static HELD: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// Result of trying to lock a data file
+pub enum Lock {
+    /// This instance may write the file; the lock is released when dropped
+    Owned(DataLock),
+    /// Another running instance holds the lock
+    HeldBy(u32),
+}
+
+/// Advisory lock on a data file: a `<file>.lock` next to it holding the owner's PID
+pub struct DataLock {
+    path: PathBuf,
+}
+
+/// Lock `data_path`, breaking the lock of a process that is no longer running
+pub fn acquire(data_path: &Path) -> Result<Lock> {
+    let path = lock_path(data_path);
+    install_panic_hook();
+
+    // A second attempt only happens after removing a stale lock
+    for _ in 0..2 {
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                writeln!(file, "{}", std::process::id())
+                    .with_context(|| format!("Failed to write lock file {}", path.display()))?;
+                *HELD.lock().unwrap_or_else(|err| err.into_inner()) = Some(path.clone());
+                return Ok(Lock::Owned(DataLock { path }));
+            }
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => match owner(&path) {
+                Some(pid) if pid != std::process::id() && process_alive(pid) => return Ok(Lock::HeldBy(pid)),
+                _ => {
+                    fs::remove_file(&path)
+                        .with_context(|| format!("Failed to remove stale lock file {}", path.display()))?;
+                }
+            },
+            Err(err) => {
+                return Err(err).with_context(|| format!("Failed to create lock file {}", path.display()));
+            }
+        }
+    }
+
+    // Someone else took the lock between removing the stale one and retrying
+    match owner(&path) {
+        Some(pid) => Ok(Lock::HeldBy(pid)),
+        None => anyhow::bail!("Could not lock {}", data_path.display()),
+    }
+}
+
+impl Drop for DataLock {
+    fn drop(&mut self) {
+        *HELD.lock().unwrap_or_else(|err| err.into_inner()) = None;
+        // Leave it alone if another instance broke our lock and took over
+        if owner(&self.path) == Some(std::process::id()) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// `applications.json` → `applications.json.lock`
+fn lock_path(data_path: &Path) -> PathBuf {
+    let mut name = data_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".lock");
+    data_path.with_file_name(name)
+}
+
+/// PID recorded in a lock file, or None if it can't be read
+fn owner(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        // If we can't tell, assume it's running rather than risk two writers
+        .map_or(true, |status| status.success())
+}
+
+#[cfg(windows)]
+fn process_alive(pid: u32) -> bool {
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .stderr(Stdio::null())
+        .output()
+        .map_or(true, |output| String::from_utf8_lossy(&output.stdout).contains(&pid.to_string()))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Remove the lock file before the previous hook runs, so a crash doesn't leave it behind
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if let Some(path) = HELD.lock().ok().and_then(|mut held| held.take()) {
+                let _ = fs::remove_file(path);
+            }
+            previous_hook(info);
+        }));
+    });
+}
@@ -1,46 +1,142 @@
 mod app;
+mod attention;
+mod cli;
+mod clipboard;
+mod columns;
+mod config;
+mod dates;
+mod diff;
+mod digest;
+mod events;
+mod export;
+mod fuzzy;
+mod goal;
 mod handlers;
+mod hooks;
+mod ical;
+mod keymap;
+mod lock;
+mod merge;
 mod models;
+mod mutation;
+mod narrate;
+mod notify;
+mod open;
+mod paths;
+mod preset;
+mod report;
+mod snippet;
+mod stats;
 mod storage;
+mod template;
+mod text;
+mod theme;
+mod tui;
 mod ui;
+mod usage;
 
 use anyhow::Result;
-use app::App;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
-use ratatui::{backend::CrosstermBackend, Terminal};
-use std::io;
+use app::{App, MessageKind};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use cli::{Cli, Command, StartArgs, StartFilter, StartView};
+use config::Config;
+use lock::Lock;
+use ratatui::Terminal;
+use events::{AppEvent, Events};
+use tui::Tui;
 
 fn main() -> Result<()> {
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let cli = Cli::parse();
+    let command = match (cli.command, cli.import_linkedin) {
+        (Some(_), Some(_)) => Cli::command()
+            .error(ErrorKind::ArgumentConflict, "--import-linkedin can't go with another command")
+            .exit(),
+        (command, path) => command.or(path.map(|path| Command::ImportLinkedin { path, dry_run: false })),
+    };
+    if let Some(command) = command {
+        if cli.start.is_set() {
+            Cli::command()
+                .error(ErrorKind::ArgumentConflict, "--view, --chart, --filter and --add only apply when starting the TUI")
+                .exit();
+        }
+        let storage = storage::open(&cli.db, Config::load()?.storage.layout)?;
+        return cli::run(command, cli.yes, &cli.db, storage.as_ref());
+    }
+
+    // Held until main returns; a second instance opens read-only instead of clobbering saves
+    let lock = lock::acquire(&cli.db)?;
 
-    // Create app state
-    let mut app = App::new()?;
+    // Load config and data before touching the terminal so errors print cleanly
+    let mut app = App::with_storage(cli.db.clone())?;
+    if let Lock::HeldBy(pid) = &lock {
+        app.set_read_only(*pid);
+    }
+    if let Err(message) = apply_start_args(&mut app, &cli.start) {
+        drop(lock);
+        Cli::command().error(ErrorKind::InvalidValue, message).exit();
+    }
 
-    // Run the app
-    let res = run_app(&mut terminal, &mut app);
+    // The guard restores the terminal when dropped, including on error
+    let mut tui = Tui::new()?;
+    let res = run_app(&mut tui.terminal, &mut app);
+    drop(tui);
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    // Don't lose pending changes if the loop ended on an error
+    app.keep_mine();
+    if app.dirty {
+        if let Err(err) = app.save() {
+            eprintln!("Error: could not save changes: {:?}", err);
+        }
+    }
 
     if let Err(err) = res {
         eprintln!("Error: {:?}", err);
     }
 
+    // An error raised while quitting never got a chance to show in the status bar
+    if let Some((message, MessageKind::Error, _)) = &app.message {
+        eprintln!("Error: {}", message);
+    }
+
+    Ok(())
+}
+
+/// Open the TUI where the command line asked for: a view, a chart, a filtered list or the add form
+fn apply_start_args(app: &mut App, start: &StartArgs) -> std::result::Result<(), String> {
+    for filter in &start.filter {
+        match filter {
+            StartFilter::Status(name) => {
+                let statuses = app.status_order();
+                let Some(status) = statuses.iter().find(|status| status.as_str().eq_ignore_ascii_case(name)) else {
+                    let names: Vec<&str> = statuses.iter().map(|status| status.as_str()).collect();
+                    return Err(format!("unknown status '{}' in --filter (expected one of: {})", name, names.join(", ")));
+                };
+                app.set_status_filter(Some(status.clone()));
+            }
+            StartFilter::WorkMode(mode) => app.work_mode_filter = Some(*mode),
+        }
+    }
+
+    if start.add {
+        app.start_add();
+        return Ok(());
+    }
+    match (start.view, start.chart) {
+        (Some(StartView::Chart) | None, Some(chart)) => {
+            app.show_chart();
+            app.chart_type = chart;
+        }
+        (Some(_), Some(_)) => return Err("--chart opens the chart view, so it can't go with another --view".to_string()),
+        (Some(StartView::Chart), None) => app.show_chart(),
+        (Some(StartView::Board), None) => app.show_board(),
+        (Some(StartView::Read), None) => app.show_narrate(),
+        (Some(StartView::Dashboard), None) => app.show_dashboard(),
+        (Some(StartView::List), None) => app.show_list(),
+        // Filters are for the list, even when the dashboard would open first
+        (None, None) if !start.filter.is_empty() => app.show_list(),
+        (None, None) => {}
+    }
     Ok(())
 }
 
@@ -49,22 +145,27 @@ fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> Result<()> {
+    let mut events = Events::new();
     loop {
-        // Render UI
         ui::render(terminal, app)?;
 
-        // Handle events
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                handlers::handle_key_event(app, key)?;
+        // Everything already queued is handled before drawing again, so a held-down key
+        // doesn't fall behind waiting on a redraw per repeat
+        let mut next = Some(events.next(app)?);
+        while let Some(event) = next {
+            match event {
+                AppEvent::Key(key) => handlers::handle_key_event(app, key),
+                AppEvent::Mouse(mouse) => handlers::handle_mouse_event(app, mouse),
+                AppEvent::Paste(text) => handlers::handle_paste(app, &text),
+                // Repaint from scratch so no cells from the old layout are left behind
+                AppEvent::Resize => terminal.clear()?,
+                AppEvent::Tick => app.on_tick(),
+                AppEvent::FileChanged => app.on_file_changed(),
             }
-        }
-
-        // Check if should quit
-        if app.should_quit {
-            break;
+            if app.should_quit {
+                return Ok(());
+            }
+            next = events.try_next(app)?;
         }
     }
-
-    Ok(())
 }
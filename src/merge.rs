@@ -0,0 +1,115 @@
+use crate::models::{Application, TrashedApplication};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// A record changed both in memory and on disk in different ways
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub id: Uuid,
+    pub company: String,
+    /// Version in the file, or None if it was removed there
+    pub disk: Option<Application>,
+    /// Whether the record was deleted here
+    pub deleted_here: bool,
+}
+
+/// Outcome of merging a changed data file into the applications in memory
+#[derive(Debug, Clone, Default)]
+pub struct Merged {
+    /// Disk order, with records added here after the ones in the file.
+    /// Conflicting records are in their in-memory version until resolved.
+    pub applications: Vec<Application>,
+    pub trash: Vec<TrashedApplication>,
+    /// Records added, changed or removed in the file and taken over
+    pub from_disk: usize,
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Three-way merge by record ID: `base` is what was last read from or written to the file,
+/// `local` what's in memory now and `disk` what the file holds now. A record changed on one
+/// side only takes that side's version; one changed on both, differently, is a conflict.
+pub fn merge(
+    base: (&[Application], &[TrashedApplication]),
+    local: (&[Application], &[TrashedApplication]),
+    disk: (&[Application], &[TrashedApplication]),
+) -> Merged {
+    let by_id = |list: &[Application]| -> HashMap<Uuid, Application> {
+        list.iter().map(|application| (application.id, application.clone())).collect()
+    };
+    let (base_apps, local_apps) = (by_id(base.0), by_id(local.0));
+    let disk_ids: HashSet<Uuid> = disk.0.iter().map(|application| application.id).collect();
+    let mut merged = Merged::default();
+
+    for on_disk in disk.0 {
+        match (base_apps.get(&on_disk.id), local_apps.get(&on_disk.id)) {
+            (_, Some(here)) if here == on_disk => merged.applications.push(here.clone()),
+            (Some(before), Some(here)) if here == before => {
+                merged.applications.push(on_disk.clone());
+                merged.from_disk += 1;
+            }
+            (Some(before), Some(here)) if on_disk == before => merged.applications.push(here.clone()),
+            (_, Some(here)) => {
+                merged.conflicts.push(conflict(here, Some(on_disk), false));
+                merged.applications.push(here.clone());
+            }
+            // Deleted here and left alone in the file
+            (Some(before), None) if on_disk == before => {}
+            (Some(_), None) => merged.conflicts.push(conflict(on_disk, Some(on_disk), true)),
+            (None, None) => {
+                merged.applications.push(on_disk.clone());
+                merged.from_disk += 1;
+            }
+        }
+    }
+
+    for here in local.0.iter().filter(|application| !disk_ids.contains(&application.id)) {
+        match base_apps.get(&here.id) {
+            None => merged.applications.push(here.clone()),
+            // Removed from the file and untouched here
+            Some(before) if before == here => merged.from_disk += 1,
+            Some(_) => {
+                merged.conflicts.push(conflict(here, None, false));
+                merged.applications.push(here.clone());
+            }
+        }
+    }
+
+    merged.trash = merge_trash(base.1, local.1, disk.1, &merged.applications);
+    merged
+}
+
+fn conflict(application: &Application, disk: Option<&Application>, deleted_here: bool) -> Conflict {
+    Conflict {
+        id: application.id,
+        company: application.company_name.trim().to_string(),
+        disk: disk.cloned(),
+        deleted_here,
+    }
+}
+
+/// Trash entries kept on both sides or added on either, minus records that are live again;
+/// an entry restored or purged on one side is gone
+fn merge_trash(
+    base: &[TrashedApplication],
+    local: &[TrashedApplication],
+    disk: &[TrashedApplication],
+    applications: &[Application],
+) -> Vec<TrashedApplication> {
+    let ids = |trash: &[TrashedApplication]| -> HashSet<Uuid> {
+        trash.iter().map(|entry| entry.application.id).collect()
+    };
+    let (base_ids, local_ids, disk_ids) = (ids(base), ids(local), ids(disk));
+    let live: HashSet<Uuid> = applications.iter().map(|application| application.id).collect();
+
+    let mut trash: Vec<TrashedApplication> = local
+        .iter()
+        .filter(|entry| disk_ids.contains(&entry.application.id) || !base_ids.contains(&entry.application.id))
+        .chain(disk.iter().filter(|entry| {
+            !local_ids.contains(&entry.application.id) && !base_ids.contains(&entry.application.id)
+        }))
+        .filter(|entry| !live.contains(&entry.application.id))
+        .cloned()
+        .collect();
+    trash.sort_by_key(|entry| entry.deleted_at);
+    trash
+}
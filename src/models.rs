@@ -1,39 +1,84 @@
-use chrono::NaiveDate;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+use uuid::Uuid;
 
-/// Application status enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Application status enum; stages beyond the built-ins are listed in the config
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "StatusRepr")]
 pub enum Status {
+    /// Queued up, not applied to yet
+    Planned,
+    #[default]
     Applied,
     Interview,
     Offer,
     Rejected,
+    Other(String),
 }
 
 impl Status {
+    /// The built-in statuses in their default order
     pub fn all() -> &'static [Status] {
-        &[Status::Applied, Status::Interview, Status::Offer, Status::Rejected]
+        &[Status::Planned, Status::Applied, Status::Interview, Status::Offer, Status::Rejected]
+    }
+
+    /// Parse a built-in status name case-insensitively
+    pub fn from_name(name: &str) -> Option<Status> {
+        Self::all()
+            .iter()
+            .find(|status| status.as_str().eq_ignore_ascii_case(name.trim()))
+            .cloned()
+    }
+
+    /// Built-in status by name, anything else as a custom status
+    pub fn from_str(s: &str) -> Self {
+        Self::from_name(s).unwrap_or_else(|| Status::Other(s.trim().to_string()))
     }
 
     pub fn as_str(&self) -> &str {
         match self {
+            Status::Planned => "Planned",
             Status::Applied => "Applied",
             Status::Interview => "Interview",
             Status::Offer => "Offer",
             Status::Rejected => "Rejected",
+            Status::Other(s) => s,
         }
     }
 }
 
-impl Default for Status {
-    fn default() -> Self {
-        Status::Applied
+/// Status as stored; a custom status named like a built-in one, such as a "Planned"
+/// stage configured before it was built in, reads back as the built-in
+#[derive(Deserialize)]
+enum StatusRepr {
+    Planned,
+    Applied,
+    Interview,
+    Offer,
+    Rejected,
+    Other(String),
+}
+
+impl From<StatusRepr> for Status {
+    fn from(repr: StatusRepr) -> Self {
+        match repr {
+            StatusRepr::Planned => Status::Planned,
+            StatusRepr::Applied => Status::Applied,
+            StatusRepr::Interview => Status::Interview,
+            StatusRepr::Offer => Status::Offer,
+            StatusRepr::Rejected => Status::Rejected,
+            StatusRepr::Other(name) => Status::from_str(&name),
+        }
     }
 }
 
 /// Platform enum with common presets
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Platform {
+    #[default]
     LinkedIn,
     Indeed,
     CompanyWebsite,
@@ -41,10 +86,22 @@ pub enum Platform {
 }
 
 impl Platform {
+    /// Built-in dropdown options; configured presets go before "Other"
     pub fn presets() -> &'static [&'static str] {
         &["LinkedIn", "Indeed", "Company Website", "Other"]
     }
 
+    /// Platform called `name`, matching `presets` case-insensitively so "otta" is stored as a
+    /// configured "Otta". Configured presets are kept as `Other`, so records using one stay
+    /// readable after it's removed from the config.
+    pub fn from_name(name: &str, presets: &[String]) -> Self {
+        let name = name.trim();
+        let preset = presets
+            .iter()
+            .find(|preset| preset.as_str() != "Other" && preset.eq_ignore_ascii_case(name));
+        Platform::from_str(preset.map_or(name, String::as_str))
+    }
+
     pub fn from_str(s: &str) -> Self {
         match s {
             "LinkedIn" => Platform::LinkedIn,
@@ -64,36 +121,397 @@ impl Platform {
     }
 }
 
-impl Default for Platform {
-    fn default() -> Self {
-        Platform::LinkedIn
+/// Where the job is done
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum WorkMode {
+    Remote,
+    Hybrid,
+    Onsite,
+    #[default]
+    Unknown,
+}
+
+impl WorkMode {
+    pub fn all() -> &'static [WorkMode] {
+        &[WorkMode::Remote, WorkMode::Hybrid, WorkMode::Onsite, WorkMode::Unknown]
+    }
+
+    /// Parse a work mode name case-insensitively
+    pub fn from_name(name: &str) -> Option<WorkMode> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            WorkMode::Remote => "Remote",
+            WorkMode::Hybrid => "Hybrid",
+            WorkMode::Onsite => "Onsite",
+            WorkMode::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Why an application ended in Rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RejectionReason {
+    NoResponse,
+    AfterPhoneScreen,
+    AfterOnsite,
+    PositionFilled,
+    /// Withdrawn by the applicant
+    Withdrawn,
+}
+
+impl RejectionReason {
+    pub fn all() -> &'static [RejectionReason] {
+        &[
+            RejectionReason::NoResponse,
+            RejectionReason::AfterPhoneScreen,
+            RejectionReason::AfterOnsite,
+            RejectionReason::PositionFilled,
+            RejectionReason::Withdrawn,
+        ]
+    }
+
+    /// Parse a reason name case-insensitively
+    pub fn from_name(name: &str) -> Option<RejectionReason> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|reason| reason.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            RejectionReason::NoResponse => "No response",
+            RejectionReason::AfterPhoneScreen => "After phone screen",
+            RejectionReason::AfterOnsite => "After onsite",
+            RejectionReason::PositionFilled => "Position filled",
+            RejectionReason::Withdrawn => "Withdrawn",
+        }
+    }
+}
+
+/// Salary range with optional currency code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SalaryRange {
+    pub min: u64,
+    pub max: u64,
+    #[serde(default)]
+    pub currency: String,
+}
+
+impl SalaryRange {
+    /// Parse inputs like "130000", "120k-150k USD" or "EUR 90,000 - 110,000"
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut currency = String::new();
+        let mut amounts = Vec::new();
+
+        for token in input.split_whitespace() {
+            if token.len() == 3 && token.chars().all(|c| c.is_ascii_alphabetic()) {
+                currency = token.to_ascii_uppercase();
+            } else {
+                amounts.push(token);
+            }
+        }
+
+        let amounts = amounts.join("");
+        let parts: Vec<&str> = amounts
+            .split(['-', '–'])
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        let (min, max) = match parts.as_slice() {
+            [single] => {
+                let value = parse_amount(single)?;
+                (value, value)
+            }
+            [min, max] => (parse_amount(min)?, parse_amount(max)?),
+            _ => return Err(format!("Invalid salary '{}'", input.trim())),
+        };
+
+        if max < min {
+            return Err("Maximum salary is below the minimum".to_string());
+        }
+
+        Ok(Self { min, max, currency })
+    }
+
+    /// Short form for table cells, e.g. "120–150k"
+    pub fn compact(&self) -> String {
+        let thousands = self.min.is_multiple_of(1000) && self.max.is_multiple_of(1000);
+        match (thousands, self.min == self.max) {
+            (true, true) => format!("{}k", self.min / 1000),
+            (true, false) => format!("{}–{}k", self.min / 1000, self.max / 1000),
+            (false, true) => self.min.to_string(),
+            (false, false) => format!("{}–{}", self.min, self.max),
+        }
     }
 }
 
+impl fmt::Display for SalaryRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let amount = |value: u64| {
+            if value.is_multiple_of(1000) {
+                format!("{}k", value / 1000)
+            } else {
+                value.to_string()
+            }
+        };
+
+        if self.min == self.max {
+            write!(f, "{}", amount(self.min))?;
+        } else {
+            write!(f, "{}-{}", amount(self.min), amount(self.max))?;
+        }
+        if !self.currency.is_empty() {
+            write!(f, " {}", self.currency)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a single amount such as "120k", "1.2m" or "$130,000"
+fn parse_amount(token: &str) -> Result<u64, String> {
+    let cleaned: String = token
+        .chars()
+        .filter(|c| !matches!(c, ',' | '_' | '$' | '€' | '£'))
+        .collect::<String>()
+        .to_ascii_lowercase();
+
+    let (number, multiplier) = if let Some(number) = cleaned.strip_suffix('k') {
+        (number, 1_000.0)
+    } else if let Some(number) = cleaned.strip_suffix('m') {
+        (number, 1_000_000.0)
+    } else {
+        (cleaned.as_str(), 1.0)
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+        .map(|value| (value * multiplier).round() as u64)
+        .ok_or_else(|| format!("Invalid salary amount '{}'", token))
+}
+
+/// Kind of interview round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InterviewKind {
+    #[default]
+    Phone,
+    Technical,
+    Onsite,
+    Other,
+}
+
+impl InterviewKind {
+    pub fn all() -> &'static [InterviewKind] {
+        &[
+            InterviewKind::Phone,
+            InterviewKind::Technical,
+            InterviewKind::Onsite,
+            InterviewKind::Other,
+        ]
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            InterviewKind::Phone => "Phone",
+            InterviewKind::Technical => "Technical",
+            InterviewKind::Onsite => "Onsite",
+            InterviewKind::Other => "Other",
+        }
+    }
+}
+
+/// One interview round
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interview {
+    pub kind: InterviewKind,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Something that happened with an application, e.g. "recruiter emailed"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub date: NaiveDate,
+    pub text: String,
+}
+
+/// A status the application moved to, and the day it happened
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub status: Status,
+    pub date: NaiveDate,
+}
+
 /// Job application record
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Application {
+    /// Stable identity; nil for records saved before IDs existed until `assign_missing_ids` runs
+    #[serde(default)]
+    pub id: Uuid,
     pub company_name: String,
+    /// Position applied for, e.g. "Backend Engineer"
+    #[serde(default)]
+    pub job_title: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Confirmation or requisition number from the company's portal, free text as given
+    #[serde(default)]
+    pub external_id: Option<String>,
     pub platform: Platform,
+    /// City or region, as typed
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub work_mode: WorkMode,
     pub resume_modified: bool,
     pub resume_version: String,
+    /// Resume file sent, e.g. a PDF per resume version; saved relative to `~` when under it
+    #[serde(default, with = "crate::paths::portable", skip_serializing_if = "Option::is_none")]
+    pub resume_path: Option<PathBuf>,
+    /// Sent with a cover letter
+    #[serde(default)]
+    pub cover_letter: bool,
+    #[serde(default)]
+    pub cover_letter_version: String,
     pub status: Status,
+    /// Why it was rejected; cleared when the status moves off Rejected
+    #[serde(default)]
+    pub rejection_reason: Option<RejectionReason>,
+    /// How much the role appeals, 1 to `MAX_INTEREST` stars; 0 when not rated
+    #[serde(default)]
+    pub interest: u8,
+    /// Status changes after the record was added, oldest first
+    #[serde(default)]
+    pub status_history: Vec<StatusChange>,
+    #[serde(default)]
+    pub salary: Option<SalaryRange>,
     pub applied_date: NaiveDate,
+    #[serde(default)]
+    pub follow_up: Option<NaiveDate>,
+    /// Last day the posting takes applications
+    #[serde(default)]
+    pub deadline: Option<NaiveDate>,
+    #[serde(default)]
+    pub contact_name: Option<String>,
+    #[serde(default)]
+    pub contact_email: Option<String>,
+    /// Applied through someone's referral rather than cold
+    #[serde(default)]
+    pub referral: bool,
+    /// Interview rounds, kept sorted by date
+    #[serde(default)]
+    pub interviews: Vec<Interview>,
+    /// Free-form timeline, kept sorted by date
+    #[serde(default)]
+    pub activity: Vec<ActivityEntry>,
     pub notes: String,
+    /// Hidden from the list by default but still counted in stats
+    #[serde(default)]
+    pub archived: bool,
 }
 
 impl Application {
     pub fn new() -> Self {
         Self {
+            id: Uuid::new_v4(),
             company_name: String::new(),
+            job_title: None,
+            url: None,
+            external_id: None,
             platform: Platform::default(),
+            location: None,
+            work_mode: WorkMode::default(),
             resume_modified: false,
             resume_version: String::new(),
+            resume_path: None,
+            cover_letter: false,
+            cover_letter_version: String::new(),
             status: Status::default(),
+            rejection_reason: None,
+            interest: 0,
+            status_history: Vec::new(),
+            salary: None,
             applied_date: chrono::Local::now().date_naive(),
+            follow_up: None,
+            deadline: None,
+            contact_name: None,
+            contact_email: None,
+            referral: false,
+            interviews: Vec::new(),
+            activity: Vec::new(),
             notes: String::new(),
+            archived: false,
         }
     }
+
+    /// Days from `today` until the deadline, negative once it has passed
+    pub fn days_to_deadline(&self, today: NaiveDate) -> Option<i64> {
+        self.deadline.map(|deadline| (deadline - today).num_days())
+    }
+
+    /// Whether a deadline passed while the application was still only planned
+    pub fn missed_deadline(&self, today: NaiveDate) -> bool {
+        self.status == Status::Planned && self.days_to_deadline(today).is_some_and(|days| days < 0)
+    }
+
+    /// Whether a resume file is set but no longer exists on disk
+    pub fn resume_missing(&self) -> bool {
+        self.resume_path.as_ref().is_some_and(|path| !path.exists())
+    }
+
+    /// Change the status, recording the change in the history if it differs
+    pub fn set_status(&mut self, status: Status, today: NaiveDate) {
+        if self.status != status {
+            if status != Status::Rejected {
+                self.rejection_reason = None;
+            }
+            self.status = status.clone();
+            self.status_history.push(StatusChange { status, date: today });
+        }
+    }
+
+    /// First day the application is known to have reached `status`.
+    /// Interview also counts the earliest logged interview round.
+    pub fn reached(&self, status: Status) -> Option<NaiveDate> {
+        let changed = self
+            .status_history
+            .iter()
+            .filter(|change| change.status == status)
+            .map(|change| change.date);
+        let rounds = self
+            .interviews
+            .iter()
+            .filter(|_| status == Status::Interview)
+            .map(|interview| interview.date);
+        changed.chain(rounds).min()
+    }
+
+    /// First sign of a response: the earliest move past Applied or interview round
+    pub fn first_response(&self) -> Option<NaiveDate> {
+        let changed = self
+            .status_history
+            .iter()
+            .filter(|change| !matches!(change.status, Status::Planned | Status::Applied))
+            .map(|change| change.date);
+        changed.chain(self.interviews.iter().map(|interview| interview.date)).min()
+    }
+
+    /// Earliest interview on or after `today`
+    pub fn next_interview(&self, today: NaiveDate) -> Option<&Interview> {
+        self.interviews
+            .iter()
+            .filter(|interview| interview.date >= today)
+            .min_by_key(|interview| interview.date)
+    }
 }
 
 impl Default for Application {
@@ -101,3 +519,55 @@ impl Default for Application {
         Self::new()
     }
 }
+
+/// Highest interest rating
+pub const MAX_INTEREST: u8 = 5;
+
+/// An interest rating as ★ characters, empty when not rated
+pub fn stars(interest: u8) -> String {
+    "★".repeat(interest.min(MAX_INTEREST) as usize)
+}
+
+/// Days a deleted application stays in the trash before it's purged on load
+pub const TRASH_DAYS: i64 = 30;
+
+/// Time left until a deadline `days` away: "3d left", "today" or "closed"
+pub fn countdown(days: i64) -> String {
+    match days {
+        0 => "today".to_string(),
+        days if days < 0 => "closed".to_string(),
+        days => format!("{}d left", days),
+    }
+}
+
+/// A deleted application, kept in the trash until it's restored or purged
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedApplication {
+    pub deleted_at: DateTime<Utc>,
+    /// Where it was in the list, so restoring puts it back in place
+    #[serde(default)]
+    pub position: usize,
+    pub application: Application,
+}
+
+/// Remove trash entries deleted more than `TRASH_DAYS` before `now`; returns how many
+pub fn purge_expired(trash: &mut Vec<TrashedApplication>, now: DateTime<Utc>) -> usize {
+    let before = trash.len();
+    trash.retain(|trashed| now - trashed.deleted_at <= Duration::days(TRASH_DAYS));
+    before - trash.len()
+}
+
+/// Give every application without an ID, or sharing one with an earlier record
+/// (e.g. copied by hand), a new one; returns whether any changed
+pub fn assign_missing_ids(applications: &mut [Application]) -> bool {
+    let mut seen = HashSet::new();
+    let mut changed = false;
+    for application in applications.iter_mut() {
+        if application.id.is_nil() || !seen.insert(application.id) {
+            application.id = Uuid::new_v4();
+            seen.insert(application.id);
+            changed = true;
+        }
+    }
+    changed
+}
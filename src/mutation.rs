@@ -0,0 +1,329 @@
+use crate::models::{Application, Status, TrashedApplication, WorkMode};
+use crate::storage::Data;
+use std::fmt;
+
+/// A change to the application list that can be previewed before it is applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mutation {
+    Delete(Vec<usize>),
+    SetStatus { indices: Vec<usize>, status: Status },
+    Add(Vec<Application>),
+    /// Fold each group's later records into its first; the later ones go to the trash
+    Merge(Vec<Vec<usize>>),
+}
+
+/// One record affected by a mutation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub record: String,
+    pub summary: String,
+}
+
+/// Everything a mutation would change (or did change)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeReport {
+    pub changes: Vec<Change>,
+}
+
+impl ChangeReport {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+}
+
+impl fmt::Display for ChangeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "  {}: {}", change.record, change.summary)?;
+        }
+        Ok(())
+    }
+}
+
+impl Mutation {
+    /// Describe what the mutation would do without modifying anything
+    pub fn simulate(&self, applications: &[Application]) -> ChangeReport {
+        let changes = match self {
+            Mutation::Delete(indices) => indices
+                .iter()
+                .filter_map(|&idx| applications.get(idx))
+                .map(|application| Change {
+                    record: describe(application),
+                    summary: format!("moved to the trash ({})", application.status.as_str()),
+                })
+                .collect(),
+            Mutation::SetStatus { indices, status } => indices
+                .iter()
+                .filter_map(|&idx| applications.get(idx))
+                .filter(|application| application.status != *status)
+                .map(|application| Change {
+                    record: describe(application),
+                    summary: format!("status {} → {}", application.status.as_str(), status.as_str()),
+                })
+                .collect(),
+            Mutation::Add(added) => added
+                .iter()
+                .map(|application| Change {
+                    record: describe(application),
+                    summary: "added".to_string(),
+                })
+                .collect(),
+            Mutation::Merge(groups) => groups
+                .iter()
+                .filter_map(|group| {
+                    let (first, rest) = group.split_first()?;
+                    let kept = applications.get(*first)?;
+                    Some(rest.iter().filter_map(|&idx| applications.get(idx)).map(move |application| Change {
+                        record: describe(application),
+                        summary: format!("merged into {}", describe(kept)),
+                    }))
+                })
+                .flatten()
+                .collect(),
+        };
+        ChangeReport { changes }
+    }
+
+    /// Apply the mutation, returning the same report `simulate` would have produced
+    pub fn apply(&self, data: &mut Data) -> ChangeReport {
+        let report = self.simulate(&data.applications);
+        let applications = &mut data.applications;
+        match self {
+            Mutation::Delete(indices) => {
+                let mut indices = indices.clone();
+                indices.sort_unstable();
+                indices.dedup();
+                let deleted_at = chrono::Utc::now();
+                for idx in indices.into_iter().rev() {
+                    if idx < applications.len() {
+                        let application = applications.remove(idx);
+                        data.trash.push(TrashedApplication { deleted_at, position: idx, application });
+                    }
+                }
+            }
+            Mutation::SetStatus { indices, status } => {
+                let today = chrono::Local::now().date_naive();
+                for &idx in indices {
+                    if let Some(application) = applications.get_mut(idx) {
+                        application.set_status(status.clone(), today);
+                    }
+                }
+            }
+            Mutation::Add(added) => applications.extend(added.iter().cloned()),
+            Mutation::Merge(groups) => {
+                let mut merged = Vec::new();
+                for group in groups {
+                    let Some((&first, rest)) = group.split_first() else {
+                        continue;
+                    };
+                    for &idx in rest {
+                        if first < applications.len() && idx < applications.len() && idx != first {
+                            let duplicate = applications[idx].clone();
+                            absorb(&mut applications[first], &duplicate);
+                            merged.push(idx);
+                        }
+                    }
+                }
+                merged.sort_unstable();
+                merged.dedup();
+                let deleted_at = chrono::Utc::now();
+                for idx in merged.into_iter().rev() {
+                    let application = applications.remove(idx);
+                    data.trash.push(TrashedApplication { deleted_at, position: idx, application });
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Fold `other`, a duplicate of `into`, into it: the earlier applied date, the status of
+/// whichever changed status last, both sets of interviews, activity, history and notes, and
+/// any field `into` left blank
+fn absorb(into: &mut Application, other: &Application) {
+    let last_change = |application: &Application| {
+        application.status_history.last().map_or(application.applied_date, |change| change.date)
+    };
+    if last_change(other) > last_change(into) {
+        into.status = other.status.clone();
+        into.rejection_reason = other.rejection_reason;
+    }
+    into.applied_date = into.applied_date.min(other.applied_date);
+
+    fn fill<T: Clone>(field: &mut Option<T>, other: &Option<T>) {
+        if field.is_none() {
+            field.clone_from(other);
+        }
+    }
+    fill(&mut into.job_title, &other.job_title);
+    fill(&mut into.url, &other.url);
+    fill(&mut into.external_id, &other.external_id);
+    fill(&mut into.location, &other.location);
+    fill(&mut into.resume_path, &other.resume_path);
+    fill(&mut into.salary, &other.salary);
+    fill(&mut into.follow_up, &other.follow_up);
+    fill(&mut into.deadline, &other.deadline);
+    fill(&mut into.contact_name, &other.contact_name);
+    fill(&mut into.contact_email, &other.contact_email);
+    if into.work_mode == WorkMode::Unknown {
+        into.work_mode = other.work_mode;
+    }
+    if into.resume_version.is_empty() {
+        into.resume_version.clone_from(&other.resume_version);
+    }
+    if into.cover_letter_version.is_empty() {
+        into.cover_letter_version.clone_from(&other.cover_letter_version);
+    }
+    into.cover_letter |= other.cover_letter;
+    into.referral |= other.referral;
+    into.interest = into.interest.max(other.interest);
+    into.archived &= other.archived;
+
+    into.status_history.extend(other.status_history.iter().cloned());
+    into.status_history.sort_by_key(|change| change.date);
+    into.interviews.extend(other.interviews.iter().cloned());
+    into.interviews.sort_by_key(|interview| interview.date);
+    into.interviews.dedup();
+    into.activity.extend(other.activity.iter().cloned());
+    into.activity.sort_by_key(|entry| entry.date);
+    into.activity.dedup();
+
+    let notes = other.notes.trim();
+    if !notes.is_empty() && !into.notes.contains(notes) {
+        if !into.notes.trim().is_empty() {
+            into.notes.push_str("\n\n");
+        }
+        into.notes.push_str(notes);
+    }
+}
+
+/// Short human-readable label for a record in change reports
+fn describe(application: &Application) -> String {
+    format!("{} ({})", application.company_name, application.applied_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ActivityEntry, Interview, InterviewKind, StatusChange};
+    use chrono::NaiveDate;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn application(company: &str, applied: u32, status: Status) -> Application {
+        Application {
+            company_name: company.to_string(),
+            job_title: Some("Engineer".to_string()),
+            applied_date: day(applied),
+            status,
+            ..Application::new()
+        }
+    }
+
+    fn data() -> Data {
+        Data {
+            applications: vec![
+                application("Acme", 1, Status::Applied),
+                application("Globex", 2, Status::Interview),
+                application("acme", 3, Status::Applied),
+            ],
+            ..Data::default()
+        }
+    }
+
+    #[test]
+    fn simulate_reports_without_changing_anything() {
+        let data = data();
+        let before = data.applications.clone();
+        for mutation in [
+            Mutation::Delete(vec![0]),
+            Mutation::SetStatus { indices: vec![0, 1], status: Status::Rejected },
+            Mutation::Add(vec![application("Initech", 4, Status::Applied)]),
+            Mutation::Merge(vec![vec![0, 2]]),
+        ] {
+            assert!(!mutation.simulate(&data.applications).is_empty());
+            assert_eq!(data.applications, before);
+        }
+    }
+
+    #[test]
+    fn apply_returns_the_simulated_report() {
+        for mutation in [
+            Mutation::Delete(vec![1]),
+            Mutation::SetStatus { indices: vec![0, 1, 2], status: Status::Interview },
+            Mutation::Add(vec![application("Initech", 4, Status::Applied)]),
+            Mutation::Merge(vec![vec![0, 2]]),
+        ] {
+            let mut data = data();
+            let simulated = mutation.simulate(&data.applications);
+            assert_eq!(mutation.apply(&mut data), simulated);
+        }
+    }
+
+    #[test]
+    fn set_status_reports_only_records_that_change() {
+        let data = data();
+        let mutation = Mutation::SetStatus { indices: vec![0, 1, 2], status: Status::Interview };
+        let report = mutation.simulate(&data.applications);
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.changes[0].record, "Acme (2024-05-01)");
+        assert_eq!(report.changes[0].summary, "status Applied → Interview");
+    }
+
+    #[test]
+    fn delete_moves_records_to_the_trash_in_place() {
+        let mut data = data();
+        Mutation::Delete(vec![2, 0]).apply(&mut data);
+        assert_eq!(data.applications.len(), 1);
+        assert_eq!(data.applications[0].company_name, "Globex");
+        let positions: Vec<usize> = data.trash.iter().map(|trashed| trashed.position).collect();
+        assert_eq!(positions, vec![2, 0]);
+    }
+
+    #[test]
+    fn merge_folds_duplicates_into_the_first() {
+        let mut data = data();
+        let duplicate = &mut data.applications[2];
+        duplicate.url = Some("https://acme.example/jobs/1".to_string());
+        duplicate.status = Status::Interview;
+        duplicate.status_history.push(StatusChange { status: Status::Interview, date: day(6) });
+        duplicate.interviews.push(Interview { kind: InterviewKind::Phone, date: day(8), notes: String::new() });
+        duplicate.activity.push(ActivityEntry { date: day(5), text: "recruiter emailed".to_string() });
+        duplicate.notes = "Referred by Sam".to_string();
+        data.applications[0].notes = "Backend team".to_string();
+
+        let report = Mutation::Merge(vec![vec![0, 2]]).apply(&mut data);
+        assert_eq!(report.changes[0].record, "acme (2024-05-03)");
+        assert_eq!(report.changes[0].summary, "merged into Acme (2024-05-01)");
+
+        assert_eq!(data.applications.len(), 2);
+        let merged = &data.applications[0];
+        assert_eq!(merged.company_name, "Acme");
+        assert_eq!(merged.applied_date, day(1));
+        assert_eq!(merged.status, Status::Interview);
+        assert_eq!(merged.url.as_deref(), Some("https://acme.example/jobs/1"));
+        assert_eq!(merged.interviews.len(), 1);
+        assert_eq!(merged.activity.len(), 1);
+        assert_eq!(merged.notes, "Backend team\n\nReferred by Sam");
+        assert_eq!(data.trash.len(), 1);
+        assert_eq!(data.trash[0].application.company_name, "acme");
+    }
+
+    #[test]
+    fn merge_keeps_the_status_that_changed_last() {
+        let mut data = data();
+        data.applications[0].status = Status::Rejected;
+        data.applications[0].status_history.push(StatusChange { status: Status::Rejected, date: day(9) });
+        data.applications[2].status = Status::Interview;
+        data.applications[2].status_history.push(StatusChange { status: Status::Interview, date: day(6) });
+        Mutation::Merge(vec![vec![0, 2]]).apply(&mut data);
+        assert_eq!(data.applications[0].status, Status::Rejected);
+        assert_eq!(data.applications[0].status_history.len(), 2);
+    }
+}
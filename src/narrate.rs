@@ -0,0 +1,150 @@
+use crate::app::CompanyGroup;
+use crate::models::{Application, Status, WorkMode};
+use chrono::NaiveDate;
+
+/// Summary sentences describing the whole list, read before individual applications
+pub fn summary<'a>(applications: impl IntoIterator<Item = &'a Application>, statuses: &[Status]) -> Vec<String> {
+    let applications: Vec<&Application> = applications.into_iter().collect();
+    if applications.is_empty() {
+        return vec!["You have no applications.".to_string()];
+    }
+
+    let counts: Vec<String> = statuses
+        .iter()
+        .map(|status| {
+            let count = applications.iter().filter(|a| a.status == *status).count();
+            format!("{} {}", count, status.as_str().to_lowercase())
+        })
+        .collect();
+
+    vec![
+        format!("You have {}.", plural(applications.len(), "application")),
+        format!("By status: {}.", counts.join(", ")),
+    ]
+}
+
+/// One sentence describing a company header in the grouped list
+pub fn describe_group(group: &CompanyGroup) -> String {
+    let hidden = if group.collapsed { " They are collapsed; press Enter on the company in the list to show them." } else { "" };
+    format!(
+        "{}: {}, furthest status {}.{}",
+        group.company,
+        plural(group.count, "application"),
+        group.best.as_str(),
+        hidden
+    )
+}
+
+/// One sentence describing an application, skipping fields that are empty
+pub fn describe(application: &Application, today: NaiveDate) -> String {
+    let mut parts = vec![application.company_name.trim().to_string()];
+    if let Some(title) = &application.job_title {
+        parts.push(format!("as {}", title));
+    }
+
+    let verb = if application.status == Status::Planned { "added" } else { "applied" };
+    parts.push(format!(
+        "{} {} via {}",
+        verb,
+        relative_day(application.applied_date, today),
+        application.platform.as_str()
+    ));
+    match application.rejection_reason {
+        Some(reason) => parts.push(format!(
+            "status {}, {}",
+            application.status.as_str(),
+            reason.as_str().to_lowercase()
+        )),
+        None => parts.push(format!("status {}", application.status.as_str())),
+    }
+    match (application.work_mode, &application.location) {
+        (WorkMode::Unknown, None) => {}
+        (WorkMode::Unknown, Some(location)) => parts.push(format!("in {}", location)),
+        (mode, None) => parts.push(mode.as_str().to_lowercase()),
+        (mode, Some(location)) => parts.push(format!("{} in {}", mode.as_str().to_lowercase(), location)),
+    }
+    if application.archived {
+        parts.push("archived".to_string());
+    }
+    if let Some(id) = &application.external_id {
+        parts.push(format!("job ID {}", id));
+    }
+
+    if let Some(salary) = &application.salary {
+        parts.push(format!("salary {}", salary));
+    }
+    if !application.resume_version.trim().is_empty() {
+        parts.push(format!("resume {}", application.resume_version.trim()));
+    }
+    if application.cover_letter {
+        match application.cover_letter_version.trim() {
+            "" => parts.push("with a cover letter".to_string()),
+            version => parts.push(format!("cover letter {}", version)),
+        }
+    }
+    if application.referral {
+        parts.push("referred".to_string());
+    }
+    if let Some(contact) = &application.contact_name {
+        parts.push(format!("contact {}", contact));
+    }
+    if !application.interviews.is_empty() {
+        let mut interviews = plural(application.interviews.len(), "interview round");
+        if let Some(next) = application.next_interview(today) {
+            interviews.push_str(&format!(
+                ", next {} {}",
+                next.kind.as_str().to_lowercase(),
+                relative_day(next.date, today)
+            ));
+        }
+        parts.push(interviews);
+    }
+    if let Some(follow_up) = application.follow_up {
+        let due = relative_day(follow_up, today);
+        if follow_up < today {
+            parts.push(format!("follow up was due {}", due));
+        } else {
+            parts.push(format!("follow up due {}", due));
+        }
+    }
+    if let Some(deadline) = application.deadline {
+        let day = relative_day(deadline, today);
+        if deadline < today {
+            parts.push(format!("posting closed {}", day));
+        } else {
+            parts.push(format!("posting closes {}", day));
+        }
+    }
+
+    let mut sentence = parts.join(", ");
+    sentence.push('.');
+
+    let notes = application.notes.split_whitespace().collect::<Vec<_>>().join(" ");
+    if !notes.is_empty() {
+        sentence.push_str(&format!(" Notes: {}", notes));
+        if !notes.ends_with(['.', '!', '?']) {
+            sentence.push('.');
+        }
+    }
+
+    sentence
+}
+
+/// "today", "yesterday", "6 days ago", "tomorrow", "in 3 days"
+pub fn relative_day(date: NaiveDate, today: NaiveDate) -> String {
+    match (date - today).num_days() {
+        0 => "today".to_string(),
+        -1 => "yesterday".to_string(),
+        1 => "tomorrow".to_string(),
+        days if days < 0 => format!("{} ago", plural(-days as usize, "day")),
+        days => format!("in {}", plural(days as usize, "day")),
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
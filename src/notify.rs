@@ -0,0 +1,99 @@
+use crate::models::Application;
+use crate::narrate;
+use chrono::{Days, NaiveDate};
+use notify_rust::Notification;
+
+/// Something worth a reminder outside the app
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reminder {
+    Interview { company: String, kind: String, date: NaiveDate },
+    FollowUp { company: String, date: NaiveDate },
+}
+
+impl Reminder {
+    /// One line, e.g. "Acme: technical interview tomorrow"
+    pub fn describe(&self, today: NaiveDate) -> String {
+        match self {
+            Reminder::Interview { company, kind, date } => {
+                format!("{}: {} interview {}", company, kind, narrate::relative_day(*date, today))
+            }
+            Reminder::FollowUp { company, date } if *date == today => {
+                format!("{}: follow up today", company)
+            }
+            Reminder::FollowUp { company, date } => {
+                format!("{}: follow up was due {}", company, narrate::relative_day(*date, today))
+            }
+        }
+    }
+}
+
+/// Interviews today or tomorrow, then follow-ups due today or earlier, each oldest first.
+/// Archived applications are skipped.
+pub fn reminders(applications: &[Application], today: NaiveDate) -> Vec<Reminder> {
+    let tomorrow = today + Days::new(1);
+    let active: Vec<&Application> = applications.iter().filter(|a| !a.archived).collect();
+
+    let mut interviews: Vec<(NaiveDate, Reminder)> = active
+        .iter()
+        .flat_map(|application| {
+            application
+                .interviews
+                .iter()
+                .filter(|interview| interview.date == today || interview.date == tomorrow)
+                .map(|interview| {
+                    let reminder = Reminder::Interview {
+                        company: application.company_name.trim().to_string(),
+                        kind: interview.kind.as_str().to_lowercase(),
+                        date: interview.date,
+                    };
+                    (interview.date, reminder)
+                })
+        })
+        .collect();
+    interviews.sort_by_key(|(date, _)| *date);
+
+    let mut follow_ups: Vec<(NaiveDate, Reminder)> = active
+        .iter()
+        .filter_map(|application| {
+            let date = application.follow_up.filter(|date| *date <= today)?;
+            let reminder = Reminder::FollowUp {
+                company: application.company_name.trim().to_string(),
+                date,
+            };
+            Some((date, reminder))
+        })
+        .collect();
+    follow_ups.sort_by_key(|(date, _)| *date);
+
+    interviews
+        .into_iter()
+        .chain(follow_ups)
+        .map(|(_, reminder)| reminder)
+        .collect()
+}
+
+/// Show the reminders as one desktop notification, printing them instead when no
+/// notification daemon answers. Nothing to report shows and prints nothing.
+pub fn send(reminders: &[Reminder], today: NaiveDate) {
+    if reminders.is_empty() {
+        return;
+    }
+
+    let lines: Vec<String> = reminders.iter().map(|reminder| reminder.describe(today)).collect();
+    let summary = if reminders.len() == 1 {
+        "jobtracker: 1 reminder".to_string()
+    } else {
+        format!("jobtracker: {} reminders", reminders.len())
+    };
+
+    let shown = Notification::new()
+        .appname("jobtracker")
+        .summary(&summary)
+        .body(&lines.join("\n"))
+        .show();
+    if shown.is_err() {
+        for line in lines {
+            println!("{}", line);
+        }
+    }
+}
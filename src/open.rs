@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use std::ffi::OsStr;
+use std::process::{Command, Stdio};
+
+/// Open a URL or file with the platform's default handler
+pub fn open(target: impl AsRef<OsStr>) -> Result<()> {
+    let mut command = opener_command(target);
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Failed to launch opener")?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn opener_command(target: impl AsRef<OsStr>) -> Command {
+    let mut command = Command::new("open");
+    command.arg(target);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn opener_command(target: impl AsRef<OsStr>) -> Command {
+    let mut command = Command::new("cmd");
+    command.args(["/C", "start", ""]).arg(target);
+    command
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn opener_command(target: impl AsRef<OsStr>) -> Command {
+    let mut command = Command::new("xdg-open");
+    command.arg(target);
+    command
+}
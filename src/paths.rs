@@ -0,0 +1,57 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Path as typed, with a leading `~` standing for the home directory
+pub fn expand_home(input: &str) -> PathBuf {
+    let input = input.trim();
+    let rest = match input {
+        "~" => Some(""),
+        _ => input.strip_prefix("~/").or_else(|| input.strip_prefix("~\\")),
+    };
+    match (rest, home_dir()) {
+        (Some(""), Some(home)) => home,
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(input),
+    }
+}
+
+/// Path as saved and shown: under the home directory it starts with `~/` and uses `/`,
+/// so a data file moved to another machine or OS still points at the same file
+pub fn contract_home(path: &Path) -> String {
+    if let Some(rest) = home_dir().and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf)) {
+        let parts: Vec<String> = rest
+            .components()
+            .map(|part| part.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        if parts.is_empty() {
+            return "~".to_string();
+        }
+        return format!("~/{}", parts.join("/"));
+    }
+    path.to_string_lossy().into_owned()
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+/// Serde adapter for optional paths, written with `contract_home` and read with `expand_home`
+pub mod portable {
+    use super::{contract_home, expand_home};
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::path::PathBuf;
+
+    pub fn serialize<S: Serializer>(path: &Option<PathBuf>, serializer: S) -> Result<S::Ok, S::Error> {
+        match path {
+            Some(path) => serializer.serialize_some(&contract_home(path)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<PathBuf>, D::Error> {
+        let path: Option<String> = Option::deserialize(deserializer)?;
+        Ok(path.map(|path| expand_home(&path)))
+    }
+}
@@ -0,0 +1,158 @@
+use anyhow::{anyhow, bail, Result};
+use toml::{Table, Value};
+
+/// Version of the preset file format; bumped on incompatible changes
+pub const PRESET_VERSION: i64 = 1;
+
+/// Key holding the format version at the top of a preset
+const VERSION_KEY: &str = "preset_version";
+
+/// Section name for the top-level settings that aren't tables, such as `week_starts_on`
+pub const GENERAL: &str = "general";
+
+/// Wrap a config file's settings into a shareable preset
+pub fn export(config: &Table) -> Result<String> {
+    let mut preset = Table::new();
+    preset.insert(VERSION_KEY.to_string(), Value::Integer(PRESET_VERSION));
+    preset.extend(config.clone());
+    Ok(toml::to_string_pretty(&preset)?)
+}
+
+/// Parse a preset, rejecting files without a version or from a newer format
+pub fn parse(content: &str) -> Result<Table> {
+    let mut preset: Table = toml::from_str(content)?;
+    let version = preset
+        .remove(VERSION_KEY)
+        .ok_or_else(|| anyhow!("Not a jobtracker preset (missing {})", VERSION_KEY))?;
+    let Some(version) = version.as_integer() else {
+        bail!("{} must be a number", VERSION_KEY);
+    };
+    if version > PRESET_VERSION {
+        bail!(
+            "Preset format version {} is newer than this jobtracker supports ({}); upgrade jobtracker to import it",
+            version,
+            PRESET_VERSION
+        );
+    }
+    Ok(preset)
+}
+
+/// Sections in a preset, general settings first, then tables by name
+pub fn sections(preset: &Table) -> Vec<String> {
+    let mut sections = Vec::new();
+    if preset.values().any(|value| !value.is_table()) {
+        sections.push(GENERAL.to_string());
+    }
+    sections.extend(
+        preset
+            .iter()
+            .filter(|(_, value)| value.is_table())
+            .map(|(key, _)| key.clone()),
+    );
+    sections
+}
+
+/// Replace one section of `config` with the preset's version of it
+pub fn apply(config: &mut Table, preset: &Table, section: &str) {
+    if section == GENERAL {
+        config.retain(|_, value| value.is_table());
+        for (key, value) in preset.iter().filter(|(_, value)| !value.is_table()) {
+            config.insert(key.clone(), value.clone());
+        }
+    } else if let Some(value) = preset.get(section) {
+        config.insert(section.to_string(), value.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    const DESKTOP: &str = r##"
+week_starts_on = "monday"
+statuses = ["Planned", "Applied", "Phone Screen", "Interview", "Offer", "Rejected"]
+
+[keys]
+save_form = "ctrl+w"
+up = ["k", "up"]
+
+[theme]
+palette = "colorblind"
+
+[theme.colors]
+accent = "#1e90ff"
+
+[list]
+columns = ["date", "company", "status"]
+"##;
+
+    const LAPTOP: &str = r##"
+week_starts_on = "sunday"
+platforms = ["Hacker News"]
+
+[keys]
+quit = "Q"
+
+[theme]
+base = "light"
+
+[goal]
+weekly = 5
+"##;
+
+    fn table(content: &str) -> Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn export_then_import_round_trips() {
+        let config = table(DESKTOP);
+        let preset = parse(&export(&config).unwrap()).unwrap();
+        assert_eq!(preset, config);
+
+        let mut imported = Table::new();
+        for section in sections(&preset) {
+            apply(&mut imported, &preset, &section);
+        }
+        assert_eq!(imported, config);
+        Config::parse(&toml::to_string_pretty(&imported).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn general_settings_come_first_in_sections() {
+        assert_eq!(sections(&table(DESKTOP)), [GENERAL, "keys", "list", "theme"]);
+    }
+
+    #[test]
+    fn partial_import_replaces_the_chosen_sections_and_keeps_the_rest() {
+        let preset = parse(&export(&table(DESKTOP)).unwrap()).unwrap();
+        let mut config = table(LAPTOP);
+        for section in ["keys", GENERAL] {
+            apply(&mut config, &preset, section);
+        }
+
+        // Chosen: replaced wholesale, so the laptop's own key and platform list are gone
+        assert_eq!(config["keys"], preset["keys"]);
+        assert_eq!(config["week_starts_on"].as_str(), Some("monday"));
+        assert_eq!(config["statuses"], preset["statuses"]);
+        assert!(!config.contains_key("platforms"));
+        // Not chosen: the laptop's sections survive, and the preset's other sections stay out
+        assert_eq!(config["theme"], table(LAPTOP)["theme"]);
+        assert_eq!(config["goal"], table(LAPTOP)["goal"]);
+        assert!(!config.contains_key("list"));
+
+        let config = Config::parse(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(config.keys.len(), 2);
+    }
+
+    #[test]
+    fn newer_or_unversioned_presets_are_rejected() {
+        let newer = format!("{} = {}\n", VERSION_KEY, PRESET_VERSION + 1);
+        let error = parse(&newer).unwrap_err().to_string();
+        assert!(error.contains("newer than this jobtracker supports"), "{}", error);
+
+        let error = parse(LAPTOP).unwrap_err().to_string();
+        assert!(error.contains("missing preset_version"), "{}", error);
+    }
+}
@@ -0,0 +1,333 @@
+use crate::models::{self, Application, Status, WorkMode};
+use crate::stats::{self, Conversion};
+use chrono::NaiveDate;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Default report file name, e.g. `report-2024-05-01.md`
+pub fn default_path(today: NaiveDate) -> PathBuf {
+    PathBuf::from(format!("report-{}.md", today))
+}
+
+/// Markdown summary of the applications: totals, breakdowns, conversion, active applications
+/// and their activity logs
+pub fn markdown<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    statuses: &[Status],
+    platforms: &[String],
+    today: NaiveDate,
+) -> String {
+    let applications: Vec<&Application> = applications.into_iter().collect();
+    let mut active: Vec<&Application> = applications
+        .iter()
+        .copied()
+        .filter(|application| application.status != Status::Rejected)
+        .collect();
+    active.sort_by_key(|application| std::cmp::Reverse(application.applied_date));
+
+    let mut report = String::new();
+    let _ = writeln!(report, "# Job Application Report\n\nGenerated {}.\n", today);
+
+    report.push_str("## Totals\n\n");
+    let _ = writeln!(report, "- Applications: {}", applications.len());
+    let _ = writeln!(report, "- Active (not rejected): {}\n", active.len());
+
+    let by_status: Vec<(String, u64)> = stats::by_status(applications.iter().copied(), statuses)
+        .into_iter()
+        .map(|(status, count)| (status.as_str().to_string(), count))
+        .collect();
+    count_table(&mut report, "By status", "Status", &by_status);
+    count_table(&mut report, "By platform", "Platform", &stats::by_platform(applications.iter().copied(), platforms));
+    count_table(
+        &mut report,
+        "By resume version",
+        "Resume version",
+        &stats::by_resume_version(applications.iter().copied()),
+    );
+
+    let conversion = Conversion::from_applications(applications.iter().copied());
+    report.push_str("## Conversion\n\n");
+    let _ = writeln!(
+        report,
+        "- Reached an interview: {}",
+        rate_text(conversion.interviewed, conversion.total)
+    );
+    let _ = writeln!(report, "- Offers: {}", rate_text(conversion.offers, conversion.total));
+    let _ = writeln!(
+        report,
+        "- Interviews that led to an offer: {}\n",
+        rate_text(conversion.offers, conversion.interviewed)
+    );
+
+    report.push_str("## Active applications\n\n");
+    if active.is_empty() {
+        report.push_str("None.\n");
+        return report;
+    }
+    report.push_str("| Company | Status | Platform | Applied | Follow-up |\n|---|---|---|---|---|\n");
+    for application in &active {
+        let _ = writeln!(
+            report,
+            "| {} | {} | {} | {} | {} |",
+            cell(&application.company_name),
+            application.status.as_str(),
+            cell(&application.platform.as_str()),
+            application.applied_date,
+            application.follow_up.map(|date| date.to_string()).unwrap_or_default()
+        );
+    }
+
+    let logged: Vec<&&Application> = active.iter().filter(|application| !application.activity.is_empty()).collect();
+    if !logged.is_empty() {
+        report.push_str("\n## Activity\n");
+        for application in logged {
+            let mut heading = application.company_name.trim().to_string();
+            if let Some(title) = &application.job_title {
+                heading.push_str(&format!(" — {}", title));
+            }
+            let _ = writeln!(report, "\n### {}\n", heading);
+            for entry in &application.activity {
+                let _ = writeln!(report, "- {}: {}", entry.date, entry.text.replace('\n', " "));
+            }
+        }
+    }
+    report
+}
+
+/// File name for an application's one-pager, e.g. `acme-backend-engineer.md`
+pub fn application_file_name(application: &Application) -> String {
+    let mut name = application.company_name.clone();
+    if let Some(title) = &application.job_title {
+        name.push(' ');
+        name.push_str(title);
+    }
+    let words: Vec<String> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect();
+    if words.is_empty() {
+        return "application.md".to_string();
+    }
+    format!("{}.md", words.join("-"))
+}
+
+/// Markdown one-pager with everything recorded about one application: its fields, status
+/// history, interview rounds, activity log and full notes. Empty fields and sections are left out.
+pub fn application_markdown(application: &Application) -> String {
+    let mut page = String::new();
+    let mut heading = application.company_name.trim().to_string();
+    if let Some(title) = &application.job_title {
+        heading.push_str(&format!(" — {}", title));
+    }
+    let _ = writeln!(page, "# {}\n", heading);
+
+    let _ = writeln!(page, "- Status: {}", application.status.as_str());
+    if let Some(reason) = application.rejection_reason {
+        let _ = writeln!(page, "- Rejection reason: {}", reason.as_str());
+    }
+    if application.interest > 0 {
+        let _ = writeln!(page, "- Interest: {}", models::stars(application.interest));
+    }
+    let _ = writeln!(page, "- Applied: {} via {}", application.applied_date, application.platform.as_str());
+    match (&application.location, application.work_mode) {
+        (None, WorkMode::Unknown) => {}
+        (None, mode) => {
+            let _ = writeln!(page, "- Work mode: {}", mode.as_str());
+        }
+        (Some(location), WorkMode::Unknown) => {
+            let _ = writeln!(page, "- Location: {}", location);
+        }
+        (Some(location), mode) => {
+            let _ = writeln!(page, "- Location: {} ({})", location, mode.as_str());
+        }
+    }
+    if let Some(url) = &application.url {
+        let _ = writeln!(page, "- Posting: {}", url);
+    }
+    if let Some(id) = &application.external_id {
+        let _ = writeln!(page, "- Job / requisition ID: {}", id);
+    }
+    if let Some(salary) = &application.salary {
+        let _ = writeln!(page, "- Salary: {}", salary);
+    }
+    if !application.resume_version.trim().is_empty() || application.resume_modified {
+        let mut resume = application.resume_version.trim().to_string();
+        if application.resume_modified {
+            resume.push_str(if resume.is_empty() { "modified" } else { " (modified)" });
+        }
+        let _ = writeln!(page, "- Resume: {}", resume);
+    }
+    if let Some(path) = &application.resume_path {
+        let _ = writeln!(page, "- Resume file: {}", path.display());
+    }
+    if application.cover_letter {
+        match application.cover_letter_version.trim() {
+            "" => page.push_str("- Cover letter: yes\n"),
+            version => {
+                let _ = writeln!(page, "- Cover letter: {}", version);
+            }
+        }
+    }
+    if application.referral {
+        page.push_str("- Referral: yes\n");
+    }
+    match (&application.contact_name, &application.contact_email) {
+        (None, None) => {}
+        (Some(name), None) => {
+            let _ = writeln!(page, "- Contact: {}", name);
+        }
+        (None, Some(email)) => {
+            let _ = writeln!(page, "- Contact: {}", email);
+        }
+        (Some(name), Some(email)) => {
+            let _ = writeln!(page, "- Contact: {} <{}>", name, email);
+        }
+    }
+    if let Some(date) = application.follow_up {
+        let _ = writeln!(page, "- Follow-up: {}", date);
+    }
+    if let Some(date) = application.deadline {
+        let _ = writeln!(page, "- Deadline: {}", date);
+    }
+    if application.archived {
+        page.push_str("- Archived: yes\n");
+    }
+
+    if !application.status_history.is_empty() {
+        page.push_str("\n## Status history\n\n");
+        for change in &application.status_history {
+            let _ = writeln!(page, "- {}: {}", change.date, change.status.as_str());
+        }
+    }
+
+    if !application.interviews.is_empty() {
+        page.push_str("\n## Interviews\n\n");
+        for (round, interview) in application.interviews.iter().enumerate() {
+            let _ = writeln!(page, "{}. {}: {}", round + 1, interview.date, interview.kind.as_str());
+            for line in interview.notes.trim().lines() {
+                let _ = writeln!(page, "   {}", line);
+            }
+        }
+    }
+
+    if !application.activity.is_empty() {
+        page.push_str("\n## Activity\n\n");
+        for entry in &application.activity {
+            let _ = writeln!(page, "- {}: {}", entry.date, entry.text.replace('\n', " "));
+        }
+    }
+
+    if !application.notes.trim().is_empty() {
+        let _ = writeln!(page, "\n## Notes\n\n{}", application.notes.trim());
+    }
+    page
+}
+
+fn count_table(report: &mut String, title: &str, column: &str, counts: &[(String, u64)]) {
+    let _ = writeln!(report, "## {}\n\n| {} | Count |\n|---|---:|", title, column);
+    for (label, count) in counts {
+        let _ = writeln!(report, "| {} | {} |", cell(label), count);
+    }
+    report.push('\n');
+}
+
+/// "3/12 (25%)", or "0/0" when there is nothing to divide by
+fn rate_text(part: u64, whole: u64) -> String {
+    match stats::rate(part, whole) {
+        Some(percent) => format!("{}/{} ({}%)", part, whole, percent),
+        None => format!("{}/{}", part, whole),
+    }
+}
+
+/// Text safe to put in a Markdown table cell
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        ActivityEntry, Interview, InterviewKind, Platform, RejectionReason, SalaryRange, StatusChange,
+    };
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    /// Every field filled in, so the snapshot shows the whole layout
+    fn application() -> Application {
+        Application {
+            company_name: "Acme".to_string(),
+            job_title: Some("Backend Engineer".to_string()),
+            url: Some("https://jobs.acme.example/123".to_string()),
+            external_id: Some("ACME-REQ-77".to_string()),
+            platform: Platform::LinkedIn,
+            location: Some("Berlin".to_string()),
+            work_mode: WorkMode::Hybrid,
+            resume_modified: true,
+            resume_version: "v3".to_string(),
+            resume_path: Some(PathBuf::from("resumes/v3.pdf")),
+            cover_letter: true,
+            cover_letter_version: "backend".to_string(),
+            status: Status::Rejected,
+            rejection_reason: Some(RejectionReason::AfterOnsite),
+            interest: 4,
+            status_history: vec![
+                StatusChange { status: Status::Applied, date: date(2) },
+                StatusChange { status: Status::Interview, date: date(9) },
+                StatusChange { status: Status::Rejected, date: date(20) },
+            ],
+            salary: Some(SalaryRange { min: 120_000, max: 150_000, currency: "EUR".to_string() }),
+            applied_date: date(2),
+            follow_up: Some(date(27)),
+            deadline: Some(date(31)),
+            contact_name: Some("Wile Coyote".to_string()),
+            contact_email: Some("wile@acme.example".to_string()),
+            referral: true,
+            interviews: vec![
+                Interview { kind: InterviewKind::Phone, date: date(9), notes: String::new() },
+                Interview {
+                    kind: InterviewKind::Technical,
+                    date: date(16),
+                    notes: "System design: rate limiter\nAsk about on-call".to_string(),
+                },
+            ],
+            activity: vec![ActivityEntry { date: date(6), text: "Recruiter called\nback next week".to_string() }],
+            notes: "  Team of six.\n\nUses Rust and Postgres.  ".to_string(),
+            archived: true,
+            ..Application::new()
+        }
+    }
+
+    fn assert_page(name: &str, page: &str) {
+        insta::with_settings!({ filters => vec![(r"\d{4}-\d{2}-\d{2}", "YYYY-MM-DD")] }, {
+            insta::assert_snapshot!(name, page);
+        });
+    }
+
+    #[test]
+    fn one_pager_lists_every_field_and_section() {
+        assert_page("one_pager", &application_markdown(&application()));
+    }
+
+    #[test]
+    fn one_pager_leaves_out_empty_fields_and_sections() {
+        let application = Application { company_name: "Globex".to_string(), applied_date: date(2), ..Application::new() };
+        assert_page("one_pager_minimal", &application_markdown(&application));
+    }
+
+    #[test]
+    fn one_pager_does_not_depend_on_the_record_id() {
+        // Each call makes a record with a fresh random ID
+        assert_eq!(application_markdown(&application()), application_markdown(&application()));
+    }
+
+    #[test]
+    fn one_pager_file_name_is_made_of_company_and_title() {
+        assert_eq!(application_file_name(&application()), "acme-backend-engineer.md");
+        let unnamed = Application { company_name: "  ?! ".to_string(), ..Application::new() };
+        assert_eq!(application_file_name(&unnamed), "application.md");
+    }
+}
@@ -0,0 +1,136 @@
+use std::ops::Range;
+
+/// Characters of context kept on each side of a match
+pub const CONTEXT: usize = 30;
+
+/// Part of a text around a match, flattened onto one line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    /// Context around the match, with `…` where the text was cut
+    pub text: String,
+    /// Character range of the match within `text`
+    pub highlight: Range<usize>,
+    /// Character index in the original text where the match starts
+    pub offset: usize,
+}
+
+/// Find `query` in `text`, ignoring case, and cut out the first match with up to `context`
+/// characters on either side. Line breaks and runs of whitespace count as a single space
+/// in both, so a query can match across lines of a multi-line note.
+/// An empty query matches nothing.
+pub fn find(query: &str, text: &str, context: usize) -> Option<Snippet> {
+    let query: Vec<char> = flatten(query).into_iter().map(|(c, _)| lowercase(c)).collect();
+    if query.is_empty() {
+        return None;
+    }
+    // Each flattened char keeps its index in the original text
+    let flat = flatten(text);
+    let lower: Vec<char> = flat.iter().map(|&(c, _)| lowercase(c)).collect();
+    let start = lower.windows(query.len()).position(|window| window == query.as_slice())?;
+    let end = start + query.len();
+
+    let from = start.saturating_sub(context);
+    let to = (end + context).min(flat.len());
+    let cut_before = from > 0;
+    let cut_after = to < flat.len();
+
+    let mut snippet = String::new();
+    if cut_before {
+        snippet.push('…');
+    }
+    snippet.extend(flat[from..to].iter().map(|&(c, _)| c));
+    if cut_after {
+        snippet.push('…');
+    }
+    let shift = start - from + usize::from(cut_before);
+    Some(Snippet {
+        text: snippet,
+        highlight: shift..shift + query.len(),
+        offset: flat[start].1,
+    })
+}
+
+/// Characters of `text` with whitespace runs collapsed to one space and the ends trimmed,
+/// each paired with its character index in `text`
+fn flatten(text: &str) -> Vec<(char, usize)> {
+    let mut flat: Vec<(char, usize)> = Vec::new();
+    for (idx, c) in text.chars().enumerate() {
+        if !c.is_whitespace() {
+            flat.push((c, idx));
+        } else if flat.last().is_some_and(|&(last, _)| last != ' ') {
+            flat.push((' ', idx));
+        }
+    }
+    if flat.last().is_some_and(|&(last, _)| last == ' ') {
+        flat.pop();
+    }
+    flat
+}
+
+/// One lowercase char per char, so indices line up with the original
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The highlighted part of a snippet
+    fn highlighted(snippet: &Snippet) -> String {
+        snippet.text.chars().skip(snippet.highlight.start).take(snippet.highlight.len()).collect()
+    }
+
+    #[test]
+    fn empty_or_missing_queries_find_nothing() {
+        assert_eq!(find("", "Recruiter called", CONTEXT), None);
+        assert_eq!(find("  \n", "Recruiter called", CONTEXT), None);
+        assert_eq!(find("offer", "Recruiter called", CONTEXT), None);
+    }
+
+    #[test]
+    fn short_text_is_kept_whole() {
+        let snippet = find("CALLED", "Recruiter called", CONTEXT).unwrap();
+        assert_eq!(snippet.text, "Recruiter called");
+        assert_eq!(highlighted(&snippet), "called");
+        assert_eq!(snippet.offset, 10);
+    }
+
+    #[test]
+    fn long_text_is_cut_with_ellipses() {
+        let snippet = find("offer", "We got to the offer stage today", 4).unwrap();
+        assert_eq!(snippet.text, "…the offer sta…");
+        assert_eq!(highlighted(&snippet), "offer");
+    }
+
+    #[test]
+    fn only_the_cut_side_gets_an_ellipsis() {
+        assert_eq!(find("we", "We got to the offer", 3).unwrap().text, "We go…");
+        assert_eq!(find("offer", "We got to the offer", 3).unwrap().text, "…he offer");
+    }
+
+    #[test]
+    fn queries_match_across_line_breaks() {
+        let text = "Phone screen\n\n  went well";
+        let snippet = find("screen went", text, CONTEXT).unwrap();
+        assert_eq!(snippet.text, "Phone screen went well");
+        assert_eq!(highlighted(&snippet), "screen went");
+        assert_eq!(snippet.offset, 6);
+    }
+
+    #[test]
+    fn offset_counts_characters_in_the_original_text() {
+        let text = "  Zürich → Genève\nonsite";
+        let snippet = find("onsite", text, 2).unwrap();
+        assert_eq!(snippet.offset, 18);
+        assert_eq!(text.chars().nth(snippet.offset), Some('o'));
+        assert_eq!(snippet.text, "…e onsite");
+        assert_eq!(highlighted(&snippet), "onsite");
+    }
+
+    #[test]
+    fn the_first_match_is_used() {
+        let snippet = find("call", "call back, then call again", CONTEXT).unwrap();
+        assert_eq!(snippet.offset, 0);
+    }
+}
@@ -0,0 +1,859 @@
+use crate::app::{ChartType, DashboardData};
+use crate::dates::{self, WeekStart};
+use crate::text;
+use crate::models::{stars, Application, Platform, RejectionReason, Status, WorkMode, MAX_INTEREST};
+use chrono::{Datelike, Days, NaiveDate};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Resume versions shown in the resume version charts, most used first
+const TOP_RESUME_VERSIONS: usize = 10;
+
+/// Platforms shown by name in the status by platform chart; the rest are folded into "Other"
+const TOP_PLATFORMS: usize = 6;
+
+/// Numbers behind a chart, shared by the chart view and chart export so both always agree
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartData {
+    /// Applications per label
+    Counts(Vec<(String, u64)>),
+    /// How far each group's applications got
+    Conversions(Vec<(String, Conversion)>),
+    Statuses(Vec<(Status, u64)>),
+    /// Applications per status within each labelled group
+    StatusByGroup(Vec<(String, Vec<(Status, u64)>)>),
+    ResponseTimes(ResponseTimes),
+}
+
+impl ChartData {
+    /// Whether there is nothing to draw
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ChartData::Counts(counts) => counts.iter().all(|(_, count)| *count == 0),
+            ChartData::Conversions(conversions) => conversions.iter().all(|(_, conversion)| conversion.total == 0),
+            ChartData::Statuses(counts) => counts.iter().all(|(_, count)| *count == 0),
+            ChartData::StatusByGroup(groups) => groups.is_empty(),
+            ChartData::ResponseTimes(times) => {
+                [times.first_response, times.applied_to_interview, times.interview_to_offer, times.waiting]
+                    .iter()
+                    .all(Option::is_none)
+            }
+        }
+    }
+
+    /// The same chart with each count replaced by its whole percentage of the total, or of its
+    /// group's total for stacked charts. Rates and day counts are left as they are.
+    pub fn into_percentages(self) -> ChartData {
+        match self {
+            ChartData::Counts(counts) => ChartData::Counts(with_percentages(counts)),
+            ChartData::Statuses(counts) => ChartData::Statuses(with_percentages(counts)),
+            ChartData::StatusByGroup(groups) => ChartData::StatusByGroup(
+                groups
+                    .into_iter()
+                    .map(|(group, counts)| (group, with_percentages(counts)))
+                    .collect(),
+            ),
+            data @ (ChartData::Conversions(_) | ChartData::ResponseTimes(_)) => data,
+        }
+    }
+}
+
+fn with_percentages<T>(counts: Vec<(T, u64)>) -> Vec<(T, u64)> {
+    let shares = percentages(&counts.iter().map(|(_, count)| *count).collect::<Vec<_>>());
+    counts.into_iter().zip(shares).map(|((key, _), share)| (key, share)).collect()
+}
+
+/// Whole percentages of `counts` that add up to exactly 100, rounding up the ones that lost the
+/// most to rounding down (ties go to the earlier count), or all zeros when there is nothing to
+/// share
+pub fn percentages(counts: &[u64]) -> Vec<u64> {
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return vec![0; counts.len()];
+    }
+    let mut shares: Vec<u64> = counts.iter().map(|count| count * 100 / total).collect();
+    let mut by_remainder: Vec<usize> = (0..counts.len()).collect();
+    by_remainder.sort_by_key(|&idx| std::cmp::Reverse(counts[idx] * 100 % total));
+    let missing = 100 - shares.iter().sum::<u64>();
+    for &idx in by_remainder.iter().take(missing as usize) {
+        shares[idx] += 1;
+    }
+    shares
+}
+
+/// Applied-date window the charts count, ending today
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateRange {
+    Last30Days,
+    Last90Days,
+    ThisYear,
+    #[default]
+    AllTime,
+}
+
+impl DateRange {
+    pub fn all() -> &'static [DateRange] {
+        &[DateRange::Last30Days, DateRange::Last90Days, DateRange::ThisYear, DateRange::AllTime]
+    }
+
+    pub fn next(&self) -> Self {
+        let ranges = Self::all();
+        let current_idx = ranges.iter().position(|r| r == self).unwrap();
+        ranges[(current_idx + 1) % ranges.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        let ranges = Self::all();
+        let current_idx = ranges.iter().position(|r| r == self).unwrap();
+        ranges[(current_idx + ranges.len() - 1) % ranges.len()]
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            DateRange::Last30Days => "Last 30 days",
+            DateRange::Last90Days => "Last 90 days",
+            DateRange::ThisYear => "This year",
+            DateRange::AllTime => "All time",
+        }
+    }
+
+    /// First applied date in the range, counting today as one of its days; None for all time
+    pub fn start(&self, today: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            DateRange::Last30Days => today.checked_sub_days(Days::new(29)),
+            DateRange::Last90Days => today.checked_sub_days(Days::new(89)),
+            DateRange::ThisYear => NaiveDate::from_ymd_opt(today.year(), 1, 1),
+            DateRange::AllTime => None,
+        }
+    }
+
+    /// Whether an application applied on `date` is counted. Dates after today are kept,
+    /// so nothing goes missing from every range but all time.
+    pub fn contains(&self, date: NaiveDate, today: NaiveDate) -> bool {
+        self.start(today).is_none_or(|start| date >= start)
+    }
+}
+
+/// The applications whose applied date falls in `range`
+pub fn in_range<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    range: DateRange,
+    today: NaiveDate,
+) -> impl Iterator<Item = &'a Application> {
+    applications
+        .into_iter()
+        .filter(move |application| range.contains(application.applied_date, today))
+}
+
+/// Data for `chart` over the applications applied in `range`, with statuses in the order
+/// of `statuses` and platforms labeled as in `platforms`, the platform presets
+pub fn chart_data<'a>(
+    chart: ChartType,
+    applications: impl IntoIterator<Item = &'a Application>,
+    range: DateRange,
+    statuses: &[Status],
+    platforms: &[String],
+    today: NaiveDate,
+) -> ChartData {
+    let applications = in_range(applications, range, today);
+    match chart {
+        ChartType::ByResumeVersion => {
+            let mut counts = by_resume_version(applications);
+            counts.truncate(TOP_RESUME_VERSIONS);
+            ChartData::Counts(counts)
+        }
+        ChartType::ByPlatform => ChartData::Counts(by_platform(applications, platforms)),
+        ChartType::ByWorkMode => ChartData::Counts(
+            by_work_mode(applications)
+                .into_iter()
+                .map(|(mode, count)| (mode.as_str().to_string(), count))
+                .collect(),
+        ),
+        ChartType::ByPlatformSuccess => ChartData::Conversions(conversion_by_platform(applications, platforms)),
+        ChartType::ByResumeSuccess => {
+            let mut conversions = conversion_by_resume_version(applications);
+            conversions.truncate(TOP_RESUME_VERSIONS);
+            ChartData::Conversions(conversions)
+        }
+        ChartType::ByReferral => ChartData::Conversions(conversion_by_referral(applications)),
+        ChartType::ByCoverLetter => ChartData::Conversions(conversion_by_cover_letter(applications)),
+        ChartType::ByStatus => ChartData::Statuses(by_status(applications, statuses)),
+        ChartType::RejectionReasons => ChartData::Counts(by_rejection_reason(applications)),
+        ChartType::StatusByPlatform => {
+            ChartData::StatusByGroup(status_by_platform(applications, statuses, platforms, TOP_PLATFORMS))
+        }
+        ChartType::StatusByInterest => ChartData::StatusByGroup(status_by_interest(applications, statuses)),
+        ChartType::ResponseTimes => ChartData::ResponseTimes(response_times(applications, today)),
+    }
+}
+
+/// Number of applications per resume version ("None" when blank), most common first
+pub fn by_resume_version<'a>(applications: impl IntoIterator<Item = &'a Application>) -> Vec<(String, u64)> {
+    sorted_counts(applications.into_iter().map(resume_version))
+}
+
+/// Resume version label, "None" when blank
+fn resume_version(application: &Application) -> String {
+    if application.resume_version.is_empty() {
+        "None".to_string()
+    } else {
+        application.resume_version.clone()
+    }
+}
+
+/// Number of applications per platform, most common first. Configured presets in `platforms`
+/// are listed even before their first use.
+pub fn by_platform<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    platforms: &[String],
+) -> Vec<(String, u64)> {
+    let mut data = sorted_counts(applications.into_iter().map(|application| platform_label(application, platforms)));
+    let configured = platforms.iter().filter(|name| {
+        !Platform::presets().contains(&name.as_str()) && !data.iter().any(|(label, _)| label == *name)
+    });
+    let unused: Vec<(String, u64)> = configured.map(|name| (name.clone(), 0)).collect();
+    data.extend(unused);
+    data
+}
+
+/// Platform name, spelled as the matching preset in `platforms` if there is one
+fn platform_label(application: &Application, platforms: &[String]) -> String {
+    Platform::from_name(&application.platform.as_str(), platforms).as_str()
+}
+
+/// Number of applications per work mode, in `WorkMode::all` order
+pub fn by_work_mode<'a>(applications: impl IntoIterator<Item = &'a Application>) -> Vec<(WorkMode, u64)> {
+    let mut counts: HashMap<WorkMode, u64> = HashMap::new();
+    for application in applications {
+        *counts.entry(application.work_mode).or_insert(0) += 1;
+    }
+    WorkMode::all()
+        .iter()
+        .map(|mode| (*mode, counts.get(mode).copied().unwrap_or(0)))
+        .collect()
+}
+
+/// Number of rejected applications per reason, in `RejectionReason::all` order,
+/// then "Unspecified" for ones rejected without a reason
+pub fn by_rejection_reason<'a>(applications: impl IntoIterator<Item = &'a Application>) -> Vec<(String, u64)> {
+    let mut counts: HashMap<Option<RejectionReason>, u64> = HashMap::new();
+    for application in applications.into_iter().filter(|application| application.status == Status::Rejected) {
+        *counts.entry(application.rejection_reason).or_insert(0) += 1;
+    }
+    let mut data: Vec<(String, u64)> = RejectionReason::all()
+        .iter()
+        .map(|reason| (reason.as_str().to_string(), counts.get(&Some(*reason)).copied().unwrap_or(0)))
+        .collect();
+    if let Some(&unspecified) = counts.get(&None) {
+        data.push(("Unspecified".to_string(), unspecified));
+    }
+    data
+}
+
+/// Number of applications in every status, in the order of `statuses`
+pub fn by_status<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    statuses: &[Status],
+) -> Vec<(Status, u64)> {
+    let mut counts: HashMap<&Status, u64> = HashMap::new();
+    for application in applications {
+        *counts.entry(&application.status).or_insert(0) += 1;
+    }
+    statuses
+        .iter()
+        .map(|status| (status.clone(), counts.get(status).copied().unwrap_or(0)))
+        .collect()
+}
+
+/// Applications per status for each platform, busiest platform first, with statuses in the
+/// order of `statuses`. Platforms past the `top` busiest are folded into one "Other" group.
+pub fn status_by_platform<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    statuses: &[Status],
+    platforms: &[String],
+    top: usize,
+) -> Vec<(String, Vec<(Status, u64)>)> {
+    let mut by_platform: HashMap<String, Vec<&Application>> = HashMap::new();
+    for application in applications {
+        by_platform
+            .entry(platform_label(application, platforms))
+            .or_default()
+            .push(application);
+    }
+    let mut platforms: Vec<(String, Vec<&Application>)> = by_platform.into_iter().collect();
+    platforms.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    let rest: Vec<&Application> = platforms
+        .iter()
+        .skip(top)
+        .flat_map(|(_, applications)| applications.iter().copied())
+        .collect();
+    platforms.truncate(top);
+    if !rest.is_empty() {
+        // A platform actually called "Other" takes the folded ones in
+        match platforms.iter_mut().find(|(platform, _)| platform == "Other") {
+            Some((_, applications)) => applications.extend(rest),
+            None => platforms.push(("Other".to_string(), rest)),
+        }
+    }
+
+    platforms
+        .into_iter()
+        .map(|(platform, applications)| (platform, by_status(applications, statuses)))
+        .collect()
+}
+
+/// Applications per status at each interest level that has any, highest rating first and
+/// "Unrated" last, with statuses in the order of `statuses`
+pub fn status_by_interest<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    statuses: &[Status],
+) -> Vec<(String, Vec<(Status, u64)>)> {
+    let mut by_interest: Vec<Vec<&Application>> = vec![Vec::new(); MAX_INTEREST as usize + 1];
+    for application in applications {
+        by_interest[application.interest.min(MAX_INTEREST) as usize].push(application);
+    }
+    by_interest
+        .into_iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, applications)| !applications.is_empty())
+        .map(|(interest, applications)| {
+            let label = if interest == 0 { "Unrated".to_string() } else { stars(interest as u8) };
+            (label, by_status(applications, statuses))
+        })
+        .collect()
+}
+
+/// How far sent applications got through the pipeline; Planned ones are left out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conversion {
+    pub total: u64,
+    /// Reached an interview: in Interview or Offer, or with interview rounds logged
+    pub interviewed: u64,
+    pub offers: u64,
+}
+
+impl Conversion {
+    pub fn from_applications<'a>(applications: impl IntoIterator<Item = &'a Application>) -> Self {
+        let mut conversion = Conversion {
+            total: 0,
+            interviewed: 0,
+            offers: 0,
+        };
+        // Planned records haven't been sent, so they'd only dilute the rates
+        for application in applications.into_iter().filter(|application| application.status != Status::Planned) {
+            conversion.total += 1;
+            let offer = application.status == Status::Offer;
+            if offer || application.status == Status::Interview || !application.interviews.is_empty() {
+                conversion.interviewed += 1;
+            }
+            if offer {
+                conversion.offers += 1;
+            }
+        }
+        conversion
+    }
+}
+
+/// Pipeline conversion per platform, best interview rate first
+pub fn conversion_by_platform<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    platforms: &[String],
+) -> Vec<(String, Conversion)> {
+    let mut data = conversion_by(applications, |application| platform_label(application, platforms));
+    data.sort_by(|a, b| {
+        let percent = |c: &Conversion| rate(c.interviewed, c.total).unwrap_or(0);
+        percent(&b.1)
+            .cmp(&percent(&a.1))
+            .then_with(|| b.1.total.cmp(&a.1.total))
+            .then_with(|| a.0.cmp(&b.0))
+    });
+    data
+}
+
+/// Pipeline conversion per resume version ("None" when blank), most used first.
+/// Versions whose applications never reached an interview stay in with a count of 0.
+pub fn conversion_by_resume_version<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+) -> Vec<(String, Conversion)> {
+    let mut data = conversion_by(applications, resume_version);
+    data.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(&b.0)));
+    data
+}
+
+/// Pipeline conversion of the applications sharing each key, unsorted
+fn conversion_by<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    key: impl Fn(&Application) -> String,
+) -> Vec<(String, Conversion)> {
+    let mut groups: HashMap<String, Vec<&Application>> = HashMap::new();
+    for application in applications.into_iter().filter(|application| application.status != Status::Planned) {
+        groups.entry(key(application)).or_default().push(application);
+    }
+    groups
+        .into_iter()
+        .map(|(key, applications)| (key, Conversion::from_applications(applications)))
+        .collect()
+}
+
+/// Pipeline conversion of referred applications, then of cold ones
+pub fn conversion_by_referral<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+) -> Vec<(String, Conversion)> {
+    let (referred, cold): (Vec<&Application>, Vec<&Application>) =
+        applications.into_iter().partition(|application| application.referral);
+    vec![
+        ("Referral".to_string(), Conversion::from_applications(referred)),
+        ("Cold".to_string(), Conversion::from_applications(cold)),
+    ]
+}
+
+/// Pipeline conversion of applications sent with a cover letter, then of ones without
+pub fn conversion_by_cover_letter<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+) -> Vec<(String, Conversion)> {
+    let (with, without): (Vec<&Application>, Vec<&Application>) =
+        applications.into_iter().partition(|application| application.cover_letter);
+    vec![
+        ("Cover letter".to_string(), Conversion::from_applications(with)),
+        ("None".to_string(), Conversion::from_applications(without)),
+    ]
+}
+
+/// Average and median of a set of day counts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DaySummary {
+    pub count: usize,
+    pub mean: f64,
+    pub median: f64,
+}
+
+impl DaySummary {
+    /// None when there are no days to summarize
+    pub fn from_days(mut days: Vec<i64>) -> Option<Self> {
+        if days.is_empty() {
+            return None;
+        }
+        days.sort_unstable();
+        let count = days.len();
+        let mean = days.iter().sum::<i64>() as f64 / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (days[count / 2 - 1] + days[count / 2]) as f64 / 2.0
+        } else {
+            days[count / 2] as f64
+        };
+        Some(Self { count, mean, median })
+    }
+}
+
+/// How long applications take to move through the pipeline, in days.
+/// Each stage only counts applications with dates at both ends; open ones are kept apart in `waiting`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseTimes {
+    /// Applied to the first status change or interview round
+    pub first_response: Option<DaySummary>,
+    pub applied_to_interview: Option<DaySummary>,
+    pub interview_to_offer: Option<DaySummary>,
+    /// Still Applied with no response: days waited so far
+    pub waiting: Option<DaySummary>,
+}
+
+pub fn response_times<'a>(
+    applications: impl IntoIterator<Item = &'a Application>,
+    today: NaiveDate,
+) -> ResponseTimes {
+    let mut first_response = Vec::new();
+    let mut applied_to_interview = Vec::new();
+    let mut interview_to_offer = Vec::new();
+    let mut waiting = Vec::new();
+
+    for application in applications {
+        let applied = application.applied_date;
+        match application.first_response() {
+            Some(date) => push_span(&mut first_response, applied, date),
+            None if application.status == Status::Applied => push_span(&mut waiting, applied, today),
+            // Moved on before history was recorded, so the date is unknown
+            None => {}
+        }
+        let interview = application.reached(Status::Interview);
+        if let Some(interview) = interview {
+            push_span(&mut applied_to_interview, applied, interview);
+        }
+        if let (Some(interview), Some(offer)) = (interview, application.reached(Status::Offer)) {
+            push_span(&mut interview_to_offer, interview, offer);
+        }
+    }
+
+    ResponseTimes {
+        first_response: DaySummary::from_days(first_response),
+        applied_to_interview: DaySummary::from_days(applied_to_interview),
+        interview_to_offer: DaySummary::from_days(interview_to_offer),
+        waiting: DaySummary::from_days(waiting),
+    }
+}
+
+/// Add the days from `from` to `to`, skipping negative spans from dates entered out of order
+fn push_span(days: &mut Vec<i64>, from: NaiveDate, to: NaiveDate) {
+    let span = (to - from).num_days();
+    if span >= 0 {
+        days.push(span);
+    }
+}
+
+/// Characters of a note's first line shown in the company summary
+const NOTE_SNIPPET: usize = 60;
+
+/// Everything applied to one company, for the company popup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanySummary {
+    /// Spelled as on the most recent application
+    pub company: String,
+    /// Statuses any application reached, through its current status, history or interview
+    /// rounds, with how many did; built-ins first, then custom statuses by name
+    pub reached: Vec<(Status, usize)>,
+    pub first_applied: NaiveDate,
+    pub last_applied: NaiveDate,
+    /// Resume versions sent ("None" when blank), most used first
+    pub resume_versions: Vec<(String, u64)>,
+    /// One entry per application, most recently applied first
+    pub applications: Vec<CompanyApplication>,
+}
+
+/// One application in a company summary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompanyApplication {
+    pub applied_date: NaiveDate,
+    pub job_title: Option<String>,
+    pub status: Status,
+    /// Start of the first line of the notes, if there are any
+    pub note: Option<String>,
+}
+
+impl CompanySummary {
+    pub fn total(&self) -> usize {
+        self.applications.len()
+    }
+}
+
+/// Summary of the applications to `company`, matched ignoring case and surrounding
+/// whitespace; None when there are none
+pub fn company_summary(company: &str, applications: &[Application]) -> Option<CompanySummary> {
+    let wanted = company.trim().to_lowercase();
+    let mut matching: Vec<&Application> = applications
+        .iter()
+        .filter(|application| application.company_name.trim().to_lowercase() == wanted)
+        .collect();
+    // Stable, so applications from the same day keep their order
+    matching.sort_by_key(|application| std::cmp::Reverse(application.applied_date));
+    let latest = matching.first()?;
+
+    let mut reached: Vec<(Status, usize)> = Vec::new();
+    for application in &matching {
+        let mut statuses: Vec<&Status> = std::iter::once(&application.status)
+            .chain(application.status_history.iter().map(|change| &change.status))
+            .collect();
+        if !application.interviews.is_empty() {
+            statuses.push(&Status::Interview);
+        }
+        statuses.sort_by_key(|status| status.as_str().to_string());
+        statuses.dedup();
+        for status in statuses {
+            match reached.iter_mut().find(|(seen, _)| seen == status) {
+                Some((_, count)) => *count += 1,
+                None => reached.push((status.clone(), 1)),
+            }
+        }
+    }
+    let rank = |status: &Status| Status::all().iter().position(|s| s == status).unwrap_or(usize::MAX);
+    reached.sort_by(|a, b| rank(&a.0).cmp(&rank(&b.0)).then_with(|| a.0.as_str().cmp(b.0.as_str())));
+
+    Some(CompanySummary {
+        company: latest.company_name.trim().to_string(),
+        reached,
+        first_applied: matching.iter().map(|application| application.applied_date).min()?,
+        last_applied: latest.applied_date,
+        resume_versions: sorted_counts(matching.iter().map(|application| resume_version(application))),
+        applications: matching
+            .iter()
+            .map(|application| CompanyApplication {
+                applied_date: application.applied_date,
+                job_title: application.job_title.clone(),
+                status: application.status.clone(),
+                note: application
+                    .notes
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(|line| text::truncate(line, NOTE_SNIPPET)),
+            })
+            .collect(),
+    })
+}
+
+/// Counts for the line above the list, over the applications it lists
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSummary {
+    pub total: usize,
+    pub attention: usize,
+    /// Applications per status, in status order
+    pub statuses: Vec<(Status, u64)>,
+}
+
+/// What the listed applications depend on besides the records themselves
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListFilter {
+    pub status: Option<Status>,
+    pub work_mode: Option<WorkMode>,
+    pub attention: bool,
+    pub show_archived: bool,
+    pub search: String,
+}
+
+/// Applications sent (not just planned) as of a day, over every record including archived
+/// ones, since those still went out
+#[derive(Debug, Clone, PartialEq)]
+pub struct Activity {
+    pub today: usize,
+    pub this_week: usize,
+    /// Company of the last sent application added
+    pub last_company: Option<String>,
+}
+
+/// Count what went out on `today` and in its week
+pub fn activity(applications: &[Application], today: NaiveDate, week_starts_on: WeekStart) -> Activity {
+    let sent: Vec<&Application> = applications
+        .iter()
+        .filter(|application| application.status != Status::Planned)
+        .collect();
+    Activity {
+        today: sent.iter().filter(|a| a.applied_date == today).count(),
+        this_week: sent
+            .iter()
+            .filter(|a| dates::same_week(a.applied_date, today, week_starts_on))
+            .count(),
+        last_company: sent.last().map(|a| a.company_name.clone()),
+    }
+}
+
+/// Chart data, list summary, activity and dashboard kept from the last draw, so redraws and cursor
+/// moves don't recount every record. Each is reused while the data generation and what it
+/// was computed for (chart and range, or list filter) are unchanged. Reads take `&self`
+/// because drawing only borrows the app.
+#[derive(Debug, Default)]
+pub struct StatsCache {
+    chart: Memo<(u64, ChartType, DateRange), ChartData>,
+    summary: Memo<(u64, ListFilter), ListSummary>,
+    activity: Memo<u64, Activity>,
+    dashboard: Memo<u64, DashboardData>,
+}
+
+impl StatsCache {
+    /// Data for a chart, computed by `compute` unless it is cached under the same key
+    pub fn chart(
+        &self,
+        generation: u64,
+        chart: ChartType,
+        range: DateRange,
+        compute: impl FnOnce() -> ChartData,
+    ) -> ChartData {
+        self.chart.get_or_insert_with((generation, chart, range), compute)
+    }
+
+    /// The list summary, computed by `compute` unless it is cached under the same key
+    pub fn summary(&self, generation: u64, filter: ListFilter, compute: impl FnOnce() -> ListSummary) -> ListSummary {
+        self.summary.get_or_insert_with((generation, filter), compute)
+    }
+
+    /// Today's and this week's activity, computed by `compute` unless it is cached for `generation`
+    pub fn activity(&self, generation: u64, compute: impl FnOnce() -> Activity) -> Activity {
+        self.activity.get_or_insert_with(generation, compute)
+    }
+
+    /// The dashboard, computed by `compute` unless it is cached for `generation`
+    pub fn dashboard(&self, generation: u64, compute: impl FnOnce() -> DashboardData) -> DashboardData {
+        self.dashboard.get_or_insert_with(generation, compute)
+    }
+}
+
+/// One cached value and the key it was computed for
+#[derive(Debug)]
+struct Memo<K, V>(RefCell<Option<(K, V)>>);
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self(RefCell::new(None))
+    }
+}
+
+impl<K: PartialEq, V: Clone> Memo<K, V> {
+    fn get_or_insert_with(&self, key: K, compute: impl FnOnce() -> V) -> V {
+        if let Some((cached, value)) = self.0.borrow().as_ref() {
+            if *cached == key {
+                return value.clone();
+            }
+        }
+        let value = compute();
+        *self.0.borrow_mut() = Some((key, value.clone()));
+        value
+    }
+}
+
+/// Percentage of `part` in `whole`, or None when `whole` is zero
+pub fn rate(part: u64, whole: u64) -> Option<u64> {
+    (whole > 0).then(|| (part * 100 + whole / 2) / whole)
+}
+
+/// Count equal keys, sorted by count descending, then by name for a stable order
+fn sorted_counts(keys: impl Iterator<Item = String>) -> Vec<(String, u64)> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for key in keys {
+        *counts.entry(key).or_insert(0) += 1;
+    }
+    let mut data: Vec<(String, u64)> = counts.into_iter().collect();
+    data.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Interview, InterviewKind, StatusChange};
+
+    fn applied(dates: &[NaiveDate]) -> Vec<Application> {
+        dates
+            .iter()
+            .map(|&applied_date| Application { applied_date, ..Application::new() })
+            .collect()
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Applied dates of the applications `range` counts
+    fn counted(applications: &[Application], range: DateRange, today: NaiveDate) -> Vec<NaiveDate> {
+        in_range(applications, range, today).map(|a| a.applied_date).collect()
+    }
+
+    #[test]
+    fn last_30_days_includes_today_and_the_29_days_before() {
+        let today = date(2024, 3, 1);
+        // 2024 is a leap year, so 29 days back crosses February 29th
+        let applications = applied(&[date(2024, 1, 31), date(2024, 2, 1), today]);
+        assert_eq!(counted(&applications, DateRange::Last30Days, today), [date(2024, 2, 1), today]);
+    }
+
+    #[test]
+    fn last_90_days_starts_89_days_back() {
+        let today = date(2024, 5, 1);
+        let applications = applied(&[date(2024, 2, 1), date(2024, 2, 2)]);
+        assert_eq!(counted(&applications, DateRange::Last90Days, today), [date(2024, 2, 2)]);
+    }
+
+    #[test]
+    fn this_year_starts_on_january_first() {
+        let today = date(2024, 1, 1);
+        let applications = applied(&[date(2023, 12, 31), today]);
+        assert_eq!(counted(&applications, DateRange::ThisYear, today), [today]);
+    }
+
+    #[test]
+    fn future_dates_are_kept_in_every_range() {
+        let today = date(2024, 12, 31);
+        let applications = applied(&[date(2025, 1, 2)]);
+        for &range in DateRange::all() {
+            assert_eq!(counted(&applications, range, today), [date(2025, 1, 2)], "{:?}", range);
+        }
+    }
+
+    #[test]
+    fn all_time_keeps_everything() {
+        let applications = applied(&[NaiveDate::MIN, date(2024, 5, 1)]);
+        assert_eq!(counted(&applications, DateRange::AllTime, NaiveDate::MIN).len(), 2);
+        assert_eq!(DateRange::AllTime.start(date(2024, 5, 1)), None);
+    }
+
+    #[test]
+    fn ranges_reaching_past_the_earliest_date_have_no_start() {
+        assert_eq!(DateRange::Last30Days.start(NaiveDate::MIN), None);
+    }
+
+    fn company(name: &str, applied: NaiveDate) -> Application {
+        Application { company_name: name.to_string(), applied_date: applied, ..Application::new() }
+    }
+
+    #[test]
+    fn company_summary_matches_ignoring_case_and_whitespace() {
+        let applications = [
+            company("Acme", date(2024, 1, 5)),
+            company("Globex", date(2024, 2, 1)),
+            company("  ACME ", date(2024, 3, 9)),
+            company("Acme Labs", date(2024, 4, 1)),
+        ];
+        let summary = company_summary(" acme", &applications).unwrap();
+        assert_eq!(summary.total(), 2);
+        // Spelled as on the latest application, trimmed
+        assert_eq!(summary.company, "ACME");
+        assert_eq!(summary.first_applied, date(2024, 1, 5));
+        assert_eq!(summary.last_applied, date(2024, 3, 9));
+        let dates: Vec<NaiveDate> = summary.applications.iter().map(|a| a.applied_date).collect();
+        assert_eq!(dates, [date(2024, 3, 9), date(2024, 1, 5)]);
+    }
+
+    #[test]
+    fn company_summary_is_none_without_applications() {
+        assert_eq!(company_summary("Acme", &[company("Globex", date(2024, 1, 5))]), None);
+        assert_eq!(company_summary("Acme", &[]), None);
+    }
+
+    #[test]
+    fn company_summary_counts_each_status_once_per_application() {
+        let rejected = Application {
+            status: Status::Rejected,
+            status_history: vec![
+                StatusChange { status: Status::Interview, date: date(2024, 1, 20) },
+                StatusChange { status: Status::Rejected, date: date(2024, 2, 1) },
+            ],
+            ..company("Acme", date(2024, 1, 5))
+        };
+        // Interview rounds count as reaching Interview even while the status lags behind
+        let interviewed = Application {
+            interviews: vec![Interview { kind: InterviewKind::Phone, date: date(2024, 3, 15), notes: String::new() }],
+            ..company("Acme", date(2024, 3, 9))
+        };
+        let custom = Application { status: Status::Other("Ghosted".to_string()), ..company("Acme", date(2024, 4, 1)) };
+        let summary = company_summary("Acme", &[rejected, interviewed, custom]).unwrap();
+        assert_eq!(
+            summary.reached,
+            [
+                (Status::Applied, 1),
+                (Status::Interview, 2),
+                (Status::Rejected, 1),
+                (Status::Other("Ghosted".to_string()), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn company_summary_lists_resume_versions_and_note_snippets() {
+        let applications = [
+            Application {
+                resume_version: "v2".to_string(),
+                notes: "\n  Referred by Sam\nmore".to_string(),
+                ..company("Acme", date(2024, 1, 5))
+            },
+            Application {
+                resume_version: "v2".to_string(),
+                notes: "x".repeat(80),
+                ..company("Acme", date(2024, 2, 5))
+            },
+            company("Acme", date(2024, 3, 5)),
+        ];
+        let summary = company_summary("Acme", &applications).unwrap();
+        assert_eq!(summary.resume_versions, [("v2".to_string(), 2), ("None".to_string(), 1)]);
+        let notes: Vec<Option<&str>> = summary.applications.iter().map(|a| a.note.as_deref()).collect();
+        let long = format!("{}{}", "x".repeat(NOTE_SNIPPET - 1), text::ELLIPSIS);
+        assert_eq!(notes, [None, Some(long.as_str()), Some("Referred by Sam")]);
+    }
+}
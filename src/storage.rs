@@ -1,35 +1,1784 @@
-use crate::models::Application;
-use anyhow::{Context, Result};
+use crate::config::StorageLayout;
+use crate::models::{
+    Application, Platform, RejectionReason, SalaryRange, Status, TrashedApplication, WorkMode, MAX_INTEREST,
+};
+use crate::paths;
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::NaiveDate;
+use rusqlite::{params, Connection, Row};
+use serde::Serialize;
+use serde_json::Value;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use uuid::Uuid;
 
-const DATA_FILE: &str = "applications.json";
+/// Default data file, relative to the current directory
+pub const DATA_FILE: &str = "applications.json";
 
-/// Load applications from JSON file
-pub fn load_applications() -> Result<Vec<Application>> {
-    let path = Path::new(DATA_FILE);
+/// Everything kept in the data file
+#[derive(Debug, Clone, Default)]
+pub struct Data {
+    pub applications: Vec<Application>,
+    /// Deleted applications, oldest deletion first
+    pub trash: Vec<TrashedApplication>,
+    /// Files of the directory layout that were skipped because they couldn't be read,
+    /// e.g. "<id>.json: EOF while parsing at line 4 column 0"
+    pub unreadable: Vec<String>,
+}
+
+/// The data file exists but isn't valid, e.g. a trailing comma or a half-written save.
+/// Holds the records that could still be read, so the rest isn't lost with the bad ones.
+#[derive(Debug)]
+pub struct CorruptFile {
+    pub path: PathBuf,
+    /// What the parser objected to, e.g. "trailing comma at line 12 column 3"
+    pub reason: String,
+    pub recovered: Data,
+    /// Records in the file that couldn't be read, a cut-off last one included
+    pub lost: usize,
+    /// Newest backup of the file, if there is one to go back to
+    pub backup: Option<PathBuf>,
+}
+
+impl fmt::Display for CorruptFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} can't be read: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for CorruptFile {}
+
+impl CorruptFile {
+    /// Where the unreadable file is moved before starting over, e.g. `applications.json.corrupt-20240502T101500`
+    pub fn keep_path(&self, now: chrono::DateTime<chrono::Local>) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".corrupt-{}", now.format("%Y%m%dT%H%M%S")));
+        self.path.with_file_name(name)
+    }
+}
+
+/// Where applications are loaded from and saved to
+pub trait Storage {
+    fn load(&self) -> Result<Data>;
+    fn save(&self, applications: &[Application], trash: &[TrashedApplication]) -> Result<()>;
+    /// Data file the applications are kept in
+    fn path(&self) -> &Path;
+
+    /// When the data was last written, by this app or anything else; None if it doesn't exist yet
+    fn modified(&self) -> Option<SystemTime> {
+        fs::metadata(self.path()).and_then(|metadata| metadata.modified()).ok()
+    }
+}
+
+/// Open the storage for a data file; `.db`, `.sqlite` and `.sqlite3` files use SQLite, anything else
+/// JSON laid out as configured
+pub fn open(path: &Path, layout: StorageLayout) -> Result<Box<dyn Storage>> {
+    if is_sqlite(path) {
+        return Ok(Box::new(SqliteStorage::open(path)?));
+    }
+    match layout {
+        StorageLayout::File => Ok(Box::new(JsonStorage::new(path))),
+        StorageLayout::Directory => Ok(Box::new(DirectoryStorage::new(directory_path(path)))),
+    }
+}
+
+/// Whether a data file is stored in SQLite rather than JSON
+pub fn is_sqlite(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| matches!(ext.to_ascii_lowercase().as_str(), "db" | "sqlite" | "sqlite3"))
+}
+
+/// Directory used instead of a data file in the directory layout: the file name without its
+/// extension, so `applications.json` becomes `applications/`
+pub fn directory_path(path: &Path) -> PathBuf {
+    path.with_extension("")
+}
+
+/// Backups of the JSON data file kept next to it, one per session that saved
+pub const BACKUPS: usize = 3;
+
+/// The `n`th newest backup of a data file, counting from 1, e.g. `applications.json.bak-1`
+pub fn backup_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".bak-{}", n));
+    path.with_file_name(name)
+}
+
+/// Newest backup of a data file that exists
+pub fn newest_backup(path: &Path) -> Option<PathBuf> {
+    (1..=BACKUPS).map(|n| backup_path(path, n)).find(|backup| backup.exists())
+}
+
+/// Version of the JSON format written by this build; files without a version are version 1
+pub const JSON_VERSION: u32 = migrations::CURRENT;
+
+/// Whole-file JSON storage
+pub struct JsonStorage {
+    path: PathBuf,
+    /// Whether the file as it was before this session's first save has been backed up
+    backed_up: Cell<bool>,
+}
+
+/// JSON layout since version 2: `{ "version": 3, "applications": [...], "trash": [...] }`
+#[derive(Serialize)]
+struct JsonEnvelope<'a> {
+    version: u32,
+    applications: &'a [Application],
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    trash: &'a [TrashedApplication],
+}
+
+impl JsonStorage {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            backed_up: Cell::new(false),
+        }
+    }
+
+    /// Error for unparseable `content`, after reading out every record that still parses
+    fn corrupt(&self, content: &str, reason: String) -> CorruptFile {
+        let (recovered, lost) = recover::data(content);
+        CorruptFile {
+            path: self.path.clone(),
+            reason,
+            recovered,
+            lost,
+            backup: newest_backup(&self.path),
+        }
+    }
+
+    /// Copy the file to `.bak-1`, shifting older backups up and dropping the oldest.
+    /// Done once per session, before its first save, so each backup is a whole session apart.
+    fn back_up(&self) -> Result<()> {
+        if self.backed_up.get() {
+            return Ok(());
+        }
+        if !self.path.exists() {
+            // A file this session creates is nothing to go back to
+            self.backed_up.set(true);
+            return Ok(());
+        }
+        for n in (1..BACKUPS).rev() {
+            let from = backup_path(&self.path, n);
+            if from.exists() {
+                fs::rename(&from, backup_path(&self.path, n + 1))
+                    .with_context(|| format!("Failed to rotate backup {}", from.display()))?;
+            }
+        }
+        let backup = backup_path(&self.path, 1);
+        fs::copy(&self.path, &backup).with_context(|| format!("Failed to back up to {}", backup.display()))?;
+        self.backed_up.set(true);
+        Ok(())
+    }
+}
+
+impl Storage for JsonStorage {
+    /// Load applications, returning an empty list if the file doesn't exist.
+    /// A file that can't be parsed fails with a [`CorruptFile`] holding what could be recovered.
+    fn load(&self) -> Result<Data> {
+        if !self.path.exists() {
+            return Ok(Data::default());
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .context("Failed to read applications file")?;
+
+        let document: Value = match serde_json::from_str(&content) {
+            Ok(document) => document,
+            Err(err) => return Err(self.corrupt(&content, err.to_string()).into()),
+        };
+        let (version, applications, trash) = match document {
+            // Bare arrays predate the envelope
+            Value::Array(_) => (1, document, None),
+            Value::Object(mut envelope) => {
+                let Some(version) = envelope.get("version").and_then(Value::as_u64).filter(|version| *version > 0) else {
+                    return Err(self.corrupt(&content, "no valid \"version\"".to_string()).into());
+                };
+                let Some(applications) = envelope.remove("applications") else {
+                    return Err(self.corrupt(&content, "no \"applications\" list".to_string()).into());
+                };
+                (version, applications, envelope.remove("trash"))
+            }
+            _ => return Err(self.corrupt(&content, "not a list of applications".to_string()).into()),
+        };
+
+        if version > u64::from(JSON_VERSION) {
+            bail!(
+                "{} was written by a newer version of jobtracker (data version {}, this build reads up to {}); \
+                 upgrade jobtracker to open it",
+                self.path.display(),
+                version,
+                JSON_VERSION
+            );
+        }
+
+        let applications = migrations::upgrade(applications, version as u32)
+            .with_context(|| format!("Failed to upgrade applications file from version {}", version))?;
+        // The trash arrived in version 3, so it never needs the older upgrades
+        let trash = match trash.map(serde_json::from_value).transpose() {
+            Ok(trash) => trash.unwrap_or_default(),
+            Err(err) => return Err(self.corrupt(&content, format!("trash: {}", err)).into()),
+        };
+        match serde_json::from_value(applications) {
+            Ok(applications) => Ok(Data { applications, trash, unreadable: Vec::new() }),
+            Err(err) => Err(self.corrupt(&content, err.to_string()).into()),
+        }
+    }
+
+    fn save(&self, applications: &[Application], trash: &[TrashedApplication]) -> Result<()> {
+        let envelope = JsonEnvelope {
+            version: JSON_VERSION,
+            applications,
+            trash,
+        };
+        let json = serde_json::to_string_pretty(&envelope)
+            .context("Failed to serialize applications")?;
+
+        self.back_up()?;
+        fs::write(&self.path, json)
+            .context("Failed to write applications file")?;
+
+        Ok(())
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Index of the directory layout, holding the format version and the list order
+const INDEX_FILE: &str = "index.json";
+
+/// Subdirectory holding one file per deleted application
+const TRASH_DIR: &str = "trash";
+
+/// JSON split into a directory so that version control diffs and merges stay small:
+/// `<id>.json` for each application, `trash/<id>.json` for each deleted one and an `index.json`
+/// holding the format version and the list order
+pub struct DirectoryStorage {
+    dir: PathBuf,
+    /// Contents of each file as last read or written, so a save only rewrites what changed.
+    /// Files that couldn't be read are never in here, so a save never deletes them.
+    written: RefCell<HashMap<PathBuf, String>>,
+}
+
+/// `index.json`: `{ "version": 3, "applications": ["<id>", ...] }`
+#[derive(Serialize)]
+struct DirectoryIndex {
+    version: u32,
+    /// Application IDs in list order
+    applications: Vec<Uuid>,
+}
+
+impl DirectoryStorage {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            written: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Format version and application order from the index; defaults when there's no index yet
+    fn read_index(&self, unreadable: &mut Vec<String>) -> Result<(u32, Vec<Uuid>)> {
+        let path = self.dir.join(INDEX_FILE);
+        if !path.exists() {
+            return Ok((JSON_VERSION, Vec::new()));
+        }
+        let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let index: Value = match serde_json::from_str(&content) {
+            Ok(index) => index,
+            Err(err) => {
+                // The records are all still there; only their order is lost
+                unreadable.push(format!("{}: {}", INDEX_FILE, err));
+                return Ok((JSON_VERSION, Vec::new()));
+            }
+        };
+
+        let version = index
+            .get("version")
+            .and_then(Value::as_u64)
+            .filter(|version| *version > 0)
+            .unwrap_or(u64::from(JSON_VERSION));
+        if version > u64::from(JSON_VERSION) {
+            bail!(
+                "{} was written by a newer version of jobtracker (data version {}, this build reads up to {}); \
+                 upgrade jobtracker to open it",
+                self.dir.display(),
+                version,
+                JSON_VERSION
+            );
+        }
+        let order = index
+            .get("applications")
+            .and_then(Value::as_array)
+            .map(|ids| ids.iter().filter_map(Value::as_str).filter_map(|id| Uuid::parse_str(id).ok()).collect())
+            .unwrap_or_default();
+        self.written.borrow_mut().insert(path, content);
+        Ok((version as u32, order))
+    }
+
+    /// Every record in `dir` that parses with `read`, in file name order; `dir` may not exist
+    fn read_records<T>(
+        &self,
+        dir: &Path,
+        unreadable: &mut Vec<String>,
+        read: impl Fn(Value) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<_>>()
+            .with_context(|| format!("Failed to read {}", dir.display()))?;
+        paths.retain(|path| {
+            path.is_file()
+                && path.extension().is_some_and(|ext| ext == "json")
+                && path.file_name().is_some_and(|name| name != INDEX_FILE)
+        });
+        paths.sort();
+
+        let mut records = Vec::new();
+        for path in paths {
+            let name = path.strip_prefix(&self.dir).unwrap_or(&path).display().to_string();
+            let content = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+            let record = serde_json::from_str(&content).map_err(anyhow::Error::from).and_then(&read);
+            match record {
+                Ok(record) => {
+                    records.push(record);
+                    self.written.borrow_mut().insert(path, content);
+                }
+                Err(err) => unreadable.push(format!("{}: {:#}", name, err)),
+            }
+        }
+        Ok(records)
+    }
+
+    fn record_path(&self, id: Uuid) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    fn trash_path(&self, id: Uuid) -> PathBuf {
+        self.dir.join(TRASH_DIR).join(format!("{}.json", id))
+    }
+}
+
+impl Storage for DirectoryStorage {
+    /// Load every record, skipping files that can't be parsed and listing them in
+    /// [`Data::unreadable`]. Records missing from the index, e.g. ones added on another machine,
+    /// come after the indexed ones.
+    fn load(&self) -> Result<Data> {
+        if !self.dir.exists() {
+            return Ok(Data::default());
+        }
+        self.written.borrow_mut().clear();
+
+        let mut unreadable = Vec::new();
+        let (version, order) = self.read_index(&mut unreadable)?;
+        let mut applications: Vec<Application> = self.read_records(&self.dir, &mut unreadable, |record| {
+            let upgraded = migrations::upgrade(Value::Array(vec![record]), version)?;
+            let mut list: Vec<Application> = serde_json::from_value(upgraded)?;
+            list.pop().ok_or_else(|| anyhow!("no application"))
+        })?;
+        applications.sort_by_key(|application| {
+            order.iter().position(|id| *id == application.id).unwrap_or(usize::MAX)
+        });
+
+        // The trash arrived in version 3, so it never needs the older upgrades
+        let mut trash: Vec<TrashedApplication> =
+            self.read_records(&self.dir.join(TRASH_DIR), &mut unreadable, |record| Ok(serde_json::from_value(record)?))?;
+        trash.sort_by_key(|trashed| trashed.deleted_at);
+
+        Ok(Data { applications, trash, unreadable })
+    }
+
+    /// Write the records that changed since the last load or save and remove the files of
+    /// records that are gone
+    fn save(&self, applications: &[Application], trash: &[TrashedApplication]) -> Result<()> {
+        let mut files = HashMap::new();
+        for application in applications {
+            if application.id.is_nil() {
+                bail!("{} has no ID", application.company_name);
+            }
+            let json = serde_json::to_string_pretty(application).context("Failed to serialize application")? + "\n";
+            if files.insert(self.record_path(application.id), json).is_some() {
+                bail!("More than one application has the ID {}", application.id);
+            }
+        }
+        for trashed in trash {
+            let json = serde_json::to_string_pretty(trashed).context("Failed to serialize application")? + "\n";
+            files.insert(self.trash_path(trashed.application.id), json);
+        }
+        let index = DirectoryIndex {
+            version: JSON_VERSION,
+            applications: applications.iter().map(|application| application.id).collect(),
+        };
+        let index = serde_json::to_string_pretty(&index).context("Failed to serialize the index")? + "\n";
+        files.insert(self.dir.join(INDEX_FILE), index);
+
+        fs::create_dir_all(self.dir.join(TRASH_DIR))
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let mut written = self.written.borrow_mut();
+        for (path, content) in &files {
+            if written.get(path) != Some(content) {
+                fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+                written.insert(path.clone(), content.clone());
+            }
+        }
+        let gone: Vec<PathBuf> = written.keys().filter(|path| !files.contains_key(*path)).cloned().collect();
+        for path in gone {
+            match fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err).with_context(|| format!("Failed to remove {}", path.display())),
+            }
+            written.remove(&path);
+        }
+        Ok(())
+    }
+
+    fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Latest change to any record file; the directories' own times cover files being removed
+    fn modified(&self) -> Option<SystemTime> {
+        let mtime = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+        let trash = self.dir.join(TRASH_DIR);
+        [self.dir.as_path(), trash.as_path()]
+            .into_iter()
+            .filter_map(|dir| {
+                let files = fs::read_dir(dir).ok()?.filter_map(|entry| mtime(&entry.ok()?.path()));
+                files.chain(mtime(dir)).max()
+            })
+            .max()
+    }
+}
+
+/// Outcome of reading applications from a LinkedIn export or a JSON stream
+#[derive(Debug, Default)]
+pub struct Import {
+    /// Rows that aren't tracked yet, as new applications
+    pub applications: Vec<Application>,
+    /// Rows matching a tracked application (or an earlier row) by company, date and title
+    pub skipped: usize,
+    /// Rows that couldn't be read, e.g. "row 4: unrecognized date 'soon'"
+    pub errors: Vec<String>,
+}
+
+impl Import {
+    /// Data rows read, not counting blank lines
+    pub fn rows(&self) -> usize {
+        self.applications.len() + self.skipped + self.errors.len()
+    }
+}
+
+/// Read LinkedIn's "My Jobs" CSV export into new applications, leaving out ones already in `existing`
+pub fn import_linkedin_csv(path: &Path, existing: &[Application]) -> Result<Import> {
+    let bytes = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    linkedin::import(&linkedin::decode(&bytes), existing)
+        .with_context(|| format!("Failed to import {}", path.display()))
+}
+
+/// Read applications piped in by other tools, as one JSON `array` or one JSON object per line,
+/// leaving out ones already in `existing`. Statuses must be among `statuses`; platforms
+/// matching one of the `platforms` presets take its spelling.
+pub fn import_json(
+    text: &str,
+    array: bool,
+    existing: &[Application],
+    statuses: &[Status],
+    platforms: &[String],
+) -> Result<Import> {
+    json_import::import(text, array, existing, statuses, platforms)
+}
+
+/// Mapping of loosely shaped JSON records onto applications
+mod json_import {
+    use super::linkedin::{identity, parse_date};
+    use super::Import;
+    use crate::models::{Application, Platform, SalaryRange, Status, WorkMode};
+    use anyhow::{Context, Result};
+    use chrono::NaiveDate;
+    use serde_json::{Map, Value};
+
+    /// Accepted keys for each field, compared ignoring case with spaces and dashes as underscores
+    const COMPANY: &[&str] = &["company", "company_name", "employer", "organization"];
+    const TITLE: &[&str] = &["title", "job_title", "position", "role"];
+    const DATE: &[&str] = &["date", "applied_date", "date_applied", "applied", "applied_on", "application_date"];
+    const URL: &[&str] = &["url", "link", "job_url", "posting_url"];
+    const EXTERNAL_ID: &[&str] = &["external_id", "job_id", "requisition_id", "req_id", "application_id"];
+    const LOCATION: &[&str] = &["location", "job_location"];
+    const PLATFORM: &[&str] = &["platform", "source", "site"];
+    const STATUS: &[&str] = &["status"];
+    const WORK_MODE: &[&str] = &["work_mode", "workplace", "remote"];
+    const SALARY: &[&str] = &["salary", "salary_range", "compensation"];
+    const DEADLINE: &[&str] = &["deadline", "closes", "closing_date"];
+    const RESUME_VERSION: &[&str] = &["resume_version", "resume"];
+    const CONTACT_NAME: &[&str] = &["contact_name", "contact", "recruiter"];
+    const CONTACT_EMAIL: &[&str] = &["contact_email", "email", "recruiter_email"];
+    const NOTES: &[&str] = &["notes", "note"];
+
+    /// Date formats tried after LinkedIn's, for ones scripts tend to write
+    const DATE_FORMATS: &[&str] = &["%Y/%m/%d", "%d.%m.%Y", "%Y%m%d"];
+
+    pub fn import(
+        text: &str,
+        array: bool,
+        existing: &[Application],
+        statuses: &[Status],
+        platforms: &[String],
+    ) -> Result<Import> {
+        // An array has to be read whole; lines are read one by one so a bad one only loses itself
+        let records: Vec<(String, Result<Value, String>)> = if array {
+            let values: Vec<Value> = serde_json::from_str(text).context("Invalid JSON array")?;
+            values
+                .into_iter()
+                .enumerate()
+                .map(|(idx, value)| (format!("record {}", idx + 1), Ok(value)))
+                .collect()
+        } else {
+            text.lines()
+                .enumerate()
+                .filter(|(_, line)| !line.trim().is_empty())
+                .map(|(idx, line)| {
+                    let value = serde_json::from_str(line).map_err(|err| format!("invalid JSON ({})", err));
+                    (format!("line {}", idx + 1), value)
+                })
+                .collect()
+        };
+
+        let mut import = Import::default();
+        let mut seen: Vec<(String, NaiveDate, String)> = existing.iter().map(identity).collect();
+        let today = chrono::Local::now().date_naive();
+        for (position, value) in records {
+            let application = value.and_then(|value| match value {
+                Value::Object(fields) => application(&fields, statuses, platforms, today),
+                _ => Err("not a JSON object".to_string()),
+            });
+            let application = match application {
+                Ok(application) => application,
+                Err(err) => {
+                    import.errors.push(format!("{}: {}", position, err));
+                    continue;
+                }
+            };
+
+            let key = identity(&application);
+            if seen.contains(&key) {
+                import.skipped += 1;
+                continue;
+            }
+            seen.push(key);
+            import.applications.push(application);
+        }
+        Ok(import)
+    }
+
+    /// Check one record and turn it into an application; a missing date means today
+    fn application(
+        fields: &Map<String, Value>,
+        statuses: &[Status],
+        platforms: &[String],
+        today: NaiveDate,
+    ) -> Result<Application, String> {
+        let field = |names: &[&str]| {
+            value(fields, names)
+                .and_then(|value| match value {
+                    Value::String(text) => Some(text.split_whitespace().collect::<Vec<_>>().join(" ")),
+                    Value::Number(number) => Some(number.to_string()),
+                    Value::Bool(flag) => Some(flag.to_string()),
+                    _ => None,
+                })
+                .filter(|text| !text.is_empty())
+        };
+
+        let mut application = Application::new();
+        application.company_name = field(COMPANY).ok_or("no company")?;
+        application.job_title = field(TITLE);
+        application.applied_date = match field(DATE) {
+            Some(text) => parse(&text).ok_or_else(|| format!("unrecognized date '{}'", text))?,
+            None => today,
+        };
+        application.url = field(URL).map(|url| {
+            if url.starts_with("http://") || url.starts_with("https://") {
+                url
+            } else {
+                format!("https://{}", url)
+            }
+        });
+        application.external_id = field(EXTERNAL_ID);
+        application.location = field(LOCATION);
+        if let Some(platform) = field(PLATFORM) {
+            application.platform = Platform::from_name(&platform, platforms);
+        }
+        if let Some(name) = field(STATUS) {
+            application.status = statuses
+                .iter()
+                .find(|status| status.as_str().eq_ignore_ascii_case(&name))
+                .cloned()
+                .ok_or_else(|| format!("unknown status '{}'", name))?;
+        }
+        if let Some(mode) = field(WORK_MODE) {
+            application.work_mode = match mode.as_str() {
+                // `"remote": true` from job boards that only flag remote roles
+                "true" => WorkMode::Remote,
+                "false" => WorkMode::Unknown,
+                mode => WorkMode::from_name(mode).ok_or_else(|| format!("unknown work mode '{}'", mode))?,
+            };
+        }
+        if let Some(salary) = field(SALARY) {
+            application.salary = Some(SalaryRange::parse(&salary).map_err(|err| format!("salary '{}': {}", salary, err))?);
+        }
+        if let Some(text) = field(DEADLINE) {
+            application.deadline = Some(parse(&text).ok_or_else(|| format!("unrecognized deadline '{}'", text))?);
+        }
+        application.resume_version = field(RESUME_VERSION).unwrap_or_default();
+        application.contact_name = field(CONTACT_NAME);
+        application.contact_email = field(CONTACT_EMAIL);
+        // Notes keep their line breaks
+        application.notes = value(fields, NOTES)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        Ok(application)
+    }
+
+    /// Value of the first key among `names`
+    fn value<'a>(fields: &'a Map<String, Value>, names: &[&str]) -> Option<&'a Value> {
+        fields
+            .iter()
+            .find(|(key, _)| names.contains(&key.trim().to_lowercase().replace([' ', '-'], "_").as_str()))
+            .map(|(_, value)| value)
+    }
+
+    /// A date in ISO form, with or without a time (2024-10-16T15:04:00Z), or in any of the
+    /// forms LinkedIn writes
+    fn parse(text: &str) -> Option<NaiveDate> {
+        parse_date(text).or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+        })
+    }
+}
+
+/// Parsing of LinkedIn's applied-jobs export, including what spreadsheet apps do to it when re-saved
+mod linkedin {
+    use super::Import;
+    use crate::models::{Application, Platform};
+    use anyhow::{bail, Result};
+    use chrono::NaiveDate;
+
+    /// Lowercased header names seen for each column
+    const COMPANY: &[&str] = &["company", "company name"];
+    const TITLE: &[&str] = &["job title", "title", "position"];
+    const DATE: &[&str] = &["date applied", "application date", "applied on", "applied at"];
+    const URL: &[&str] = &["job url", "job link", "url"];
+    const LOCATION: &[&str] = &["location", "job location"];
+    const EXTERNAL_ID: &[&str] = &["job id", "requisition id", "application id"];
+
+    /// Date formats tried in order; two-digit years first so "24" isn't read as the year 24
+    const DATE_FORMATS: &[&str] = &[
+        "%Y-%m-%d",
+        "%m/%d/%y",
+        "%m/%d/%Y",
+        "%b %d, %Y",
+        "%B %d, %Y",
+        "%b %d %Y",
+        "%B %d %Y",
+        "%d %b %Y",
+        "%d %B %Y",
+    ];
+
+    pub fn import(text: &str, existing: &[Application]) -> Result<Import> {
+        let mut records = parse(text).into_iter();
+        let Some(header) = records.next() else {
+            bail!("The file is empty");
+        };
+        let column = |names: &[&str]| {
+            header
+                .iter()
+                .position(|name| names.contains(&name.trim().to_lowercase().as_str()))
+        };
+        let (Some(company), Some(date)) = (column(COMPANY), column(DATE)) else {
+            bail!("Expected Company and Date Applied columns, found: {}", header.join(", "));
+        };
+        let (title, url, location, external_id) =
+            (column(TITLE), column(URL), column(LOCATION), column(EXTERNAL_ID));
+
+        let mut import = Import::default();
+        let mut seen: Vec<(String, NaiveDate, String)> = existing.iter().map(identity).collect();
+        let rows = records.filter(|record| record.iter().any(|field| !field.trim().is_empty()));
+        for (idx, record) in rows.enumerate() {
+            let row = idx + 1;
+            // Line breaks inside quoted fields become spaces
+            let field = |column: Option<usize>| {
+                column
+                    .and_then(|column| record.get(column))
+                    .map(|field| field.split_whitespace().collect::<Vec<_>>().join(" "))
+                    .filter(|field| !field.is_empty())
+            };
+
+            let Some(company_name) = field(Some(company)) else {
+                import.errors.push(format!("row {}: no company", row));
+                continue;
+            };
+            let date_text = field(Some(date)).unwrap_or_default();
+            let Some(applied_date) = parse_date(&date_text) else {
+                import.errors.push(format!("row {}: unrecognized date '{}'", row, date_text));
+                continue;
+            };
+
+            let mut application = Application::new();
+            application.company_name = company_name;
+            application.job_title = field(title);
+            application.url = field(url).map(|url| {
+                if url.starts_with("http://") || url.starts_with("https://") {
+                    url
+                } else {
+                    format!("https://{}", url)
+                }
+            });
+            application.location = field(location);
+            application.external_id = field(external_id);
+            application.platform = Platform::LinkedIn;
+            application.applied_date = applied_date;
+
+            let key = identity(&application);
+            if seen.contains(&key) {
+                import.skipped += 1;
+                continue;
+            }
+            seen.push(key);
+            import.applications.push(application);
+        }
+        Ok(import)
+    }
+
+    /// What makes two rows the same application: company, date and title, ignoring case
+    pub(super) fn identity(application: &Application) -> (String, NaiveDate, String) {
+        (
+            application.company_name.trim().to_lowercase(),
+            application.applied_date,
+            application.job_title.as_deref().unwrap_or_default().trim().to_lowercase(),
+        )
+    }
+
+    /// A date as LinkedIn writes it ("10/16/24, 3:04 PM", "Oct 16, 2024"), ignoring any time of day
+    pub(super) fn parse_date(text: &str) -> Option<NaiveDate> {
+        let date = match text.find(':') {
+            // Drop the hour before the colon and whatever separated the time from the date
+            Some(colon) => text[..colon]
+                .trim_end_matches(|c: char| c.is_ascii_digit())
+                .trim_end_matches([',', ' ', 'T']),
+            None => text,
+        };
+        DATE_FORMATS
+            .iter()
+            .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+    }
+
+    /// Split the text into records. Quoted fields may hold delimiters, doubled quotes and line breaks.
+    /// The delimiter is whichever of comma, semicolon or tab the header uses most, since spreadsheet
+    /// apps in some locales re-save with semicolons and Excel's "Unicode text" uses tabs.
+    fn parse(text: &str) -> Vec<Vec<String>> {
+        let header = text.lines().next().unwrap_or_default();
+        let delimiter = [',', ';', '\t']
+            .into_iter()
+            .max_by_key(|&d| header.matches(d).count())
+            .unwrap_or(',');
+
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' if quoted => quoted = false,
+                // A quote in the middle of an unquoted field is kept as-is
+                '"' if field.is_empty() => quoted = true,
+                _ if quoted => field.push(c),
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\r' | '\n' => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                _ if c == delimiter => record.push(std::mem::take(&mut field)),
+                _ => field.push(c),
+            }
+        }
+        if !field.is_empty() || !record.is_empty() {
+            record.push(field);
+            records.push(record);
+        }
+        records
+    }
+
+    /// The file's text: UTF-8 with or without a BOM, UTF-16 with a BOM, and Windows-1252 otherwise
+    pub fn decode(bytes: &[u8]) -> String {
+        if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+            return String::from_utf8_lossy(rest).into_owned();
+        }
+        if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+            return utf16(rest, u16::from_le_bytes);
+        }
+        if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+            return utf16(rest, u16::from_be_bytes);
+        }
+        match std::str::from_utf8(bytes) {
+            Ok(text) => text.to_string(),
+            Err(_) => bytes.iter().map(|&byte| windows_1252(byte)).collect(),
+        }
+    }
+
+    fn utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+        let units: Vec<u16> = bytes.chunks_exact(2).map(|pair| unit([pair[0], pair[1]])).collect();
+        String::from_utf16_lossy(&units)
+    }
+
+    /// Windows-1252 differs from Latin-1 only in 0x80–0x9F
+    fn windows_1252(byte: u8) -> char {
+        const HIGH: [char; 32] = [
+            '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+            '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+        ];
+        match byte {
+            0x80..=0x9F => HIGH[(byte - 0x80) as usize],
+            _ => byte as char,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const EXPORT: &str = "Company,Job Title,Date Applied,Job URL\n\
+                              Acme,Engineer,10/16/24,linkedin.com/jobs/1\n";
+
+        fn companies(import: &Import) -> Vec<&str> {
+            import.applications.iter().map(|a| a.company_name.as_str()).collect()
+        }
+
+        fn utf16_le(text: &str) -> Vec<u8> {
+            let mut bytes = vec![0xFF, 0xFE];
+            bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+            bytes
+        }
+
+        #[test]
+        fn reads_a_plain_export() {
+            let import = import(EXPORT, &[]).unwrap();
+            let application = &import.applications[0];
+            assert_eq!(application.company_name, "Acme");
+            assert_eq!(application.job_title.as_deref(), Some("Engineer"));
+            assert_eq!(application.applied_date, NaiveDate::from_ymd_opt(2024, 10, 16).unwrap());
+            assert_eq!(application.url.as_deref(), Some("https://linkedin.com/jobs/1"));
+            assert_eq!(application.platform, Platform::LinkedIn);
+        }
+
+        #[test]
+        fn utf8_bom_is_dropped_before_the_header() {
+            let mut bytes = vec![0xEF, 0xBB, 0xBF];
+            bytes.extend(EXPORT.as_bytes());
+            assert_eq!(companies(&import(&decode(&bytes), &[]).unwrap()), ["Acme"]);
+        }
+
+        #[test]
+        fn utf16_with_bom_is_decoded_in_either_byte_order() {
+            let text = "Company\tDate Applied\r\nZürich AG\tOct 16, 2024\r\n";
+            assert_eq!(companies(&import(&decode(&utf16_le(text)), &[]).unwrap()), ["Zürich AG"]);
+
+            let mut big_endian = vec![0xFE, 0xFF];
+            big_endian.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+            assert_eq!(decode(&big_endian), text);
+        }
 
-    if !path.exists() {
-        // Return empty vector if file doesn't exist
-        return Ok(Vec::new());
+        #[test]
+        fn windows_1252_is_the_fallback() {
+            assert_eq!(decode(b"Caf\xe9 \x80"), "Café €");
+        }
+
+        #[test]
+        fn crlf_line_endings_leave_no_carriage_returns() {
+            let import = import(&EXPORT.replace('\n', "\r\n"), &[]).unwrap();
+            assert_eq!(import.errors, Vec::<String>::new());
+            assert_eq!(import.applications[0].url.as_deref(), Some("https://linkedin.com/jobs/1"));
+        }
+
+        #[test]
+        fn quoted_fields_hold_delimiters_quotes_and_line_breaks() {
+            let text = "Company,Job Title,Date Applied\n\
+                        \"Acme, Inc.\",\"The \"\"Best\"\"\nEngineer\",2024-10-16\n";
+            let import = import(text, &[]).unwrap();
+            let application = &import.applications[0];
+            assert_eq!(application.company_name, "Acme, Inc.");
+            assert_eq!(application.job_title.as_deref(), Some("The \"Best\" Engineer"));
+        }
+
+        #[test]
+        fn delimiter_follows_the_header() {
+            let text = "Company;Date Applied;Location\nAcme;2024-10-16;Berlin, DE\n";
+            let import = import(text, &[]).unwrap();
+            assert_eq!(import.applications[0].location.as_deref(), Some("Berlin, DE"));
+        }
+
+        #[test]
+        fn dates_with_a_time_of_day_are_read() {
+            let expected = NaiveDate::from_ymd_opt(2024, 10, 16);
+            assert_eq!(parse_date("10/16/24, 3:04 PM"), expected);
+            assert_eq!(parse_date("2024-10-16T15:04:00Z"), expected);
+            assert_eq!(parse_date("16 October 2024"), expected);
+            assert_eq!(parse_date("soon"), None);
+        }
+
+        #[test]
+        fn tracked_and_repeated_rows_are_skipped() {
+            let existing = import(EXPORT, &[]).unwrap().applications;
+            let text = format!("{}ACME,engineer,2024-10-16\nGlobex,,2024-10-16\n", EXPORT);
+            let import = import(&text, &existing).unwrap();
+            assert_eq!(companies(&import), ["Globex"]);
+            assert_eq!(import.skipped, 2);
+        }
+
+        #[test]
+        fn bad_rows_are_reported_and_blank_ones_ignored() {
+            let text = "Company,Date Applied\n,2024-10-16\n,\nAcme,soon\n";
+            let import = import(text, &[]).unwrap();
+            assert_eq!(import.errors, ["row 1: no company", "row 2: unrecognized date 'soon'"]);
+            assert_eq!(import.rows(), 2);
+        }
+
+        #[test]
+        fn missing_columns_are_an_error() {
+            assert!(import("Name,When\nAcme,2024-10-16\n", &[]).is_err());
+            assert!(import("", &[]).is_err());
+        }
     }
+}
 
-    let content = fs::read_to_string(path)
-        .context("Failed to read applications file")?;
+/// Upgrades of the JSON applications list from older file versions, one step at a time
+mod migrations {
+    use anyhow::Result;
+    use serde_json::Value;
 
-    let applications: Vec<Application> = serde_json::from_str(&content)
-        .context("Failed to parse applications JSON")?;
+    /// `STEPS[i]` upgrades the applications list from version `i + 1` to `i + 2`
+    const STEPS: &[fn(Value) -> Result<Value>] = &[v1_to_v2, v2_to_v3];
 
-    Ok(applications)
+    /// Version after every step has run
+    pub const CURRENT: u32 = STEPS.len() as u32 + 1;
+
+    /// Run the steps from `version` up to `CURRENT`; `version` must be between 1 and `CURRENT`
+    pub fn upgrade(mut applications: Value, version: u32) -> Result<Value> {
+        for step in &STEPS[(version - 1) as usize..] {
+            applications = step(applications)?;
+        }
+        Ok(applications)
+    }
+
+    /// Version 2 moved the list into an envelope; the records themselves are unchanged
+    fn v1_to_v2(applications: Value) -> Result<Value> {
+        Ok(applications)
+    }
+
+    /// Version 3 added the trash next to the list; the records themselves are unchanged
+    fn v2_to_v3(applications: Value) -> Result<Value> {
+        Ok(applications)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use serde_json::json;
+
+        fn records() -> Value {
+            json!([{ "company_name": "Acme", "status": "Applied", "applied_date": "2024-05-01" }])
+        }
+
+        #[test]
+        fn v1_to_v2_keeps_the_records() {
+            assert_eq!(v1_to_v2(records()).unwrap(), records());
+        }
+
+        #[test]
+        fn v2_to_v3_keeps_the_records() {
+            assert_eq!(v2_to_v3(records()).unwrap(), records());
+        }
+
+        #[test]
+        fn upgrade_runs_the_steps_from_any_version() {
+            assert_eq!(CURRENT, 3);
+            for version in 1..=CURRENT {
+                assert_eq!(upgrade(records(), version).unwrap(), records());
+            }
+        }
+    }
 }
 
-/// Save applications to JSON file
-pub fn save_applications(applications: &[Application]) -> Result<()> {
-    let json = serde_json::to_string_pretty(applications)
-        .context("Failed to serialize applications")?;
+/// Lenient reading of a damaged JSON file: each record is parsed on its own, so one bad
+/// record or a cut-off end only loses what's actually broken
+mod recover {
+    use super::{migrations, Data};
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+
+    /// Records that parse from the `applications` and `trash` lists, and how many didn't
+    pub fn data(content: &str) -> (Data, usize) {
+        let trimmed = content.trim_start();
+        let (applications, version) = if trimmed.starts_with('[') {
+            (list(trimmed), 1)
+        } else {
+            (key(content, "applications").map(list).unwrap_or_default(), version(content))
+        };
+        let trash = key(content, "trash").map(list).unwrap_or_default();
+
+        let mut lost = 0;
+        let applications = parse(&applications, &mut lost, |value| {
+            let upgraded = migrations::upgrade(Value::Array(vec![value]), version).ok()?;
+            let mut list: Vec<_> = serde_json::from_value(upgraded).ok()?;
+            list.pop()
+        });
+        let trash = parse(&trash, &mut lost, |value| serde_json::from_value(value).ok());
+        (Data { applications, trash, unreadable: Vec::new() }, lost)
+    }
+
+    fn parse<T: DeserializeOwned>(objects: &[&str], lost: &mut usize, read: impl Fn(Value) -> Option<T>) -> Vec<T> {
+        let mut records = Vec::new();
+        for object in objects {
+            match serde_json::from_str(object).ok().and_then(&read) {
+                Some(record) => records.push(record),
+                None => *lost += 1,
+            }
+        }
+        records
+    }
+
+    /// Text after the first `"name":`, where its value starts
+    fn value<'a>(content: &'a str, name: &str) -> Option<&'a str> {
+        let after = &content[content.find(&format!("\"{}\"", name))? + name.len() + 2..];
+        after.trim_start().strip_prefix(':').map(str::trim_start)
+    }
+
+    /// Where the list named `name` starts
+    fn key<'a>(content: &'a str, name: &str) -> Option<&'a str> {
+        value(content, name).filter(|value| value.starts_with('['))
+    }
+
+    /// The `"version"` number, or the current version when it can't be read; the file is
+    /// most likely a save from this build
+    fn version(content: &str) -> u32 {
+        value(content, "version")
+            .and_then(|value| value.chars().take_while(char::is_ascii_digit).collect::<String>().parse().ok())
+            .filter(|version| (1..=migrations::CURRENT).contains(version))
+            .unwrap_or(migrations::CURRENT)
+    }
+
+    /// Each top-level `{...}` in the list starting at `text`, ending at the closing bracket or
+    /// wherever the text stops; an object cut off by the end is returned too and fails to parse
+    fn list(text: &str) -> Vec<&str> {
+        let mut objects = Vec::new();
+        let (mut depth, mut in_string, mut escaped) = (0usize, false, false);
+        let mut start = None;
+        for (idx, c) in text.char_indices() {
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match c {
+                '"' => in_string = true,
+                '[' | '{' => {
+                    depth += 1;
+                    if c == '{' && depth == 2 {
+                        start = Some(idx);
+                    }
+                }
+                ']' | '}' => {
+                    depth = depth.saturating_sub(1);
+                    if c == '}' && depth == 1 {
+                        if let Some(from) = start.take() {
+                            objects.push(&text[from..=idx]);
+                        }
+                    }
+                    if depth == 0 {
+                        return objects;
+                    }
+                }
+                _ => {}
+            }
+        }
+        objects.extend(start.map(|from| &text[from..]));
+        objects
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::models::Application;
+
+        fn application(n: u128, company: &str) -> Application {
+            Application {
+                id: uuid::Uuid::from_u128(n),
+                company_name: company.to_string(),
+                applied_date: chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+                ..Application::new()
+            }
+        }
+
+        /// Acme, a company name with brackets and quotes to trip up the scanning, and Initech
+        fn applications() -> Vec<Application> {
+            vec![
+                application(1, "Acme"),
+                application(2, "Globex {\"West\"} [EU]"),
+                application(3, "Initech"),
+            ]
+        }
+
+        /// A save from this build, as the app writes it
+        fn saved() -> String {
+            serde_json::to_string_pretty(&serde_json::json!({
+                "version": migrations::CURRENT,
+                "applications": applications(),
+            }))
+            .unwrap()
+        }
+
+        fn companies(data: &Data) -> Vec<&str> {
+            data.applications.iter().map(|application| application.company_name.as_str()).collect()
+        }
+
+        #[test]
+        fn a_truncated_file_keeps_the_records_before_the_cut() {
+            let content = saved();
+            // Half way through Initech
+            let cut = content.find("Initech").unwrap();
+            let (data, lost) = data(&content[..cut]);
+            assert_eq!(data.applications, applications()[..2]);
+            assert_eq!(lost, 1);
+        }
+
+        #[test]
+        fn a_trailing_comma_loses_nothing() {
+            let content = saved();
+            let end = content.rfind(']').unwrap();
+            let with_comma = format!("{},{}", content[..end].trim_end(), &content[end..]);
+            assert!(serde_json::from_str::<Value>(&with_comma).is_err());
+            let (data, lost) = data(&with_comma);
+            assert_eq!(data.applications, applications());
+            assert_eq!(lost, 0);
+
+            // The same in a version 1 bare list
+            let list = serde_json::to_string(&applications()).unwrap();
+            let (data, lost) = super::data(&format!("{},]", &list[..list.len() - 1]));
+            assert_eq!(companies(&data), ["Acme", "Globex {\"West\"} [EU]", "Initech"]);
+            assert_eq!(lost, 0);
+        }
+
+        #[test]
+        fn a_garbage_tail_is_ignored() {
+            let content = format!("{}\n\u{0}\u{0}}}]], \"version\": tail of an older save", saved());
+            assert!(serde_json::from_str::<Value>(&content).is_err());
+            let (data, lost) = data(&content);
+            assert_eq!(data.applications, applications());
+            assert_eq!(lost, 0);
+        }
+
+        #[test]
+        fn one_bad_record_loses_only_itself() {
+            let content = saved().replacen("\"2024-05-01\"", "\"May 1st\"", 2);
+            let (data, lost) = data(&content);
+            assert_eq!(companies(&data), ["Initech"]);
+            assert_eq!(lost, 2);
+        }
+
+        #[test]
+        fn the_trash_is_recovered_too() {
+            let trashed = crate::models::TrashedApplication {
+                deleted_at: chrono::Utc::now(),
+                position: 1,
+                application: application(4, "Hooli"),
+            };
+            let content = serde_json::to_string(&serde_json::json!({
+                "version": migrations::CURRENT,
+                "applications": applications(),
+                "trash": [trashed],
+            }))
+            .unwrap();
+            // Cut off inside the deleted record
+            let (data, lost) = data(&content[..content.find("Hooli").unwrap()]);
+            assert_eq!(data.applications, applications());
+            assert!(data.trash.is_empty());
+            assert_eq!(lost, 1);
+
+            let (data, lost) = super::data(&content);
+            assert_eq!(data.trash, vec![trashed]);
+            assert_eq!(lost, 0);
+        }
+    }
+}
+
+/// Schema changes in order; `PRAGMA user_version` records how many have been applied
+const MIGRATIONS: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS applications (
+        id INTEGER PRIMARY KEY,
+        position INTEGER NOT NULL,
+        company_name TEXT NOT NULL,
+        url TEXT,
+        platform TEXT NOT NULL,
+        resume_modified INTEGER NOT NULL,
+        resume_version TEXT NOT NULL,
+        status TEXT NOT NULL,
+        salary_min INTEGER,
+        salary_max INTEGER,
+        salary_currency TEXT,
+        applied_date TEXT NOT NULL,
+        follow_up TEXT,
+        notes TEXT NOT NULL
+    );",
+    // Interview rounds as a JSON array
+    "ALTER TABLE applications ADD COLUMN interviews TEXT NOT NULL DEFAULT '[]';",
+    "ALTER TABLE applications ADD COLUMN archived INTEGER NOT NULL DEFAULT 0;",
+    "ALTER TABLE applications ADD COLUMN contact_name TEXT;
+     ALTER TABLE applications ADD COLUMN contact_email TEXT;
+     ALTER TABLE applications ADD COLUMN referral INTEGER NOT NULL DEFAULT 0;",
+    // Empty until the app assigns IDs to older records
+    "ALTER TABLE applications ADD COLUMN uuid TEXT NOT NULL DEFAULT '';",
+    // Status changes as a JSON array
+    "ALTER TABLE applications ADD COLUMN status_history TEXT NOT NULL DEFAULT '[]';",
+    "ALTER TABLE applications ADD COLUMN location TEXT;
+     ALTER TABLE applications ADD COLUMN work_mode TEXT NOT NULL DEFAULT 'Unknown';",
+    "ALTER TABLE applications ADD COLUMN job_title TEXT;",
+    // Stored with `~/` for the home directory, like the JSON file
+    "ALTER TABLE applications ADD COLUMN resume_path TEXT;",
+    // Activity log as a JSON array
+    "ALTER TABLE applications ADD COLUMN activity TEXT NOT NULL DEFAULT '[]';",
+    // 0 when not rated
+    "ALTER TABLE applications ADD COLUMN interest INTEGER NOT NULL DEFAULT 0;",
+    // Deleted applications, stored whole as JSON since they're only read back to be restored
+    "CREATE TABLE IF NOT EXISTS trash (
+        id INTEGER PRIMARY KEY,
+        deleted_at TEXT NOT NULL,
+        position INTEGER NOT NULL,
+        application TEXT NOT NULL
+    );",
+    "ALTER TABLE applications ADD COLUMN cover_letter INTEGER NOT NULL DEFAULT 0;
+     ALTER TABLE applications ADD COLUMN cover_letter_version TEXT NOT NULL DEFAULT '';",
+    "ALTER TABLE applications ADD COLUMN deadline TEXT;",
+    // Display name of the reason, e.g. 'After onsite'
+    "ALTER TABLE applications ADD COLUMN rejection_reason TEXT;",
+    "ALTER TABLE applications ADD COLUMN external_id TEXT;",
+];
+
+/// SQLite storage with one row per application
+pub struct SqliteStorage {
+    conn: Connection,
+    path: PathBuf,
+}
+
+impl SqliteStorage {
+    /// Open the database, creating or upgrading the schema as needed.
+    /// A new database imports `applications.json` from the same directory if one exists.
+    pub fn open(path: &Path) -> Result<Self> {
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open database {}", path.display()))?;
+        let storage = Self {
+            conn,
+            path: path.to_path_buf(),
+        };
+
+        let version: usize = storage
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .context("Failed to read database schema version")?;
+
+        if version > MIGRATIONS.len() {
+            return Err(anyhow!(
+                "Database {} was created by a newer version of jobtracker",
+                path.display()
+            ));
+        }
+
+        for migration in &MIGRATIONS[version..] {
+            storage
+                .conn
+                .execute_batch(migration)
+                .context("Failed to update database schema")?;
+        }
+
+        // The interest column was listed ahead of the trash table, so databases that already
+        // had the trash table skipped it
+        if !storage.has_column("applications", "interest")? {
+            storage
+                .conn
+                .execute_batch("ALTER TABLE applications ADD COLUMN interest INTEGER NOT NULL DEFAULT 0;")
+                .context("Failed to update database schema")?;
+        }
+
+        if version == 0 {
+            let json_path = path.with_file_name(DATA_FILE);
+            if json_path.exists() {
+                let data = JsonStorage::new(&json_path).load()?;
+                storage.save(&data.applications, &data.trash)?;
+                eprintln!(
+                    "Imported {} application(s) from {} into {}",
+                    data.applications.len(),
+                    json_path.display(),
+                    path.display()
+                );
+            }
+        }
 
-    fs::write(DATA_FILE, json)
-        .context("Failed to write applications file")?;
+        // Only record the new version once everything above succeeded
+        storage
+            .conn
+            .pragma_update(None, "user_version", MIGRATIONS.len())
+            .context("Failed to set database schema version")?;
 
-    Ok(())
+        Ok(storage)
+    }
+
+    /// Whether `table` has a column named `column`
+    fn has_column(&self, table: &str, column: &str) -> Result<bool> {
+        let count: usize = self
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info(?1) WHERE name = ?2",
+                [table, column],
+                |row| row.get(0),
+            )
+            .context("Failed to read database schema")?;
+        Ok(count > 0)
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn load(&self) -> Result<Data> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT company_name, url, platform, resume_modified, resume_version, status,
+                        salary_min, salary_max, salary_currency, applied_date, follow_up, notes,
+                        interviews, archived, contact_name, contact_email, referral, uuid, status_history,
+                        location, work_mode, job_title, resume_path, activity, interest,
+                        cover_letter, cover_letter_version, deadline, rejection_reason, external_id
+                 FROM applications ORDER BY position",
+            )
+            .context("Failed to query applications")?;
+
+        let rows = stmt
+            .query_map([], ApplicationRow::read)
+            .context("Failed to query applications")?;
+
+        let applications = rows
+            .map(|row| row.context("Failed to read application row")?.into_application())
+            .collect::<Result<_>>()?;
+
+        let mut stmt = self
+            .conn
+            .prepare("SELECT deleted_at, position, application FROM trash ORDER BY id")
+            .context("Failed to query the trash")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?, row.get::<_, String>(2)?)))
+            .context("Failed to query the trash")?;
+        let trash = rows
+            .map(|row| {
+                let (deleted_at, position, application) = row.context("Failed to read trash row")?;
+                Ok(TrashedApplication {
+                    deleted_at: deleted_at
+                        .parse()
+                        .with_context(|| format!("Invalid deletion time '{}'", deleted_at))?,
+                    position: position as usize,
+                    application: serde_json::from_str(&application).context("Invalid application in the trash")?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Data { applications, trash, unreadable: Vec::new() })
+    }
+
+    /// Replace every row in a single transaction
+    fn save(&self, applications: &[Application], trash: &[TrashedApplication]) -> Result<()> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .context("Failed to start transaction")?;
+
+        tx.execute("DELETE FROM applications", [])
+            .context("Failed to clear applications")?;
+
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT INTO applications (
+                        position, company_name, url, platform, resume_modified, resume_version, status,
+                        salary_min, salary_max, salary_currency, applied_date, follow_up, notes,
+                        interviews, archived, contact_name, contact_email, referral, uuid, status_history,
+                        location, work_mode, job_title, resume_path, activity, interest,
+                        cover_letter, cover_letter_version, deadline, rejection_reason, external_id
+                     ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20,
+                               ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31)",
+                )
+                .context("Failed to prepare insert")?;
+
+            for (position, application) in applications.iter().enumerate() {
+                let salary = application.salary.as_ref();
+                let interviews = serde_json::to_string(&application.interviews)
+                    .context("Failed to serialize interviews")?;
+                let status_history = serde_json::to_string(&application.status_history)
+                    .context("Failed to serialize status history")?;
+                let activity = serde_json::to_string(&application.activity)
+                    .context("Failed to serialize activity")?;
+                stmt.execute(params![
+                    position as i64,
+                    application.company_name,
+                    application.url,
+                    application.platform.as_str(),
+                    application.resume_modified,
+                    application.resume_version,
+                    application.status.as_str(),
+                    salary.map(|s| s.min as i64),
+                    salary.map(|s| s.max as i64),
+                    salary.map(|s| s.currency.as_str()),
+                    application.applied_date.to_string(),
+                    application.follow_up.map(|date| date.to_string()),
+                    application.notes,
+                    interviews,
+                    application.archived,
+                    application.contact_name,
+                    application.contact_email,
+                    application.referral,
+                    application.id.to_string(),
+                    status_history,
+                    application.location,
+                    application.work_mode.as_str(),
+                    application.job_title,
+                    application.resume_path.as_deref().map(paths::contract_home),
+                    activity,
+                    application.interest,
+                    application.cover_letter,
+                    application.cover_letter_version,
+                    application.deadline.map(|date| date.to_string()),
+                    application.rejection_reason.map(|reason| reason.as_str().to_string()),
+                    application.external_id,
+                ])
+                .with_context(|| format!("Failed to save {}", application.company_name))?;
+            }
+        }
+
+        tx.execute("DELETE FROM trash", []).context("Failed to clear the trash")?;
+        for trashed in trash {
+            let application =
+                serde_json::to_string(&trashed.application).context("Failed to serialize application")?;
+            tx.execute(
+                "INSERT INTO trash (deleted_at, position, application) VALUES (?1, ?2, ?3)",
+                params![trashed.deleted_at.to_rfc3339(), trashed.position as i64, application],
+            )
+            .with_context(|| format!("Failed to save {} to the trash", trashed.application.company_name))?;
+        }
+
+        tx.commit().context("Failed to commit applications")?;
+        Ok(())
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Columns of one `applications` row, before parsing
+struct ApplicationRow {
+    company_name: String,
+    url: Option<String>,
+    platform: String,
+    resume_modified: bool,
+    resume_version: String,
+    status: String,
+    salary_min: Option<i64>,
+    salary_max: Option<i64>,
+    salary_currency: Option<String>,
+    applied_date: String,
+    follow_up: Option<String>,
+    notes: String,
+    interviews: String,
+    archived: bool,
+    contact_name: Option<String>,
+    contact_email: Option<String>,
+    referral: bool,
+    uuid: String,
+    status_history: String,
+    location: Option<String>,
+    work_mode: String,
+    job_title: Option<String>,
+    resume_path: Option<String>,
+    activity: String,
+    interest: u8,
+    cover_letter: bool,
+    cover_letter_version: String,
+    deadline: Option<String>,
+    rejection_reason: Option<String>,
+    external_id: Option<String>,
+}
+
+impl ApplicationRow {
+    fn read(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            company_name: row.get(0)?,
+            url: row.get(1)?,
+            platform: row.get(2)?,
+            resume_modified: row.get(3)?,
+            resume_version: row.get(4)?,
+            status: row.get(5)?,
+            salary_min: row.get(6)?,
+            salary_max: row.get(7)?,
+            salary_currency: row.get(8)?,
+            applied_date: row.get(9)?,
+            follow_up: row.get(10)?,
+            notes: row.get(11)?,
+            interviews: row.get(12)?,
+            archived: row.get(13)?,
+            contact_name: row.get(14)?,
+            contact_email: row.get(15)?,
+            referral: row.get(16)?,
+            uuid: row.get(17)?,
+            status_history: row.get(18)?,
+            location: row.get(19)?,
+            work_mode: row.get(20)?,
+            job_title: row.get(21)?,
+            resume_path: row.get(22)?,
+            activity: row.get(23)?,
+            interest: row.get(24)?,
+            cover_letter: row.get(25)?,
+            cover_letter_version: row.get(26)?,
+            deadline: row.get(27)?,
+            rejection_reason: row.get(28)?,
+            external_id: row.get(29)?,
+        })
+    }
+
+    fn into_application(self) -> Result<Application> {
+        // Names other than the built-ins are custom statuses from the config
+        if self.status.trim().is_empty() {
+            bail!("Missing status for {}", self.company_name);
+        }
+        let status = Status::from_str(&self.status);
+
+        let interviews = serde_json::from_str(&self.interviews)
+            .with_context(|| format!("Invalid interviews for {}", self.company_name))?;
+        let status_history = serde_json::from_str(&self.status_history)
+            .with_context(|| format!("Invalid status history for {}", self.company_name))?;
+        let activity = serde_json::from_str(&self.activity)
+            .with_context(|| format!("Invalid activity for {}", self.company_name))?;
+
+        let salary = match (self.salary_min, self.salary_max) {
+            (Some(min), Some(max)) => Some(SalaryRange {
+                min: min as u64,
+                max: max as u64,
+                currency: self.salary_currency.unwrap_or_default(),
+            }),
+            _ => None,
+        };
+
+        let id = if self.uuid.is_empty() {
+            Uuid::nil()
+        } else {
+            Uuid::parse_str(&self.uuid).with_context(|| format!("Invalid id for {}", self.company_name))?
+        };
+
+        Ok(Application {
+            id,
+            company_name: self.company_name,
+            job_title: self.job_title,
+            url: self.url,
+            external_id: self.external_id,
+            platform: Platform::from_str(&self.platform),
+            location: self.location,
+            // Anything unrecognised reads as Unknown rather than failing the whole load
+            work_mode: WorkMode::from_name(&self.work_mode).unwrap_or_default(),
+            resume_modified: self.resume_modified,
+            resume_version: self.resume_version,
+            resume_path: self.resume_path.as_deref().map(paths::expand_home),
+            cover_letter: self.cover_letter,
+            cover_letter_version: self.cover_letter_version,
+            status,
+            // An unrecognised reason reads as unspecified
+            rejection_reason: self.rejection_reason.as_deref().and_then(RejectionReason::from_name),
+            interest: self.interest.min(MAX_INTEREST),
+            status_history,
+            salary,
+            applied_date: parse_date(&self.applied_date)?,
+            follow_up: self.follow_up.as_deref().map(parse_date).transpose()?,
+            deadline: self.deadline.as_deref().map(parse_date).transpose()?,
+            contact_name: self.contact_name,
+            contact_email: self.contact_email,
+            referral: self.referral,
+            interviews,
+            activity,
+            notes: self.notes,
+            archived: self.archived,
+        })
+    }
+}
+
+fn parse_date(text: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").with_context(|| format!("Invalid date '{}'", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acme() -> Application {
+        Application {
+            id: uuid::Uuid::from_u128(1),
+            company_name: "Acme".to_string(),
+            applied_date: chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+            ..Application::new()
+        }
+    }
+
+    /// Load `content` as the whole data file
+    fn load_json(content: &str) -> Result<Data> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DATA_FILE);
+        fs::write(&path, content).unwrap();
+        JsonStorage::new(path).load()
+    }
+
+    #[test]
+    fn version_1_bare_list_loads() {
+        let content = serde_json::to_string(&[acme()]).unwrap();
+        assert_eq!(load_json(&content).unwrap().applications, vec![acme()]);
+    }
+
+    #[test]
+    fn version_2_envelope_without_trash_loads() {
+        let content = serde_json::json!({ "version": 2, "applications": [acme()] }).to_string();
+        let data = load_json(&content).unwrap();
+        assert_eq!(data.applications, vec![acme()]);
+        assert!(data.trash.is_empty());
+    }
+
+    #[test]
+    fn version_3_envelope_with_trash_loads() {
+        let trashed = TrashedApplication { deleted_at: chrono::Utc::now(), position: 0, application: acme() };
+        let content = serde_json::json!({ "version": 3, "applications": [], "trash": [trashed] }).to_string();
+        let data = load_json(&content).unwrap();
+        assert!(data.applications.is_empty());
+        assert_eq!(data.trash, vec![trashed]);
+    }
+
+    #[test]
+    fn newer_versions_are_refused() {
+        let content = serde_json::json!({ "version": JSON_VERSION + 1, "applications": [] }).to_string();
+        let err = load_json(&content).unwrap_err();
+        assert!(err.to_string().contains("newer version"));
+    }
+
+    #[test]
+    fn saving_writes_the_current_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DATA_FILE);
+        JsonStorage::new(&path).save(&[acme()], &[]).unwrap();
+        let document: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(document["version"], JSON_VERSION);
+    }
+
+    fn companies_in(path: &Path) -> Vec<String> {
+        let data = JsonStorage::new(path).load().unwrap();
+        data.applications.into_iter().map(|application| application.company_name).collect()
+    }
+
+    /// One session: a storage opened on `path` that saves `company` as the only record twice
+    fn session(path: &Path, company: &str) {
+        let storage = JsonStorage::new(path);
+        let application = Application { company_name: company.to_string(), ..acme() };
+        storage.save(std::slice::from_ref(&application), &[]).unwrap();
+        storage.save(&[application], &[]).unwrap();
+    }
+
+    #[test]
+    fn each_session_backs_up_the_file_before_its_first_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DATA_FILE);
+        session(&path, "One");
+        assert_eq!(newest_backup(&path), None, "nothing to back up before the first save");
+
+        session(&path, "Two");
+        assert_eq!(newest_backup(&path), Some(backup_path(&path, 1)));
+        // The second save of a session leaves the backup alone
+        assert_eq!(companies_in(&backup_path(&path, 1)), ["One"]);
+
+        for company in ["Three", "Four", "Five"] {
+            session(&path, company);
+        }
+        assert_eq!(companies_in(&path), ["Five"]);
+        assert_eq!(companies_in(&backup_path(&path, 1)), ["Four"]);
+        assert_eq!(companies_in(&backup_path(&path, 2)), ["Three"]);
+        assert_eq!(companies_in(&backup_path(&path, BACKUPS)), ["Two"]);
+        assert!(!backup_path(&path, BACKUPS + 1).exists());
+    }
+
+    #[test]
+    fn an_unreadable_file_points_at_the_newest_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DATA_FILE);
+        session(&path, "One");
+        session(&path, "Two");
+        fs::write(&path, "{ \"version\": 3, \"applications\": [").unwrap();
+
+        let err = JsonStorage::new(&path).load().unwrap_err();
+        let corrupt = err.downcast::<CorruptFile>().unwrap();
+        assert_eq!(corrupt.backup, Some(dir.path().join("applications.json.bak-1")));
+        assert!(corrupt.recovered.applications.is_empty());
+    }
+
+    #[test]
+    fn a_trailing_comma_is_a_corrupt_file_with_every_record_recovered() {
+        let content = serde_json::to_string(&serde_json::json!({ "version": 3, "applications": [acme()] })).unwrap();
+        let content = content.replace("}]", "},]");
+        let corrupt = load_json(&content).unwrap_err().downcast::<CorruptFile>().unwrap();
+        assert!(corrupt.reason.contains("trailing comma"), "{}", corrupt.reason);
+        assert_eq!(corrupt.recovered.applications, vec![acme()]);
+        assert_eq!(corrupt.lost, 0);
+        assert_eq!(corrupt.backup, None);
+    }
+
+    #[test]
+    fn database_from_before_interest_gets_the_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("applications.db");
+        // The schema as of the trash table: every step before interest, then the trash table
+        let conn = Connection::open(&path).unwrap();
+        for migration in MIGRATIONS[..10].iter().chain(&MIGRATIONS[11..12]) {
+            conn.execute_batch(migration).unwrap();
+        }
+        conn.pragma_update(None, "user_version", 11).unwrap();
+        drop(conn);
+
+        let storage = SqliteStorage::open(&path).unwrap();
+        assert!(storage.has_column("applications", "interest").unwrap());
+        let mut application = Application::new();
+        application.company_name = "Acme".to_string();
+        application.interest = 4;
+        storage.save(&[application.clone()], &[]).unwrap();
+        assert_eq!(storage.load().unwrap().applications, vec![application]);
+    }
 }
@@ -0,0 +1,104 @@
+use crate::models::{Application, Platform, WorkMode};
+use crate::paths;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Form fields shared by applications sent the same way, pre-filled when adding from a template
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Template {
+    pub name: String,
+    pub platform: Platform,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub work_mode: WorkMode,
+    #[serde(default)]
+    pub resume_modified: bool,
+    #[serde(default)]
+    pub resume_version: String,
+    #[serde(default, with = "paths::portable")]
+    pub resume_path: Option<PathBuf>,
+    #[serde(default)]
+    pub cover_letter: bool,
+    #[serde(default)]
+    pub cover_letter_version: String,
+    #[serde(default)]
+    pub referral: bool,
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Template {
+    /// Template named `name` holding the reusable fields of `application`
+    pub fn from_application(name: &str, application: &Application) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            platform: application.platform.clone(),
+            location: application.location.clone(),
+            work_mode: application.work_mode,
+            resume_modified: application.resume_modified,
+            resume_version: application.resume_version.clone(),
+            resume_path: application.resume_path.clone(),
+            cover_letter: application.cover_letter,
+            cover_letter_version: application.cover_letter_version.clone(),
+            referral: application.referral,
+            notes: application.notes.clone(),
+        }
+    }
+
+    /// Copy the template's fields onto `application`, leaving the rest as they are
+    pub fn apply(&self, application: &mut Application) {
+        application.platform = self.platform.clone();
+        application.location = self.location.clone();
+        application.work_mode = self.work_mode;
+        application.resume_modified = self.resume_modified;
+        application.resume_version = self.resume_version.clone();
+        application.resume_path = self.resume_path.clone();
+        application.cover_letter = self.cover_letter;
+        application.cover_letter_version = self.cover_letter_version.clone();
+        application.referral = self.referral;
+        application.notes = self.notes.clone();
+    }
+}
+
+/// Templates file next to the data file, so templates travel with the applications
+pub fn templates_path(data_path: &Path) -> PathBuf {
+    data_path
+        .parent()
+        .unwrap_or(Path::new("."))
+        .join("templates.json")
+}
+
+/// Load the templates, sorted by name; none when the file doesn't exist
+pub fn load(path: &Path) -> Result<Vec<Template>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read templates {}", path.display()))?;
+    let mut templates: Vec<Template> = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse templates {}", path.display()))?;
+    sort(&mut templates);
+    Ok(templates)
+}
+
+/// Write the templates, removing the file once the last one is deleted
+pub fn save(path: &Path, templates: &[Template]) -> Result<()> {
+    if templates.is_empty() {
+        if path.exists() {
+            fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))?;
+        }
+        return Ok(());
+    }
+
+    let json = serde_json::to_string_pretty(templates).context("Failed to serialize templates")?;
+    fs::write(path, json).with_context(|| format!("Failed to write templates {}", path.display()))
+}
+
+/// Order templates by name, ignoring case
+pub fn sort(templates: &mut [Template]) {
+    templates.sort_by_key(|template| template.name.to_lowercase());
+}
@@ -0,0 +1,389 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Marks where `truncate` cut the text
+pub const ELLIPSIS: &str = "…";
+
+/// Remove the last user-perceived character, so Backspace takes a whole emoji, CJK character
+/// or letter with its combining accents rather than part of one
+pub fn pop_grapheme(text: &mut String) {
+    if let Some((start, _)) = text.grapheme_indices(true).next_back() {
+        text.truncate(start);
+    }
+}
+
+/// Character index of the grapheme boundary before `cursor`, or 0 at the start
+pub fn previous_boundary(text: &str, cursor: usize) -> usize {
+    let mut previous = 0;
+    let mut chars = 0;
+    for grapheme in text.graphemes(true) {
+        if chars >= cursor {
+            break;
+        }
+        previous = chars;
+        chars += grapheme.chars().count();
+    }
+    previous
+}
+
+/// Character index of the grapheme boundary after `cursor`, or the length at the end
+pub fn next_boundary(text: &str, cursor: usize) -> usize {
+    let mut chars = 0;
+    for grapheme in text.graphemes(true) {
+        chars += grapheme.chars().count();
+        if chars > cursor {
+            return chars;
+        }
+    }
+    chars
+}
+
+/// Number of graphemes in `text`
+pub fn grapheme_count(text: &str) -> usize {
+    text.graphemes(true).count()
+}
+
+/// Character index where the grapheme at `index` starts, or the length past the end
+pub fn grapheme_to_char(text: &str, index: usize) -> usize {
+    text.graphemes(true).take(index).map(|grapheme| grapheme.chars().count()).sum()
+}
+
+/// `text` cut to at most `max` terminal columns, ending in `…` when anything was cut. Wide
+/// characters such as CJK and most emoji count as two columns, and cuts only fall between
+/// graphemes, so no character is split or left without its accents.
+pub fn truncate(text: &str, max: usize) -> String {
+    if text.width() <= max {
+        return text.to_string();
+    }
+    let mut cut = String::new();
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if used + grapheme_width + ELLIPSIS.width() > max {
+            break;
+        }
+        cut.push_str(grapheme);
+        used += grapheme_width;
+    }
+    if max >= ELLIPSIS.width() {
+        cut.push_str(ELLIPSIS);
+    }
+    cut
+}
+
+/// Editing of a string at a cursor counted in chars, like the notes cursor. Words are runs of
+/// letters, digits and underscores; whitespace and punctuation separate them. Deletions and
+/// motions move by whole graphemes so accents and emoji stay intact.
+pub struct TextBuffer<'a> {
+    text: &'a mut String,
+    cursor: usize,
+}
+
+impl<'a> TextBuffer<'a> {
+    /// Buffer over `text` with the cursor at `cursor`, clamped to the end
+    pub fn new(text: &'a mut String, cursor: usize) -> Self {
+        let cursor = cursor.min(text.chars().count());
+        Self { text, cursor }
+    }
+
+    /// Buffer with the cursor at the end, for fields that are only typed into at the end
+    pub fn at_end(text: &'a mut String) -> Self {
+        let cursor = text.chars().count();
+        Self { text, cursor }
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn insert(&mut self, c: char) {
+        let byte = self.byte_offset(self.cursor);
+        self.text.insert(byte, c);
+        self.cursor += 1;
+    }
+
+    /// Insert a whole string, such as a paste, leaving the cursor after it
+    pub fn insert_str(&mut self, text: &str) {
+        let byte = self.byte_offset(self.cursor);
+        self.text.insert_str(byte, text);
+        self.cursor += text.chars().count();
+    }
+
+    /// Backspace: delete the grapheme before the cursor
+    pub fn delete_back(&mut self) {
+        let start = previous_boundary(self.text, self.cursor);
+        self.delete_to(start);
+    }
+
+    /// Ctrl+W: delete back to the start of the word before the cursor, with the separators
+    /// between them
+    pub fn delete_word_back(&mut self) {
+        let start = self.word_start();
+        self.delete_to(start);
+    }
+
+    /// Ctrl+U: delete back to the start of the line
+    pub fn delete_to_line_start(&mut self) {
+        let before = &self.text[..self.byte_offset(self.cursor)];
+        let start = before.rfind('\n').map_or(0, |byte| before[..=byte].chars().count());
+        self.delete_to(start);
+    }
+
+    /// Ctrl+K: delete up to the end of the line, keeping the line break
+    pub fn delete_to_line_end(&mut self) {
+        let line_end = self.text.chars().skip(self.cursor).take_while(|&c| c != '\n').count();
+        self.delete_to(self.cursor + line_end);
+    }
+
+    /// Alt+B: move to the start of the word before the cursor
+    pub fn word_back(&mut self) {
+        self.cursor = self.word_start();
+    }
+
+    /// Alt+F: move to the end of the word after the cursor
+    pub fn word_forward(&mut self) {
+        let graphemes = self.graphemes();
+        let mut rest = graphemes.iter().skip_while(|(start, _)| *start < self.cursor).peekable();
+        let mut end = self.cursor;
+        while let Some(&&(start, word)) = rest.peek() {
+            if word {
+                break;
+            }
+            end = self.grapheme_end(&graphemes, start);
+            rest.next();
+        }
+        while let Some(&&(start, word)) = rest.peek() {
+            if !word {
+                break;
+            }
+            end = self.grapheme_end(&graphemes, start);
+            rest.next();
+        }
+        self.cursor = end;
+    }
+
+    /// Start of the word before the cursor, skipping separators right before it
+    fn word_start(&self) -> usize {
+        let graphemes = self.graphemes();
+        let mut before = graphemes.iter().rev().skip_while(|(start, _)| *start >= self.cursor).peekable();
+        let mut start = self.cursor;
+        while let Some(&&(grapheme, word)) = before.peek() {
+            if word {
+                break;
+            }
+            start = grapheme;
+            before.next();
+        }
+        while let Some(&&(grapheme, word)) = before.peek() {
+            if !word {
+                break;
+            }
+            start = grapheme;
+            before.next();
+        }
+        start
+    }
+
+    /// Char index each grapheme starts at, and whether it is part of a word
+    fn graphemes(&self) -> Vec<(usize, bool)> {
+        let mut chars = 0;
+        self.text
+            .graphemes(true)
+            .map(|grapheme| {
+                let start = chars;
+                chars += grapheme.chars().count();
+                let word = grapheme.chars().next().is_some_and(|c| c.is_alphanumeric() || c == '_');
+                (start, word)
+            })
+            .collect()
+    }
+
+    /// Char index where the grapheme starting at `start` ends
+    fn grapheme_end(&self, graphemes: &[(usize, bool)], start: usize) -> usize {
+        graphemes
+            .iter()
+            .map(|(next, _)| *next)
+            .find(|next| *next > start)
+            .unwrap_or_else(|| self.text.chars().count())
+    }
+
+    /// Remove the text between the cursor and `other`, leaving the cursor at the earlier one
+    fn delete_to(&mut self, other: usize) {
+        let (from, to) = (self.cursor.min(other), self.cursor.max(other));
+        let range = self.byte_offset(from)..self.byte_offset(to);
+        self.text.replace_range(range, "");
+        self.cursor = from;
+    }
+
+    fn byte_offset(&self, cursor: usize) -> usize {
+        self.text.char_indices().nth(cursor).map_or(self.text.len(), |(byte, _)| byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Combining acute accent, so "e\u{301}" is one é made of two chars
+    const ACUTE: char = '\u{301}';
+
+    fn buffer_after(text: &str, cursor: usize, edit: impl FnOnce(&mut TextBuffer)) -> (String, usize) {
+        let mut text = text.to_string();
+        let mut buffer = TextBuffer::new(&mut text, cursor);
+        edit(&mut buffer);
+        let cursor = buffer.cursor();
+        (text, cursor)
+    }
+
+    #[test]
+    fn truncate_keeps_text_that_fits() {
+        assert_eq!(truncate("Acme", 4), "Acme");
+        assert_eq!(truncate("東京", 4), "東京");
+        assert_eq!(truncate("", 0), "");
+    }
+
+    #[test]
+    fn truncate_ends_in_an_ellipsis_within_the_width() {
+        assert_eq!(truncate("Globex Corporation", 8), "Globex …");
+        assert_eq!(truncate("Acme", 1), ELLIPSIS);
+        assert_eq!(truncate("Acme", 0), "");
+    }
+
+    #[test]
+    fn truncate_counts_wide_characters_as_two_columns() {
+        assert_eq!(truncate("東京都庁", 5), "東京…");
+        // Half a character doesn't fit, so the column is left empty
+        assert_eq!(truncate("東京都庁", 4), "東…");
+        assert_eq!(truncate("🚀🚀🚀", 5), "🚀🚀…");
+    }
+
+    #[test]
+    fn truncate_keeps_accents_with_their_letters() {
+        let cafe = format!("Cafe{} Noir", ACUTE);
+        assert_eq!(truncate(&cafe, 5), format!("Cafe{}…", ACUTE));
+        assert_eq!(truncate(&cafe, 4), "Caf…");
+    }
+
+    #[test]
+    fn truncate_never_splits_an_emoji_sequence() {
+        let thumbs = "👍🏽";
+        let cut = truncate(&thumbs.repeat(3), 4);
+        assert_eq!(cut.strip_suffix(ELLIPSIS).unwrap().graphemes(true).collect::<Vec<_>>(), [thumbs]);
+    }
+
+    #[test]
+    fn pop_grapheme_takes_the_whole_last_character() {
+        let mut text = "ok👍🏽".to_string();
+        pop_grapheme(&mut text);
+        assert_eq!(text, "ok");
+        let mut text = format!("Cafe{}", ACUTE);
+        pop_grapheme(&mut text);
+        assert_eq!(text, "Caf");
+        let mut text = String::new();
+        pop_grapheme(&mut text);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn boundaries_step_over_whole_graphemes() {
+        // a, then 👍 and its skin tone modifier, then b
+        let text = "a👍🏽b";
+        assert_eq!(previous_boundary(text, 3), 1);
+        assert_eq!(previous_boundary(text, 1), 0);
+        assert_eq!(previous_boundary(text, 0), 0);
+        assert_eq!(next_boundary(text, 1), 3);
+        assert_eq!(next_boundary(text, 2), 3);
+        assert_eq!(next_boundary(text, 4), 4);
+    }
+
+    #[test]
+    fn graphemes_map_to_char_indices() {
+        let text = format!("e{}東👍🏽", ACUTE);
+        assert_eq!(grapheme_count(&text), 3);
+        assert_eq!(grapheme_to_char(&text, 1), 2);
+        assert_eq!(grapheme_to_char(&text, 2), 3);
+        assert_eq!(grapheme_to_char(&text, 9), 5);
+    }
+
+    #[test]
+    fn buffer_clamps_the_cursor_to_the_end() {
+        assert_eq!(buffer_after("東京", 9, |_| {}), ("東京".to_string(), 2));
+        let mut text = "東京".to_string();
+        assert_eq!(TextBuffer::at_end(&mut text).cursor(), 2);
+    }
+
+    #[test]
+    fn buffer_inserts_at_the_cursor_counted_in_chars() {
+        assert_eq!(buffer_after("東京", 1, |b| b.insert('都')), ("東都京".to_string(), 2));
+        assert_eq!(buffer_after("ab", 1, |b| b.insert_str("🚀é")), ("a🚀éb".to_string(), 3));
+    }
+
+    #[test]
+    fn backspace_deletes_whole_graphemes() {
+        assert_eq!(buffer_after("ok👍🏽", 4, |b| b.delete_back()), ("ok".to_string(), 2));
+        let cafe = format!("Cafe{}!", ACUTE);
+        assert_eq!(buffer_after(&cafe, 5, |b| b.delete_back()), ("Caf!".to_string(), 3));
+        assert_eq!(buffer_after("東京", 1, |b| b.delete_back()), ("京".to_string(), 0));
+        assert_eq!(buffer_after("東京", 0, |b| b.delete_back()), ("東京".to_string(), 0));
+    }
+
+    #[test]
+    fn ctrl_w_deletes_the_word_before_and_the_separators_after_it() {
+        assert_eq!(buffer_after("call Acme", 9, |b| b.delete_word_back()), ("call ".to_string(), 5));
+        assert_eq!(buffer_after("call Acme  ", 11, |b| b.delete_word_back()), ("call ".to_string(), 5));
+        assert_eq!(buffer_after("re: offer...", 12, |b| b.delete_word_back()), ("re: ".to_string(), 4));
+        assert_eq!(buffer_after("snake_case", 10, |b| b.delete_word_back()), (String::new(), 0));
+        assert_eq!(buffer_after("Acme", 0, |b| b.delete_word_back()), ("Acme".to_string(), 0));
+    }
+
+    #[test]
+    fn ctrl_w_keeps_the_text_after_the_cursor() {
+        assert_eq!(buffer_after("one two three", 7, |b| b.delete_word_back()), ("one  three".to_string(), 4));
+    }
+
+    #[test]
+    fn word_boundaries_hold_for_accents_cjk_and_emoji() {
+        let cafe = format!("cafe{} ", ACUTE);
+        assert_eq!(buffer_after(&cafe, 6, |b| b.delete_word_back()), (String::new(), 0));
+        assert_eq!(buffer_after("面接 東京", 5, |b| b.delete_word_back()), ("面接 ".to_string(), 3));
+        // Emoji aren't letters, so they separate words like punctuation
+        assert_eq!(buffer_after("go🚀team", 7, |b| b.word_back()), ("go🚀team".to_string(), 3));
+        assert_eq!(buffer_after("go👍🏽", 4, |b| b.delete_word_back()), (String::new(), 0));
+    }
+
+    #[test]
+    fn alt_b_moves_to_the_start_of_the_previous_word() {
+        let text = "Phone screen, then onsite";
+        assert_eq!(buffer_after(text, 25, |b| b.word_back()).1, 19);
+        assert_eq!(buffer_after(text, 19, |b| b.word_back()).1, 14);
+        assert_eq!(buffer_after(text, 14, |b| b.word_back()).1, 6);
+        assert_eq!(buffer_after(text, 8, |b| b.word_back()).1, 6);
+        assert_eq!(buffer_after(text, 0, |b| b.word_back()).1, 0);
+    }
+
+    #[test]
+    fn alt_f_moves_to_the_end_of_the_next_word() {
+        let text = "Phone screen, then onsite";
+        assert_eq!(buffer_after(text, 0, |b| b.word_forward()).1, 5);
+        assert_eq!(buffer_after(text, 5, |b| b.word_forward()).1, 12);
+        assert_eq!(buffer_after(text, 12, |b| b.word_forward()).1, 18);
+        assert_eq!(buffer_after(text, 20, |b| b.word_forward()).1, 25);
+        assert_eq!(buffer_after(text, 25, |b| b.word_forward()).1, 25);
+        assert_eq!(buffer_after("a 👍🏽 b", 1, |b| b.word_forward()).1, 6);
+    }
+
+    #[test]
+    fn ctrl_u_and_ctrl_k_stop_at_line_breaks() {
+        let notes = "first line\nsecond line\nthird";
+        assert_eq!(buffer_after(notes, 18, |b| b.delete_to_line_start()), ("first line\nline\nthird".to_string(), 11));
+        assert_eq!(buffer_after(notes, 4, |b| b.delete_to_line_start()), ("t line\nsecond line\nthird".to_string(), 0));
+        assert_eq!(buffer_after(notes, 17, |b| b.delete_to_line_end()), ("first line\nsecond\nthird".to_string(), 17));
+        assert_eq!(buffer_after(notes, 26, |b| b.delete_to_line_end()), ("first line\nsecond line\nthi".to_string(), 26));
+    }
+
+    #[test]
+    fn ctrl_u_and_ctrl_k_count_chars_not_bytes() {
+        assert_eq!(buffer_after("東京\n面接です", 5, |b| b.delete_to_line_start()), ("東京\nです".to_string(), 3));
+        assert_eq!(buffer_after("東京\n面接です", 1, |b| b.delete_to_line_end()), ("東\n面接です".to_string(), 1));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::models::Status;
+use anyhow::{anyhow, bail, Context, Result};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::bar;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Colors used for statuses and chart bars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Palette {
+    #[default]
+    Default,
+    /// Blue/orange/purple ramp that stays distinct with red-green color blindness
+    Colorblind,
+    /// No colors; chart bars use a different fill pattern per status instead
+    None,
+}
+
+/// Built-in colors the `[theme]` overrides start from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Base {
+    /// For terminals with a dark background
+    #[default]
+    Dark,
+    /// For terminals with a light background
+    Light,
+}
+
+/// Color of a part of the interface that `[theme.colors]` can override
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    SelectionBg,
+    SelectionFg,
+    MarkedBg,
+    Title,
+    Accent,
+    Key,
+    Info,
+    Error,
+    Warning,
+    Muted,
+    Axis,
+    Text,
+}
+
+impl Role {
+    pub fn all() -> &'static [Role] {
+        &[
+            Role::SelectionBg,
+            Role::SelectionFg,
+            Role::MarkedBg,
+            Role::Title,
+            Role::Accent,
+            Role::Key,
+            Role::Info,
+            Role::Error,
+            Role::Warning,
+            Role::Muted,
+            Role::Axis,
+            Role::Text,
+        ]
+    }
+
+    /// Key in the `[theme.colors]` config section
+    pub fn name(&self) -> &str {
+        match self {
+            Role::SelectionBg => "selection_bg",
+            Role::SelectionFg => "selection_fg",
+            Role::MarkedBg => "marked_bg",
+            Role::Title => "title",
+            Role::Accent => "accent",
+            Role::Key => "key",
+            Role::Info => "info",
+            Role::Error => "error",
+            Role::Warning => "warning",
+            Role::Muted => "muted",
+            Role::Axis => "axis",
+            Role::Text => "text",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Role> {
+        Self::all().iter().copied().find(|role| role.name() == name)
+    }
+
+    /// Built-in color; warning only applies with the default palette
+    fn default_color(&self, base: Base) -> Color {
+        match (base, self) {
+            (Base::Dark, Role::SelectionBg) => Color::DarkGray,
+            (Base::Dark, Role::SelectionFg) => Color::White,
+            (Base::Dark, Role::MarkedBg) => Color::Blue,
+            (Base::Dark, Role::Title) => Color::Cyan,
+            (Base::Dark, Role::Accent) => Color::Yellow,
+            (Base::Dark, Role::Warning) => Color::LightRed,
+            (Base::Dark, Role::Muted) => Color::DarkGray,
+            (Base::Dark, Role::Axis) => Color::Gray,
+            (Base::Dark, Role::Text) => Color::White,
+            (Base::Light, Role::SelectionBg) => Color::Gray,
+            (Base::Light, Role::SelectionFg) => Color::Black,
+            (Base::Light, Role::MarkedBg) => Color::LightBlue,
+            (Base::Light, Role::Title) => Color::Blue,
+            (Base::Light, Role::Accent) => Color::Magenta,
+            (Base::Light, Role::Warning) => Color::Rgb(215, 95, 0),
+            (Base::Light, Role::Muted | Role::Axis) => Color::DarkGray,
+            (Base::Light, Role::Text) => Color::Black,
+            (_, Role::Key | Role::Info) => Color::Green,
+            (_, Role::Error) => Color::Red,
+        }
+    }
+}
+
+/// Display settings from the `[theme]` config section
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub base: Base,
+    pub palette: Palette,
+    /// Overrides by role name, e.g. `accent = "blue"`
+    pub colors: BTreeMap<String, String>,
+    /// Status colors by status name, custom statuses included
+    pub statuses: BTreeMap<String, String>,
+    /// Bar colors of single-series charts, replacing the palette's
+    pub chart: Vec<String>,
+}
+
+impl Theme {
+    /// Check every override names a known role and a color `parse_color` understands
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in &self.colors {
+            if Role::from_name(name).is_none() {
+                let names: Vec<&str> = Role::all().iter().map(|role| role.name()).collect();
+                bail!("Unknown theme color '{}' (expected one of: {})", name, names.join(", "));
+            }
+            parse_color(value).with_context(|| format!("Invalid color for {}", name))?;
+        }
+        for (status, value) in &self.statuses {
+            parse_color(value).with_context(|| format!("Invalid color for status {}", status))?;
+        }
+        for value in &self.chart {
+            parse_color(value).context("Invalid chart color")?;
+        }
+        Ok(())
+    }
+
+    fn own_color(&self, role: Role) -> Option<Color> {
+        self.colors.get(role.name()).and_then(|value| parse_color(value).ok())
+    }
+
+    /// Color for a role: its override, else the base theme's
+    fn color(&self, role: Role) -> Color {
+        self.own_color(role).unwrap_or_else(|| role.default_color(self.base))
+    }
+
+    fn fg(&self, role: Role) -> Style {
+        Style::default().fg(self.color(role))
+    }
+
+    /// Highlighted row or item under the cursor
+    pub fn selection_style(&self) -> Style {
+        self.fg(Role::SelectionFg).bg(self.color(Role::SelectionBg))
+    }
+
+    /// Rows marked for a batch action
+    pub fn marked_style(&self) -> Style {
+        self.fg(Role::SelectionFg).bg(self.color(Role::MarkedBg))
+    }
+
+    /// Screen and block titles
+    pub fn title_style(&self) -> Style {
+        self.fg(Role::Title)
+    }
+
+    /// Table headers, headings, focused labels and search matches
+    pub fn accent_style(&self) -> Style {
+        self.fg(Role::Accent)
+    }
+
+    /// Keys in help bars
+    pub fn key_style(&self) -> Style {
+        self.fg(Role::Key)
+    }
+
+    /// Info messages in the status bar
+    pub fn info_style(&self) -> Style {
+        self.fg(Role::Info)
+    }
+
+    /// Errors, and keys that cancel or quit
+    pub fn error_style(&self) -> Style {
+        self.fg(Role::Error)
+    }
+
+    /// Hints, borders of secondary blocks and greyed-out values
+    pub fn muted_style(&self) -> Style {
+        self.fg(Role::Muted)
+    }
+
+    /// Chart axes and their labels
+    pub fn axis_style(&self) -> Style {
+        self.fg(Role::Axis)
+    }
+
+    /// Plain text in popups
+    pub fn text_style(&self) -> Style {
+        self.fg(Role::Text)
+    }
+
+    /// Style for a status in the list, charts and legends
+    pub fn status_style(&self, status: &Status) -> Style {
+        let own = self
+            .statuses
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(status.as_str()))
+            .and_then(|(_, value)| parse_color(value).ok());
+        if let Some(color) = own {
+            return Style::default().fg(color);
+        }
+
+        let color = match (self.palette, self.base, status) {
+            (Palette::Default, Base::Dark, Status::Planned) => Color::Blue,
+            (Palette::Default, Base::Dark, Status::Applied) => Color::Yellow,
+            (Palette::Default, Base::Dark, Status::Interview) => Color::Cyan,
+            (Palette::Default, Base::Dark, Status::Offer) => Color::Green,
+            (Palette::Default, Base::Dark, Status::Rejected) => Color::Red,
+            (Palette::Default, Base::Dark, Status::Other(_)) => Color::Magenta,
+            (Palette::Default, Base::Light, Status::Planned) => Color::Rgb(0, 0, 175),
+            (Palette::Default, Base::Light, Status::Applied) => Color::Rgb(175, 135, 0),
+            (Palette::Default, Base::Light, Status::Interview) => Color::Rgb(0, 135, 175),
+            (Palette::Default, Base::Light, Status::Offer) => Color::Rgb(0, 135, 0),
+            (Palette::Default, Base::Light, Status::Rejected) => Color::Rgb(175, 0, 0),
+            (Palette::Default, Base::Light, Status::Other(_)) => Color::Rgb(135, 0, 175),
+            (Palette::Colorblind, _, Status::Planned) => Color::Rgb(0, 158, 115),
+            (Palette::Colorblind, _, Status::Applied) => Color::Rgb(86, 180, 233),
+            (Palette::Colorblind, _, Status::Interview) => Color::Rgb(204, 121, 167),
+            (Palette::Colorblind, _, Status::Offer) => Color::Rgb(230, 159, 0),
+            (Palette::Colorblind, _, Status::Rejected) => Color::Rgb(0, 114, 178),
+            (Palette::Colorblind, _, Status::Other(_)) => Color::Rgb(240, 228, 66),
+            (Palette::None, _, _) => return Style::default(),
+        };
+        Style::default().fg(color)
+    }
+
+    /// Style for values that need attention, such as stale dates
+    pub fn warning_style(&self) -> Style {
+        if let Some(color) = self.own_color(Role::Warning) {
+            return Style::default().fg(color);
+        }
+        match self.palette {
+            Palette::Default => self.fg(Role::Warning),
+            Palette::Colorblind => Style::default().fg(Color::Rgb(213, 94, 0)),
+            Palette::None => Style::default().add_modifier(Modifier::UNDERLINED),
+        }
+    }
+
+    /// Style for the bars of a single-series chart (0 = resume version, 1 = platform)
+    pub fn series_style(&self, series: usize) -> Style {
+        let own: Vec<Color> = self.chart.iter().filter_map(|value| parse_color(value).ok()).collect();
+        if !own.is_empty() {
+            return Style::default().fg(own[series % own.len()]);
+        }
+        let colors: &[Color] = match (self.palette, self.base) {
+            (Palette::Default, Base::Dark) => &[Color::Green, Color::Blue],
+            (Palette::Default, Base::Light) => &[Color::Rgb(0, 135, 0), Color::Blue],
+            (Palette::Colorblind, _) => &[Color::Rgb(0, 114, 178), Color::Rgb(230, 159, 0)],
+            (Palette::None, _) => return Style::default(),
+        };
+        Style::default().fg(colors[series % colors.len()])
+    }
+
+    /// Bar fill for a status; only differs per status when colors are off
+    pub fn status_bar_set(&self, status: &Status) -> bar::Set {
+        if self.palette != Palette::None {
+            return bar::NINE_LEVELS;
+        }
+        let fill = status_fill(status);
+        bar::Set {
+            full: fill,
+            seven_eighths: fill,
+            three_quarters: fill,
+            five_eighths: fill,
+            half: fill,
+            three_eighths: fill,
+            one_quarter: fill,
+            one_eighth: fill,
+            empty: " ",
+        }
+    }
+
+    /// Swatch shown next to a status in the chart legend
+    pub fn status_swatch(&self, status: &Status) -> &'static str {
+        match self.palette {
+            Palette::None => status_fill(status),
+            _ => bar::FULL,
+        }
+    }
+}
+
+/// A color name such as "green" or "dark gray", a hex color such as "#1e90ff",
+/// or a 0-255 terminal palette index
+pub fn parse_color(value: &str) -> Result<Color> {
+    Color::from_str(value.trim()).map_err(|_| {
+        anyhow!(
+            "Unknown color '{}' (expected a name such as \"green\" or \"dark gray\", a hex color such as \"#1e90ff\", or a number 0-255)",
+            value
+        )
+    })
+}
+
+fn status_fill(status: &Status) -> &'static str {
+    match status {
+        Status::Planned => "▚",
+        Status::Applied => "█",
+        Status::Interview => "▓",
+        Status::Offer => "▒",
+        Status::Rejected => "░",
+        Status::Other(_) => "▞",
+    }
+}
@@ -0,0 +1,101 @@
+use crate::app::App;
+use crate::keymap::Action;
+use crate::text;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Narrowest a status column gets before the board pages horizontally
+const MIN_COLUMN_WIDTH: u16 = 18;
+
+/// Render the pipeline board: a column per status listing its applications, newest first
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(frame.area());
+
+    let columns = app.board_columns();
+    let per_page = (chunks[0].width / MIN_COLUMN_WIDTH).clamp(1, columns.len().max(1) as u16) as usize;
+    let first = app.board.column / per_page * per_page;
+    let shown = columns.len().saturating_sub(first).min(per_page);
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, per_page as u32); per_page])
+        .split(chunks[0]);
+
+    let theme = &app.config.theme;
+    let mut offsets = Vec::new();
+    for (position, (status, cards)) in columns.iter().enumerate().skip(first).take(shown) {
+        let area = areas[position - first];
+        let active = position == app.board.column;
+        let width = area.width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = cards
+            .iter()
+            .map(|&idx| ListItem::new(text::truncate(app.applications[idx].company_name.trim(), width)))
+            .collect();
+        let empty = items.is_empty();
+
+        let mut title_style = theme.status_style(status);
+        if active {
+            title_style = title_style.add_modifier(Modifier::BOLD | Modifier::REVERSED);
+        }
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(format!(" {} ({}) ", status.as_str(), cards.len()), title_style));
+        if active {
+            block = block.border_style(theme.accent_style());
+        }
+
+        if empty {
+            let placeholder = Paragraph::new("No applications").style(theme.muted_style()).block(block);
+            frame.render_widget(placeholder, area);
+            continue;
+        }
+
+        // Only the active column shows its selection; every column keeps its own scroll
+        let row = app.board.row(position).min(cards.len() - 1);
+        let list = List::new(items).block(block).highlight_style(theme.selection_style());
+        let mut state = ListState::default()
+            .with_offset(app.board.offset(position))
+            .with_selected(active.then_some(row));
+        frame.render_stateful_widget(list, area, &mut state);
+        offsets.push((position, state.offset()));
+    }
+    for (position, offset) in offsets {
+        app.board.set_offset(position, offset);
+    }
+
+    render_help(frame, app, chunks[1], first, shown, columns.len());
+}
+
+fn render_help(frame: &mut Frame, app: &App, area: Rect, first: usize, shown: usize, total: usize) {
+    let theme = &app.config.theme;
+    // Paging first, since the keys are cut off on the narrow screens that page
+    let mut help_text = Vec::new();
+    if shown < total {
+        help_text.push(Span::styled(
+            format!("Columns {}-{} of {}  ", first + 1, first + shown, total),
+            theme.muted_style(),
+        ));
+    }
+    help_text.extend([
+        Span::styled("←/→", theme.key_style()),
+        Span::raw(": Column  "),
+        Span::styled(format!("{}/{}", app.keymap.label(Action::Up), app.keymap.label(Action::Down)), theme.key_style()),
+        Span::raw(": Card  "),
+        Span::styled("[/]", theme.key_style()),
+        Span::raw(": Move to Previous/Next Status  "),
+        Span::styled(app.keymap.label(Action::Help), theme.key_style()),
+        Span::raw(": Help  "),
+        Span::styled(app.keymap.label(Action::Cancel), theme.error_style()),
+        Span::raw(": Back to List"),
+    ]);
+    let help = Paragraph::new(Line::from(help_text))
+        .block(Block::default().borders(Borders::ALL).title("Help"));
+    frame.render_widget(help, area);
+}
@@ -1,191 +1,677 @@
+use super::list;
 use crate::app::{App, ChartType};
+use crate::goal::GoalProgress;
+use crate::keymap::Action;
 use crate::models::Status;
+use crate::stats::{self, ChartData, Conversion, ResponseTimes};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Row, Table},
     Frame,
 };
-use std::collections::HashMap;
 
 /// Render the chart view
 pub fn render(frame: &mut Frame, app: &App) {
+    let goal = app.goal_progress();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
             Constraint::Min(0),
+            Constraint::Length(if goal.is_some() { 3 } else { 0 }),
             Constraint::Length(3),
         ])
         .split(frame.area());
 
     // Title
-    let title = Paragraph::new(app.chart_type.title())
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        .block(Block::default().borders(Borders::ALL));
-    frame.render_widget(title, chunks[0]);
+    render_title(frame, app, chunks[0]);
 
     // Chart
     render_chart(frame, app, chunks[1]);
 
+    // Goal
+    if let Some(goal) = goal {
+        render_goal(frame, app, goal, chunks[2]);
+    }
+
     // Help
-    render_chart_help(frame, chunks[2]);
+    render_chart_help(frame, app, chunks[3]);
 }
 
-fn render_chart(frame: &mut Frame, app: &App, area: Rect) {
-    match app.chart_type {
-        ChartType::ByResumeVersion => render_resume_version_chart(frame, app, area),
-        ChartType::ByPlatform => render_platform_chart(frame, app, area),
-        ChartType::ByStatus => render_status_chart(frame, app, area),
+/// Chart title with its position, and the charts either side dimmed
+fn render_title(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let block = Block::default().borders(Borders::ALL);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chart = app.chart_type;
+    let mut title = Line::from(vec![
+        Span::styled(chart.title(), theme.title_style().add_modifier(Modifier::BOLD)),
+        Span::raw(format!("  {}/{}", chart.number(), ChartType::all().len())),
+        Span::styled(format!("  · {}", app.chart_range.label()), theme.accent_style()),
+    ]);
+    let ghosted = app.ghosted_ids().len();
+    if ghosted > 0 {
+        title.push_span(Span::styled(
+            format!("  · {} likely ghosted", ghosted),
+            theme.warning_style(),
+        ));
+    }
+    // The title keeps its width; the neighbours get what's left
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(title.width() as u16), Constraint::Min(0)])
+        .split(inner_area);
+    frame.render_widget(Paragraph::new(title), chunks[0]);
+
+    let neighbours = format!("← {}  |  {} →", chart.prev().short_title(), chart.next().short_title());
+    // Leave them out rather than cut them off on narrow screens
+    if (chunks[1].width as usize) < neighbours.chars().count() + 2 {
+        return;
     }
+    let neighbours = Paragraph::new(neighbours)
+        .style(theme.muted_style())
+        .alignment(Alignment::Right);
+    frame.render_widget(neighbours, chunks[1]);
 }
 
-fn render_resume_version_chart(frame: &mut Frame, app: &App, area: Rect) {
-    let mut counts: HashMap<String, u64> = HashMap::new();
+fn render_goal(frame: &mut Frame, app: &App, goal: GoalProgress, area: Rect) {
+    let remaining = goal.target.saturating_sub(goal.done);
+    let title = if remaining == 0 {
+        "Goal reached".to_string()
+    } else {
+        format!("Goal: {} to go", remaining)
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+    list::render_goal_gauge(frame, app, goal, inner_area);
+}
 
-    for application in &app.applications {
-        let version = if application.resume_version.is_empty() {
-            "None".to_string()
-        } else {
-            application.resume_version.clone()
-        };
-        *counts.entry(version).or_insert(0) += 1;
+fn render_chart(frame: &mut Frame, app: &App, area: Rect) {
+    let area = if app.chart_legend {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Min(0), Constraint::Length(22)])
+            .split(area);
+        render_legend(frame, app, chunks[1]);
+        chunks[0]
+    } else {
+        area
+    };
+
+    let mut data = app.chart_data();
+    if app.chart_percent {
+        data = data.into_percentages();
+    }
+    match data {
+        ChartData::Counts(data) => render_count_chart(frame, app, area, &data),
+        ChartData::Conversions(data) if app.chart_type == ChartType::ByResumeSuccess => {
+            render_resume_rate_chart(frame, app, area, &data)
+        }
+        ChartData::Conversions(data) => render_interview_rate_chart(frame, app, area, &data),
+        ChartData::Statuses(data) => render_status_chart(frame, app, area, &data),
+        ChartData::StatusByGroup(data) => render_status_by_group(frame, app, area, &data),
+        ChartData::ResponseTimes(times) => render_response_times(frame, app, area, &times),
     }
+}
 
-    let mut data: Vec<(String, u64)> = counts.into_iter().collect();
-    // Sort by count descending, then by name ascending for stable sort
-    data.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+/// Title of the block around a chart, with whether it shows counts or percentages
+fn block_title(app: &App) -> String {
+    let title = match app.chart_type {
+        ChartType::ByResumeVersion => "Count by Resume Version",
+        ChartType::ByPlatform => "Count by Platform",
+        ChartType::ByWorkMode => "Count by Work Mode",
+        ChartType::ByPlatformSuccess => "% Reaching Interview by Platform",
+        ChartType::ByResumeSuccess => "Sent and Reaching Interview by Resume Version",
+        ChartType::ByReferral => "% Reaching Interview, Referral vs Cold",
+        ChartType::ByCoverLetter => "% Reaching Interview, With vs Without Cover Letter",
+        ChartType::ByStatus => "Count by Status",
+        ChartType::RejectionReasons => "Count of Rejections by Reason",
+        ChartType::StatusByPlatform => "Applications by Platform and Status",
+        ChartType::StatusByInterest => "Applications by Interest and Status",
+        ChartType::ResponseTimes => "Days Between Stages",
+    };
+    let mode = match app.chart_type {
+        chart if !chart.has_percentages() => return title.to_string(),
+        _ if !app.chart_percent => "counts",
+        ChartType::StatusByPlatform | ChartType::StatusByInterest => "% of each column",
+        _ => "% of total",
+    };
+    format!("{} ({})", title, mode)
+}
 
-    if data.is_empty() {
+/// Single-series bar chart of application counts per label
+fn render_count_chart(frame: &mut Frame, app: &App, area: Rect, data: &[(String, u64)]) {
+    let theme = &app.config.theme;
+    let series = app.chart_type.series();
+    if data.iter().all(|(_, count)| *count == 0) {
         let empty = Paragraph::new("No data available")
             .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::Gray));
+            .style(theme.axis_style());
         frame.render_widget(empty, area);
         return;
     }
 
-    // Take top 10
-    data.truncate(10);
+    let block = Block::default().borders(Borders::ALL).title(block_title(app));
+    let total: u64 = data.iter().map(|(_, count)| count).sum();
+    let Some(bar_width) = fit_bar_width(block.inner(area).width, data.len()) else {
+        let rows: Vec<BarRow> = data
+            .iter()
+            .map(|(label, count)| BarRow {
+                label: label.clone(),
+                count: *count,
+                fill: "█",
+                style: theme.series_style(series),
+            })
+            .collect();
+        render_bar_list(frame, app, block, area, &rows, total);
+        return;
+    };
 
     let bars: Vec<Bar> = data
         .iter()
         .map(|(label, count)| {
             Bar::default()
                 .value(*count)
+                .text_value(value_label(app, *count, total, bar_width))
                 .label(Line::from(label.as_str()))
-                .style(Style::default().fg(Color::Green))
+                .style(theme.series_style(series))
         })
         .collect();
 
-    let chart = BarChart::default()
-        .block(Block::default().borders(Borders::ALL).title("Count by Resume Version"))
+    let mut chart = BarChart::default()
+        .block(block)
         .data(BarGroup::default().bars(&bars))
-        .bar_width(9)
-        .bar_gap(1)
-        .bar_style(Style::default().fg(Color::Green));
+        .bar_width(bar_width)
+        .bar_gap(BAR_GAP)
+        .bar_style(theme.series_style(series));
+    if app.chart_percent {
+        chart = chart.max(100);
+    }
 
     frame.render_widget(chart, area);
 }
 
-fn render_platform_chart(frame: &mut Frame, app: &App, area: Rect) {
-    let mut counts: HashMap<String, u64> = HashMap::new();
-
-    for application in &app.applications {
-        let platform = application.platform.as_str();
-        *counts.entry(platform).or_insert(0) += 1;
-    }
-
-    let mut data: Vec<(String, u64)> = counts.into_iter().collect();
-    // Sort by count descending, then by name ascending for stable sort
-    data.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
-
-    if data.is_empty() {
+/// Share of each group's applications that reached an interview, on a 0–100 scale
+fn render_interview_rate_chart(frame: &mut Frame, app: &App, area: Rect, data: &[(String, Conversion)]) {
+    let theme = &app.config.theme;
+    if data.iter().all(|(_, conversion)| conversion.total == 0) {
         let empty = Paragraph::new("No data available")
             .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::Gray));
+            .style(theme.axis_style());
         frame.render_widget(empty, area);
         return;
     }
 
+    let min_sample = app.config.charts.min_sample;
     let bars: Vec<Bar> = data
         .iter()
-        .map(|(label, count)| {
+        .map(|(group, conversion)| {
+            let percent = stats::rate(conversion.interviewed, conversion.total).unwrap_or(0);
+            // Tiny samples are greyed out and starred so 1/1 doesn't read as a sure thing
+            let (style, label) = if conversion.total < min_sample {
+                (theme.muted_style(), format!("{}*", group))
+            } else {
+                (theme.series_style(1), group.clone())
+            };
             Bar::default()
-                .value(*count)
-                .label(Line::from(label.as_str()))
-                .style(Style::default().fg(Color::Blue))
+                .value(percent)
+                .text_value(format!("{}/{}", conversion.interviewed, conversion.total))
+                .label(Line::from(label))
+                .style(style)
         })
         .collect();
 
+    let mut block = Block::default().borders(Borders::ALL).title(block_title(app));
+    if data.iter().any(|(_, conversion)| conversion.total < min_sample) {
+        block = block.title_bottom(format!("* fewer than {} applications", min_sample));
+    }
+    // Rates are short enough to read on thin bars, so there's no list to fall back to
+    let bar_width = fit_bar_width(block.inner(area).width, bars.len()).unwrap_or(MIN_BAR_WIDTH);
     let chart = BarChart::default()
-        .block(Block::default().borders(Borders::ALL).title("Count by Platform"))
+        .block(block)
         .data(BarGroup::default().bars(&bars))
-        .bar_width(9)
-        .bar_gap(1)
-        .bar_style(Style::default().fg(Color::Blue));
+        .bar_width(bar_width)
+        .bar_gap(BAR_GAP)
+        .max(100);
 
     frame.render_widget(chart, area);
 }
 
-fn render_status_chart(frame: &mut Frame, app: &App, area: Rect) {
-    let mut counts: HashMap<String, u64> = HashMap::new();
+/// Applications sent and reaching an interview per resume version, side by side, with the rate as label
+fn render_resume_rate_chart(frame: &mut Frame, app: &App, area: Rect, data: &[(String, Conversion)]) {
+    let theme = &app.config.theme;
+    if data.is_empty() {
+        let empty = Paragraph::new("No data available")
+            .block(Block::default().borders(Borders::ALL))
+            .style(theme.axis_style());
+        frame.render_widget(empty, area);
+        return;
+    }
 
-    for application in &app.applications {
-        let status = application.status.as_str();
-        *counts.entry(status.to_string()).or_insert(0) += 1;
+    let min_sample = app.config.charts.min_sample;
+    let mut block = Block::default().borders(Borders::ALL).title(block_title(app));
+    if data.iter().any(|(_, conversion)| conversion.total < min_sample) {
+        block = block.title_bottom(format!("* fewer than {} applications", min_sample));
     }
+    // Widen the bars to fill the space, but keep two bars and the gaps per group on screen
+    let groups = data.len() as u16;
+    let bar_width = ((block.inner(area).width / groups).saturating_sub(3) / 2).clamp(3, 8);
+    let group_width = (bar_width * 2 + 1) as usize;
 
-    // Ensure all statuses are represented
-    for status in Status::all() {
-        counts.entry(status.as_str().to_string()).or_insert(0);
+    let mut chart = BarChart::default()
+        .block(block)
+        .bar_width(bar_width)
+        .bar_gap(1)
+        .group_gap(2);
+    for (version, conversion) in data {
+        let percent = stats::rate(conversion.interviewed, conversion.total).unwrap_or(0);
+        let star = if conversion.total < min_sample { "*" } else { "" };
+        // Counts go under the bars rather than inside them, so a bar of 0 still shows its value
+        let bars = [
+            Bar::default()
+                .value(conversion.total)
+                .text_value(String::new())
+                .label(Line::from(conversion.total.to_string()))
+                .style(theme.series_style(0)),
+            Bar::default()
+                .value(conversion.interviewed)
+                .text_value(String::new())
+                .label(Line::from(conversion.interviewed.to_string()))
+                .style(theme.series_style(1)),
+        ];
+        let rate = format!("{} {}%", star, percent);
+        let name_width = group_width.saturating_sub(rate.chars().count());
+        let label: String = version.chars().take(name_width).collect::<String>() + &rate;
+        chart = chart.data(BarGroup::default().label(Line::from(label)).bars(&bars));
     }
 
-    let data: Vec<(String, u64)> = Status::all()
-        .iter()
-        .map(|s| (s.as_str().to_string(), *counts.get(s.as_str()).unwrap_or(&0)))
-        .collect();
+    frame.render_widget(chart, area);
+}
 
+fn render_status_chart(frame: &mut Frame, app: &App, area: Rect, data: &[(Status, u64)]) {
+    let theme = &app.config.theme;
     if data.iter().all(|(_, count)| *count == 0) {
         let empty = Paragraph::new("No data available")
             .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::Gray));
+            .style(theme.axis_style());
         frame.render_widget(empty, area);
         return;
     }
 
-    let bars: Vec<Bar> = data
+    let block = Block::default().borders(Borders::ALL).title(block_title(app));
+    let total: u64 = data.iter().map(|(_, count)| count).sum();
+    let Some(bar_width) = fit_bar_width(block.inner(area).width, data.len()) else {
+        let rows: Vec<BarRow> = data
+            .iter()
+            .map(|(status, count)| BarRow {
+                label: status.as_str().to_string(),
+                count: *count,
+                fill: theme.status_swatch(status),
+                style: theme.status_style(status),
+            })
+            .collect();
+        render_bar_list(frame, app, block, area, &rows, total);
+        return;
+    };
+
+    // One chart per status so each can use its own bar fill when colors are off
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let max = if app.chart_percent { 100 } else { data.iter().map(|(_, count)| *count).max().unwrap_or(0) };
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(data.iter().map(|_| Constraint::Length(bar_width + BAR_GAP)))
+        .split(inner);
+
+    for ((status, count), column) in data.iter().zip(columns.iter()) {
+        let bar = Bar::default()
+            .value(*count)
+            .text_value(value_label(app, *count, total, bar_width))
+            .label(Line::from(status.as_str()))
+            .style(theme.status_style(status));
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[bar]))
+            .bar_width(bar_width)
+            .bar_gap(BAR_GAP)
+            .bar_set(theme.status_bar_set(status))
+            .max(max);
+        frame.render_widget(chart, *column);
+    }
+}
+
+/// Gap between the bars of a single-series chart
+const BAR_GAP: u16 = 1;
+
+/// Narrowest bar still worth drawing; below this the chart becomes a list
+const MIN_BAR_WIDTH: u16 = 3;
+
+/// Widest bar, so a chart of two bars doesn't turn into two blocks
+const MAX_BAR_WIDTH: u16 = 12;
+
+/// Bar width that fits `bars` bars side by side in `width` columns,
+/// or None when they'd be too thin to read
+fn fit_bar_width(width: u16, bars: usize) -> Option<u16> {
+    let bars = bars.max(1) as u16;
+    let bar_width = ((width + BAR_GAP) / bars).saturating_sub(BAR_GAP);
+    (bar_width >= MIN_BAR_WIDTH).then(|| bar_width.min(MAX_BAR_WIDTH))
+}
+
+/// Value shown in a bar: "12 40%" when it fits, else just the count, or "40%" when the chart
+/// shows percentages. Drawn even when the bar itself rounds down to nothing next to a much taller one.
+fn value_label(app: &App, count: u64, total: u64, bar_width: u16) -> String {
+    if app.chart_percent {
+        return format!("{}%", count);
+    }
+    let full = format!("{} {}%", count, stats::rate(count, total).unwrap_or(0));
+    if full.chars().count() < bar_width as usize {
+        full
+    } else {
+        count.to_string()
+    }
+}
+
+/// One line of the list drawn instead of a bar chart on narrow terminals
+struct BarRow {
+    label: String,
+    count: u64,
+    fill: &'static str,
+    style: Style,
+}
+
+/// Horizontal bars, one per line, with the label before the bar and the count and share after it
+/// (just the share when the chart shows percentages)
+fn render_bar_list(frame: &mut Frame, app: &App, block: Block, area: Rect, rows: &[BarRow], total: u64) {
+    let inner = block.inner(area);
+    let label_width = rows
         .iter()
-        .map(|(label, count)| {
-            let color = match label.as_str() {
-                "Applied" => Color::Yellow,
-                "Interview" => Color::Cyan,
-                "Offer" => Color::Green,
-                "Rejected" => Color::Red,
-                _ => Color::White,
+        .map(|row| row.label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(inner.width as usize / 3);
+    let values: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            if app.chart_percent {
+                format!(" {}%", row.count)
+            } else {
+                format!(" {} ({}%)", row.count, stats::rate(row.count, total).unwrap_or(0))
+            }
+        })
+        .collect();
+    let value_width = values.iter().map(|value| value.chars().count()).max().unwrap_or(0);
+    let bar_space = (inner.width as usize).saturating_sub(label_width + 1 + value_width) as u64;
+    let max = if app.chart_percent {
+        100
+    } else {
+        rows.iter().map(|row| row.count).max().unwrap_or(0).max(1)
+    };
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .zip(values)
+        .map(|(row, value)| {
+            let label: String = row.label.chars().take(label_width).collect();
+            // Every non-zero count gets at least one cell so it doesn't vanish next to a big one
+            let cells = match row.count {
+                0 => 0,
+                count => ((count * bar_space + max / 2) / max).max(1) as usize,
             };
+            Line::from(vec![
+                Span::raw(format!("{:<width$} ", label, width = label_width)),
+                Span::styled(row.fill.repeat(cells), row.style),
+                Span::raw(value),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
 
-            Bar::default()
-                .value(*count)
-                .label(Line::from(label.as_str()))
-                .style(Style::default().fg(color))
+/// One stacked column per group, such as a platform, split by status, with a legend of the
+/// statuses underneath. Drawn by hand since ratatui has no stacked bars; every status present
+/// gets at least one row.
+fn render_status_by_group(
+    frame: &mut Frame,
+    app: &App,
+    area: Rect,
+    data: &[(String, Vec<(Status, u64)>)],
+) {
+    let theme = &app.config.theme;
+    let statuses = app.status_order();
+    if data.is_empty() {
+        let empty = Paragraph::new("No data available")
+            .block(Block::default().borders(Borders::ALL))
+            .style(theme.axis_style());
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let block = Block::default().borders(Borders::ALL).title(block_title(app));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1), Constraint::Length(1)])
+        .split(inner);
+    let (bars_area, labels_area) = (chunks[0], chunks[1]);
+
+    // Statuses nobody is in would only crowd the legend
+    let shown: Vec<&Status> = statuses
+        .iter()
+        .filter(|status| data.iter().any(|(_, counts)| counts.iter().any(|(s, count)| s == *status && *count > 0)))
+        .collect();
+    let legend: Vec<Span> = shown
+        .iter()
+        .flat_map(|status| {
+            [
+                Span::styled(theme.status_swatch(status).repeat(2), theme.status_style(status)),
+                Span::raw(format!(" {}  ", status.as_str())),
+            ]
         })
         .collect();
+    frame.render_widget(Paragraph::new(Line::from(legend)).alignment(Alignment::Center), chunks[2]);
 
-    let chart = BarChart::default()
-        .block(Block::default().borders(Borders::ALL).title("Count by Status"))
-        .data(BarGroup::default().bars(&bars))
-        .bar_width(9)
-        .bar_gap(1);
+    let column_width = (inner.width / data.len() as u16).clamp(3, 14);
+    let bar_width = column_width.saturating_sub(2).max(1);
+    let left = inner.x + inner.width.saturating_sub(column_width * data.len() as u16) / 2;
+    // One row stays free above the tallest column for its total
+    let height = bars_area.height.saturating_sub(1) as u64;
+    let max = if app.chart_percent {
+        100
+    } else {
+        data.iter()
+            .map(|(_, counts)| counts.iter().map(|(_, count)| count).sum::<u64>())
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    };
 
-    frame.render_widget(chart, area);
+    let buffer = frame.buffer_mut();
+    let bottom = bars_area.bottom();
+    for (idx, (group, counts)) in data.iter().enumerate() {
+        let x = left + idx as u16 * column_width + 1;
+        if x + bar_width > inner.right() {
+            break;
+        }
+
+        let mut top = bottom;
+        for (status, count) in counts.iter().filter(|(_, count)| *count > 0) {
+            let rows = ((count * height + max / 2) / max).max(1) as u16;
+            // Clipped at the total's row if rounding up small segments overshoots
+            let rows = rows.min(top.saturating_sub(bars_area.y + 1));
+            if rows == 0 {
+                break;
+            }
+            top -= rows;
+            let style = theme.status_style(status);
+            for y in top..top + rows {
+                buffer.set_string(x, y, theme.status_swatch(status).repeat(bar_width as usize), style);
+            }
+            // Count or percentage in the middle of its segment when it fits
+            let text = if app.chart_percent { format!("{}%", count) } else { count.to_string() };
+            if text.len() as u16 <= bar_width {
+                let offset = (bar_width - text.len() as u16) / 2;
+                buffer.set_string(x + offset, top + (rows - 1) / 2, &text, style.add_modifier(Modifier::REVERSED));
+            }
+        }
+
+        // Every column adds up to 100% when showing percentages, so only counts get a total
+        if !app.chart_percent {
+            let total: u64 = counts.iter().map(|(_, count)| count).sum();
+            buffer.set_string(x, top.saturating_sub(1).max(bars_area.y), total.to_string(), Style::default());
+        }
+        let label: String = group.chars().take(column_width.saturating_sub(1) as usize).collect();
+        buffer.set_string(x, labels_area.y, label, Style::default());
+    }
+}
+
+/// Average and median days per pipeline stage, with open applications listed apart
+fn render_response_times(frame: &mut Frame, app: &App, area: Rect, times: &ResponseTimes) {
+    let header = Row::new(["Stage", "Apps", "Average", "Median"])
+        .style(app.config.theme.accent_style().add_modifier(Modifier::BOLD))
+        .bottom_margin(1);
+    let stages = [
+        ("Applied → first response", times.first_response),
+        ("Applied → interview", times.applied_to_interview),
+        ("Interview → offer", times.interview_to_offer),
+        ("Still waiting (so far)", times.waiting),
+    ];
+    let rows = stages.iter().map(|(stage, summary)| {
+        let cells = match summary {
+            Some(summary) => [
+                stage.to_string(),
+                summary.count.to_string(),
+                format_days(summary.mean),
+                format_days(summary.median),
+            ],
+            None => [stage.to_string(), "0".to_string(), "—".to_string(), "—".to_string()],
+        };
+        let style = if stage.starts_with("Still") {
+            app.config.theme.muted_style()
+        } else {
+            Style::default()
+        };
+        Row::new(cells).style(style)
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(26),
+            Constraint::Length(6),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(block_title(app))
+            .title_bottom("Only applications with dates for both stages count"),
+    );
+    frame.render_widget(table, area);
+}
+
+/// "12 days", "1 day", "4.5 days"
+fn format_days(days: f64) -> String {
+    let rounded = (days * 10.0).round() / 10.0;
+    if rounded == 1.0 {
+        "1 day".to_string()
+    } else if rounded.fract() == 0.0 {
+        format!("{} days", rounded)
+    } else {
+        format!("{:.1} days", rounded)
+    }
+}
+
+/// Key mapping each bar color/fill to what it counts
+fn render_legend(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let lines: Vec<Line> = match app.chart_type {
+        ChartType::ByStatus | ChartType::StatusByPlatform | ChartType::StatusByInterest => app
+            .status_order()
+            .iter()
+            .map(|status| {
+                Line::from(vec![
+                    Span::styled(theme.status_swatch(status).repeat(3), theme.status_style(status)),
+                    Span::raw(format!(" {}", status.as_str())),
+                ])
+            })
+            .collect(),
+        ChartType::ByPlatformSuccess | ChartType::ByReferral | ChartType::ByCoverLetter => vec![
+            Line::from(vec![
+                Span::styled("███", theme.series_style(1)),
+                Span::raw(" Interview rate"),
+            ]),
+            Line::from(vec![
+                Span::styled("███", theme.muted_style()),
+                Span::raw(format!(" Under {} apps", app.config.charts.min_sample)),
+            ]),
+        ],
+        ChartType::ByResumeSuccess => vec![
+            Line::from(vec![
+                Span::styled("███", theme.series_style(0)),
+                Span::raw(" Sent"),
+            ]),
+            Line::from(vec![
+                Span::styled("███", theme.series_style(1)),
+                Span::raw(" Interviewed"),
+            ]),
+        ],
+        ChartType::ResponseTimes => vec![
+            Line::from("Dates come from"),
+            Line::from("status changes and"),
+            Line::from("interview rounds"),
+        ],
+        ChartType::ByResumeVersion | ChartType::ByPlatform | ChartType::ByWorkMode => {
+            let series = app.chart_type.series();
+            vec![Line::from(vec![
+                Span::styled("███", theme.series_style(series)),
+                Span::raw(" Applications"),
+            ])]
+        }
+        ChartType::RejectionReasons => vec![Line::from(vec![
+            Span::styled("███", theme.series_style(app.chart_type.series())),
+            Span::raw(" Rejections"),
+        ])],
+    };
+
+    let legend = Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Legend"));
+    frame.render_widget(legend, area);
 }
 
-fn render_chart_help(frame: &mut Frame, area: Rect) {
+fn render_chart_help(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
     let help_text = vec![
-        Span::styled("Tab", Style::default().fg(Color::Green)),
+        Span::styled(
+            format!("{} {}", app.keymap.label(Action::PrevChart), app.keymap.label(Action::NextChart)),
+            theme.key_style(),
+        ),
         Span::raw(": Switch Chart  "),
-        Span::styled("Esc", Style::default().fg(Color::Red)),
+        Span::styled(
+            format!("{} {}", app.keymap.label(Action::PrevRange), app.keymap.label(Action::NextRange)),
+            theme.key_style(),
+        ),
+        Span::raw(": Date Range  "),
+        Span::styled(app.keymap.label(Action::Legend), theme.key_style()),
+        Span::raw(": Legend  "),
+        Span::styled(app.keymap.label(Action::Percent), theme.key_style()),
+        Span::raw(if app.chart_percent { ": Counts  " } else { ": Percent  " }),
+        Span::styled(app.keymap.label(Action::Report), theme.key_style()),
+        Span::raw(": Markdown Report  "),
+        Span::styled(app.keymap.label(Action::ExportChart), theme.key_style()),
+        Span::raw(": Export SVG  "),
+        Span::styled(app.keymap.label(Action::Palette), theme.key_style()),
+        Span::raw(": Commands  "),
+        Span::styled(app.keymap.label(Action::Help), theme.key_style()),
+        Span::raw(": Help  "),
+        Span::styled(app.keymap.label(Action::Cancel), theme.error_style()),
         Span::raw(": Back to List"),
     ];
 
@@ -0,0 +1,166 @@
+use crate::app::{App, DashboardData, DashboardEntry, DashboardSection};
+use crate::keymap::Action;
+use crate::text;
+use crate::ui::list;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+/// Render the dashboard: this week against the goal, then the pipeline, upcoming interviews,
+/// follow-ups due and recent applications in a grid
+pub fn render(frame: &mut Frame, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0), Constraint::Length(3)])
+        .split(frame.area());
+    let data = app.dashboard_data();
+
+    render_week(frame, app, &data, chunks[0]);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(chunks[1]);
+    let mut areas = Vec::new();
+    for row in rows.iter() {
+        areas.extend(
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+                .split(*row)
+                .iter()
+                .copied(),
+        );
+    }
+    for (&section, &area) in DashboardSection::all().iter().zip(&areas) {
+        render_section(frame, app, &data, section, area);
+    }
+
+    render_help(frame, app, chunks[2]);
+}
+
+/// "7 applied this week", with the goal gauge beside it when a goal is set
+fn render_week(frame: &mut Frame, app: &App, data: &DashboardData, area: Rect) {
+    let block = Block::default().borders(Borders::ALL).title("Dashboard");
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if data.goal.is_some() { 36 } else { 0 }),
+        ])
+        .split(inner_area);
+    let week = Span::styled(
+        format!("{} applied this week", app.activity().this_week),
+        app.config.theme.title_style().add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(Paragraph::new(Line::from(week)), chunks[0]);
+    if let Some(goal) = data.goal {
+        list::render_goal_gauge(frame, app, goal, chunks[1]);
+    }
+}
+
+fn render_section(frame: &mut Frame, app: &App, data: &DashboardData, section: DashboardSection, area: Rect) {
+    let theme = &app.config.theme;
+    let active = app.dashboard.section == section;
+    let mut title_style = theme.title_style();
+    if active {
+        title_style = title_style.add_modifier(Modifier::BOLD | Modifier::REVERSED);
+    }
+    let title = match data.entries(section) {
+        Some(entries) => format!(" {} ({}) ", section.title(), entries.len()),
+        None => format!(" {} ", section.title()),
+    };
+    let mut block = Block::default().borders(Borders::ALL).title(Span::styled(title, title_style));
+    if active {
+        block = block.border_style(theme.accent_style());
+    }
+
+    let width = area.width.saturating_sub(2) as usize;
+    let items = match data.entries(section) {
+        None => pipeline_items(app, data, width),
+        Some(entries) => entries.iter().map(|entry| entry_item(app, entry, width)).collect(),
+    };
+    if items.is_empty() {
+        let placeholder = match section {
+            DashboardSection::Interviews => "No interviews in the next week",
+            DashboardSection::FollowUps => "No follow-ups due",
+            DashboardSection::Pipeline | DashboardSection::Recent => "No applications",
+        };
+        frame.render_widget(Paragraph::new(placeholder).style(theme.muted_style()).block(block), area);
+        return;
+    }
+
+    // Only the focused section shows its selection
+    let row = app.dashboard.row.min(items.len() - 1);
+    let list = List::new(items).block(block).highlight_style(theme.selection_style());
+    let mut state = ListState::default().with_selected(active.then_some(row));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// A short bar per status, in the status color, with its count after it
+fn pipeline_items(app: &App, data: &DashboardData, width: usize) -> Vec<ListItem<'static>> {
+    let theme = &app.config.theme;
+    let label_width = data
+        .pipeline
+        .iter()
+        .map(|(status, _)| status.as_str().chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(width / 3);
+    let count_width = data.pipeline.iter().map(|(_, count)| count.to_string().len()).max().unwrap_or(0);
+    let bar_space = width.saturating_sub(label_width + count_width + 2) as u64;
+    let max = data.pipeline.iter().map(|(_, count)| *count).max().unwrap_or(0).max(1);
+    data.pipeline
+        .iter()
+        .map(|(status, count)| {
+            // Every non-zero count gets at least one cell so it doesn't vanish next to a big one
+            let cells = match *count {
+                0 => 0,
+                count => ((count * bar_space + max / 2) / max).max(1) as usize,
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{:<width$} ", text::truncate(status.as_str(), label_width), width = label_width)),
+                Span::styled(theme.status_swatch(status).repeat(cells), theme.status_style(status)),
+                Span::raw(format!(" {}", count)),
+            ]))
+        })
+        .collect()
+}
+
+/// "2024-05-14  Acme Corp  Technical"
+fn entry_item(app: &App, entry: &DashboardEntry, width: usize) -> ListItem<'static> {
+    let theme = &app.config.theme;
+    let company = app.applications[entry.index].company_name.trim();
+    let room = width.saturating_sub(12 + entry.detail.chars().count() + 2);
+    ListItem::new(Line::from(vec![
+        Span::styled(format!("{}  ", entry.date), theme.muted_style()),
+        Span::raw(text::truncate(company, room)),
+        Span::styled(format!("  {}", entry.detail), theme.muted_style()),
+    ]))
+}
+
+fn render_help(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let help_text = vec![
+        Span::styled("Tab/Shift+Tab", theme.key_style()),
+        Span::raw(": Section  "),
+        Span::styled(format!("{}/{}", app.keymap.label(Action::Up), app.keymap.label(Action::Down)), theme.key_style()),
+        Span::raw(": Row  "),
+        Span::styled("Enter", theme.key_style()),
+        Span::raw(": Open  "),
+        Span::styled(app.keymap.label(Action::Help), theme.key_style()),
+        Span::raw(": Help  "),
+        Span::styled(format!("l/{}", app.keymap.label(Action::Cancel)), theme.error_style()),
+        Span::raw(": List"),
+    ];
+    let help = Paragraph::new(Line::from(help_text))
+        .block(Block::default().borders(Borders::ALL).title("Help"));
+    frame.render_widget(help, area);
+}
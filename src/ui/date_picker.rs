@@ -0,0 +1,111 @@
+use crate::dates::{self, WeekStart};
+use crate::theme::Theme;
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Month grid popup for choosing a date with the keyboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DatePicker {
+    /// Highlighted day
+    pub date: NaiveDate,
+}
+
+impl DatePicker {
+    pub fn new(date: NaiveDate) -> Self {
+        Self { date }
+    }
+
+    /// Move the highlight by `days`, backwards when negative
+    pub fn move_days(&mut self, days: i64) {
+        if let Some(date) = self.date.checked_add_signed(Duration::days(days)) {
+            self.date = date;
+        }
+    }
+
+    /// Same day `months` away, or that month's last day when it's shorter
+    pub fn move_months(&mut self, months: i32) {
+        let moved = if months < 0 {
+            self.date.checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.date.checked_add_months(Months::new(months as u32))
+        };
+        if let Some(date) = moved {
+            self.date = date;
+        }
+    }
+}
+
+/// Width of a day cell, e.g. " 7 "
+const CELL_WIDTH: u16 = 3;
+
+/// Month, weekday names, six weeks and the help line, inside the border
+const ROWS: u16 = 9;
+
+/// Render the calendar centered on the screen, titled with the field it fills in
+pub fn render(frame: &mut Frame, theme: &Theme, picker: &DatePicker, title: &str, starts_on: WeekStart, today: NaiveDate) {
+    let screen = frame.area();
+    let width = (CELL_WIDTH * 7 + 2).max(title.chars().count() as u16 + 4).min(screen.width);
+    let height = (ROWS + 2).min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::styled(picker.date.format("%B %Y").to_string(), theme.title_style().add_modifier(Modifier::BOLD)),
+        Line::styled(weekday_header(starts_on), theme.muted_style()),
+    ];
+
+    let first = picker.date.with_day(1).unwrap_or(picker.date);
+    let mut day = dates::week_start(first, starts_on);
+    for _ in 0..6 {
+        let mut cells = Vec::new();
+        for _ in 0..7 {
+            let text = if dates::same_month(day, first) {
+                format!("{:>2} ", day.day())
+            } else {
+                "   ".to_string()
+            };
+            let style = if day == picker.date {
+                theme.selection_style()
+            } else if day == today {
+                theme.accent_style().add_modifier(Modifier::BOLD)
+            } else {
+                theme.text_style()
+            };
+            cells.push(Span::styled(text, style));
+            day += Duration::days(1);
+        }
+        lines.push(Line::from(cells));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("[ ]", theme.key_style()),
+        Span::raw(" month "),
+        Span::styled("t", theme.key_style()),
+        Span::raw(" today"),
+    ]));
+
+    let calendar = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(Block::default().title(title.to_string()).borders(Borders::ALL).style(theme.accent_style()));
+    frame.render_widget(calendar, area);
+}
+
+/// "Mo Tu We Th Fr Sa Su", starting on the configured day
+fn weekday_header(starts_on: WeekStart) -> String {
+    let names = match starts_on {
+        WeekStart::Monday => ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+        WeekStart::Sunday => ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"],
+    };
+    names.map(|name| format!("{} ", name)).concat()
+}
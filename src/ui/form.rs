@@ -1,22 +1,35 @@
+use super::centered_rect;
 use crate::app::{App, FormField, FormMode};
-use crate::models::{Platform, Status};
+use crate::dates;
+use crate::keymap::Action;
+use crate::models::{self, Application, WorkMode};
+use crate::paths;
+use crate::text;
+use crate::theme::Theme;
+use chrono::NaiveDate;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
 /// Render the form view
-pub fn render(frame: &mut Frame, app: &App) {
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(100)])
-        .split(frame.area());
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let screen = frame.area();
 
-    // Center the form
-    let form_area = centered_rect(60, 80, chunks[0]);
+    // Center the form; when the fields don't fit, use the full height with compact rows
+    let mut form_area = centered_rect(60, 80, screen);
+    let full_height: u16 = FormField::all()
+        .iter()
+        .map(|&field| field_height(app, field, false, u16::MAX))
+        .sum();
+    let summary_height = if app.form_errors.is_empty() { 0 } else { 1 };
+    let compact = full_height + summary_height + HELP_HEIGHT + 2 > form_area.height;
+    if compact {
+        form_area = centered_rect(60, 100, screen);
+    }
 
     // Title
     let title = match app.form_mode {
@@ -28,141 +41,383 @@ pub fn render(frame: &mut Frame, app: &App) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .style(Style::default().fg(Color::Cyan));
+        .style(app.config.theme.title_style());
 
     let inner_area = block.inner(form_area);
     frame.render_widget(block, form_area);
 
-    // Split inner area for fields and help
+    // Split inner area for fields, the error summary and help; the help line always keeps its row
+    let help_height = if compact { 1 } else { HELP_HEIGHT };
     let inner_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(summary_height),
+            Constraint::Length(help_height),
+        ])
         .split(inner_area);
 
     // Render form fields
-    render_fields(frame, app, inner_chunks[0]);
+    render_fields(frame, app, inner_chunks[0], compact);
+
+    render_error_summary(frame, app, inner_chunks[1]);
 
     // Render help
-    render_form_help(frame, inner_chunks[1]);
+    render_form_help(frame, app, inner_chunks[2]);
 }
 
-fn render_fields(frame: &mut Frame, app: &App, area: Rect) {
-    // Adjust constraints based on active field to give dropdowns more space
-    let platform_height = if app.form_field == FormField::Platform { 7 } else { 3 };
-    let resume_modified_height = if app.form_field == FormField::ResumeModified { 5 } else { 3 };
-    let status_height = if app.form_field == FormField::Status { 7 } else { 3 };
+/// Rows reserved for the help line outside compact mode
+const HELP_HEIGHT: u16 = 3;
 
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3), // Company Name
-            Constraint::Length(platform_height), // Platform
-            Constraint::Length(resume_modified_height), // Resume Modified
-            Constraint::Length(3), // Resume Version
-            Constraint::Length(status_height), // Status
-            Constraint::Length(3), // Date
-            Constraint::Length(5), // Notes (multi-line)
-        ])
-        .split(area);
-
-    // Company Name
-    render_text_field(
-        frame,
-        chunks[0],
-        "Company Name",
-        &app.form_data.company_name,
-        app.form_field == FormField::CompanyName,
-    );
+/// Rows the Notes field needs at minimum
+const NOTES_MIN_HEIGHT: u16 = 5;
 
-    // Platform
-    if app.form_field == FormField::Platform {
-        render_dropdown_field(
-            frame,
-            chunks[1],
-            "Platform",
-            Platform::presets(),
-            app.platform_dropdown_selected,
-        );
-    } else {
-        render_text_field(
-            frame,
-            chunks[1],
-            "Platform",
-            &app.form_data.platform.as_str(),
-            false,
-        );
+/// Rows a field takes; expanded dropdowns are capped at half of `available` in compact mode
+fn field_height(app: &App, field: FormField, compact: bool, available: u16) -> u16 {
+    let active = app.form_field == field;
+    let dropdown = |full: u16| {
+        if compact {
+            full.min((available / 2).max(3))
+        } else {
+            full
+        }
+    };
+    match field {
+        FormField::WorkMode if active => dropdown(7),
+        FormField::Platform if active => dropdown(app.platform_presets().len().min(9) as u16 + 3),
+        FormField::Interest if active => dropdown(models::MAX_INTEREST as u16 + 4),
+        FormField::ResumeModified | FormField::CoverLetter | FormField::Referral if active => dropdown(5),
+        // Scrolls past 9 statuses so custom statuses don't crowd out the other fields
+        FormField::Status if active => dropdown(app.status_order().len().min(9) as u16 + 3),
+        FormField::Notes => NOTES_MIN_HEIGHT,
+        // The second row holds validation errors
+        _ if compact => 2,
+        _ => 3,
     }
+}
 
-    // Resume Modified
-    if app.form_field == FormField::ResumeModified {
-        render_dropdown_field(
-            frame,
-            chunks[2],
-            "Resume Modified",
-            &["Yes", "No"],
-            app.resume_modified_dropdown_selected,
-        );
-    } else {
-        render_text_field(
-            frame,
-            chunks[2],
-            "Resume Modified",
-            if app.form_data.resume_modified { "Yes" } else { "No" },
-            false,
-        );
+/// Render the fields, scrolled so the active field stays visible when they don't all fit
+fn render_fields(frame: &mut Frame, app: &mut App, area: Rect, compact: bool) {
+    let fields = FormField::all();
+    let heights: Vec<u16> = fields
+        .iter()
+        .map(|&field| field_height(app, field, compact, area.height).min(area.height))
+        .collect();
+    let total: u16 = heights.iter().sum();
+
+    // Keep the last row for the scroll indicator when the fields overflow
+    let scrolling = total > area.height;
+    let view_height = if scrolling { area.height.saturating_sub(1) } else { area.height };
+    let active = fields.iter().position(|&field| field == app.form_field).unwrap_or(0);
+
+    let mut scroll = if scrolling { app.form_scroll.min(active) } else { 0 };
+    while scroll < active && heights[scroll..=active].iter().sum::<u16>() > view_height {
+        scroll += 1;
     }
+    app.form_scroll = scroll;
 
-    // Resume Version
-    render_text_field(
-        frame,
-        chunks[3],
-        "Resume Version",
-        &app.form_data.resume_version,
-        app.form_field == FormField::ResumeVersion,
-    );
+    let mut y = area.y;
+    let mut shown = scroll;
+    for (idx, &field) in fields.iter().enumerate().skip(scroll) {
+        let remaining = area.y + view_height - y;
+        // Notes takes whatever is left, as long as its border and one line fit
+        let height = if field == FormField::Notes && remaining >= 3 {
+            remaining
+        } else {
+            heights[idx]
+        };
+        if height == 0 || height > remaining {
+            break;
+        }
+        render_field(frame, app, field, Rect { y, height, ..area });
+        y += height;
+        shown = idx + 1;
+    }
+
+    if scrolling {
+        render_scroll_indicator(frame, &app.config.theme, scroll, fields.len() - shown, Rect {
+            y: area.y + view_height,
+            height: area.height - view_height,
+            ..area
+        });
+    }
+}
+
+/// "↑ 2 more  ↓ 5 more" under the fields
+fn render_scroll_indicator(frame: &mut Frame, theme: &Theme, above: usize, below: usize, area: Rect) {
+    let mut parts = Vec::new();
+    if above > 0 {
+        parts.push(format!("↑ {} more", above));
+    }
+    if below > 0 {
+        parts.push(format!("↓ {} more", below));
+    }
+    let indicator = Paragraph::new(parts.join("  "))
+        .style(theme.muted_style())
+        .alignment(Alignment::Right);
+    frame.render_widget(indicator, area);
+}
 
-    // Status
-    if app.form_field == FormField::Status {
-        let status_options: Vec<&str> = Status::all().iter().map(|s| s.as_str()).collect();
-        render_dropdown_field(
-            frame,
-            chunks[4],
-            "Status",
-            &status_options,
-            app.status_dropdown_selected,
-        );
+fn render_field(frame: &mut Frame, app: &App, field: FormField, area: Rect) {
+    let is_selected = app.form_field == field;
+    match field {
+        FormField::CompanyName => {
+            render_text_field(frame, &app.config.theme, area, field.label(), &app.form_data.company_name, is_selected);
+            render_field_error(frame, app, field, area);
+        }
+        FormField::JobTitle => {
+            let title = app.form_data.job_title.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), title, is_selected);
+        }
+        FormField::Url => {
+            let url = app.form_data.url.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), url, is_selected);
+            render_field_error(frame, app, field, area);
+        }
+        FormField::Platform if is_selected => {
+            let presets = app.platform_presets();
+            let options: Vec<&str> = presets.iter().map(String::as_str).collect();
+            render_dropdown_field(
+                frame,
+                &app.config.theme,
+                area,
+                field.label(),
+                &options,
+                app.platform_dropdown_selected,
+            );
+        }
+        FormField::Platform => {
+            render_text_field(frame, &app.config.theme, area, field.label(), &app.form_data.platform.as_str(), false);
+        }
+        FormField::ExternalId => {
+            let id = app.form_data.external_id.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), id, is_selected);
+        }
+        FormField::Location => {
+            let location = app.form_data.location.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), location, is_selected);
+        }
+        FormField::WorkMode if is_selected => {
+            let options: Vec<&str> = WorkMode::all().iter().map(|mode| mode.as_str()).collect();
+            render_dropdown_field(frame, &app.config.theme, area, field.label(), &options, app.work_mode_dropdown_selected);
+        }
+        FormField::WorkMode => {
+            render_text_field(frame, &app.config.theme, area, field.label(), app.form_data.work_mode.as_str(), false);
+        }
+        FormField::ResumeModified if is_selected => {
+            render_dropdown_field(
+                frame,
+                &app.config.theme,
+                area,
+                field.label(),
+                &["Yes", "No"],
+                app.resume_modified_dropdown_selected,
+            );
+        }
+        FormField::ResumeModified => {
+            let value = if app.form_data.resume_modified { "Yes" } else { "No" };
+            render_text_field(frame, &app.config.theme, area, field.label(), value, false);
+        }
+        FormField::ResumeVersion => {
+            render_text_field(frame, &app.config.theme, area, field.label(), &app.form_data.resume_version, is_selected);
+        }
+        FormField::ResumeFile => {
+            // Flag a file that isn't there, but still let the form save
+            let path = app.resume_path_input.trim();
+            let value = if !path.is_empty() && !paths::expand_home(path).exists() {
+                format!("{}  (file not found)", app.resume_path_input)
+            } else {
+                app.resume_path_input.clone()
+            };
+            render_text_field(frame, &app.config.theme, area, field.label(), &value, is_selected);
+        }
+        FormField::CoverLetter if is_selected => {
+            render_dropdown_field(
+                frame,
+                &app.config.theme,
+                area,
+                field.label(),
+                &["Yes", "No"],
+                app.cover_letter_dropdown_selected,
+            );
+        }
+        FormField::CoverLetter => {
+            let value = if app.form_data.cover_letter { "Yes" } else { "No" };
+            render_text_field(frame, &app.config.theme, area, field.label(), value, false);
+        }
+        FormField::CoverLetterVersion => {
+            let version = &app.form_data.cover_letter_version;
+            render_text_field(frame, &app.config.theme, area, field.label(), version, is_selected);
+        }
+        FormField::Status if is_selected => {
+            let statuses = app.status_order();
+            let status_options: Vec<&str> = statuses.iter().map(|s| s.as_str()).collect();
+            render_dropdown_field(
+                frame,
+                &app.config.theme,
+                area,
+                field.label(),
+                &status_options,
+                app.status_dropdown_selected,
+            );
+        }
+        FormField::Status => {
+            let value = match app.form_data.rejection_reason {
+                Some(reason) => format!("{} ({})", app.form_data.status.as_str(), reason.as_str()),
+                None => app.form_data.status.as_str().to_string(),
+            };
+            render_text_field(frame, &app.config.theme, area, field.label(), &value, false);
+        }
+        FormField::Interest if is_selected => {
+            let options: Vec<String> = (0..=models::MAX_INTEREST).map(interest_label).collect();
+            let options: Vec<&str> = options.iter().map(String::as_str).collect();
+            render_dropdown_field(frame, &app.config.theme, area, field.label(), &options, app.interest_dropdown_selected);
+        }
+        FormField::Interest => {
+            render_text_field(frame, &app.config.theme, area, field.label(), &interest_label(app.form_data.interest), false);
+        }
+        FormField::Salary => {
+            render_text_field(frame, &app.config.theme, area, field.label(), &app.salary_input, is_selected);
+            render_field_error(frame, app, field, area);
+        }
+        FormField::Date => {
+            // Show what a shortcut like "-3" or "mon" resolves to before it's applied
+            let today = chrono::Local::now().date_naive();
+            let date = match dates::parse_date_input(&app.date_input, today) {
+                Ok(date) if date.to_string() != app.date_input.trim() => {
+                    format!("{}  → {}", app.date_input, date)
+                }
+                _ => app.date_input.clone(),
+            };
+            render_text_field(frame, &app.config.theme, area, field.label(), &date, is_selected);
+            render_field_error(frame, app, field, area);
+        }
+        FormField::FollowUp => {
+            let follow_up = app.form_data.follow_up.map(|date| date.to_string()).unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), &follow_up, is_selected);
+        }
+        FormField::Deadline => {
+            let today = chrono::Local::now().date_naive();
+            let deadline = match (app.form_data.deadline, app.form_data.days_to_deadline(today)) {
+                (Some(date), Some(days)) => format!("{}  ({})", date, models::countdown(days)),
+                _ => String::new(),
+            };
+            render_text_field(frame, &app.config.theme, area, field.label(), &deadline, is_selected);
+        }
+        FormField::ContactName => {
+            let name = app.form_data.contact_name.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), name, is_selected);
+        }
+        FormField::ContactEmail => {
+            let email = app.form_data.contact_email.as_deref().unwrap_or_default();
+            render_text_field(frame, &app.config.theme, area, field.label(), email, is_selected);
+            render_field_error(frame, app, field, area);
+        }
+        FormField::Referral if is_selected => {
+            render_dropdown_field(
+                frame,
+                &app.config.theme,
+                area,
+                field.label(),
+                &["Yes", "No"],
+                app.referral_dropdown_selected,
+            );
+        }
+        FormField::Referral => {
+            let value = if app.form_data.referral { "Yes" } else { "No" };
+            render_text_field(frame, &app.config.theme, area, field.label(), value, false);
+        }
+        FormField::Interviews => {
+            let today = chrono::Local::now().date_naive();
+            let mut interviews = interview_summary(&app.form_data, today);
+            if is_selected {
+                interviews.push_str(" (Enter to edit)");
+            }
+            render_text_field(frame, &app.config.theme, area, field.label(), &interviews, is_selected);
+        }
+        FormField::Notes => render_notes_field(frame, app, area),
+    }
+}
+
+/// Stars for a rating, or "Not rated"
+fn interest_label(interest: u8) -> String {
+    if interest == 0 {
+        "Not rated".to_string()
     } else {
-        render_text_field(
-            frame,
-            chunks[4],
-            "Status",
-            app.form_data.status.as_str(),
-            false,
-        );
+        models::stars(interest)
     }
+}
 
-    // Date
-    render_text_field(
-        frame,
-        chunks[5],
-        "Application Date",
-        &app.form_data.applied_date.to_string(),
-        app.form_field == FormField::Date,
-    );
+/// "None", or the number of rounds and the next upcoming one
+pub fn interview_summary(application: &Application, today: NaiveDate) -> String {
+    let count = application.interviews.len();
+    let mut summary = match count {
+        0 => return "None".to_string(),
+        1 => "1 round".to_string(),
+        _ => format!("{} rounds", count),
+    };
+    if let Some(next) = application.next_interview(today) {
+        summary.push_str(&format!(", next {} on {}", next.kind.as_str(), next.date));
+    }
+    summary
+}
 
-    // Notes
-    render_text_field(
-        frame,
-        chunks[6],
-        "Notes",
-        &app.form_data.notes,
-        app.form_field == FormField::Notes,
-    );
+/// Render the notes with wrapping, scrolled to keep the cursor line visible
+fn render_notes_field(frame: &mut Frame, app: &App, area: Rect) {
+    let is_selected = app.form_field == FormField::Notes;
+    let style = if is_selected {
+        app.config.theme.accent_style().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let block = Block::default()
+        .title(Span::styled(FormField::Notes.label(), style))
+        .borders(Borders::ALL);
+    let inner_height = block.inner(area).height as usize;
+
+    let (cursor_line, cursor_column) = app.notes_cursor_position();
+    let lines: Vec<Line> = app
+        .form_data
+        .notes
+        .split('\n')
+        .enumerate()
+        .map(|(idx, line)| {
+            if is_selected && idx == cursor_line {
+                line_with_cursor(line, cursor_column)
+            } else {
+                Line::from(line.to_string())
+            }
+        })
+        .collect();
+
+    let scroll = cursor_line.saturating_sub(inner_height.saturating_sub(1)) as u16;
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// A line of text with a block cursor drawn on grapheme `column`
+fn line_with_cursor(line: &str, column: usize) -> Line<'static> {
+    let start = text::grapheme_to_char(line, column);
+    let end = text::grapheme_to_char(line, column + 1);
+    let before: String = line.chars().take(start).collect();
+    let at: String = line.chars().skip(start).take(end - start).collect();
+    let after: String = line.chars().skip(end).collect();
+    let cursor = if at.is_empty() { " ".to_string() } else { at };
+
+    Line::from(vec![
+        Span::raw(before),
+        Span::styled(cursor, Style::default().add_modifier(Modifier::REVERSED)),
+        Span::raw(after),
+    ])
 }
 
-fn render_text_field(frame: &mut Frame, area: Rect, label: &str, value: &str, is_selected: bool) {
+fn render_text_field(frame: &mut Frame, theme: &Theme, area: Rect, label: &str, value: &str, is_selected: bool) {
     let style = if is_selected {
-        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+        theme.accent_style().add_modifier(Modifier::BOLD)
     } else {
         Style::default()
     };
@@ -179,8 +434,41 @@ fn render_text_field(frame: &mut Frame, area: Rect, label: &str, value: &str, is
     frame.render_widget(paragraph, area);
 }
 
-fn render_dropdown_field(
+/// One line listing what keeps the form from saving, e.g. "Can't save: Company Name, Salary"
+fn render_error_summary(frame: &mut Frame, app: &App, area: Rect) {
+    if app.form_errors.is_empty() {
+        return;
+    }
+    let fields: Vec<&str> = app.form_errors.iter().map(|(field, _)| field.label()).collect();
+    let summary = if let [(field, message)] = app.form_errors.as_slice() {
+        format!("Can't save: {} — {}", field.label(), message)
+    } else {
+        format!("Can't save: {} fields need fixing ({})", fields.len(), fields.join(", "))
+    };
+    let summary = Paragraph::new(summary)
+        .style(app.config.theme.error_style().add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center);
+    frame.render_widget(summary, area);
+}
+
+/// Render the validation error for `field` on the line below its value
+fn render_field_error(frame: &mut Frame, app: &App, field: FormField, area: Rect) {
+    let Some(message) = app.field_error(field) else {
+        return;
+    };
+
+    let error_area = Rect {
+        y: area.y + 1,
+        height: area.height.saturating_sub(1).min(1),
+        ..area
+    };
+    let error = Paragraph::new(message).style(app.config.theme.error_style());
+    frame.render_widget(error, error_area);
+}
+
+pub fn render_dropdown_field(
     frame: &mut Frame,
+    theme: &Theme,
     area: Rect,
     label: &str,
     options: &[&str],
@@ -191,7 +479,7 @@ fn render_dropdown_field(
         .enumerate()
         .map(|(idx, opt)| {
             let style = if idx == selected {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                theme.selection_style()
             } else {
                 Style::default()
             };
@@ -203,46 +491,41 @@ fn render_dropdown_field(
         Block::default()
             .title(format!("{} (j/k to select)", label))
             .borders(Borders::ALL)
-            .style(Style::default().fg(Color::Yellow)),
+            .style(theme.accent_style()),
     );
 
-    frame.render_widget(list, area);
+    // Stateful so a dropdown shorter than its options still scrolls to the selection
+    let mut state = ListState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(list, area, &mut state);
 }
 
-fn render_form_help(frame: &mut Frame, area: Rect) {
+fn render_form_help(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let keys = &app.keymap;
     let help_text = vec![
-        Span::styled("↑/↓", Style::default().fg(Color::Green)),
+        Span::styled(
+            format!("{}/{}", keys.label(Action::PrevField), keys.label(Action::NextField)),
+            theme.key_style(),
+        ),
         Span::raw(": Navigate Fields  "),
-        Span::styled("j/k", Style::default().fg(Color::Green)),
+        Span::styled(
+            format!("{}/{}", keys.label(Action::Up), keys.label(Action::Down)),
+            theme.key_style(),
+        ),
         Span::raw(": Select in Dropdown  "),
-        Span::styled("Enter", Style::default().fg(Color::Green)),
-        Span::raw(": Next/Save  "),
-        Span::styled("Esc", Style::default().fg(Color::Red)),
-        Span::raw(": Cancel"),
+        Span::styled("Enter", theme.key_style()),
+        Span::raw(": Next/Newline  "),
+        Span::styled(keys.label(Action::SaveForm), theme.key_style()),
+        Span::raw(": Save  "),
+        Span::styled(keys.label(Action::Help), theme.key_style()),
+        Span::raw(": Help  "),
+        Span::styled(keys.label(Action::Cancel), theme.error_style()),
+        Span::raw(": Cancel  "),
+        Span::styled("Ctrl+C", theme.error_style()),
+        Span::raw(": Quit"),
     ];
 
     let help = Paragraph::new(Line::from(help_text))
         .alignment(Alignment::Center);
     frame.render_widget(help, area);
 }
-
-/// Create a centered rect using up certain percentage of the available rect `r`
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
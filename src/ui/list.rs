@@ -1,95 +1,573 @@
-use crate::app::App;
+use crate::app::{App, CompanyGroup, ListRow, SortKey};
+use crate::columns::{Column, ListColumn};
+use crate::config::Config;
+use crate::dates;
+use crate::fuzzy;
+use crate::keymap::Action;
+use crate::goal::GoalProgress;
+use crate::models::{self, Application, Status, WorkMode};
+use crate::text;
+use chrono::NaiveDate;
+use unicode_segmentation::UnicodeSegmentation;
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, LineGauge, Paragraph, Row, Table, TableState},
     Frame,
 };
 
+/// Screen height below which the summary line is left out to make room for the table
+const SUMMARY_MIN_HEIGHT: u16 = 16;
+
 /// Render the list view
-pub fn render(frame: &mut Frame, app: &App) {
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let summary_height = if frame.area().height >= SUMMARY_MIN_HEIGHT { 1 } else { 0 };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),
+            Constraint::Length(summary_height),
             Constraint::Min(0),
             Constraint::Length(3),
         ])
         .split(frame.area());
 
     // Title
-    render_title(frame, chunks[0]);
+    render_title(frame, app, chunks[0]);
+
+    render_summary(frame, app, chunks[1]);
 
     // Table
-    render_table(frame, app, chunks[1]);
+    render_table(frame, app, chunks[2]);
 
     // Help text
-    render_help(frame, chunks[2]);
+    render_help(frame, app, chunks[3]);
+}
+
+/// One line of counts for the listed applications, e.g. "87 total · 52 applied · 20 interview
+/// · 4 this week", with each status in its color. "This week" is over everything sent, like the
+/// title's. Segments are dropped from the end until the line fits, so the total stays longest.
+fn render_summary(frame: &mut Frame, app: &App, area: Rect) {
+    let theme = &app.config.theme;
+    let summary = app.list_summary();
+
+    let total = Span::styled(format!("{} total", summary.total), Style::default().add_modifier(Modifier::BOLD));
+    let mut segments = vec![total];
+    if summary.attention > 0 {
+        segments.push(Span::styled(format!("{} need attention", summary.attention), theme.warning_style()));
+    }
+    for (status, count) in summary.statuses {
+        if count > 0 {
+            segments.push(Span::styled(
+                format!("{} {}", count, status.as_str().to_lowercase()),
+                theme.status_style(&status),
+            ));
+        }
+    }
+    segments.push(Span::styled(format!("{} this week", app.activity().this_week), theme.muted_style()));
+
+    // Lined up with the table's contents, inside its border
+    let area = area.inner(Margin { horizontal: 1, vertical: 0 });
+    let line = loop {
+        let mut spans = Vec::new();
+        for (idx, segment) in segments.iter().enumerate() {
+            if idx > 0 {
+                spans.push(Span::styled(" · ", theme.muted_style()));
+            }
+            spans.push(segment.clone());
+        }
+        let line = Line::from(spans);
+        if line.width() <= area.width as usize || segments.len() == 1 {
+            break line;
+        }
+        segments.pop();
+    };
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+fn render_title(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default().borders(Borders::ALL);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let goal = app.goal_progress();
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(if goal.is_some() { 36 } else { 0 }),
+        ])
+        .split(inner_area);
+
+    let mut title = vec![Span::styled(
+        "Job Application Tracker",
+        app.config.theme.title_style().add_modifier(Modifier::BOLD),
+    )];
+    if let Some(pid) = app.read_only {
+        title.push(Span::styled(
+            format!("  READ-ONLY (open in PID {})", pid),
+            app.config.theme.error_style().add_modifier(Modifier::BOLD),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(title)), chunks[0]);
+
+    if let Some(goal) = goal {
+        render_goal_gauge(frame, app, goal, chunks[1]);
+    }
 }
 
-fn render_title(frame: &mut Frame, area: Rect) {
-    let title = Paragraph::new("Job Application Tracker")
-        .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        .block(Block::default().borders(Borders::ALL));
-    frame.render_widget(title, area);
+/// One-line gauge labelled "Goal 7/10 this week"
+pub fn render_goal_gauge(frame: &mut Frame, app: &App, goal: GoalProgress, area: Rect) {
+    let theme = &app.config.theme;
+    let filled = if goal.reached() {
+        theme.status_style(&Status::Offer)
+    } else {
+        theme.series_style(0)
+    };
+    let gauge = LineGauge::default()
+        .label(format!("Goal {}", goal))
+        .ratio(goal.ratio())
+        .filled_style(filled)
+        .unfilled_style(theme.muted_style());
+    frame.render_widget(gauge, area);
 }
 
-fn render_table(frame: &mut Frame, app: &App, area: Rect) {
-    let header_cells = ["Company", "Platform", "Resume Ver", "Status", "Date"]
+fn render_table(frame: &mut Frame, app: &mut App, area: Rect) {
+    let today = chrono::Local::now().date_naive();
+    let visible = app.visible_indices();
+    // The location column only appears once some listed application has one
+    let show_location = visible.iter().any(|&idx| {
+        let application = &app.applications[idx];
+        application.location.is_some() || application.work_mode != WorkMode::Unknown
+    });
+    // Likewise for deadlines
+    let show_deadline = visible.iter().any(|&idx| app.applications[idx].deadline.is_some());
+    let columns: Vec<ListColumn> = app
+        .list_columns
+        .iter()
+        .filter(|shown| shown.column != Column::Location || show_location)
+        .filter(|shown| shown.column != Column::Deadline || show_deadline)
+        .copied()
+        .collect();
+
+    // The widths the table will give each column, so cells are cut with an ellipsis at a
+    // character boundary rather than wherever the column ends
+    let widths: Vec<Constraint> = columns.iter().map(|shown| Constraint::Fill(shown.width)).collect();
+    let cell_widths: Vec<usize> = Layout::horizontal(widths.clone())
+        .spacing(1)
+        .split(Rect::new(0, 0, area.width.saturating_sub(2), 1))
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+        .map(|rect| rect.width as usize)
+        .collect();
+
+    let header_cells = columns.iter().zip(&cell_widths).map(|(shown, &width)| {
+        let header = match shown.column {
+            Column::Date if app.config.list.relative_dates => "Age",
+            _ => shown.column.header(),
+        };
+        Cell::from(text::truncate(header, width)).style(app.config.theme.accent_style().add_modifier(Modifier::BOLD))
+    });
     let header = Row::new(header_cells)
         .style(Style::default())
         .height(1)
         .bottom_margin(1);
 
-    let rows = app.applications.iter().enumerate().map(|(idx, app_record)| {
-        let cells = vec![
-            Cell::from(app_record.company_name.clone()),
-            Cell::from(app_record.platform.as_str()),
-            Cell::from(app_record.resume_version.clone()),
-            Cell::from(app_record.status.as_str()),
-            Cell::from(app_record.applied_date.to_string()),
-        ];
+    let batch = app.batch_ids();
+    // Checkboxes only appear while marking so the list looks as usual otherwise
+    let marking = !batch.is_empty() || app.visual_anchor.is_some();
+    let list_rows = app.list_rows();
+    let rows = list_rows.iter().enumerate().map(|(idx, row)| {
+        let is_selected = idx == app.list_selected;
+        let record_idx = match row {
+            ListRow::Group(group) => return group_row(app, &columns, &cell_widths, group, is_selected),
+            ListRow::Application(index) | ListRow::Member(index) => *index,
+        };
+        let app_record = &app.applications[record_idx];
+        let marked = batch.contains(&app_record.id);
+        // Applications under a company header are indented below it
+        let indent = if matches!(row, ListRow::Member(_)) { "  " } else { "" };
+
+        let cells = columns.iter().enumerate().map(|(position, shown)| {
+            let (mut text, mut style) = cell(&app.config, shown.column, app_record, today);
+            if shown.column == Column::Status && is_selected {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            // The checkbox goes in front of whichever column comes first
+            let checkbox = if marking && position == 0 {
+                if marked { "[x] " } else { "[ ] " }
+            } else {
+                ""
+            };
+            let prefix = if position == 0 { format!("{}{}", indent, checkbox) } else { String::new() };
+            let width = cell_widths[position];
+            if shown.column == Column::Company && !app.search_query.is_empty() {
+                return Cell::from(highlight_matches(app, prefix, app_record, width)).style(style);
+            }
+            text.insert_str(0, &prefix);
+            Cell::from(text::truncate(&text, width)).style(style)
+        });
 
-        let style = if idx == app.list_selected {
-            Style::default().bg(Color::DarkGray).fg(Color::White)
+        let mut style = if is_selected {
+            app.config.theme.selection_style()
+        } else if marked {
+            app.config.theme.marked_style()
         } else {
             Style::default()
         };
+        if app_record.archived {
+            style = style.add_modifier(Modifier::DIM);
+        }
 
         Row::new(cells).style(style).height(1)
     });
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Percentage(25),
-            Constraint::Percentage(20),
-            Constraint::Percentage(15),
-            Constraint::Percentage(15),
-            Constraint::Percentage(25),
-        ],
-    )
+    let table = Table::new(rows, widths)
     .header(header)
-    .block(Block::default().borders(Borders::ALL).title("Applications"));
+    .block(Block::default().borders(Borders::ALL).title(table_title(app, visible.len(), batch.len())));
+
+    // Borders plus the header row and its margin
+    app.list_page_size = area.height.saturating_sub(4) as usize;
+    app.list_area = area;
+
+    let mut state = TableState::default()
+        .with_offset(app.list_offset)
+        .with_selected(Some(app.list_selected));
+    frame.render_stateful_widget(table, area, &mut state);
+    app.list_offset = state.offset();
+}
+
+fn table_title(app: &App, visible: usize, marked: usize) -> String {
+    let mut archived = archived_note(app);
+    if !app.search_query.is_empty() {
+        archived.push_str(&format!(" · search \"{}\"", app.search_query));
+    }
+    if let Some(mode) = app.work_mode_filter {
+        archived.push_str(&format!(" · {} only", mode.as_str()));
+    }
+    if app.attention_filter {
+        archived.push_str(" · needing attention");
+    }
+    if app.sort != SortKey::Added {
+        archived.push_str(&format!(" · by {}", app.sort.label()));
+    }
+    if marked > 0 {
+        archived.push_str(&format!(" · {} marked", marked));
+    }
+    if app.grouped {
+        archived.push_str(" · grouped by company");
+    }
+    if let Some(status) = &app.status_filter {
+        return format!("Applications — {} ({}){}", status.as_str(), visible, archived);
+    }
+    if visible == 0 {
+        return format!("Applications{}", archived);
+    }
 
-    frame.render_widget(table, area);
+    let this_week = app.activity().this_week;
+
+    // Header rows make the selected row a poor position among the applications
+    if app.grouped {
+        return format!("Applications ({}) — {} this week{}", visible, this_week, archived);
+    }
+    format!(
+        "Applications ({}/{}) — {} this week{}",
+        app.list_selected + 1,
+        visible,
+        this_week,
+        archived
+    )
+}
+
+/// " · 3 archived hidden" or " · archived shown", empty when nothing is archived
+fn archived_note(app: &App) -> String {
+    let archived = app.applications.iter().filter(|a| a.archived).count();
+    if archived == 0 {
+        String::new()
+    } else if app.show_archived {
+        " · archived shown".to_string()
+    } else {
+        format!(" · {} archived hidden", archived)
+    }
 }
 
-fn render_help(frame: &mut Frame, area: Rect) {
+/// Header row of a company group: the company and its count in the company column (or
+/// the first one), and the furthest status reached in the status column
+fn group_row<'a>(
+    app: &App,
+    columns: &[ListColumn],
+    widths: &[usize],
+    group: &CompanyGroup,
+    is_selected: bool,
+) -> Row<'a> {
+    let theme = &app.config.theme;
+    let label_column = columns
+        .iter()
+        .position(|shown| shown.column == Column::Company)
+        .unwrap_or(0);
+    let cells = columns.iter().enumerate().map(|(position, shown)| {
+        if position == label_column {
+            let arrow = if group.collapsed { "▸" } else { "▾" };
+            let label = format!("{} {} ({})", arrow, group.company, group.count);
+            Cell::from(text::truncate(&label, widths[position]))
+                .style(Style::default().add_modifier(Modifier::BOLD))
+        } else if shown.column == Column::Status {
+            Cell::from(group.best.as_str().to_string())
+                .style(theme.status_style(&group.best).add_modifier(Modifier::BOLD))
+        } else {
+            Cell::from("")
+        }
+    });
+    let style = if is_selected { theme.selection_style() } else { Style::default() };
+    Row::new(cells).style(style).height(1)
+}
+
+/// Company cell with the characters matching the search query highlighted, cut to `width`
+/// columns like the other cells
+fn highlight_matches(app: &App, prefix: String, application: &Application, width: usize) -> Line<'static> {
+    let positions = fuzzy::score(&app.search_query, &application.company_name)
+        .map(|m| m.positions)
+        .unwrap_or_default();
+    let highlight = app.config.theme.accent_style().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+    let suffix = if application.referral { " ★" } else { "" };
+    let full = format!("{}{}{}", prefix, application.company_name, suffix);
+    let cut = text::truncate(&full, width);
+    // Characters of the full text still shown; the cut ends in an ellipsis when it's shorter
+    let shown = if cut == full { usize::MAX } else { cut.chars().count().saturating_sub(1) };
+
+    // One span per grapheme so an accent or emoji sequence isn't split across spans
+    let prefix_len = prefix.chars().count();
+    let mut spans = Vec::new();
+    let mut idx = 0;
+    for grapheme in full.graphemes(true) {
+        if idx >= shown {
+            break;
+        }
+        let chars = idx..idx + grapheme.chars().count();
+        let matched = chars.clone().any(|idx| idx >= prefix_len && positions.contains(&(idx - prefix_len)));
+        if matched {
+            spans.push(Span::styled(grapheme.to_string(), highlight));
+        } else {
+            spans.push(Span::raw(grapheme.to_string()));
+        }
+        idx = chars.end;
+    }
+    if shown != usize::MAX && !cut.is_empty() {
+        spans.push(Span::raw(text::ELLIPSIS));
+    }
+    Line::from(spans)
+}
+
+/// Location and work mode, e.g. "Berlin · Hybrid"; an unknown mode is left out
+fn location_cell(application: &Application) -> String {
+    let mode = (application.work_mode != WorkMode::Unknown).then(|| application.work_mode.as_str());
+    match (application.location.as_deref(), mode) {
+        (Some(location), Some(mode)) => format!("{} · {}", location, mode),
+        (Some(location), None) => location.to_string(),
+        (None, Some(mode)) => mode.to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+/// Round count and the date of the next upcoming round, e.g. "2 · 10-20"
+fn interview_cell(application: &Application, today: NaiveDate) -> String {
+    match (application.interviews.len(), application.next_interview(today)) {
+        (0, _) => String::new(),
+        (count, Some(next)) => format!("{} · {}", count, next.date.format("%m-%d")),
+        (count, None) => count.to_string(),
+    }
+}
+
+/// Text and style of one column for an application
+fn cell(config: &Config, column: Column, application: &Application, today: NaiveDate) -> (String, Style) {
+    let text = match column {
+        Column::Company if application.referral => format!("{} ★", application.company_name),
+        Column::Company => application.company_name.clone(),
+        Column::JobTitle => application.job_title.clone().unwrap_or_default(),
+        Column::Platform => application.platform.as_str().to_string(),
+        Column::Location => location_cell(application),
+        Column::ResumeVersion if application.resume_missing() => format!("{} ⚠", application.resume_version),
+        Column::ResumeVersion => application.resume_version.clone(),
+        // Still Applied long after applying: most likely a silent rejection
+        Column::Status if config.ghosting.is_ghosted(application, today) => "Ghosted?".to_string(),
+        Column::Status => application.status.as_str().to_string(),
+        Column::Interest => models::stars(application.interest),
+        Column::Salary => application.salary.as_ref().map(|s| s.compact()).unwrap_or_default(),
+        Column::Interviews => interview_cell(application, today),
+        Column::Date => return date_cell(config, application, today),
+        Column::Deadline => return deadline_cell(config, application, today),
+    };
+    let style = match column {
+        Column::Status if config.ghosting.is_ghosted(application, today) => config.theme.warning_style(),
+        Column::Status => config.theme.status_style(&application.status),
+        Column::ResumeVersion if application.resume_missing() => config.theme.warning_style(),
+        _ => Style::default(),
+    };
+    (text, style)
+}
+
+/// Applied date, marked with its age when past the status aging threshold, or only the age
+/// when the list shows relative dates
+fn date_cell(config: &Config, application: &Application, today: NaiveDate) -> (String, Style) {
+    if config.list.relative_dates {
+        let style = match config.aging.staleness(application, today) {
+            Some(_) => config.theme.warning_style(),
+            None => Style::default(),
+        };
+        return (dates::format_relative(application.applied_date, today), style);
+    }
+    match config.aging.staleness(application, today) {
+        Some(stale) => (
+            format!("{} ({}d)", application.applied_date, stale.days),
+            config.theme.warning_style(),
+        ),
+        None => (application.applied_date.to_string(), Style::default()),
+    }
+}
+
+/// Time left until the deadline, more urgent colors as it gets closer
+fn deadline_cell(config: &Config, application: &Application, today: NaiveDate) -> (String, Style) {
+    let Some(days) = application.days_to_deadline(today) else {
+        return (String::new(), Style::default());
+    };
+    let style = match days {
+        days if days < 0 => config.theme.muted_style(),
+        0..=2 => config.theme.error_style(),
+        3..=7 => config.theme.warning_style(),
+        _ => Style::default(),
+    };
+    (models::countdown(days), style)
+}
+
+fn render_help(frame: &mut Frame, app: &App, area: Rect) {
+    let keys = &app.keymap;
+    let theme = &app.config.theme;
+    let key = |action, style| Span::styled(keys.label(action), style);
+    if app.search_typing {
+        let help_text = vec![
+            Span::raw("Search: "),
+            Span::styled(format!("{}█", app.search_query), theme.accent_style()),
+            Span::raw("  "),
+            Span::styled("↑/↓", theme.key_style()),
+            Span::raw(": Navigate  "),
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Keep Results  "),
+            Span::styled("Esc", theme.error_style()),
+            Span::raw(": Clear"),
+        ];
+        let help = Paragraph::new(Line::from(help_text))
+            .block(Block::default().borders(Borders::ALL).title("Help — search"));
+        frame.render_widget(help, area);
+        return;
+    }
+    if let Some(jump) = app.active_jump() {
+        let mut help_text = vec![
+            Span::raw("Jump: "),
+            Span::styled(format!("{}█", jump.prefix), theme.accent_style()),
+            Span::raw("  "),
+        ];
+        // Keys typed after the jump key all go to the prefix until Enter or Esc
+        if jump.explicit {
+            help_text.extend([
+                Span::styled("Enter", theme.key_style()),
+                Span::raw("/"),
+                Span::styled("Esc", theme.key_style()),
+                Span::raw(": Done"),
+            ]);
+        } else {
+            help_text.extend([
+                key(Action::JumpNext, theme.key_style()),
+                Span::raw("/"),
+                key(Action::JumpPrev, theme.key_style()),
+                Span::raw(": Next/Previous Match"),
+            ]);
+        }
+        let help = Paragraph::new(Line::from(help_text))
+            .block(Block::default().borders(Borders::ALL).title("Help — jump"));
+        frame.render_widget(help, area);
+        return;
+    }
+    if app.visual_anchor.is_some() || !app.marked.is_empty() {
+        let help_text = vec![
+            key(Action::Mark, theme.key_style()),
+            Span::raw(": Mark  "),
+            key(Action::VisualSelect, theme.key_style()),
+            Span::raw(if app.visual_anchor.is_some() { ": Keep Range  " } else { ": Select Range  " }),
+            key(Action::Delete, theme.key_style()),
+            Span::raw(": Delete Marked  "),
+            key(Action::QuickStatus, theme.key_style()),
+            Span::raw(": Status of Marked  "),
+            key(Action::Cancel, theme.error_style()),
+            Span::raw(": Clear Marks"),
+        ];
+        let help = Paragraph::new(Line::from(help_text))
+            .block(Block::default().borders(Borders::ALL).title("Help — marking"));
+        frame.render_widget(help, area);
+        return;
+    }
     let help_text = vec![
-        Span::raw("↑/↓/j/k: Navigate  "),
-        Span::styled("a", Style::default().fg(Color::Green)),
+        Span::raw(format!(
+            "{}/{}: Navigate  ",
+            keys.label(Action::Up),
+            keys.label(Action::Down)
+        )),
+        Span::styled(
+            format!("{}/{}", keys.label(Action::Top), keys.label(Action::Bottom)),
+            theme.key_style(),
+        ),
+        Span::raw(": Top/Bottom  "),
+        key(Action::Add, theme.key_style()),
         Span::raw(": Add  "),
-        Span::styled("e", Style::default().fg(Color::Green)),
+        key(Action::Templates, theme.key_style()),
+        Span::raw(": Templates  "),
+        key(Action::Edit, theme.key_style()),
         Span::raw(": Edit  "),
-        Span::styled("d", Style::default().fg(Color::Green)),
+        key(Action::Archive, theme.key_style()),
+        Span::raw(": Archive  "),
+        key(Action::ShowArchived, theme.key_style()),
+        Span::raw(": Show Archived  "),
+        key(Action::Delete, theme.key_style()),
         Span::raw(": Delete  "),
-        Span::styled("g", Style::default().fg(Color::Green)),
+        key(Action::Deleted, theme.key_style()),
+        Span::raw(": Trash  "),
+        key(Action::Mark, theme.key_style()),
+        Span::raw(": Mark  "),
+        key(Action::VisualSelect, theme.key_style()),
+        Span::raw(": Select Range  "),
+        key(Action::QuickStatus, theme.key_style()),
+        Span::raw(": Status  "),
+        key(Action::OpenUrl, theme.key_style()),
+        Span::raw(": Open URL  "),
+        key(Action::Chart, theme.key_style()),
         Span::raw(": Charts  "),
-        Span::styled("q", Style::default().fg(Color::Red)),
+        key(Action::ReadMode, theme.key_style()),
+        Span::raw(": Read Mode  "),
+        Span::styled(
+            format!(
+                "{}-{}/{}",
+                keys.label(Action::FilterApplied),
+                keys.label(Action::FilterRejected),
+                keys.label(Action::ClearFilter)
+            ),
+            theme.key_style(),
+        ),
+        Span::raw(": Filter  "),
+        key(Action::Search, theme.key_style()),
+        Span::raw(": Search  "),
+        key(Action::SearchNotes, theme.key_style()),
+        Span::raw(": Search Notes  "),
+        key(Action::Jump, theme.key_style()),
+        Span::raw(": Jump  "),
+        key(Action::Group, theme.key_style()),
+        Span::raw(": Group  "),
+        key(Action::FilterWorkMode, theme.key_style()),
+        Span::raw(": Work Mode  "),
+        key(Action::Palette, theme.key_style()),
+        Span::raw(": Commands  "),
+        key(Action::Help, theme.key_style()),
+        Span::raw(": Help  "),
+        key(Action::Quit, theme.error_style()),
         Span::raw(": Quit"),
     ];
 
@@ -1,22 +1,410 @@
 pub mod list;
 pub mod form;
 pub mod chart;
+pub mod narrate;
+pub mod board;
+pub mod dashboard;
+pub mod popup;
+pub mod date_picker;
 
-use crate::app::{App, View};
+use crate::app::{App, MessageKind, View};
+use crate::theme::Theme;
 use ratatui::{
     backend::Backend,
-    Terminal,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    widgets::{Clear, Paragraph, Wrap},
+    Frame, Terminal,
 };
 use anyhow::Result;
 
+/// Smallest screen every view still fits on; the form scrolls its fields down to this height
+pub const MIN_WIDTH: u16 = 80;
+pub const MIN_HEIGHT: u16 = 24;
+
 /// Main UI rendering function
-pub fn render<B: Backend>(terminal: &mut Terminal<B>, app: &App) -> Result<()> {
+pub fn render<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()> {
     terminal.draw(|frame| {
+        let screen = frame.area();
+        if screen.width < MIN_WIDTH || screen.height < MIN_HEIGHT {
+            // Nothing on screen to click until it's big enough again
+            app.list_area = Rect::default();
+            render_too_small(frame, &app.config.theme, screen);
+            return;
+        }
+
         match app.view {
             View::List => list::render(frame, app),
             View::Form => form::render(frame, app),
             View::Chart => chart::render(frame, app),
+            View::Narrate => narrate::render(frame, app),
+            View::Board => board::render(frame, app),
+            View::Dashboard => dashboard::render(frame, app),
+        }
+
+        if let Some(editor) = &app.interview_editor {
+            popup::render_interview_editor(frame, app, editor);
+        }
+
+        if let Some((target, picker)) = &app.date_picker {
+            let today = chrono::Local::now().date_naive();
+            date_picker::render(frame, &app.config.theme, picker, target.label(), app.config.week_starts_on, today);
+        }
+
+        if let Some(log) = &app.activity_log {
+            popup::render_activity_log(frame, app, log);
+        }
+
+        if let Some(selected) = app.status_picker {
+            popup::render_status_picker(frame, app, selected);
+        }
+
+        if let Some(picker) = &app.rejection_picker {
+            popup::render_rejection_picker(frame, app, picker);
+        }
+
+        if let Some(selected) = app.copy_picker {
+            popup::render_copy_picker(frame, app, selected);
+        }
+
+        if let Some(popup) = app.company_popup.as_mut() {
+            popup::render_company_popup(frame, &app.config.theme, popup);
+        }
+
+        if let Some(selected) = app.trash_picker {
+            popup::render_trash(frame, app, selected);
+        }
+
+        if let Some(selected) = app.column_picker {
+            popup::render_column_picker(frame, app, selected);
+        }
+
+        if let Some(search) = &app.notes_search {
+            popup::render_notes_search(frame, app, search);
+        }
+
+        if let Some(picker) = &app.template_picker {
+            popup::render_template_picker(frame, app, picker);
+        }
+
+        if let Some(palette) = &app.palette {
+            popup::render_palette(frame, app, palette);
+        }
+
+        if let Some(name) = &app.template_name {
+            popup::render_template_name(frame, &app.config.theme, name);
+        }
+
+        if let Some(corrupt) = &app.corrupt {
+            popup::render_corrupt(frame, &app.config.theme, corrupt);
+        }
+
+        if let Some((message, kind, _)) = &app.message {
+            render_status_bar(frame, &app.config.theme, message, *kind);
+        }
+
+        if let Some(prompt) = &app.prompt {
+            popup::render_prompt(frame, &app.config.theme, prompt);
+        }
+
+        if app.help_open {
+            popup::render_help(frame, app);
+        }
+
+        // Drawn last so it stays on top of every view and popup
+        if let Some(summary) = &app.today_summary {
+            popup::render_today_summary(frame, &app.config.theme, summary);
         }
     })?;
     Ok(())
 }
+
+/// Show a message over the bottom border of the screen, in red for errors
+fn render_status_bar(frame: &mut Frame, theme: &Theme, message: &str, kind: MessageKind) {
+    let text = format!(" {} ", message);
+    let screen = frame.area();
+    let area = Rect {
+        x: screen.x + 1,
+        y: screen.bottom().saturating_sub(1),
+        width: (text.chars().count() as u16).min(screen.width.saturating_sub(2)),
+        height: screen.height.min(1),
+    };
+    let style = match kind {
+        MessageKind::Info => theme.info_style(),
+        MessageKind::Error => theme.error_style(),
+    };
+    frame.render_widget(Clear, area);
+    let text = Paragraph::new(text)
+        .style(style.add_modifier(Modifier::BOLD));
+    frame.render_widget(text, area);
+}
+
+/// Placeholder shown instead of views that would be cut off
+fn render_too_small(frame: &mut Frame, theme: &Theme, screen: Rect) {
+    let text = format!(
+        "Terminal too small: {}x{}, need at least {}x{}",
+        screen.width, screen.height, MIN_WIDTH, MIN_HEIGHT
+    );
+    let height = screen.height.min(2);
+    let area = Rect {
+        y: screen.y + (screen.height - height) / 2,
+        height,
+        ..screen
+    };
+    let text = Paragraph::new(text)
+        .style(theme.warning_style())
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(text, area);
+}
+
+/// Create a centered rect using up certain percentage of the available rect `r`
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(100u16.saturating_sub(percent_y) / 2),
+            Constraint::Percentage(percent_y.min(100)),
+            Constraint::Percentage(100u16.saturating_sub(percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(100u16.saturating_sub(percent_x) / 2),
+            Constraint::Percentage(percent_x.min(100)),
+            Constraint::Percentage(100u16.saturating_sub(percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{ChartType, FormField};
+    use crate::config::Config;
+    use crate::models::{Application, Platform, Status, WorkMode};
+    use crate::storage::{Data, JsonStorage, DATA_FILE};
+    use chrono::{Days, NaiveDate};
+    use crate::theme::{Palette, Theme};
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::style::Color;
+    use ratatui::symbols::bar;
+    use tempfile::TempDir;
+
+    fn days_ago(days: u64) -> NaiveDate {
+        chrono::Local::now().date_naive() - Days::new(days)
+    }
+
+    fn applications() -> Vec<Application> {
+        vec![
+            Application {
+                id: uuid::Uuid::from_u128(1),
+                company_name: "Acme".to_string(),
+                job_title: Some("Backend Engineer".to_string()),
+                status: Status::Interview,
+                platform: Platform::LinkedIn,
+                work_mode: WorkMode::Remote,
+                interest: 4,
+                applied_date: days_ago(0),
+                ..Application::new()
+            },
+            Application {
+                id: uuid::Uuid::from_u128(2),
+                company_name: "Globex".to_string(),
+                job_title: Some("Platform Engineer".to_string()),
+                platform: Platform::Indeed,
+                resume_version: "v2".to_string(),
+                applied_date: days_ago(12),
+                ..Application::new()
+            },
+            Application {
+                id: uuid::Uuid::from_u128(3),
+                company_name: "Initech".to_string(),
+                job_title: Some("Software Engineer".to_string()),
+                status: Status::Rejected,
+                platform: Platform::CompanyWebsite,
+                referral: true,
+                applied_date: days_ago(40),
+                ..Application::new()
+            },
+        ]
+    }
+
+    fn app() -> (TempDir, App) {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = JsonStorage::new(dir.path().join(DATA_FILE));
+        let data = Data { applications: applications(), ..Data::default() };
+        let app = App::with_data(Config::default(), Box::new(storage), data, Vec::new()).unwrap();
+        (dir, app)
+    }
+
+    /// Snapshots at the smallest supported screen and a roomier one
+    const SIZES: [(u16, u16); 2] = [(MIN_WIDTH, MIN_HEIGHT), (120, 40)];
+
+    /// Render `app` at each size and compare with the stored snapshots, with dates masked
+    /// since the fixtures are relative to today
+    fn assert_screens(name: &str, app: &mut App) {
+        for (width, height) in SIZES {
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            super::render(&mut terminal, app).unwrap();
+            insta::with_settings!({ filters => vec![(r"\d{4}-\d{2}-\d{2}", "YYYY-MM-DD")] }, {
+                insta::assert_snapshot!(format!("{}_{}x{}", name, width, height), terminal.backend());
+            });
+        }
+    }
+
+    #[test]
+    fn list() {
+        let (_dir, mut app) = app();
+        assert_screens("list", &mut app);
+    }
+
+    #[test]
+    fn add_form_with_each_field_focused() {
+        let (_dir, mut app) = app();
+        app.start_add();
+        for &field in FormField::all() {
+            app.form_field = field;
+            assert_screens(&format!("add_form_{:?}", field), &mut app);
+        }
+    }
+
+    #[test]
+    fn edit_form_with_each_field_focused() {
+        let (_dir, mut app) = app();
+        app.start_edit();
+        for &field in FormField::all() {
+            app.form_field = field;
+            assert_screens(&format!("edit_form_{:?}", field), &mut app);
+        }
+    }
+
+    #[test]
+    fn every_chart() {
+        let (_dir, mut app) = app();
+        app.show_chart();
+        for &chart in ChartType::all() {
+            app.chart_type = chart;
+            assert_screens(&format!("chart_{:?}", chart), &mut app);
+        }
+    }
+
+    #[test]
+    fn list_title_counts_this_week_from_the_stats_cache() {
+        let (_dir, mut app) = app();
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        super::render(&mut terminal, &mut app).unwrap();
+        assert!(terminal.backend().to_string().contains("Applications (1/3) — 1 this week"));
+
+        // Changed behind the cache's back: a recount would find two this week
+        app.applications[1].applied_date = days_ago(0);
+        app.select_next();
+        super::render(&mut terminal, &mut app).unwrap();
+        assert!(terminal.backend().to_string().contains("Applications (2/3) — 1 this week"));
+    }
+
+    #[test]
+    fn title_and_summary_line_agree_on_this_week_under_a_filter() {
+        let (_dir, mut app) = app();
+        // Only Globex is listed, and it wasn't sent this week; Acme was
+        app.search_query = "Globex".to_string();
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        super::render(&mut terminal, &mut app).unwrap();
+        let screen = terminal.backend().to_string();
+        assert!(screen.contains("1 total · 1 applied · 1 this week"), "{}", screen);
+        assert!(screen.contains("Applications (1/1) — 1 this week"), "{}", screen);
+    }
+
+    /// Render the status chart with its legend open under `palette`
+    fn status_chart(palette: Palette) -> Buffer {
+        let (_dir, mut app) = app();
+        app.config.theme.palette = palette;
+        app.show_chart();
+        app.chart_type = ChartType::ByStatus;
+        app.chart_legend = true;
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        super::render(&mut terminal, &mut app).unwrap();
+        terminal.backend().buffer().clone()
+    }
+
+    fn has_cell(buffer: &Buffer, symbol: &str, fg: Color) -> bool {
+        buffer.content().iter().any(|cell| cell.symbol() == symbol && cell.fg == fg)
+    }
+
+    /// Colors of the cells drawn with a bar or swatch fill
+    fn fill_colors(buffer: &Buffer) -> Vec<Color> {
+        let fills = ["█", "▓", "▒", "░", "▚", "▞"];
+        buffer.content().iter().filter(|cell| fills.contains(&cell.symbol())).map(|cell| cell.fg).collect()
+    }
+
+    #[test]
+    fn colorblind_palette_reaches_the_chart() {
+        let buffer = status_chart(Palette::Colorblind);
+        let theme = Theme { palette: Palette::Colorblind, ..Theme::default() };
+        for status in [Status::Applied, Status::Interview, Status::Rejected] {
+            let color = theme.status_style(&status).fg.unwrap();
+            assert!(has_cell(&buffer, bar::FULL, color), "no {:?} bar in {:?}", status, color);
+        }
+        // The default palette's red/green pair is gone from bars and swatches
+        let colors = fill_colors(&buffer);
+        assert!(!colors.contains(&Color::Red) && !colors.contains(&Color::Green), "{:?}", colors);
+    }
+
+    #[test]
+    fn no_color_palette_draws_each_status_with_its_own_fill() {
+        let buffer = status_chart(Palette::None);
+        let theme = Theme { palette: Palette::None, ..Theme::default() };
+        let statuses = [Status::Applied, Status::Interview, Status::Rejected];
+        let fills: Vec<&str> = statuses.iter().map(|status| theme.status_swatch(status)).collect();
+        for (status, fill) in statuses.iter().zip(&fills) {
+            assert!(has_cell(&buffer, fill, Color::Reset), "no {} fill for {:?}", fill, status);
+        }
+        let mut unique = fills.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), fills.len(), "statuses share a fill: {:?}", fills);
+        // Nothing on the bars or swatches depends on color
+        assert!(fill_colors(&buffer).iter().all(|&color| color == Color::Reset));
+    }
+
+    /// Every field in turn stays on screen with the help line, including heights below the
+    /// minimum that the form's own compact layout still has to handle
+    #[test]
+    fn form_keeps_the_active_field_visible_at_short_heights() {
+        for height in [15, 20, 30] {
+            let (_dir, mut app) = app();
+            app.start_edit();
+            let mut terminal = Terminal::new(TestBackend::new(MIN_WIDTH, height)).unwrap();
+            for (step, &field) in FormField::all().iter().enumerate() {
+                if step > 0 {
+                    app.next_field();
+                }
+                assert_eq!(app.form_field, field);
+                terminal.draw(|frame| form::render(frame, &mut app)).unwrap();
+                let screen = terminal.backend().to_string();
+                // "Label:" on text fields, or a box titled "Label (j/k to select)" or "Label"
+                let label = field.label();
+                let shown = [format!("│{}:", label), format!("┌{} (", label), format!("┌{}─", label)]
+                    .iter()
+                    .any(|start| screen.contains(start));
+                assert!(shown, "{} not visible at height {}:\n{}", label, height, screen);
+                assert!(screen.contains("Navigate Fields"), "help missing at height {}:\n{}", height, screen);
+            }
+        }
+    }
+
+    #[test]
+    fn screens_below_the_minimum_show_a_placeholder() {
+        let (_dir, mut app) = app();
+        app.start_edit();
+        for height in [15, 20] {
+            let mut terminal = Terminal::new(TestBackend::new(MIN_WIDTH, height)).unwrap();
+            super::render(&mut terminal, &mut app).unwrap();
+            let screen = terminal.backend().to_string();
+            assert!(screen.contains("too small"), "{}", screen);
+        }
+    }
+}
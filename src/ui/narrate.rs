@@ -0,0 +1,111 @@
+use crate::app::{App, ListRow};
+use crate::keymap::Action;
+use crate::narrate;
+use crate::paths;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the linear view: summary sentences and one application per screen
+pub fn render(frame: &mut Frame, app: &App) {
+    let theme = &app.config.theme;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+
+    let summary = Paragraph::new(narrate::summary(app.applications.iter(), &app.status_order()).join(" "))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title("Summary"));
+    frame.render_widget(summary, chunks[0]);
+
+    render_application(frame, app, chunks[1]);
+
+    let help_text = vec![
+        Span::styled("←/→", theme.key_style()),
+        Span::raw(": Previous/Next  "),
+        Span::styled(app.keymap.label(Action::OpenResume), theme.key_style()),
+        Span::raw(": Open Resume  "),
+        Span::styled(app.keymap.label(Action::Activity), theme.key_style()),
+        Span::raw(": Activity  "),
+        Span::styled(app.keymap.label(Action::ExportApplication), theme.key_style()),
+        Span::raw(": Export  "),
+        Span::styled(app.keymap.label(Action::Palette), theme.key_style()),
+        Span::raw(": Commands  "),
+        Span::styled(app.keymap.label(Action::Help), theme.key_style()),
+        Span::raw(": Help  "),
+        Span::styled(app.keymap.label(Action::Cancel), theme.error_style()),
+        Span::raw(": Back to List"),
+    ];
+    let help = Paragraph::new(Line::from(help_text))
+        .block(Block::default().borders(Borders::ALL).title("Help"));
+    frame.render_widget(help, chunks[2]);
+}
+
+fn render_application(frame: &mut Frame, app: &App, area: Rect) {
+    let rows = app.list_rows();
+    let index = match rows.get(app.list_selected) {
+        Some(ListRow::Group(group)) => {
+            let sentence = Paragraph::new(narrate::describe_group(group))
+                .style(Style::default().add_modifier(Modifier::BOLD))
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title("Company"));
+            frame.render_widget(sentence, area);
+            return;
+        }
+        Some(row) => row.index().unwrap_or_default(),
+        None => {
+            let empty = Paragraph::new("No applications.")
+                .block(Block::default().borders(Borders::ALL));
+            frame.render_widget(empty, area);
+            return;
+        }
+    };
+
+    // Count applications only, so company headers in the grouped list don't skew the position
+    let today = chrono::Local::now().date_naive();
+    let position = rows[..=app.list_selected].iter().filter(|row| row.index().is_some()).count();
+    let total = rows.iter().filter(|row| row.index().is_some()).count();
+    let title = format!("Application {} of {}", position, total);
+    let application = &app.applications[index];
+    let mut lines = vec![Line::from(narrate::describe(application, today))];
+    if let Some(path) = &application.resume_path {
+        lines.push(Line::from(""));
+        if application.resume_missing() {
+            lines.push(Line::styled(
+                format!("Resume file not found: {}", paths::contract_home(path)),
+                app.config.theme.warning_style(),
+            ));
+        } else {
+            lines.push(Line::from(format!("Resume file: {}", paths::contract_home(path))));
+        }
+    }
+    if let Some(stale) = app.config.aging.staleness(application, today) {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(format!("Stale: {}.", stale), app.config.theme.warning_style()));
+    }
+    if let Some(reason) = app.needs_attention(application.id) {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(format!("Needs attention: {}.", reason), app.config.theme.warning_style()));
+    }
+    if !application.activity.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from("Activity:"));
+        for entry in &application.activity {
+            lines.push(Line::from(format!("{}  {}", entry.date, entry.text)));
+        }
+    }
+    let sentence = Paragraph::new(lines)
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(title));
+    frame.render_widget(sentence, area);
+}
@@ -0,0 +1,852 @@
+use super::{centered_rect, form};
+use crate::app::{
+    ActivityDraft, ActivityField, ActivityLog, App, CompanyPopup, CopyField, InterviewDraft, InterviewEditor, InterviewField, NotesSearch, Palette, Prompt, PromptAction, RejectionPicker, TemplatePicker, TodaySummary, View,
+};
+use crate::columns::Column;
+use crate::keymap::{Action, Binding, HelpKey, BINDINGS};
+use crate::models::{RejectionReason, WorkMode, TRASH_DAYS};
+use crate::storage::CorruptFile;
+use crate::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render a yes/no prompt centered over the current view
+pub fn render_prompt(frame: &mut Frame, theme: &Theme, prompt: &Prompt) {
+    // Grow past the usual size when the message wraps onto more lines, e.g. save errors
+    let screen = frame.area();
+    let mut area = centered_rect(50, 20, screen);
+    let inner_width = area.width.saturating_sub(2).max(1) as usize;
+    let message_lines = prompt.message.chars().count().div_ceil(inner_width) as u16;
+    if message_lines + 4 > area.height {
+        area = list_popup_area(screen, 50, message_lines as usize + 2);
+    }
+    frame.render_widget(Clear, area);
+
+    // Warnings about what's being saved stand out from routine questions
+    let (title, style) = match prompt.action {
+        PromptAction::SaveDespiteWarnings => ("Warning", theme.warning_style()),
+        _ => ("Confirm", theme.accent_style()),
+    };
+    let block = Block::default().title(title).borders(Borders::ALL).style(style);
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner_area);
+
+    let message = Paragraph::new(prompt.message.as_str())
+        .style(theme.text_style().add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(message, chunks[0]);
+
+    let help_text = vec![
+        Span::styled("y", theme.key_style()),
+        Span::raw(": Yes  "),
+        Span::styled("n", theme.error_style()),
+        Span::raw(": No"),
+    ];
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the choices for starting over from a data file that can't be parsed
+pub fn render_corrupt(frame: &mut Frame, theme: &Theme, corrupt: &CorruptFile) {
+    let name = corrupt.path.file_name().unwrap_or_default().to_string_lossy();
+    let recovered = corrupt.recovered.applications.len();
+    let found = if corrupt.lost == 0 {
+        format!("{} application(s) could still be read.", recovered)
+    } else {
+        format!("{} application(s) could still be read; {} record(s) are damaged.", recovered, corrupt.lost)
+    };
+    let mut lines = vec![
+        Line::styled(corrupt.reason.as_str(), theme.error_style()),
+        Line::from(""),
+        Line::from(found),
+        Line::from(""),
+    ];
+    if let Some(backup) = &corrupt.backup {
+        let backup = backup.file_name().unwrap_or_default().to_string_lossy();
+        lines.push(Line::from(vec![
+            Span::styled("b", theme.key_style()),
+            Span::raw(format!(": Open the newest backup, {}", backup)),
+        ]));
+    }
+    if recovered > 0 {
+        lines.push(Line::from(vec![
+            Span::styled("r", theme.key_style()),
+            Span::raw(": Start with the recovered applications"),
+        ]));
+    }
+    lines.push(Line::from(vec![Span::styled("e", theme.key_style()), Span::raw(": Start empty")]));
+    lines.push(Line::from(vec![
+        Span::styled("q", theme.error_style()),
+        Span::raw(": Quit without changing anything"),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!("Before starting over, the file is moved to {}.corrupt-<date and time>.", name)));
+
+    let area = list_popup_area(frame.area(), 70, lines.len() + 2);
+    frame.render_widget(Clear, area);
+    let block = Block::default()
+        .title(format!("Can't read {}", name))
+        .borders(Borders::ALL)
+        .style(theme.accent_style());
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }).block(block), area);
+}
+
+/// Render the quick status picker centered over the list
+pub fn render_status_picker(frame: &mut Frame, app: &App, selected: usize) {
+    let statuses = app.status_order();
+    let options: Vec<&str> = statuses.iter().map(|s| s.as_str()).collect();
+    let area = list_popup_area(frame.area(), 40, options.len());
+    let marked = app.batch_ids().len();
+    let title = if marked > 0 {
+        format!("Change Status of {} Marked", marked)
+    } else {
+        "Change Status".to_string()
+    };
+
+    frame.render_widget(Clear, area);
+    form::render_dropdown_field(frame, &app.config.theme, area, &title, &options, selected);
+}
+
+/// Render the rejection reason picker
+pub fn render_rejection_picker(frame: &mut Frame, app: &App, picker: &RejectionPicker) {
+    let options: Vec<&str> = RejectionReason::all().iter().map(|reason| reason.as_str()).collect();
+    let area = list_popup_area(frame.area(), 40, options.len());
+    let title = match &picker.ids {
+        Some(ids) if ids.len() > 1 => format!("Why Were {} Rejected? Esc skips", ids.len()),
+        _ => "Why Rejected? Esc skips".to_string(),
+    };
+
+    frame.render_widget(Clear, area);
+    form::render_dropdown_field(frame, &app.config.theme, area, &title, &options, picker.selected);
+}
+
+/// Render the copy picker, showing each field's value so empty ones stand out
+pub fn render_copy_picker(frame: &mut Frame, app: &App, selected: usize) {
+    let application = app.selected_index().map(|idx| &app.applications[idx]);
+    let options: Vec<String> = CopyField::all()
+        .iter()
+        .map(|field| {
+            let value = application.and_then(|application| field.value(application)).unwrap_or("(empty)");
+            format!("{}: {}", field.label(), value.lines().next().unwrap_or_default())
+        })
+        .collect();
+    let options: Vec<&str> = options.iter().map(String::as_str).collect();
+    let area = list_popup_area(frame.area(), 60, options.len());
+
+    frame.render_widget(Clear, area);
+    form::render_dropdown_field(frame, &app.config.theme, area, "Copy", &options, selected);
+}
+
+/// Render the "applied today" counts in a small box at the top right
+pub fn render_today_summary(frame: &mut Frame, theme: &Theme, summary: &TodaySummary) {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(summary.today.to_string(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" applied today"),
+        ]),
+        Line::from(format!("{} this week", summary.this_week)),
+    ];
+    if let Some(goal) = summary.goal {
+        let style = if goal.reached() {
+            theme.key_style()
+        } else {
+            Style::default()
+        };
+        lines.push(Line::styled(format!("Goal: {}", goal), style));
+    }
+    lines.push(Line::from(format!(
+        "Last: {}",
+        summary.last_company.as_deref().unwrap_or("none yet")
+    )));
+
+    let screen = frame.area();
+    let width = 34.min(screen.width);
+    let area = Rect {
+        x: screen.x + screen.width - width,
+        y: screen.y,
+        width,
+        height: (lines.len() as u16 + 2).min(screen.height),
+    };
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Today")
+        .borders(Borders::ALL)
+        .style(theme.title_style());
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Render the bindings of the current view, then the ones that work everywhere
+pub fn render_help(frame: &mut Frame, app: &App) {
+    let theme = &app.config.theme;
+    let label = |binding: &Binding| match binding.key {
+        HelpKey::Action(action) => app.keymap.label(action),
+        HelpKey::Fixed(key) => key.to_string(),
+    };
+    let in_view: Vec<&Binding> = BINDINGS.iter().filter(|b| b.views.contains(&app.view)).collect();
+    let global: Vec<&Binding> = BINDINGS.iter().filter(|b| b.is_global()).collect();
+    let key_width = in_view.iter().chain(&global).map(|b| label(b).chars().count()).max().unwrap_or(0);
+
+    let heading = theme.accent_style().add_modifier(Modifier::BOLD);
+    let entry = |binding: &&Binding| {
+        Line::from(vec![
+            Span::styled(
+                format!("{:>width$}  ", label(binding), width = key_width),
+                theme.key_style(),
+            ),
+            Span::raw(binding.description),
+        ])
+    };
+    let mut lines = vec![Line::styled(view_title(app.view), heading)];
+    lines.extend(in_view.iter().map(entry));
+    lines.push(Line::raw(""));
+    lines.push(Line::styled("Everywhere", heading));
+    lines.extend(global.iter().map(entry));
+
+    // Split into two columns when one doesn't fit on the screen
+    let screen = frame.area();
+    let columns = if lines.len() + 2 > screen.height as usize { 2 } else { 1 };
+    let rows = lines.len().div_ceil(columns);
+    let area = list_popup_area(screen, if columns == 2 { 90 } else { 60 }, rows);
+    frame.render_widget(Clear, area);
+
+    let close = format!(" {} or {}: Close ", app.keymap.label(Action::Help), app.keymap.label(Action::Cancel));
+    let block = Block::default()
+        .title("Keys")
+        .title_bottom(Line::from(close).alignment(Alignment::Right))
+        .borders(Borders::ALL)
+        .style(theme.title_style());
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, columns as u32); columns])
+        .split(inner_area);
+    for (column, chunk) in lines.chunks(rows).enumerate() {
+        let text = Paragraph::new(chunk.to_vec()).style(theme.text_style());
+        frame.render_widget(text, halves[column]);
+    }
+}
+
+fn view_title(view: View) -> &'static str {
+    match view {
+        View::List => "List",
+        View::Form => "Form",
+        View::Chart => "Charts",
+        View::Narrate => "Read Mode",
+        View::Board => "Board",
+        View::Dashboard => "Dashboard",
+    }
+}
+
+/// Render the summary of one company's applications, scrolled to `popup.scroll`, which is
+/// kept from going past the last line
+pub fn render_company_popup(frame: &mut Frame, theme: &Theme, popup: &mut CompanyPopup) {
+    let summary = &popup.summary;
+    let count = match summary.total() {
+        1 => "1 application".to_string(),
+        total => format!("{} applications", total),
+    };
+    let dates = if summary.first_applied == summary.last_applied {
+        format!(" on {}", summary.last_applied)
+    } else {
+        format!(", {} to {}", summary.first_applied, summary.last_applied)
+    };
+    let mut reached = vec![Span::raw("Reached: ")];
+    for (position, (status, count)) in summary.reached.iter().enumerate() {
+        if position > 0 {
+            reached.push(Span::raw(", "));
+        }
+        reached.push(Span::styled(status.as_str().to_string(), theme.status_style(status)));
+        reached.push(Span::raw(format!(" {}", count)));
+    }
+    let versions: Vec<String> = summary
+        .resume_versions
+        .iter()
+        .map(|(version, count)| format!("{} ×{}", version, count))
+        .collect();
+
+    let mut lines = vec![
+        Line::from(Span::styled(count + &dates, Style::default().add_modifier(Modifier::BOLD))),
+        Line::from(reached),
+        Line::from(format!("Resume versions: {}", versions.join(", "))),
+    ];
+    for application in &summary.applications {
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::raw(format!("{}  ", application.applied_date)),
+            Span::styled(application.status.as_str().to_string(), theme.status_style(&application.status)),
+            Span::raw(format!("  {}", application.job_title.as_deref().unwrap_or("No title"))),
+        ]));
+        if let Some(note) = &application.note {
+            lines.push(Line::styled(format!("  {}", note), theme.muted_style()));
+        }
+    }
+
+    let area = list_popup_area(frame.area(), 60, lines.len() + 1);
+    frame.render_widget(Clear, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let visible = chunks[0].height.saturating_sub(2);
+    let total_lines = lines.len();
+    let max_scroll = (total_lines as u16).saturating_sub(visible);
+    popup.scroll = popup.scroll.min(max_scroll);
+    let block = Block::default()
+        .title(summary.company.clone())
+        .borders(Borders::ALL)
+        .style(theme.accent_style());
+    let text = Paragraph::new(lines)
+        .style(theme.text_style())
+        .block(block)
+        .scroll((popup.scroll, 0));
+    frame.render_widget(text, chunks[0]);
+
+    let mut help_text = vec![
+        Span::styled("j/k", theme.key_style()),
+        Span::raw(": Scroll  "),
+        Span::styled("Esc", theme.error_style()),
+        Span::raw(": Close"),
+    ];
+    if max_scroll > 0 {
+        help_text.push(Span::styled(
+            format!("  Line {} of {}", popup.scroll + 1, total_lines),
+            theme.muted_style(),
+        ));
+    }
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the trash, most recent deletion first
+pub fn render_trash(frame: &mut Frame, app: &App, selected: usize) {
+    let theme = &app.config.theme;
+    let labels: Vec<String> = app
+        .trash
+        .iter()
+        .rev()
+        .map(|trashed| {
+            let application = &trashed.application;
+            format!(
+                "{} ({}), deleted {}",
+                application.company_name,
+                application.status.as_str(),
+                trashed.deleted_at.with_timezone(&chrono::Local).format("%Y-%m-%d")
+            )
+        })
+        .collect();
+    let options: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let area = list_popup_area(frame.area(), 50, options.len() + 1);
+
+    frame.render_widget(Clear, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    let title = format!("Trash (kept {} days)", TRASH_DAYS);
+    form::render_dropdown_field(frame, &app.config.theme, chunks[0], &title, &options, selected);
+
+    let help_text = vec![
+        Span::styled("r", theme.key_style()),
+        Span::raw(": Restore  "),
+        Span::styled("R", theme.key_style()),
+        Span::raw(": Restore All  "),
+        Span::styled("D", theme.key_style()),
+        Span::raw(": Delete Forever  "),
+        Span::styled("Esc", theme.error_style()),
+        Span::raw(": Close"),
+    ];
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the saved templates with what each one fills in, the one being renamed as typed
+pub fn render_template_picker(frame: &mut Frame, app: &App, picker: &TemplatePicker) {
+    let theme = &app.config.theme;
+    let labels: Vec<String> = app
+        .templates
+        .iter()
+        .enumerate()
+        .map(|(idx, template)| match &picker.renaming {
+            Some(name) if idx == picker.selected => format!("{}█", name),
+            _ => {
+                let mut parts = vec![template.platform.as_str().to_string()];
+                if !template.resume_version.is_empty() {
+                    parts.push(format!("resume {}", template.resume_version));
+                }
+                if template.work_mode != WorkMode::Unknown {
+                    parts.push(template.work_mode.as_str().to_string());
+                }
+                format!("{} — {}", template.name, parts.join(", "))
+            }
+        })
+        .collect();
+    let options: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let area = list_popup_area(frame.area(), 50, options.len() + 1);
+
+    frame.render_widget(Clear, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    form::render_dropdown_field(frame, theme, chunks[0], "Templates", &options, picker.selected);
+
+    let help_text = if picker.renaming.is_some() {
+        vec![
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Rename  "),
+            Span::styled("Esc", theme.error_style()),
+            Span::raw(": Cancel"),
+        ]
+    } else {
+        vec![
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Add Application  "),
+            Span::styled("r", theme.key_style()),
+            Span::raw(": Rename  "),
+            Span::styled(app.keymap.label(Action::Delete), theme.key_style()),
+            Span::raw(": Delete  "),
+            Span::styled(app.keymap.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Close"),
+        ]
+    };
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the name prompt for saving the form as a template
+pub fn render_template_name(frame: &mut Frame, theme: &Theme, name: &str) {
+    let area = list_popup_area(frame.area(), 50, 2);
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Save as Template")
+        .borders(Borders::ALL)
+        .style(theme.accent_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .split(inner);
+    let input = Line::from(vec![
+        Span::styled("Name: ", theme.text_style()),
+        Span::styled(format!("{}█", name), theme.accent_style()),
+    ]);
+    frame.render_widget(Paragraph::new(input), chunks[0]);
+
+    let help_text = vec![
+        Span::styled("Enter", theme.key_style()),
+        Span::raw(": Save  "),
+        Span::styled("Esc", theme.error_style()),
+        Span::raw(": Cancel"),
+    ];
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the list column picker, checking the columns that are shown
+pub fn render_column_picker(frame: &mut Frame, app: &App, selected: usize) {
+    let labels: Vec<String> = Column::all()
+        .iter()
+        .map(|column| {
+            let shown = app.list_columns.iter().any(|c| c.column == *column);
+            format!("{} {}", if shown { "[x]" } else { "[ ]" }, column.header())
+        })
+        .collect();
+    let options: Vec<&str> = labels.iter().map(String::as_str).collect();
+    let area = list_popup_area(frame.area(), 40, options.len() + 1);
+
+    frame.render_widget(Clear, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+    form::render_dropdown_field(frame, &app.config.theme, chunks[0], "List Columns", &options, selected);
+
+    let help_text = vec![
+        Span::styled(app.keymap.label(Action::Mark), app.config.theme.key_style()),
+        Span::raw(": Show/Hide  "),
+        Span::styled(app.keymap.label(Action::Cancel), app.config.theme.error_style()),
+        Span::raw(": Done"),
+    ];
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the notes search: the query, then each matching application with its snippet
+pub fn render_notes_search(frame: &mut Frame, app: &App, search: &NotesSearch) {
+    let theme = &app.config.theme;
+    let area = centered_rect(70, 70, frame.area());
+    frame.render_widget(Clear, area);
+
+    let results = app.notes_search_results();
+    let title = match results.len() {
+        _ if search.query.is_empty() => "Search Notes".to_string(),
+        1 => "Search Notes — 1 result".to_string(),
+        count => format!("Search Notes — {} results", count),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .style(theme.accent_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let query = if search.typing {
+        Span::styled(format!("{}█", search.query), theme.accent_style())
+    } else {
+        Span::styled(search.query.as_str(), theme.text_style())
+    };
+    frame.render_widget(Paragraph::new(Line::from(vec![Span::raw("Notes: "), query])), chunks[0]);
+
+    if results.is_empty() {
+        let hint = if search.query.is_empty() {
+            "Type to search the notes of every application"
+        } else {
+            "No notes match"
+        };
+        frame.render_widget(Paragraph::new(hint).style(theme.muted_style()), chunks[1]);
+    } else {
+        let items: Vec<ListItem> = results
+            .iter()
+            .enumerate()
+            .map(|(idx, (index, snippet))| {
+                let application = &app.applications[*index];
+                let selected = !search.typing && idx == search.selected;
+                let hit = if selected {
+                    Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                } else {
+                    theme.warning_style().add_modifier(Modifier::BOLD)
+                };
+                let chars: Vec<char> = snippet.text.chars().collect();
+                let before: String = chars[..snippet.highlight.start].iter().collect();
+                let matched: String = chars[snippet.highlight.clone()].iter().collect();
+                let after: String = chars[snippet.highlight.end..].iter().collect();
+
+                let mut heading = vec![
+                    Span::styled(application.company_name.clone(), theme.title_style()),
+                    Span::styled(format!("  {}", application.applied_date), theme.muted_style()),
+                ];
+                if application.archived {
+                    heading.push(Span::styled("  archived", theme.muted_style()));
+                }
+                let item = ListItem::new(vec![
+                    Line::from(heading),
+                    Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(before, theme.text_style()),
+                        Span::styled(matched, hit),
+                        Span::styled(after, theme.text_style()),
+                    ]),
+                ]);
+                if selected {
+                    item.style(theme.selection_style())
+                } else {
+                    item
+                }
+            })
+            .collect();
+        let mut state = ListState::default().with_selected((!search.typing).then_some(search.selected));
+        frame.render_stateful_widget(List::new(items), chunks[1], &mut state);
+    }
+
+    let keys = &app.keymap;
+    let help_text = if search.typing {
+        vec![
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Browse Results  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Close"),
+        ]
+    } else {
+        vec![
+            Span::styled(
+                format!("{}/{}", keys.label(Action::Down), keys.label(Action::Up)),
+                theme.key_style(),
+            ),
+            Span::raw(": Move  "),
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Edit  "),
+            Span::styled(keys.label(Action::Search), theme.key_style()),
+            Span::raw(": Change Query  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Close"),
+        ]
+    };
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Render the command palette: the query, then each matching action with its keys
+pub fn render_palette(frame: &mut Frame, app: &App, palette: &Palette) {
+    let theme = &app.config.theme;
+    let area = centered_rect(60, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Command Palette")
+        .borders(Borders::ALL)
+        .style(theme.accent_style());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let query = Span::styled(format!("{}█", palette.query), theme.accent_style());
+    frame.render_widget(Paragraph::new(Line::from(vec![Span::raw(":"), query])), chunks[0]);
+
+    let results = app.palette_results();
+    if results.is_empty() {
+        frame.render_widget(Paragraph::new("No matching command").style(theme.muted_style()), chunks[1]);
+    } else {
+        let width = chunks[1].width as usize;
+        let items: Vec<ListItem> = results
+            .iter()
+            .map(|(action, description)| {
+                let keys = app.keymap.label(*action);
+                let gap = width.saturating_sub(description.chars().count() + keys.chars().count()).max(1);
+                ListItem::new(Line::from(vec![
+                    Span::styled(*description, theme.text_style()),
+                    Span::raw(" ".repeat(gap)),
+                    Span::styled(keys, theme.key_style()),
+                ]))
+            })
+            .collect();
+        let mut state = ListState::default().with_selected(Some(palette.selected));
+        let list = List::new(items).highlight_style(theme.selection_style());
+        frame.render_stateful_widget(list, chunks[1], &mut state);
+    }
+
+    let help = Paragraph::new(Line::from(vec![
+        Span::styled("↑/↓", theme.key_style()),
+        Span::raw(": Move  "),
+        Span::styled("Enter", theme.key_style()),
+        Span::raw(": Run  "),
+        Span::styled("Esc", theme.error_style()),
+        Span::raw(": Close"),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+/// Render the form's interview rounds, with the round being added below them
+pub fn render_interview_editor(frame: &mut Frame, app: &App, editor: &InterviewEditor) {
+    let theme = &app.config.theme;
+    let area = centered_rect(60, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title("Interview Rounds")
+        .borders(Borders::ALL)
+        .style(theme.title_style());
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let draft_height = if editor.draft.is_some() { 5 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(draft_height),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    let interviews = &app.form_data.interviews;
+    let rounds: Vec<ListItem> = if interviews.is_empty() {
+        vec![ListItem::new("No interview rounds yet.")]
+    } else {
+        interviews
+            .iter()
+            .enumerate()
+            .map(|(idx, interview)| {
+                let mut text = format!("{:<10} {}", interview.kind.as_str(), interview.date);
+                if !interview.notes.is_empty() {
+                    text.push_str(&format!("  {}", interview.notes));
+                }
+                let style = if idx == editor.selected && editor.draft.is_none() {
+                    theme.selection_style()
+                } else {
+                    theme.text_style()
+                };
+                ListItem::new(text).style(style)
+            })
+            .collect()
+    };
+    frame.render_widget(List::new(rounds), chunks[0]);
+
+    let keys = &app.keymap;
+    let help_text = if let Some(draft) = &editor.draft {
+        render_interview_draft(frame, &app.config.theme, draft, chunks[1]);
+        vec![
+            Span::styled("←/→", theme.key_style()),
+            Span::raw(": Kind  "),
+            Span::styled("Tab", theme.key_style()),
+            Span::raw(": Next Field  "),
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Add  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Discard"),
+        ]
+    } else {
+        vec![
+            Span::styled(keys.label(Action::Add), theme.key_style()),
+            Span::raw(": Add  "),
+            Span::styled(keys.label(Action::Delete), theme.key_style()),
+            Span::raw(": Remove  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Done"),
+        ]
+    };
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+fn render_interview_draft(frame: &mut Frame, theme: &Theme, draft: &InterviewDraft, area: Rect) {
+    let field = |field: InterviewField, label: &str, value: String| {
+        let style = if draft.field == field {
+            theme.accent_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Line::from(vec![Span::styled(format!("{}: ", label), style), Span::raw(value)])
+    };
+
+    let mut lines = vec![
+        field(InterviewField::Kind, "Kind", format!("< {} >", draft.kind.as_str())),
+        field(InterviewField::Date, "Date", draft.date.clone()),
+        field(InterviewField::Notes, "Notes", draft.notes.clone()),
+    ];
+    if let Some(error) = &draft.error {
+        lines.push(Line::styled(error.as_str(), theme.error_style()));
+    }
+
+    let draft = Paragraph::new(lines).block(Block::default().borders(Borders::TOP).title("New Round"));
+    frame.render_widget(draft, area);
+}
+
+/// Render an application's activity log, with the entry being typed below it
+pub fn render_activity_log(frame: &mut Frame, app: &App, log: &ActivityLog) {
+    let Some(index) = app.activity_log_index() else {
+        return;
+    };
+    let application = &app.applications[index];
+    let theme = &app.config.theme;
+    let area = centered_rect(70, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!("Activity: {}", application.company_name.trim()))
+        .borders(Borders::ALL)
+        .style(theme.title_style());
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let draft_height = if log.draft.is_some() { 4 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(draft_height),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    if application.activity.is_empty() {
+        frame.render_widget(Paragraph::new("Nothing logged yet.").style(theme.muted_style()), chunks[0]);
+    } else {
+        let items: Vec<ListItem> = application
+            .activity
+            .iter()
+            .map(|entry| ListItem::new(format!("{}  {}", entry.date, entry.text)).style(theme.text_style()))
+            .collect();
+        let list = List::new(items).highlight_style(theme.selection_style());
+        let mut state = ListState::default().with_selected(log.draft.is_none().then_some(log.selected));
+        frame.render_stateful_widget(list, chunks[0], &mut state);
+    }
+
+    let keys = &app.keymap;
+    let help_text = if let Some(draft) = &log.draft {
+        render_activity_draft(frame, theme, draft, chunks[1]);
+        vec![
+            Span::styled("Tab", theme.key_style()),
+            Span::raw(": Date/Text  "),
+            Span::styled("Enter", theme.key_style()),
+            Span::raw(": Save  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Discard"),
+        ]
+    } else {
+        vec![
+            Span::styled(keys.label(Action::Add), theme.key_style()),
+            Span::raw(": Add  "),
+            Span::styled(keys.label(Action::Edit), theme.key_style()),
+            Span::raw(": Edit  "),
+            Span::styled(keys.label(Action::Delete), theme.key_style()),
+            Span::raw(": Remove  "),
+            Span::styled(keys.label(Action::Cancel), theme.error_style()),
+            Span::raw(": Done"),
+        ]
+    };
+    let help = Paragraph::new(Line::from(help_text)).alignment(Alignment::Center);
+    frame.render_widget(help, chunks[2]);
+}
+
+fn render_activity_draft(frame: &mut Frame, theme: &Theme, draft: &ActivityDraft, area: Rect) {
+    let field = |field: ActivityField, label: &str, value: &str| {
+        let style = if draft.field == field {
+            theme.accent_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        Line::from(vec![Span::styled(format!("{}: ", label), style), Span::raw(value.to_string())])
+    };
+
+    let mut lines = vec![
+        field(ActivityField::Date, "Date", &draft.date),
+        field(ActivityField::Text, "What happened", &draft.text),
+    ];
+    if let Some(error) = &draft.error {
+        lines.push(Line::styled(error.as_str(), theme.error_style()));
+    }
+
+    let title = if draft.editing.is_some() { "Edit Entry" } else { "New Entry" };
+    let draft = Paragraph::new(lines).block(Block::default().borders(Borders::TOP).title(title));
+    frame.render_widget(draft, area);
+}
+
+/// Centered area tall enough for `rows` list entries plus borders
+fn list_popup_area(screen: Rect, percent_x: u16, rows: usize) -> Rect {
+    let height = (rows as u16).saturating_add(2);
+    let horizontal = centered_rect(percent_x, 100, screen);
+    Rect {
+        y: screen.y + screen.height.saturating_sub(height) / 2,
+        height: height.min(screen.height),
+        ..horizontal
+    }
+}
@@ -0,0 +1,201 @@
+use crate::app::View;
+use crate::config;
+use crate::keymap::Action;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Totals accumulated across sessions in the usage stats file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageStats {
+    pub sessions: u64,
+    /// Seconds spent in each view, by view name
+    pub view_seconds: BTreeMap<String, u64>,
+    /// Number of times each action was used, by action name
+    pub actions: BTreeMap<String, u64>,
+}
+
+impl UsageStats {
+    /// Load the stats file, starting from zero when it doesn't exist
+    pub fn load() -> Result<Self> {
+        match usage_path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read usage stats {}", path.display()))?;
+
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse usage stats {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize usage stats")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write usage stats {}", path.display()))
+    }
+
+    /// Most-used action and its count
+    pub fn top_action(&self) -> Option<(Action, u64)> {
+        self.actions
+            .iter()
+            .filter_map(|(name, count)| Action::from_name(name).map(|action| (action, *count)))
+            .max_by_key(|(_, count)| *count)
+    }
+}
+
+/// Opt-in recorder for the current session; does nothing unless enabled
+#[derive(Debug)]
+pub struct Usage {
+    enabled: bool,
+    /// Stats file; None when there is no data directory
+    path: Option<PathBuf>,
+    stats: UsageStats,
+    view: View,
+    since: Instant,
+}
+
+impl Usage {
+    /// Start a session, loading previous totals when metrics are enabled
+    pub fn start(enabled: bool) -> Result<Self> {
+        Self::start_at(enabled, usage_path())
+    }
+
+    /// Start a session recording to the stats file at `path`
+    fn start_at(enabled: bool, path: Option<PathBuf>) -> Result<Self> {
+        let mut stats = UsageStats::default();
+        if enabled {
+            if let Some(path) = &path {
+                stats = UsageStats::load_from(path)?;
+            }
+            stats.sessions += 1;
+        }
+        Ok(Self {
+            enabled,
+            path,
+            stats,
+            view: View::List,
+            since: Instant::now(),
+        })
+    }
+
+    /// Count an action dispatched from a key press
+    pub fn record_action(&mut self, action: Action) {
+        if self.enabled {
+            *self.stats.actions.entry(action.name().to_string()).or_default() += 1;
+        }
+    }
+
+    /// Note the current view, adding the time spent in the previous one when it changed
+    pub fn track_view(&mut self, view: View) {
+        if self.enabled && view != self.view {
+            self.add_view_time();
+            self.view = view;
+        }
+    }
+
+    /// Add the time in the current view and write the stats file
+    pub fn finish(&mut self) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.add_view_time();
+        match &self.path {
+            Some(path) => self.stats.save(path),
+            None => Ok(()),
+        }
+    }
+
+    fn add_view_time(&mut self) {
+        let seconds = self.since.elapsed().as_secs_f64().round() as u64;
+        *self.stats.view_seconds.entry(self.view.name().to_string()).or_default() += seconds;
+        self.since = Instant::now();
+    }
+}
+
+/// Location of the usage stats file in the data directory
+pub fn usage_path() -> Option<PathBuf> {
+    config::data_dir().map(|dir| dir.join("usage.json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats_file() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("usage.json");
+        (dir, path)
+    }
+
+    #[test]
+    fn counts_actions_and_sessions_across_runs() {
+        let (_dir, path) = stats_file();
+        let mut usage = Usage::start_at(true, Some(path.clone())).unwrap();
+        usage.record_action(Action::Add);
+        usage.record_action(Action::Add);
+        usage.record_action(Action::Chart);
+        usage.track_view(View::Chart);
+        usage.finish().unwrap();
+
+        let mut usage = Usage::start_at(true, Some(path.clone())).unwrap();
+        usage.record_action(Action::Chart);
+        usage.finish().unwrap();
+
+        let stats = UsageStats::load_from(&path).unwrap();
+        assert_eq!(stats.sessions, 2);
+        assert_eq!(stats.actions["add"], 2);
+        assert_eq!(stats.actions["chart"], 2);
+        let views: Vec<&str> = stats.view_seconds.keys().map(String::as_str).collect();
+        assert_eq!(views, ["chart", "list"]);
+    }
+
+    #[test]
+    fn disabled_tracking_writes_nothing() {
+        let (dir, path) = stats_file();
+        let mut usage = Usage::start_at(false, Some(path.clone())).unwrap();
+        usage.record_action(Action::Add);
+        usage.track_view(View::Chart);
+        usage.finish().unwrap();
+        assert!(!path.exists());
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn disabled_tracking_leaves_existing_stats_alone() {
+        let (_dir, path) = stats_file();
+        fs::write(&path, r#"{"sessions": 4, "actions": {"add": 9}}"#).unwrap();
+        let mut usage = Usage::start_at(false, Some(path.clone())).unwrap();
+        usage.record_action(Action::Add);
+        usage.finish().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"sessions": 4, "actions": {"add": 9}}"#);
+    }
+
+    #[test]
+    fn top_action_is_the_most_used_known_action() {
+        let stats = UsageStats {
+            actions: [("add", 3), ("chart", 7), ("retired_action", 50)]
+                .into_iter()
+                .map(|(name, count)| (name.to_string(), count))
+                .collect(),
+            ..UsageStats::default()
+        };
+        assert_eq!(stats.top_action(), Some((Action::Chart, 7)));
+    }
+}